@@ -0,0 +1,198 @@
+use super::parse_component;
+use super::parse_host;
+use super::percent;
+use super::percent::EncodeSet;
+use super::Authority;
+use super::Char;
+use super::Fragment;
+use super::Host;
+use super::Path;
+use super::Port;
+use super::QueryBuilder;
+use super::Scheme;
+use super::Segment;
+use super::Uri;
+use super::UriError;
+use super::Userinfo;
+use common_failures::prelude::*;
+
+/// Assembles a `Uri` from unencoded components. Every component is percent-encoded as needed to
+/// fit its rule of RFC 3986, so "a b" becomes the path segment "a%20b" and "a/b" becomes "a%2Fb".
+#[derive(Clone, Debug, Default)]
+pub struct UriBuilder {
+    scheme: Option<String>,
+    user: Option<Vec<Char>>,
+    password: Option<Vec<Char>>,
+    host: Option<String>,
+    port: Option<u16>,
+    segments: Vec<Segment>,
+    params_without_segment: bool,
+    query: Option<QueryBuilder>,
+    fragment: Option<Vec<Char>>,
+}
+
+impl UriBuilder {
+    pub fn new() -> UriBuilder {
+        UriBuilder::default()
+    }
+
+    pub fn scheme(mut self, scheme: &str) -> UriBuilder {
+        self.scheme = Some(scheme.to_string());
+        self
+    }
+
+    pub fn user(mut self, user: &str) -> UriBuilder {
+        self.user = Some(percent::encode(user.as_bytes(), USER));
+        self
+    }
+
+    pub fn password(mut self, password: &str) -> UriBuilder {
+        self.password = Some(percent::encode(password.as_bytes(), percent::USERINFO));
+        self
+    }
+
+    /// Sets a reg-name, an IPv4 address or, in square brackets, an IP-literal.
+    pub fn host(mut self, host: &str) -> UriBuilder {
+        self.host = Some(match host.starts_with('[') {
+            true => host.to_string(),
+            false => to_string(&percent::encode(host.as_bytes(), percent::REG_NAME)),
+        });
+        self
+    }
+
+    pub fn port(mut self, port: u16) -> UriBuilder {
+        self.port = Some(port);
+        self
+    }
+
+    /// Appends a segment to the path, which is absolute as soon as there is a host.
+    pub fn path_segment(mut self, segment: &str) -> UriBuilder {
+        self.segments.push(Segment(percent::encode(
+            segment.as_bytes(),
+            percent::PATH_SEGMENT,
+        )));
+        self
+    }
+
+    /// Appends the matrix parameter ";name=value" to the last path segment. Without a segment
+    /// `build` fails.
+    pub fn matrix_param(mut self, name: &str, value: &str) -> UriBuilder {
+        match self.segments.last_mut() {
+            Some(segment) => segment.push_param(name, value),
+            None => self.params_without_segment = true,
+        }
+        self
+    }
+
+    /// Removes the matrix parameters named `name` from the last path segment.
+    pub fn remove_matrix_param(mut self, name: &str) -> UriBuilder {
+        if let Some(segment) = self.segments.last_mut() {
+            segment.remove_param(name);
+        }
+        self
+    }
+
+    /// Appends "name=value" to the query, with pairs separated by '&'.
+    pub fn query_pair(mut self, name: &str, value: &str) -> UriBuilder {
+        let mut query = self.query.take().unwrap_or_default();
+        query.append(name, value);
+        self.query = Some(query);
+        self
+    }
+
+    pub fn fragment(mut self, fragment: &str) -> UriBuilder {
+        self.fragment = Some(percent::encode(fragment.as_bytes(), percent::FRAGMENT));
+        self
+    }
+
+    pub fn build(&self) -> Result<Uri> {
+        let scheme = match &self.scheme {
+            Some(scheme) => match Scheme::parse(scheme) {
+                Ok(scheme) => scheme,
+                Err(_) => return Err(invalid("scheme", format!("'{}' is malformed", scheme))),
+            },
+            None => return Err(invalid("scheme", "missing".to_string())),
+        };
+
+        let authority = match &self.host {
+            Some(host) => Some(Authority {
+                userinfo: self.userinfo(),
+                host: build_host(host)?,
+                port: self.port.map(Port::new),
+            }),
+            None if self.user.is_some() || self.password.is_some() || self.port.is_some() => {
+                return Err(invalid(
+                    "host",
+                    "missing but required by the userinfo or port".to_string(),
+                ))
+            }
+            None => None,
+        };
+
+        if self.params_without_segment {
+            return Err(invalid(
+                "path",
+                "matrix parameters need a segment to go with".to_string(),
+            ));
+        }
+        let path = match self.segments.is_empty() {
+            true => Path::empty(),
+            false => {
+                // without an authority "//" would start one, and "/" alone is not a segment-nz
+                if authority.is_none() && self.segments[0].0.is_empty() {
+                    return Err(invalid(
+                        "path",
+                        "the first segment must not be empty without a host".to_string(),
+                    ));
+                }
+                Path {
+                    segments: self.segments.clone(),
+                    absolute: authority.is_some(),
+                }
+            }
+        };
+
+        Ok(Uri {
+            scheme,
+            authority,
+            path,
+            query: self.query.as_ref().map(QueryBuilder::build),
+            fragment: self.fragment.clone().map(Fragment),
+        })
+    }
+
+    //    userinfo      = *( unreserved / pct-encoded / sub-delims / ":" )
+    fn userinfo(&self) -> Option<Userinfo> {
+        if self.user.is_none() && self.password.is_none() {
+            return None;
+        }
+        let mut chars = self.user.clone().unwrap_or_default();
+        if let Some(password) = &self.password {
+            chars.push(Char::Ascii(b':'));
+            chars.extend(password);
+        }
+        Some(Userinfo(chars))
+    }
+}
+
+// The password may contain ':', the user may not
+const USER: EncodeSet = EncodeSet::new(is_user_char);
+
+fn invalid(component: &str, reason: String) -> Error {
+    UriError::InvalidComponent(component.to_string(), reason).into()
+}
+
+fn build_host(host: &str) -> Result<Host> {
+    match parse_component(host, parse_host) {
+        Ok(parsed) => Ok(parsed),
+        Err(_) => Err(invalid("host", format!("'{}' is malformed", host))),
+    }
+}
+
+fn to_string(chars: &[Char]) -> String {
+    chars.iter().map(|c| c.to_string()).collect()
+}
+
+fn is_user_char(c: Char) -> bool {
+    c.is_unreserved() || c.is_sub_delim()
+}
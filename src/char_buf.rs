@@ -12,14 +12,56 @@ pub fn new_char_buf<R: Read>(read: R) -> TokenBuffer<Char, CharStream<ByteStream
     TokenBuffer::new(CharStream::from(read))
 }
 
+pub fn new_iri_char_buf<R: Read>(read: R) -> TokenBuffer<Char, CharStream<ByteStream<R>>> {
+    TokenBuffer::new(CharStream::iri(read))
+}
+
 pub struct CharStream<T: TokenStream<u8>> {
     byte_stream: T,
+    iri: bool,
+}
+
+impl<R: Read> CharStream<ByteStream<R>> {
+    /// A stream that decodes UTF-8 into `Char::Unicode` instead of passing every byte on as
+    /// `Char::Ascii`.
+    pub fn iri(read: R) -> CharStream<ByteStream<R>> {
+        let byte_stream = ByteStream::from(read);
+        CharStream {
+            byte_stream,
+            iri: true,
+        }
+    }
 }
 
 impl<R: Read> From<R> for CharStream<ByteStream<R>> {
     fn from(read: R) -> CharStream<ByteStream<R>> {
         let byte_stream = ByteStream::from(read);
-        CharStream { byte_stream }
+        CharStream {
+            byte_stream,
+            iri: false,
+        }
+    }
+}
+
+impl<T: TokenStream<u8>> CharStream<T> {
+    fn next_unicode(&mut self, b: u8) -> Result<Char> {
+        let len = match b {
+            0xC0..=0xDF => 2,
+            0xE0..=0xEF => 3,
+            0xF0..=0xF7 => 4,
+            _ => return Err(format_err!("Invalid UTF-8 sequence.")),
+        };
+        let mut bytes = vec![b];
+        for _ in 1..len {
+            match self.byte_stream.next()? {
+                Some(b) => bytes.push(b),
+                None => return Err(format_err!("Unexpected end of UTF-8 sequence.")),
+            }
+        }
+        match std::str::from_utf8(&bytes) {
+            Ok(s) => Ok(Char::Unicode(s.chars().next().unwrap())),
+            Err(_) => Err(format_err!("Invalid UTF-8 sequence.")),
+        }
     }
 }
 
@@ -46,6 +88,8 @@ impl<T: TokenStream<u8>> TokenStream<Char> for CharStream<T> {
                 None => return Err(format_err!("Unexpected end of escape sequence.")),
             };
             c = Char::PctEncoded(b2, b3);
+        } else if self.iri && b >= 0x80 {
+            c = self.next_unicode(b)?;
         } else {
             c = Char::Ascii(b);
         }
@@ -57,9 +101,15 @@ impl<T: TokenStream<u8>> TokenStream<Char> for CharStream<T> {
 pub enum Char {
     Ascii(u8),
     PctEncoded(u8, u8),
+    Unicode(char),
 }
 
 impl Char {
+    pub fn pct_encoded(byte: u8) -> Char {
+        const HEX: &[u8; 16] = b"0123456789ABCDEF";
+        Char::PctEncoded(HEX[(byte >> 4) as usize], HEX[(byte & 0xF) as usize])
+    }
+
     pub fn is(&self, byte: u8) -> bool {
         match self {
             Char::Ascii(b) => *b == byte,
@@ -76,17 +126,35 @@ impl Char {
     }
 
     pub fn is_pct_encoded(&self) -> bool {
-        match self {
-            Char::Ascii(_) => false,
-            Char::PctEncoded(_, _) => true,
-        }
+        matches!(self, Char::PctEncoded(_, _))
     }
 
+    /// Also matches the `ucschar` of RFC 3987, which makes the parsers accept IRIs whenever the
+    /// input comes from an IRI `CharStream`.
     pub fn is_unreserved(&self) -> bool {
         match self {
             Char::Ascii(byte) => {
-                is_alpha(*byte) || is_digit(*byte) || match byte {
-                    b'-' | b'.' | b'_' | b'~' => true,
+                is_alpha(*byte) || is_digit(*byte) || matches!(byte, b'-' | b'.' | b'_' | b'~')
+            }
+            Char::Unicode(_) => self.is_ucschar(),
+            _ => false,
+        }
+    }
+
+    //    ucschar        = %xA0-D7FF / %xF900-FDCF / %xFDF0-FFEF
+    //                   / %x10000-1FFFD / %x20000-2FFFD / %x30000-3FFFD
+    //                   / %x40000-4FFFD / %x50000-5FFFD / %x60000-6FFFD
+    //                   / %x70000-7FFFD / %x80000-8FFFD / %x90000-9FFFD
+    //                   / %xA0000-AFFFD / %xB0000-BFFFD / %xC0000-CFFFD
+    //                   / %xD0000-DFFFD / %xE1000-EFFFD
+    pub fn is_ucschar(&self) -> bool {
+        match self {
+            Char::Unicode(c) => {
+                let c = *c as u32;
+                match c {
+                    0xA0..=0xD7FF | 0xF900..=0xFDCF | 0xFDF0..=0xFFEF => true,
+                    0xE1000..=0xEFFFD => true,
+                    0x10000..=0xDFFFD => c & 0xFFFF <= 0xFFFD,
                     _ => false,
                 }
             }
@@ -94,26 +162,40 @@ impl Char {
         }
     }
 
+    //    iprivate       = %xE000-F8FF / %xF0000-FFFFD / %x100000-10FFFD
+    pub fn is_iprivate(&self) -> bool {
+        match self {
+            Char::Unicode(c) => matches!(
+                *c as u32,
+                0xE000..=0xF8FF | 0xF0000..=0xFFFFD | 0x10_0000..=0x10_FFFD
+            ),
+            _ => false,
+        }
+    }
+
     pub fn is_reserved(&self) -> bool {
         self.is_gen_delim() || self.is_sub_delim()
     }
 
     pub fn is_gen_delim(&self) -> bool {
-        match self {
-            Char::Ascii(byte) => match byte {
-                b':' | b'|' | b'?' | b'#' | b'[' | b']' | b'@' => true,
-                _ => false,
-            },
-            _ => false,
-        }
+        matches!(
+            self,
+            Char::Ascii(b':' | b'/' | b'?' | b'#' | b'[' | b']' | b'@')
+        )
     }
 
     pub fn is_sub_delim(&self) -> bool {
+        matches!(
+            self,
+            Char::Ascii(
+                b'!' | b'$' | b'&' | b'\'' | b'(' | b')' | b'*' | b'+' | b',' | b';' | b'='
+            )
+        )
+    }
+
+    pub fn is_alpha(&self) -> bool {
         match self {
-            Char::Ascii(byte) => match byte {
-                b'!' | b'$' | b'&' | b'\'' | b'(' | b')' | b'*' | b'+' | b',' | b';' | b'=' => true,
-                _ => false,
-            },
+            Char::Ascii(byte) => is_alpha(*byte),
             _ => false,
         }
     }
@@ -131,6 +213,14 @@ impl Char {
             _ => false,
         }
     }
+
+    /// The byte a percent-encoded character stands for.
+    pub fn decode(&self) -> Option<u8> {
+        match self {
+            Char::PctEncoded(byte1, byte2) => Some((hex_value(*byte1) << 4) | hex_value(*byte2)),
+            _ => None,
+        }
+    }
 }
 
 impl Display for Char {
@@ -142,72 +232,39 @@ impl Display for Char {
                 fmt.write_char(*byte1 as char)?;
                 fmt.write_char(*byte2 as char)?;
             }
+            Char::Unicode(c) => fmt.write_char(*c)?,
         };
         Ok(())
     }
 }
 
-fn is_reserved(b: u8) -> bool {
-    match b {
-        b';' => true,
-        b'/' => true,
-        b'?' => true,
-        b':' => true,
-        b'@' => true,
-        b'&' => true,
-        b'=' => true,
-        b'+' => true,
-        b'$' => true,
-        b',' => true,
-        _ => false,
-    }
-}
-
-fn is_unreserved(b: u8) -> bool {
-    is_alphanum(b) || is_mark(b)
+fn is_hex(b: u8) -> bool {
+    is_digit(b) || (65..=70).contains(&b) || (97..=102).contains(&b)
 }
 
-fn is_mark(b: u8) -> bool {
+fn hex_value(b: u8) -> u8 {
     match b {
-        b'-' => true,
-        b'_' => true,
-        b'.' => true,
-        b'!' => true,
-        b'~' => true,
-        b'*' => true,
-        b'\'' => true,
-        b'(' => true,
-        b')' => true,
-        _ => false,
+        b'0'..=b'9' => b - b'0',
+        b'a'..=b'f' => b - b'a' + 10,
+        b'A'..=b'F' => b - b'A' + 10,
+        _ => 0,
     }
 }
 
-fn is_escaped(bytes: (u8, u8, u8)) -> bool {
-    bytes.0 == b'%' && is_hex(bytes.1) && is_hex(bytes.2)
-}
-
-fn is_hex(b: u8) -> bool {
-    (b >= 65 && b <= 70) || (b >= 97 && b <= 102)
-}
-
-fn is_alphanum(b: u8) -> bool {
-    is_alpha(b) || is_digit(b)
-}
-
 fn is_alpha(b: u8) -> bool {
     is_low_alpha(b) || is_up_alpha(b)
 }
 
 fn is_low_alpha(b: u8) -> bool {
-    b >= 97 && b <= 122
+    (97..=122).contains(&b)
 }
 
 fn is_up_alpha(b: u8) -> bool {
-    b >= 65 && b <= 90
+    (65..=90).contains(&b)
 }
 
 fn is_digit(b: u8) -> bool {
-    b >= 48 && b <= 57
+    (48..=57).contains(&b)
 }
 
 #[cfg(test)]
@@ -231,4 +288,31 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_unicode() -> Result<()> {
+        let mut cs: CharStream<_> = "ü".as_bytes().into();
+        assert_eq!(Char::Ascii(0xC3), cs.next()?.unwrap());
+        assert_eq!(Char::Ascii(0xBC), cs.next()?.unwrap());
+        assert_eq!(None, cs.next()?);
+
+        let mut cs = CharStream::iri("aü€𐍈%C3".as_bytes());
+        assert_eq!(Char::Ascii(b'a'), cs.next()?.unwrap());
+        assert_eq!(Char::Unicode('ü'), cs.next()?.unwrap());
+        assert_eq!(Char::Unicode('€'), cs.next()?.unwrap());
+        assert_eq!(Char::Unicode('𐍈'), cs.next()?.unwrap());
+        assert_eq!(Char::PctEncoded(b'C', b'3'), cs.next()?.unwrap());
+        assert_eq!(None, cs.next()?);
+
+        let mut cs = CharStream::iri(&[0xC3u8][..]);
+        assert!(cs.next().is_err());
+
+        let mut cs = CharStream::iri(&[0xBCu8, b'a'][..]);
+        assert!(cs.next().is_err());
+
+        let mut cs = CharStream::iri(&[0xED, 0xA0, 0x80][..]);
+        assert!(cs.next().is_err());
+
+        Ok(())
+    }
 }
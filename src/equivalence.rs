@@ -0,0 +1,94 @@
+use super::Path;
+use super::SchemeDefaults;
+use super::Uri;
+
+/// The rungs of the comparison ladder of RFC 3986 section 6.2.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Level {
+    /// Character-by-character comparison of the URIs (section 6.2.1).
+    Simple,
+    /// Comparison after `Uri::normalize` (section 6.2.2).
+    Syntax,
+    /// Comparison after `Uri::normalize_for_scheme` with the default scheme table (section
+    /// 6.2.3).
+    Scheme,
+    /// Scheme-based comparison that also ignores the fragment, which is never sent to the
+    /// server, and a trailing slash after a non-empty path (section 6.2.4).
+    Protocol,
+}
+
+impl Uri {
+    pub fn equivalent(&self, other: &Uri, level: Level) -> bool {
+        self.equivalence_key(level) == other.equivalence_key(level)
+    }
+
+    fn equivalence_key(&self, level: Level) -> String {
+        match level {
+            Level::Simple => self.to_string(),
+            Level::Syntax => self.normalize().to_string(),
+            Level::Scheme => self
+                .normalize_for_scheme(&SchemeDefaults::default())
+                .to_string(),
+            Level::Protocol => {
+                let mut uri = self.normalize_for_scheme(&SchemeDefaults::default());
+                uri.fragment = None;
+                let segments = uri.path.segments.len();
+                if segments > 1 && uri.path.segments[segments - 1].0.is_empty() {
+                    uri.path = Path {
+                        segments: uri.path.segments[..segments - 1].to_vec(),
+                        absolute: uri.path.absolute,
+                    };
+                }
+                uri.to_string()
+            }
+        }
+    }
+}
+
+/// A `Uri` that is equal to, and hashes like, every URI equivalent to it at the chosen `Level`.
+#[derive(Clone, Debug)]
+pub struct NormalizedUri {
+    uri: Uri,
+    level: Level,
+    key: String,
+}
+
+impl NormalizedUri {
+    pub fn new(uri: Uri, level: Level) -> NormalizedUri {
+        let key = uri.equivalence_key(level);
+        NormalizedUri { uri, level, key }
+    }
+
+    pub fn uri(&self) -> &Uri {
+        &self.uri
+    }
+
+    pub fn level(&self) -> Level {
+        self.level
+    }
+
+    pub fn into_uri(self) -> Uri {
+        self.uri
+    }
+}
+
+impl PartialEq for NormalizedUri {
+    fn eq(&self, other: &NormalizedUri) -> bool {
+        self.level == other.level && self.key == other.key
+    }
+}
+
+impl Eq for NormalizedUri {}
+
+impl std::hash::Hash for NormalizedUri {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.level.hash(state);
+        self.key.hash(state);
+    }
+}
+
+impl std::fmt::Display for NormalizedUri {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(fmt, "{}", &self.uri)
+    }
+}
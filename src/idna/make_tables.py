@@ -0,0 +1,173 @@
+#!/usr/bin/env python3
+#
+# Generates tables.rs, the Unicode data behind the IDNA processing in this module:
+#
+#   python3 make_tables.py IdnaMappingTable.txt DerivedJoiningType.txt > tables.rs
+#
+# IdnaMappingTable.txt comes from https://www.unicode.org/Public/idna/ and DerivedJoiningType.txt
+# from https://www.unicode.org/Public/UCD/<version>/ucd/extracted/. Normalization data, bidi
+# classes and combining marks are taken from the unicodedata module of the running Python, whose
+# Unicode version (unicodedata.unidata_version) must be the one of IdnaMappingTable.txt. Joining
+# types of code points that version does not assign are left out.
+
+import sys
+import unicodedata
+
+STATUS = {
+    'valid': 'Valid',
+    'ignored': 'Ignored',
+    'mapped': 'Mapped',
+    'deviation': 'Deviation',
+    'disallowed': 'Disallowed',
+    'disallowed_STD3_valid': 'DisallowedStd3Valid',
+    'disallowed_STD3_mapped': 'DisallowedStd3Mapped',
+}
+
+BIDI_CLASSES = ['R', 'AL', 'EN', 'ES', 'ET', 'AN', 'CS', 'NSM', 'BN', 'ON']
+
+S_BASE, S_COUNT = 0xAC00, 11172
+
+
+def parse_ranges(path):
+    for line in open(path, encoding='utf-8'):
+        line = line.split('#')[0].strip()
+        if not line:
+            continue
+        fields = [field.strip() for field in line.split(';')]
+        first, _, last = fields[0].partition('..')
+        yield int(first, 16), int(last or first, 16), fields[1:]
+
+
+def rust_str(s):
+    out = []
+    for c in s:
+        if c in '"\\' or not c.isprintable() or unicodedata.category(c).startswith('M'):
+            out.append('\\u{%X}' % ord(c))
+        else:
+            out.append(c)
+    return '"' + ''.join(out) + '"'
+
+
+def ranges(values):
+    # (first, last, value) runs over all code points with a value other than None
+    runs = []
+    for cp in range(0x110000):
+        value = values(cp)
+        if value is None:
+            continue
+        if runs and runs[-1][1] == cp - 1 and runs[-1][2] == value:
+            runs[-1][1] = cp
+        else:
+            runs.append([cp, cp, value])
+    return runs
+
+
+def emit(name, rust_type, entries, per_line):
+    print('pub const %s: &[%s] = &[' % (name, rust_type))
+    for i in range(0, len(entries), per_line):
+        print('    ' + ' '.join(entry + ',' for entry in entries[i:i + per_line]))
+    print('];')
+    print()
+
+
+def mapping_table_version(path):
+    for line in open(path, encoding='utf-8'):
+        if line.startswith('# Version:'):
+            return line.split(':')[1].strip()
+    return None
+
+
+def main(mapping_table, joining_types):
+    version = mapping_table_version(mapping_table)
+    if version != unicodedata.unidata_version:
+        sys.exit('IdnaMappingTable.txt is version %s, but unicodedata is version %s'
+                 % (version, unicodedata.unidata_version))
+
+    print('// Generated by make_tables.py from the Unicode %s data: IdnaMappingTable.txt,' % version)
+    print('// DerivedJoiningType.txt and the Python unicodedata module, do not edit.')
+    print()
+    print('use super::BidiClass;')
+    print('use super::JoiningType;')
+    print('use super::Status;')
+    print()
+
+    # UTS #46 mapping: (first code point, status, mapping offset, mapping length), each entry
+    # spanning up to the first code point of the next one
+    mapping_data = ''
+    offsets = {}
+    entries = []
+    previous = None
+    for first, last, fields in parse_ranges(mapping_table):
+        status = STATUS[fields[0]]
+        mapping = ''
+        if status in ('Mapped', 'Deviation', 'DisallowedStd3Mapped') and len(fields) > 1:
+            mapping = ''.join(chr(int(cp, 16)) for cp in fields[1].split())
+        if mapping not in offsets:
+            offsets[mapping] = len(mapping_data.encode('utf-8'))
+            mapping_data += mapping
+        entry = (status, offsets[mapping], len(mapping.encode('utf-8')))
+        if entry != previous:
+            entries.append('(0x%X, Status::%s, %d, %d)' % ((first,) + entry))
+            previous = entry
+    emit('MAPPING_TABLE', '(u32, Status, u16, u8)', entries, 3)
+    print('pub const MAPPING_DATA: &str = %s;' % rust_str(mapping_data))
+    print()
+
+    # Canonical combining classes other than 0
+    runs = ranges(lambda cp: unicodedata.combining(chr(cp)) or None)
+    emit('COMBINING_CLASS', '(u32, u32, u8)',
+         ['(0x%X, 0x%X, %d)' % tuple(run) for run in runs], 4)
+
+    # Full canonical decompositions, Hangul syllables excluded
+    decomposition_data = ''
+    entries = []
+    compositions = []
+    for cp in range(0x110000):
+        c = chr(cp)
+        if S_BASE <= cp < S_BASE + S_COUNT:
+            continue
+        nfd = unicodedata.normalize('NFD', c)
+        if nfd == c:
+            continue
+        entries.append('(0x%X, %d, %d)' % (cp, len(decomposition_data.encode('utf-8')),
+                                           len(nfd.encode('utf-8'))))
+        decomposition_data += nfd
+        decomposition = unicodedata.decomposition(c).split()
+        if len(decomposition) == 2 and unicodedata.normalize('NFC', c) == c:
+            compositions.append((int(decomposition[0], 16), int(decomposition[1], 16), cp))
+    emit('DECOMPOSITION', '(u32, u16, u8)', entries, 4)
+    print('pub const DECOMPOSITION_DATA: &str = %s;' % rust_str(decomposition_data))
+    print()
+
+    # Primary composites, sorted by their two canonical components
+    emit('COMPOSITION', '(u32, u32, u32)',
+         ['(0x%X, 0x%X, 0x%X)' % composition for composition in sorted(compositions)], 4)
+
+    # Bidi classes of RFC 5893, L for everything not listed
+    def bidi_class(cp):
+        bidi = unicodedata.bidirectional(chr(cp))
+        if bidi == 'L' or bidi == '':
+            return None
+        return bidi.capitalize() if bidi in BIDI_CLASSES else 'Other'
+
+    runs = ranges(bidi_class)
+    emit('BIDI_CLASS', '(u32, u32, BidiClass)',
+         ['(0x%X, 0x%X, BidiClass::%s)' % tuple(run) for run in runs], 3)
+
+    # General category Mark
+    runs = ranges(lambda cp: unicodedata.category(chr(cp)).startswith('M') or None)
+    emit('COMBINING_MARK', '(u32, u32)', ['(0x%X, 0x%X)' % tuple(run[:2]) for run in runs], 5)
+
+    # Joining types other than U (Non_Joining)
+    joining = {}
+    for first, last, fields in parse_ranges(joining_types):
+        for cp in range(first, last + 1):
+            if fields[0] != 'U' and unicodedata.category(chr(cp)) != 'Cn':
+                joining[cp] = fields[0]
+    runs = ranges(joining.get)
+    emit('JOINING_TYPE', '(u32, u32, JoiningType)',
+         ['(0x%X, 0x%X, JoiningType::%s)' % tuple(run) for run in runs], 3)
+
+
+if __name__ == '__main__':
+    main(sys.argv[1], sys.argv[2])
@@ -0,0 +1,488 @@
+// Internationalized domain names as specified by UTS #46, Unicode IDNA Compatibility Processing,
+// version 13.0.0, which implements IDNA2008 (RFC 5890 - 5893) on top of a mapping step.
+//
+// The Unicode 13.0.0 data needed for the mapping, NFC normalization and the bidi and joiner rules
+// is bundled in tables.rs, see make_tables.py.
+
+mod punycode;
+#[rustfmt::skip]
+mod tables;
+
+use common_failures::prelude::*;
+
+const ACE_PREFIX: &str = "xn--";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Status {
+    Valid,
+    Ignored,
+    Mapped,
+    Deviation,
+    Disallowed,
+    DisallowedStd3Valid,
+    DisallowedStd3Mapped,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum BidiClass {
+    L,
+    R,
+    Al,
+    En,
+    Es,
+    Et,
+    An,
+    Cs,
+    Nsm,
+    Bn,
+    On,
+    Other,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum JoiningType {
+    C,
+    D,
+    L,
+    R,
+    T,
+}
+
+#[derive(Clone, Debug)]
+pub struct IdnaOptions {
+    /// Disallows the ASCII characters that are not letters, digits or '-' (STD3 rules).
+    pub use_std3_ascii_rules: bool,
+    /// Rejects labels with "--" in the third and fourth position or a leading or trailing '-'.
+    pub check_hyphens: bool,
+    /// Applies the bidi rule of RFC 5893 to domain names with right-to-left labels.
+    pub check_bidi: bool,
+    /// Applies the ContextJ rules of RFC 5892 to ZERO WIDTH (NON-)JOINER.
+    pub check_joiners: bool,
+    /// Maps deviation characters like 'ß' the IDNA2003 way instead of keeping them.
+    pub transitional_processing: bool,
+    /// Limits ASCII results to 63 octets per label and 253 octets in total.
+    pub verify_dns_length: bool,
+}
+
+impl Default for IdnaOptions {
+    fn default() -> IdnaOptions {
+        IdnaOptions {
+            use_std3_ascii_rules: true,
+            check_hyphens: true,
+            check_bidi: true,
+            check_joiners: true,
+            transitional_processing: false,
+            verify_dns_length: true,
+        }
+    }
+}
+
+/// The ToASCII operation of UTS #46, section 4.2.
+pub fn to_ascii(domain: &str, options: &IdnaOptions) -> Result<String> {
+    let labels = process(domain, options)?;
+    let mut ascii_labels: Vec<String> = Vec::new();
+    for label in &labels {
+        match label.is_ascii() {
+            true => ascii_labels.push(label.clone()),
+            false => match punycode::encode(label) {
+                Some(encoded) => ascii_labels.push(format!("{}{}", ACE_PREFIX, encoded)),
+                None => return Err(format_err!("Punycode overflow in label '{}'.", label)),
+            },
+        }
+    }
+
+    let ascii = ascii_labels.join(".");
+    if options.verify_dns_length {
+        let len = match ascii.ends_with('.') {
+            true => ascii.len() - 1,
+            false => ascii.len(),
+        };
+        if len == 0 || len > 253 {
+            return Err(format_err!(
+                "Domain name '{}' is empty or exceeds 253 octets.",
+                ascii
+            ));
+        }
+        let labels = match ascii.ends_with('.') {
+            true => &ascii_labels[..ascii_labels.len() - 1],
+            false => &ascii_labels[..],
+        };
+        for label in labels {
+            if label.is_empty() || label.len() > 63 {
+                return Err(format_err!(
+                    "Label '{}' is empty or exceeds 63 octets.",
+                    label
+                ));
+            }
+        }
+    }
+    Ok(ascii)
+}
+
+/// The ToUnicode operation of UTS #46, section 4.3.
+pub fn to_unicode(domain: &str, options: &IdnaOptions) -> Result<String> {
+    Ok(process(domain, options)?.join("."))
+}
+
+// Section 4, Processing: map, normalize, break into labels, convert and validate.
+fn process(domain: &str, options: &IdnaOptions) -> Result<Vec<String>> {
+    let mut mapped = String::new();
+    for c in domain.chars() {
+        let (status, mapping) = lookup_mapping(c);
+        match status {
+            Status::Valid => mapped.push(c),
+            Status::Ignored => {}
+            Status::Mapped => mapped.push_str(mapping),
+            Status::Deviation => match options.transitional_processing {
+                true => mapped.push_str(mapping),
+                false => mapped.push(c),
+            },
+            Status::Disallowed => return Err(disallowed(c)),
+            Status::DisallowedStd3Valid => match options.use_std3_ascii_rules {
+                true => return Err(disallowed(c)),
+                false => mapped.push(c),
+            },
+            Status::DisallowedStd3Mapped => match options.use_std3_ascii_rules {
+                true => return Err(disallowed(c)),
+                false => mapped.push_str(mapping),
+            },
+        }
+    }
+
+    let normalized = nfc(&mapped);
+    let mut labels: Vec<String> = Vec::new();
+    for label in normalized.split('.') {
+        if let Some(encoded) = label.strip_prefix(ACE_PREFIX) {
+            let decoded = match punycode::decode(encoded) {
+                Some(decoded) => decoded,
+                None => return Err(format_err!("Invalid Punycode label '{}'.", label)),
+            };
+            validate_label(&decoded, options, false)?;
+            labels.push(decoded);
+        } else {
+            validate_label(label, options, options.transitional_processing)?;
+            labels.push(label.to_string());
+        }
+    }
+
+    if options.check_bidi && labels.iter().any(|label| is_rtl_label(label)) {
+        for label in &labels {
+            check_bidi(label)?;
+        }
+    }
+    Ok(labels)
+}
+
+fn disallowed(c: char) -> Error {
+    format_err!("Disallowed character U+{:04X}.", c as u32)
+}
+
+// Section 4.1, Validity Criteria, except for the bidi rule that depends on the whole domain.
+fn validate_label(label: &str, options: &IdnaOptions, transitional: bool) -> Result<()> {
+    let invalid =
+        |reason: &str| -> Result<()> { Err(format_err!("Invalid label '{}': {}.", label, reason)) };
+
+    if nfc(label) != label {
+        return invalid("not in Normalization Form C");
+    }
+    if options.check_hyphens {
+        if label.chars().skip(2).take(2).eq("--".chars()) {
+            return invalid("'--' in the third and fourth position");
+        }
+        if label.starts_with('-') || label.ends_with('-') {
+            return invalid("starts or ends with '-'");
+        }
+    }
+    if label.contains('.') {
+        return invalid("contains '.'");
+    }
+    if let Some(c) = label.chars().next() {
+        if is_combining_mark(c) {
+            return invalid("starts with a combining mark");
+        }
+    }
+
+    let chars: Vec<char> = label.chars().collect();
+    for (i, &c) in chars.iter().enumerate() {
+        let valid = match lookup_mapping(c).0 {
+            Status::Valid => true,
+            Status::Deviation => !transitional,
+            Status::DisallowedStd3Valid => !options.use_std3_ascii_rules,
+            _ => false,
+        };
+        if !valid {
+            return Err(disallowed(c));
+        }
+        if options.check_joiners && !is_valid_joiner(&chars, i) {
+            return invalid("ZERO WIDTH (NON-)JOINER out of context");
+        }
+    }
+    Ok(())
+}
+
+// The ContextJ rules of RFC 5892, appendix A.1 and A.2, for the character at `i`.
+fn is_valid_joiner(chars: &[char], i: usize) -> bool {
+    let c = chars[i];
+    if c != '\u{200C}' && c != '\u{200D}' {
+        return true;
+    }
+    if i > 0 && combining_class(chars[i - 1]) == 9 {
+        return true;
+    }
+    if c == '\u{200D}' {
+        return false;
+    }
+
+    // (Joining_Type:{L,D})(Joining_Type:T)*‌(Joining_Type:T)*(Joining_Type:{R,D})
+    let before = chars[..i]
+        .iter()
+        .rev()
+        .map(|&c| joining_type(c))
+        .find(|&joining_type| joining_type != Some(JoiningType::T));
+    let after = chars[i + 1..]
+        .iter()
+        .map(|&c| joining_type(c))
+        .find(|&joining_type| joining_type != Some(JoiningType::T));
+    let before = matches!(
+        before,
+        Some(Some(JoiningType::L)) | Some(Some(JoiningType::D))
+    );
+    let after = matches!(
+        after,
+        Some(Some(JoiningType::R)) | Some(Some(JoiningType::D))
+    );
+    before && after
+}
+
+fn is_rtl_label(label: &str) -> bool {
+    label
+        .chars()
+        .any(|c| matches!(bidi_class(c), BidiClass::R | BidiClass::Al | BidiClass::An))
+}
+
+// The bidi rule of RFC 5893, section 2.
+fn check_bidi(label: &str) -> Result<()> {
+    let invalid = |rule: u8| -> Result<()> {
+        Err(format_err!(
+            "Label '{}' violates rule {} of the bidi rule.",
+            label,
+            rule
+        ))
+    };
+
+    let classes: Vec<BidiClass> = label.chars().map(bidi_class).collect();
+    let first = match classes.first() {
+        Some(&first) => first,
+        None => return Ok(()),
+    };
+    let last = classes
+        .iter()
+        .rev()
+        .find(|&&class| class != BidiClass::Nsm)
+        .cloned();
+    match first {
+        BidiClass::R | BidiClass::Al => {
+            for &class in &classes {
+                match class {
+                    BidiClass::R
+                    | BidiClass::Al
+                    | BidiClass::An
+                    | BidiClass::En
+                    | BidiClass::Es
+                    | BidiClass::Cs
+                    | BidiClass::Et
+                    | BidiClass::On
+                    | BidiClass::Bn
+                    | BidiClass::Nsm => {}
+                    _ => return invalid(2),
+                }
+            }
+            match last {
+                Some(BidiClass::R) | Some(BidiClass::Al) | Some(BidiClass::En)
+                | Some(BidiClass::An) => {}
+                _ => return invalid(3),
+            }
+            if classes.contains(&BidiClass::En) && classes.contains(&BidiClass::An) {
+                return invalid(4);
+            }
+        }
+        BidiClass::L => {
+            for &class in &classes {
+                match class {
+                    BidiClass::L
+                    | BidiClass::En
+                    | BidiClass::Es
+                    | BidiClass::Cs
+                    | BidiClass::Et
+                    | BidiClass::On
+                    | BidiClass::Bn
+                    | BidiClass::Nsm => {}
+                    _ => return invalid(5),
+                }
+            }
+            match last {
+                Some(BidiClass::L) | Some(BidiClass::En) => {}
+                _ => return invalid(6),
+            }
+        }
+        _ => return invalid(1),
+    }
+    Ok(())
+}
+
+fn lookup_mapping(c: char) -> (Status, &'static str) {
+    let cp = c as u32;
+    let i = match tables::MAPPING_TABLE.binary_search_by_key(&cp, |entry| entry.0) {
+        Ok(i) => i,
+        Err(i) => i - 1,
+    };
+    let (_, status, offset, len) = tables::MAPPING_TABLE[i];
+    let offset = offset as usize;
+    (status, &tables::MAPPING_DATA[offset..offset + len as usize])
+}
+
+fn lookup_range<V>(table: &[(u32, u32, V)], c: char) -> Option<V>
+where
+    V: Copy,
+{
+    let cp = c as u32;
+    match table.binary_search_by(|entry| {
+        if entry.1 < cp {
+            std::cmp::Ordering::Less
+        } else if entry.0 > cp {
+            std::cmp::Ordering::Greater
+        } else {
+            std::cmp::Ordering::Equal
+        }
+    }) {
+        Ok(i) => Some(table[i].2),
+        Err(_) => None,
+    }
+}
+
+fn combining_class(c: char) -> u8 {
+    lookup_range(tables::COMBINING_CLASS, c).unwrap_or(0)
+}
+
+fn bidi_class(c: char) -> BidiClass {
+    lookup_range(tables::BIDI_CLASS, c).unwrap_or(BidiClass::L)
+}
+
+fn joining_type(c: char) -> Option<JoiningType> {
+    lookup_range(tables::JOINING_TYPE, c)
+}
+
+fn is_combining_mark(c: char) -> bool {
+    let cp = c as u32;
+    tables::COMBINING_MARK
+        .binary_search_by(|&(first, last)| {
+            if last < cp {
+                std::cmp::Ordering::Less
+            } else if first > cp {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+// Hangul syllables are composed and decomposed algorithmically, see section 3.12 of the Unicode
+// standard.
+const S_BASE: u32 = 0xAC00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11A7;
+const L_COUNT: u32 = 19;
+const V_COUNT: u32 = 21;
+const T_COUNT: u32 = 28;
+const N_COUNT: u32 = V_COUNT * T_COUNT;
+const S_COUNT: u32 = L_COUNT * N_COUNT;
+
+fn decompose(c: char, decomposed: &mut Vec<char>) {
+    let cp = c as u32;
+    if (S_BASE..S_BASE + S_COUNT).contains(&cp) {
+        let s = cp - S_BASE;
+        let t = s % T_COUNT;
+        decomposed.push(std::char::from_u32(L_BASE + s / N_COUNT).unwrap());
+        decomposed.push(std::char::from_u32(V_BASE + (s % N_COUNT) / T_COUNT).unwrap());
+        if t != 0 {
+            decomposed.push(std::char::from_u32(T_BASE + t).unwrap());
+        }
+        return;
+    }
+    match tables::DECOMPOSITION.binary_search_by_key(&cp, |entry| entry.0) {
+        Ok(i) => {
+            let (_, offset, len) = tables::DECOMPOSITION[i];
+            let offset = offset as usize;
+            decomposed.extend(tables::DECOMPOSITION_DATA[offset..offset + len as usize].chars());
+        }
+        Err(_) => decomposed.push(c),
+    }
+}
+
+fn compose(first: char, second: char) -> Option<char> {
+    let (first, second) = (first as u32, second as u32);
+    if (L_BASE..L_BASE + L_COUNT).contains(&first) && (V_BASE..V_BASE + V_COUNT).contains(&second) {
+        let lv = S_BASE + ((first - L_BASE) * V_COUNT + (second - V_BASE)) * T_COUNT;
+        return std::char::from_u32(lv);
+    }
+    if (S_BASE..S_BASE + S_COUNT).contains(&first)
+        && (T_BASE + 1..T_BASE + T_COUNT).contains(&second)
+    {
+        let t = (first - S_BASE) % T_COUNT;
+        if t == 0 {
+            return std::char::from_u32(first + (second - T_BASE));
+        }
+    }
+    match tables::COMPOSITION.binary_search_by_key(&(first, second), |entry| (entry.0, entry.1)) {
+        Ok(i) => std::char::from_u32(tables::COMPOSITION[i].2),
+        Err(_) => None,
+    }
+}
+
+// Normalization Form C: canonical decomposition, canonical ordering, canonical composition.
+fn nfc(s: &str) -> String {
+    let mut chars: Vec<char> = Vec::new();
+    for c in s.chars() {
+        decompose(c, &mut chars);
+    }
+
+    let mut i = 0;
+    while i < chars.len() {
+        let start = i;
+        while i < chars.len() && combining_class(chars[i]) != 0 {
+            i += 1;
+        }
+        chars[start..i].sort_by_key(|&c| combining_class(c));
+        i += 1;
+    }
+
+    let mut composed: Vec<char> = Vec::new();
+    let mut starter: Option<usize> = None;
+    let mut last_class: Option<u8> = None;
+    for c in chars {
+        let class = combining_class(c);
+        if let Some(starter) = starter {
+            // c combines with the last starter unless a character in between blocks it
+            let blocked = match last_class {
+                Some(last_class) => last_class >= class,
+                None => false,
+            };
+            if !blocked {
+                if let Some(composite) = compose(composed[starter], c) {
+                    composed[starter] = composite;
+                    continue;
+                }
+            }
+        }
+        if class == 0 {
+            starter = Some(composed.len());
+            last_class = None;
+        } else {
+            last_class = Some(class);
+        }
+        composed.push(c);
+    }
+    composed.into_iter().collect()
+}
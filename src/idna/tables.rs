@@ -0,0 +1,3934 @@
+// Generated by make_tables.py from the Unicode 13.0.0 data: IdnaMappingTable.txt,
+// DerivedJoiningType.txt and the Python unicodedata module, do not edit.
+
+use super::BidiClass;
+use super::JoiningType;
+use super::Status;
+
+pub const MAPPING_TABLE: &[(u32, Status, u16, u8)] = &[
+    (0x0, Status::DisallowedStd3Valid, 0, 0), (0x2D, Status::Valid, 0, 0), (0x2F, Status::DisallowedStd3Valid, 0, 0),
+    (0x30, Status::Valid, 0, 0), (0x3A, Status::DisallowedStd3Valid, 0, 0), (0x41, Status::Mapped, 0, 1),
+    (0x42, Status::Mapped, 1, 1), (0x43, Status::Mapped, 2, 1), (0x44, Status::Mapped, 3, 1),
+    (0x45, Status::Mapped, 4, 1), (0x46, Status::Mapped, 5, 1), (0x47, Status::Mapped, 6, 1),
+    (0x48, Status::Mapped, 7, 1), (0x49, Status::Mapped, 8, 1), (0x4A, Status::Mapped, 9, 1),
+    (0x4B, Status::Mapped, 10, 1), (0x4C, Status::Mapped, 11, 1), (0x4D, Status::Mapped, 12, 1),
+    (0x4E, Status::Mapped, 13, 1), (0x4F, Status::Mapped, 14, 1), (0x50, Status::Mapped, 15, 1),
+    (0x51, Status::Mapped, 16, 1), (0x52, Status::Mapped, 17, 1), (0x53, Status::Mapped, 18, 1),
+    (0x54, Status::Mapped, 19, 1), (0x55, Status::Mapped, 20, 1), (0x56, Status::Mapped, 21, 1),
+    (0x57, Status::Mapped, 22, 1), (0x58, Status::Mapped, 23, 1), (0x59, Status::Mapped, 24, 1),
+    (0x5A, Status::Mapped, 25, 1), (0x5B, Status::DisallowedStd3Valid, 0, 0), (0x61, Status::Valid, 0, 0),
+    (0x7B, Status::DisallowedStd3Valid, 0, 0), (0x80, Status::Disallowed, 0, 0), (0xA0, Status::DisallowedStd3Mapped, 26, 1),
+    (0xA1, Status::Valid, 0, 0), (0xA8, Status::DisallowedStd3Mapped, 27, 3), (0xA9, Status::Valid, 0, 0),
+    (0xAA, Status::Mapped, 0, 1), (0xAB, Status::Valid, 0, 0), (0xAD, Status::Ignored, 0, 0),
+    (0xAE, Status::Valid, 0, 0), (0xAF, Status::DisallowedStd3Mapped, 30, 3), (0xB0, Status::Valid, 0, 0),
+    (0xB2, Status::Mapped, 33, 1), (0xB3, Status::Mapped, 34, 1), (0xB4, Status::DisallowedStd3Mapped, 35, 3),
+    (0xB5, Status::Mapped, 38, 2), (0xB6, Status::Valid, 0, 0), (0xB8, Status::DisallowedStd3Mapped, 40, 3),
+    (0xB9, Status::Mapped, 43, 1), (0xBA, Status::Mapped, 14, 1), (0xBB, Status::Valid, 0, 0),
+    (0xBC, Status::Mapped, 44, 5), (0xBD, Status::Mapped, 49, 5), (0xBE, Status::Mapped, 54, 5),
+    (0xBF, Status::Valid, 0, 0), (0xC0, Status::Mapped, 59, 2), (0xC1, Status::Mapped, 61, 2),
+    (0xC2, Status::Mapped, 63, 2), (0xC3, Status::Mapped, 65, 2), (0xC4, Status::Mapped, 67, 2),
+    (0xC5, Status::Mapped, 69, 2), (0xC6, Status::Mapped, 71, 2), (0xC7, Status::Mapped, 73, 2),
+    (0xC8, Status::Mapped, 75, 2), (0xC9, Status::Mapped, 77, 2), (0xCA, Status::Mapped, 79, 2),
+    (0xCB, Status::Mapped, 81, 2), (0xCC, Status::Mapped, 83, 2), (0xCD, Status::Mapped, 85, 2),
+    (0xCE, Status::Mapped, 87, 2), (0xCF, Status::Mapped, 89, 2), (0xD0, Status::Mapped, 91, 2),
+    (0xD1, Status::Mapped, 93, 2), (0xD2, Status::Mapped, 95, 2), (0xD3, Status::Mapped, 97, 2),
+    (0xD4, Status::Mapped, 99, 2), (0xD5, Status::Mapped, 101, 2), (0xD6, Status::Mapped, 103, 2),
+    (0xD7, Status::Valid, 0, 0), (0xD8, Status::Mapped, 105, 2), (0xD9, Status::Mapped, 107, 2),
+    (0xDA, Status::Mapped, 109, 2), (0xDB, Status::Mapped, 111, 2), (0xDC, Status::Mapped, 113, 2),
+    (0xDD, Status::Mapped, 115, 2), (0xDE, Status::Mapped, 117, 2), (0xDF, Status::Deviation, 119, 2),
+    (0xE0, Status::Valid, 0, 0), (0x100, Status::Mapped, 121, 2), (0x101, Status::Valid, 0, 0),
+    (0x102, Status::Mapped, 123, 2), (0x103, Status::Valid, 0, 0), (0x104, Status::Mapped, 125, 2),
+    (0x105, Status::Valid, 0, 0), (0x106, Status::Mapped, 127, 2), (0x107, Status::Valid, 0, 0),
+    (0x108, Status::Mapped, 129, 2), (0x109, Status::Valid, 0, 0), (0x10A, Status::Mapped, 131, 2),
+    (0x10B, Status::Valid, 0, 0), (0x10C, Status::Mapped, 133, 2), (0x10D, Status::Valid, 0, 0),
+    (0x10E, Status::Mapped, 135, 2), (0x10F, Status::Valid, 0, 0), (0x110, Status::Mapped, 137, 2),
+    (0x111, Status::Valid, 0, 0), (0x112, Status::Mapped, 139, 2), (0x113, Status::Valid, 0, 0),
+    (0x114, Status::Mapped, 141, 2), (0x115, Status::Valid, 0, 0), (0x116, Status::Mapped, 143, 2),
+    (0x117, Status::Valid, 0, 0), (0x118, Status::Mapped, 145, 2), (0x119, Status::Valid, 0, 0),
+    (0x11A, Status::Mapped, 147, 2), (0x11B, Status::Valid, 0, 0), (0x11C, Status::Mapped, 149, 2),
+    (0x11D, Status::Valid, 0, 0), (0x11E, Status::Mapped, 151, 2), (0x11F, Status::Valid, 0, 0),
+    (0x120, Status::Mapped, 153, 2), (0x121, Status::Valid, 0, 0), (0x122, Status::Mapped, 155, 2),
+    (0x123, Status::Valid, 0, 0), (0x124, Status::Mapped, 157, 2), (0x125, Status::Valid, 0, 0),
+    (0x126, Status::Mapped, 159, 2), (0x127, Status::Valid, 0, 0), (0x128, Status::Mapped, 161, 2),
+    (0x129, Status::Valid, 0, 0), (0x12A, Status::Mapped, 163, 2), (0x12B, Status::Valid, 0, 0),
+    (0x12C, Status::Mapped, 165, 2), (0x12D, Status::Valid, 0, 0), (0x12E, Status::Mapped, 167, 2),
+    (0x12F, Status::Valid, 0, 0), (0x130, Status::Mapped, 169, 3), (0x131, Status::Valid, 0, 0),
+    (0x132, Status::Mapped, 172, 2), (0x134, Status::Mapped, 174, 2), (0x135, Status::Valid, 0, 0),
+    (0x136, Status::Mapped, 176, 2), (0x137, Status::Valid, 0, 0), (0x139, Status::Mapped, 178, 2),
+    (0x13A, Status::Valid, 0, 0), (0x13B, Status::Mapped, 180, 2), (0x13C, Status::Valid, 0, 0),
+    (0x13D, Status::Mapped, 182, 2), (0x13E, Status::Valid, 0, 0), (0x13F, Status::Mapped, 184, 3),
+    (0x141, Status::Mapped, 187, 2), (0x142, Status::Valid, 0, 0), (0x143, Status::Mapped, 189, 2),
+    (0x144, Status::Valid, 0, 0), (0x145, Status::Mapped, 191, 2), (0x146, Status::Valid, 0, 0),
+    (0x147, Status::Mapped, 193, 2), (0x148, Status::Valid, 0, 0), (0x149, Status::Mapped, 195, 3),
+    (0x14A, Status::Mapped, 198, 2), (0x14B, Status::Valid, 0, 0), (0x14C, Status::Mapped, 200, 2),
+    (0x14D, Status::Valid, 0, 0), (0x14E, Status::Mapped, 202, 2), (0x14F, Status::Valid, 0, 0),
+    (0x150, Status::Mapped, 204, 2), (0x151, Status::Valid, 0, 0), (0x152, Status::Mapped, 206, 2),
+    (0x153, Status::Valid, 0, 0), (0x154, Status::Mapped, 208, 2), (0x155, Status::Valid, 0, 0),
+    (0x156, Status::Mapped, 210, 2), (0x157, Status::Valid, 0, 0), (0x158, Status::Mapped, 212, 2),
+    (0x159, Status::Valid, 0, 0), (0x15A, Status::Mapped, 214, 2), (0x15B, Status::Valid, 0, 0),
+    (0x15C, Status::Mapped, 216, 2), (0x15D, Status::Valid, 0, 0), (0x15E, Status::Mapped, 218, 2),
+    (0x15F, Status::Valid, 0, 0), (0x160, Status::Mapped, 220, 2), (0x161, Status::Valid, 0, 0),
+    (0x162, Status::Mapped, 222, 2), (0x163, Status::Valid, 0, 0), (0x164, Status::Mapped, 224, 2),
+    (0x165, Status::Valid, 0, 0), (0x166, Status::Mapped, 226, 2), (0x167, Status::Valid, 0, 0),
+    (0x168, Status::Mapped, 228, 2), (0x169, Status::Valid, 0, 0), (0x16A, Status::Mapped, 230, 2),
+    (0x16B, Status::Valid, 0, 0), (0x16C, Status::Mapped, 232, 2), (0x16D, Status::Valid, 0, 0),
+    (0x16E, Status::Mapped, 234, 2), (0x16F, Status::Valid, 0, 0), (0x170, Status::Mapped, 236, 2),
+    (0x171, Status::Valid, 0, 0), (0x172, Status::Mapped, 238, 2), (0x173, Status::Valid, 0, 0),
+    (0x174, Status::Mapped, 240, 2), (0x175, Status::Valid, 0, 0), (0x176, Status::Mapped, 242, 2),
+    (0x177, Status::Valid, 0, 0), (0x178, Status::Mapped, 244, 2), (0x179, Status::Mapped, 246, 2),
+    (0x17A, Status::Valid, 0, 0), (0x17B, Status::Mapped, 248, 2), (0x17C, Status::Valid, 0, 0),
+    (0x17D, Status::Mapped, 250, 2), (0x17E, Status::Valid, 0, 0), (0x17F, Status::Mapped, 18, 1),
+    (0x180, Status::Valid, 0, 0), (0x181, Status::Mapped, 252, 2), (0x182, Status::Mapped, 254, 2),
+    (0x183, Status::Valid, 0, 0), (0x184, Status::Mapped, 256, 2), (0x185, Status::Valid, 0, 0),
+    (0x186, Status::Mapped, 258, 2), (0x187, Status::Mapped, 260, 2), (0x188, Status::Valid, 0, 0),
+    (0x189, Status::Mapped, 262, 2), (0x18A, Status::Mapped, 264, 2), (0x18B, Status::Mapped, 266, 2),
+    (0x18C, Status::Valid, 0, 0), (0x18E, Status::Mapped, 268, 2), (0x18F, Status::Mapped, 270, 2),
+    (0x190, Status::Mapped, 272, 2), (0x191, Status::Mapped, 274, 2), (0x192, Status::Valid, 0, 0),
+    (0x193, Status::Mapped, 276, 2), (0x194, Status::Mapped, 278, 2), (0x195, Status::Valid, 0, 0),
+    (0x196, Status::Mapped, 280, 2), (0x197, Status::Mapped, 282, 2), (0x198, Status::Mapped, 284, 2),
+    (0x199, Status::Valid, 0, 0), (0x19C, Status::Mapped, 286, 2), (0x19D, Status::Mapped, 288, 2),
+    (0x19E, Status::Valid, 0, 0), (0x19F, Status::Mapped, 290, 2), (0x1A0, Status::Mapped, 292, 2),
+    (0x1A1, Status::Valid, 0, 0), (0x1A2, Status::Mapped, 294, 2), (0x1A3, Status::Valid, 0, 0),
+    (0x1A4, Status::Mapped, 296, 2), (0x1A5, Status::Valid, 0, 0), (0x1A6, Status::Mapped, 298, 2),
+    (0x1A7, Status::Mapped, 300, 2), (0x1A8, Status::Valid, 0, 0), (0x1A9, Status::Mapped, 302, 2),
+    (0x1AA, Status::Valid, 0, 0), (0x1AC, Status::Mapped, 304, 2), (0x1AD, Status::Valid, 0, 0),
+    (0x1AE, Status::Mapped, 306, 2), (0x1AF, Status::Mapped, 308, 2), (0x1B0, Status::Valid, 0, 0),
+    (0x1B1, Status::Mapped, 310, 2), (0x1B2, Status::Mapped, 312, 2), (0x1B3, Status::Mapped, 314, 2),
+    (0x1B4, Status::Valid, 0, 0), (0x1B5, Status::Mapped, 316, 2), (0x1B6, Status::Valid, 0, 0),
+    (0x1B7, Status::Mapped, 318, 2), (0x1B8, Status::Mapped, 320, 2), (0x1B9, Status::Valid, 0, 0),
+    (0x1BC, Status::Mapped, 322, 2), (0x1BD, Status::Valid, 0, 0), (0x1C4, Status::Mapped, 324, 3),
+    (0x1C7, Status::Mapped, 327, 2), (0x1CA, Status::Mapped, 329, 2), (0x1CD, Status::Mapped, 331, 2),
+    (0x1CE, Status::Valid, 0, 0), (0x1CF, Status::Mapped, 333, 2), (0x1D0, Status::Valid, 0, 0),
+    (0x1D1, Status::Mapped, 335, 2), (0x1D2, Status::Valid, 0, 0), (0x1D3, Status::Mapped, 337, 2),
+    (0x1D4, Status::Valid, 0, 0), (0x1D5, Status::Mapped, 339, 2), (0x1D6, Status::Valid, 0, 0),
+    (0x1D7, Status::Mapped, 341, 2), (0x1D8, Status::Valid, 0, 0), (0x1D9, Status::Mapped, 343, 2),
+    (0x1DA, Status::Valid, 0, 0), (0x1DB, Status::Mapped, 345, 2), (0x1DC, Status::Valid, 0, 0),
+    (0x1DE, Status::Mapped, 347, 2), (0x1DF, Status::Valid, 0, 0), (0x1E0, Status::Mapped, 349, 2),
+    (0x1E1, Status::Valid, 0, 0), (0x1E2, Status::Mapped, 351, 2), (0x1E3, Status::Valid, 0, 0),
+    (0x1E4, Status::Mapped, 353, 2), (0x1E5, Status::Valid, 0, 0), (0x1E6, Status::Mapped, 355, 2),
+    (0x1E7, Status::Valid, 0, 0), (0x1E8, Status::Mapped, 357, 2), (0x1E9, Status::Valid, 0, 0),
+    (0x1EA, Status::Mapped, 359, 2), (0x1EB, Status::Valid, 0, 0), (0x1EC, Status::Mapped, 361, 2),
+    (0x1ED, Status::Valid, 0, 0), (0x1EE, Status::Mapped, 363, 2), (0x1EF, Status::Valid, 0, 0),
+    (0x1F1, Status::Mapped, 365, 2), (0x1F4, Status::Mapped, 367, 2), (0x1F5, Status::Valid, 0, 0),
+    (0x1F6, Status::Mapped, 369, 2), (0x1F7, Status::Mapped, 371, 2), (0x1F8, Status::Mapped, 373, 2),
+    (0x1F9, Status::Valid, 0, 0), (0x1FA, Status::Mapped, 375, 2), (0x1FB, Status::Valid, 0, 0),
+    (0x1FC, Status::Mapped, 377, 2), (0x1FD, Status::Valid, 0, 0), (0x1FE, Status::Mapped, 379, 2),
+    (0x1FF, Status::Valid, 0, 0), (0x200, Status::Mapped, 381, 2), (0x201, Status::Valid, 0, 0),
+    (0x202, Status::Mapped, 383, 2), (0x203, Status::Valid, 0, 0), (0x204, Status::Mapped, 385, 2),
+    (0x205, Status::Valid, 0, 0), (0x206, Status::Mapped, 387, 2), (0x207, Status::Valid, 0, 0),
+    (0x208, Status::Mapped, 389, 2), (0x209, Status::Valid, 0, 0), (0x20A, Status::Mapped, 391, 2),
+    (0x20B, Status::Valid, 0, 0), (0x20C, Status::Mapped, 393, 2), (0x20D, Status::Valid, 0, 0),
+    (0x20E, Status::Mapped, 395, 2), (0x20F, Status::Valid, 0, 0), (0x210, Status::Mapped, 397, 2),
+    (0x211, Status::Valid, 0, 0), (0x212, Status::Mapped, 399, 2), (0x213, Status::Valid, 0, 0),
+    (0x214, Status::Mapped, 401, 2), (0x215, Status::Valid, 0, 0), (0x216, Status::Mapped, 403, 2),
+    (0x217, Status::Valid, 0, 0), (0x218, Status::Mapped, 405, 2), (0x219, Status::Valid, 0, 0),
+    (0x21A, Status::Mapped, 407, 2), (0x21B, Status::Valid, 0, 0), (0x21C, Status::Mapped, 409, 2),
+    (0x21D, Status::Valid, 0, 0), (0x21E, Status::Mapped, 411, 2), (0x21F, Status::Valid, 0, 0),
+    (0x220, Status::Mapped, 413, 2), (0x221, Status::Valid, 0, 0), (0x222, Status::Mapped, 415, 2),
+    (0x223, Status::Valid, 0, 0), (0x224, Status::Mapped, 417, 2), (0x225, Status::Valid, 0, 0),
+    (0x226, Status::Mapped, 419, 2), (0x227, Status::Valid, 0, 0), (0x228, Status::Mapped, 421, 2),
+    (0x229, Status::Valid, 0, 0), (0x22A, Status::Mapped, 423, 2), (0x22B, Status::Valid, 0, 0),
+    (0x22C, Status::Mapped, 425, 2), (0x22D, Status::Valid, 0, 0), (0x22E, Status::Mapped, 427, 2),
+    (0x22F, Status::Valid, 0, 0), (0x230, Status::Mapped, 429, 2), (0x231, Status::Valid, 0, 0),
+    (0x232, Status::Mapped, 431, 2), (0x233, Status::Valid, 0, 0), (0x23A, Status::Mapped, 433, 3),
+    (0x23B, Status::Mapped, 436, 2), (0x23C, Status::Valid, 0, 0), (0x23D, Status::Mapped, 438, 2),
+    (0x23E, Status::Mapped, 440, 3), (0x23F, Status::Valid, 0, 0), (0x241, Status::Mapped, 443, 2),
+    (0x242, Status::Valid, 0, 0), (0x243, Status::Mapped, 445, 2), (0x244, Status::Mapped, 447, 2),
+    (0x245, Status::Mapped, 449, 2), (0x246, Status::Mapped, 451, 2), (0x247, Status::Valid, 0, 0),
+    (0x248, Status::Mapped, 453, 2), (0x249, Status::Valid, 0, 0), (0x24A, Status::Mapped, 455, 2),
+    (0x24B, Status::Valid, 0, 0), (0x24C, Status::Mapped, 457, 2), (0x24D, Status::Valid, 0, 0),
+    (0x24E, Status::Mapped, 459, 2), (0x24F, Status::Valid, 0, 0), (0x2B0, Status::Mapped, 7, 1),
+    (0x2B1, Status::Mapped, 461, 2), (0x2B2, Status::Mapped, 9, 1), (0x2B3, Status::Mapped, 17, 1),
+    (0x2B4, Status::Mapped, 463, 2), (0x2B5, Status::Mapped, 465, 2), (0x2B6, Status::Mapped, 467, 2),
+    (0x2B7, Status::Mapped, 22, 1), (0x2B8, Status::Mapped, 24, 1), (0x2B9, Status::Valid, 0, 0),
+    (0x2D8, Status::DisallowedStd3Mapped, 469, 3), (0x2D9, Status::DisallowedStd3Mapped, 472, 3), (0x2DA, Status::DisallowedStd3Mapped, 475, 3),
+    (0x2DB, Status::DisallowedStd3Mapped, 478, 3), (0x2DC, Status::DisallowedStd3Mapped, 481, 3), (0x2DD, Status::DisallowedStd3Mapped, 484, 3),
+    (0x2DE, Status::Valid, 0, 0), (0x2E0, Status::Mapped, 278, 2), (0x2E1, Status::Mapped, 11, 1),
+    (0x2E2, Status::Mapped, 18, 1), (0x2E3, Status::Mapped, 23, 1), (0x2E4, Status::Mapped, 487, 2),
+    (0x2E5, Status::Valid, 0, 0), (0x340, Status::Mapped, 489, 2), (0x341, Status::Mapped, 491, 2),
+    (0x342, Status::Valid, 0, 0), (0x343, Status::Mapped, 493, 2), (0x344, Status::Mapped, 495, 4),
+    (0x345, Status::Mapped, 499, 2), (0x346, Status::Valid, 0, 0), (0x34F, Status::Ignored, 0, 0),
+    (0x350, Status::Valid, 0, 0), (0x370, Status::Mapped, 501, 2), (0x371, Status::Valid, 0, 0),
+    (0x372, Status::Mapped, 503, 2), (0x373, Status::Valid, 0, 0), (0x374, Status::Mapped, 505, 2),
+    (0x375, Status::Valid, 0, 0), (0x376, Status::Mapped, 507, 2), (0x377, Status::Valid, 0, 0),
+    (0x378, Status::Disallowed, 0, 0), (0x37A, Status::DisallowedStd3Mapped, 509, 3), (0x37B, Status::Valid, 0, 0),
+    (0x37E, Status::DisallowedStd3Mapped, 512, 1), (0x37F, Status::Mapped, 513, 2), (0x380, Status::Disallowed, 0, 0),
+    (0x384, Status::DisallowedStd3Mapped, 35, 3), (0x385, Status::DisallowedStd3Mapped, 515, 5), (0x386, Status::Mapped, 520, 2),
+    (0x387, Status::Mapped, 522, 2), (0x388, Status::Mapped, 524, 2), (0x389, Status::Mapped, 526, 2),
+    (0x38A, Status::Mapped, 528, 2), (0x38B, Status::Disallowed, 0, 0), (0x38C, Status::Mapped, 530, 2),
+    (0x38D, Status::Disallowed, 0, 0), (0x38E, Status::Mapped, 532, 2), (0x38F, Status::Mapped, 534, 2),
+    (0x390, Status::Valid, 0, 0), (0x391, Status::Mapped, 536, 2), (0x392, Status::Mapped, 538, 2),
+    (0x393, Status::Mapped, 540, 2), (0x394, Status::Mapped, 542, 2), (0x395, Status::Mapped, 544, 2),
+    (0x396, Status::Mapped, 546, 2), (0x397, Status::Mapped, 548, 2), (0x398, Status::Mapped, 550, 2),
+    (0x399, Status::Mapped, 499, 2), (0x39A, Status::Mapped, 552, 2), (0x39B, Status::Mapped, 554, 2),
+    (0x39C, Status::Mapped, 38, 2), (0x39D, Status::Mapped, 556, 2), (0x39E, Status::Mapped, 558, 2),
+    (0x39F, Status::Mapped, 560, 2), (0x3A0, Status::Mapped, 562, 2), (0x3A1, Status::Mapped, 564, 2),
+    (0x3A2, Status::Disallowed, 0, 0), (0x3A3, Status::Mapped, 566, 2), (0x3A4, Status::Mapped, 568, 2),
+    (0x3A5, Status::Mapped, 570, 2), (0x3A6, Status::Mapped, 572, 2), (0x3A7, Status::Mapped, 574, 2),
+    (0x3A8, Status::Mapped, 576, 2), (0x3A9, Status::Mapped, 578, 2), (0x3AA, Status::Mapped, 580, 2),
+    (0x3AB, Status::Mapped, 582, 2), (0x3AC, Status::Valid, 0, 0), (0x3C2, Status::Deviation, 566, 2),
+    (0x3C3, Status::Valid, 0, 0), (0x3CF, Status::Mapped, 584, 2), (0x3D0, Status::Mapped, 538, 2),
+    (0x3D1, Status::Mapped, 550, 2), (0x3D2, Status::Mapped, 570, 2), (0x3D3, Status::Mapped, 532, 2),
+    (0x3D4, Status::Mapped, 582, 2), (0x3D5, Status::Mapped, 572, 2), (0x3D6, Status::Mapped, 562, 2),
+    (0x3D7, Status::Valid, 0, 0), (0x3D8, Status::Mapped, 586, 2), (0x3D9, Status::Valid, 0, 0),
+    (0x3DA, Status::Mapped, 588, 2), (0x3DB, Status::Valid, 0, 0), (0x3DC, Status::Mapped, 590, 2),
+    (0x3DD, Status::Valid, 0, 0), (0x3DE, Status::Mapped, 592, 2), (0x3DF, Status::Valid, 0, 0),
+    (0x3E0, Status::Mapped, 594, 2), (0x3E1, Status::Valid, 0, 0), (0x3E2, Status::Mapped, 596, 2),
+    (0x3E3, Status::Valid, 0, 0), (0x3E4, Status::Mapped, 598, 2), (0x3E5, Status::Valid, 0, 0),
+    (0x3E6, Status::Mapped, 600, 2), (0x3E7, Status::Valid, 0, 0), (0x3E8, Status::Mapped, 602, 2),
+    (0x3E9, Status::Valid, 0, 0), (0x3EA, Status::Mapped, 604, 2), (0x3EB, Status::Valid, 0, 0),
+    (0x3EC, Status::Mapped, 606, 2), (0x3ED, Status::Valid, 0, 0), (0x3EE, Status::Mapped, 608, 2),
+    (0x3EF, Status::Valid, 0, 0), (0x3F0, Status::Mapped, 552, 2), (0x3F1, Status::Mapped, 564, 2),
+    (0x3F2, Status::Mapped, 566, 2), (0x3F3, Status::Valid, 0, 0), (0x3F4, Status::Mapped, 550, 2),
+    (0x3F5, Status::Mapped, 544, 2), (0x3F6, Status::Valid, 0, 0), (0x3F7, Status::Mapped, 610, 2),
+    (0x3F8, Status::Valid, 0, 0), (0x3F9, Status::Mapped, 566, 2), (0x3FA, Status::Mapped, 612, 2),
+    (0x3FB, Status::Valid, 0, 0), (0x3FD, Status::Mapped, 614, 2), (0x3FE, Status::Mapped, 616, 2),
+    (0x3FF, Status::Mapped, 618, 2), (0x400, Status::Mapped, 620, 2), (0x401, Status::Mapped, 622, 2),
+    (0x402, Status::Mapped, 624, 2), (0x403, Status::Mapped, 626, 2), (0x404, Status::Mapped, 628, 2),
+    (0x405, Status::Mapped, 630, 2), (0x406, Status::Mapped, 632, 2), (0x407, Status::Mapped, 634, 2),
+    (0x408, Status::Mapped, 636, 2), (0x409, Status::Mapped, 638, 2), (0x40A, Status::Mapped, 640, 2),
+    (0x40B, Status::Mapped, 642, 2), (0x40C, Status::Mapped, 644, 2), (0x40D, Status::Mapped, 646, 2),
+    (0x40E, Status::Mapped, 648, 2), (0x40F, Status::Mapped, 650, 2), (0x410, Status::Mapped, 652, 2),
+    (0x411, Status::Mapped, 654, 2), (0x412, Status::Mapped, 656, 2), (0x413, Status::Mapped, 658, 2),
+    (0x414, Status::Mapped, 660, 2), (0x415, Status::Mapped, 662, 2), (0x416, Status::Mapped, 664, 2),
+    (0x417, Status::Mapped, 666, 2), (0x418, Status::Mapped, 668, 2), (0x419, Status::Mapped, 670, 2),
+    (0x41A, Status::Mapped, 672, 2), (0x41B, Status::Mapped, 674, 2), (0x41C, Status::Mapped, 676, 2),
+    (0x41D, Status::Mapped, 678, 2), (0x41E, Status::Mapped, 680, 2), (0x41F, Status::Mapped, 682, 2),
+    (0x420, Status::Mapped, 684, 2), (0x421, Status::Mapped, 686, 2), (0x422, Status::Mapped, 688, 2),
+    (0x423, Status::Mapped, 690, 2), (0x424, Status::Mapped, 692, 2), (0x425, Status::Mapped, 694, 2),
+    (0x426, Status::Mapped, 696, 2), (0x427, Status::Mapped, 698, 2), (0x428, Status::Mapped, 700, 2),
+    (0x429, Status::Mapped, 702, 2), (0x42A, Status::Mapped, 704, 2), (0x42B, Status::Mapped, 706, 2),
+    (0x42C, Status::Mapped, 708, 2), (0x42D, Status::Mapped, 710, 2), (0x42E, Status::Mapped, 712, 2),
+    (0x42F, Status::Mapped, 714, 2), (0x430, Status::Valid, 0, 0), (0x460, Status::Mapped, 716, 2),
+    (0x461, Status::Valid, 0, 0), (0x462, Status::Mapped, 718, 2), (0x463, Status::Valid, 0, 0),
+    (0x464, Status::Mapped, 720, 2), (0x465, Status::Valid, 0, 0), (0x466, Status::Mapped, 722, 2),
+    (0x467, Status::Valid, 0, 0), (0x468, Status::Mapped, 724, 2), (0x469, Status::Valid, 0, 0),
+    (0x46A, Status::Mapped, 726, 2), (0x46B, Status::Valid, 0, 0), (0x46C, Status::Mapped, 728, 2),
+    (0x46D, Status::Valid, 0, 0), (0x46E, Status::Mapped, 730, 2), (0x46F, Status::Valid, 0, 0),
+    (0x470, Status::Mapped, 732, 2), (0x471, Status::Valid, 0, 0), (0x472, Status::Mapped, 734, 2),
+    (0x473, Status::Valid, 0, 0), (0x474, Status::Mapped, 736, 2), (0x475, Status::Valid, 0, 0),
+    (0x476, Status::Mapped, 738, 2), (0x477, Status::Valid, 0, 0), (0x478, Status::Mapped, 740, 2),
+    (0x479, Status::Valid, 0, 0), (0x47A, Status::Mapped, 742, 2), (0x47B, Status::Valid, 0, 0),
+    (0x47C, Status::Mapped, 744, 2), (0x47D, Status::Valid, 0, 0), (0x47E, Status::Mapped, 746, 2),
+    (0x47F, Status::Valid, 0, 0), (0x480, Status::Mapped, 748, 2), (0x481, Status::Valid, 0, 0),
+    (0x48A, Status::Mapped, 750, 2), (0x48B, Status::Valid, 0, 0), (0x48C, Status::Mapped, 752, 2),
+    (0x48D, Status::Valid, 0, 0), (0x48E, Status::Mapped, 754, 2), (0x48F, Status::Valid, 0, 0),
+    (0x490, Status::Mapped, 756, 2), (0x491, Status::Valid, 0, 0), (0x492, Status::Mapped, 758, 2),
+    (0x493, Status::Valid, 0, 0), (0x494, Status::Mapped, 760, 2), (0x495, Status::Valid, 0, 0),
+    (0x496, Status::Mapped, 762, 2), (0x497, Status::Valid, 0, 0), (0x498, Status::Mapped, 764, 2),
+    (0x499, Status::Valid, 0, 0), (0x49A, Status::Mapped, 766, 2), (0x49B, Status::Valid, 0, 0),
+    (0x49C, Status::Mapped, 768, 2), (0x49D, Status::Valid, 0, 0), (0x49E, Status::Mapped, 770, 2),
+    (0x49F, Status::Valid, 0, 0), (0x4A0, Status::Mapped, 772, 2), (0x4A1, Status::Valid, 0, 0),
+    (0x4A2, Status::Mapped, 774, 2), (0x4A3, Status::Valid, 0, 0), (0x4A4, Status::Mapped, 776, 2),
+    (0x4A5, Status::Valid, 0, 0), (0x4A6, Status::Mapped, 778, 2), (0x4A7, Status::Valid, 0, 0),
+    (0x4A8, Status::Mapped, 780, 2), (0x4A9, Status::Valid, 0, 0), (0x4AA, Status::Mapped, 782, 2),
+    (0x4AB, Status::Valid, 0, 0), (0x4AC, Status::Mapped, 784, 2), (0x4AD, Status::Valid, 0, 0),
+    (0x4AE, Status::Mapped, 786, 2), (0x4AF, Status::Valid, 0, 0), (0x4B0, Status::Mapped, 788, 2),
+    (0x4B1, Status::Valid, 0, 0), (0x4B2, Status::Mapped, 790, 2), (0x4B3, Status::Valid, 0, 0),
+    (0x4B4, Status::Mapped, 792, 2), (0x4B5, Status::Valid, 0, 0), (0x4B6, Status::Mapped, 794, 2),
+    (0x4B7, Status::Valid, 0, 0), (0x4B8, Status::Mapped, 796, 2), (0x4B9, Status::Valid, 0, 0),
+    (0x4BA, Status::Mapped, 798, 2), (0x4BB, Status::Valid, 0, 0), (0x4BC, Status::Mapped, 800, 2),
+    (0x4BD, Status::Valid, 0, 0), (0x4BE, Status::Mapped, 802, 2), (0x4BF, Status::Valid, 0, 0),
+    (0x4C0, Status::Disallowed, 0, 0), (0x4C1, Status::Mapped, 804, 2), (0x4C2, Status::Valid, 0, 0),
+    (0x4C3, Status::Mapped, 806, 2), (0x4C4, Status::Valid, 0, 0), (0x4C5, Status::Mapped, 808, 2),
+    (0x4C6, Status::Valid, 0, 0), (0x4C7, Status::Mapped, 810, 2), (0x4C8, Status::Valid, 0, 0),
+    (0x4C9, Status::Mapped, 812, 2), (0x4CA, Status::Valid, 0, 0), (0x4CB, Status::Mapped, 814, 2),
+    (0x4CC, Status::Valid, 0, 0), (0x4CD, Status::Mapped, 816, 2), (0x4CE, Status::Valid, 0, 0),
+    (0x4D0, Status::Mapped, 818, 2), (0x4D1, Status::Valid, 0, 0), (0x4D2, Status::Mapped, 820, 2),
+    (0x4D3, Status::Valid, 0, 0), (0x4D4, Status::Mapped, 822, 2), (0x4D5, Status::Valid, 0, 0),
+    (0x4D6, Status::Mapped, 824, 2), (0x4D7, Status::Valid, 0, 0), (0x4D8, Status::Mapped, 826, 2),
+    (0x4D9, Status::Valid, 0, 0), (0x4DA, Status::Mapped, 828, 2), (0x4DB, Status::Valid, 0, 0),
+    (0x4DC, Status::Mapped, 830, 2), (0x4DD, Status::Valid, 0, 0), (0x4DE, Status::Mapped, 832, 2),
+    (0x4DF, Status::Valid, 0, 0), (0x4E0, Status::Mapped, 834, 2), (0x4E1, Status::Valid, 0, 0),
+    (0x4E2, Status::Mapped, 836, 2), (0x4E3, Status::Valid, 0, 0), (0x4E4, Status::Mapped, 838, 2),
+    (0x4E5, Status::Valid, 0, 0), (0x4E6, Status::Mapped, 840, 2), (0x4E7, Status::Valid, 0, 0),
+    (0x4E8, Status::Mapped, 842, 2), (0x4E9, Status::Valid, 0, 0), (0x4EA, Status::Mapped, 844, 2),
+    (0x4EB, Status::Valid, 0, 0), (0x4EC, Status::Mapped, 846, 2), (0x4ED, Status::Valid, 0, 0),
+    (0x4EE, Status::Mapped, 848, 2), (0x4EF, Status::Valid, 0, 0), (0x4F0, Status::Mapped, 850, 2),
+    (0x4F1, Status::Valid, 0, 0), (0x4F2, Status::Mapped, 852, 2), (0x4F3, Status::Valid, 0, 0),
+    (0x4F4, Status::Mapped, 854, 2), (0x4F5, Status::Valid, 0, 0), (0x4F6, Status::Mapped, 856, 2),
+    (0x4F7, Status::Valid, 0, 0), (0x4F8, Status::Mapped, 858, 2), (0x4F9, Status::Valid, 0, 0),
+    (0x4FA, Status::Mapped, 860, 2), (0x4FB, Status::Valid, 0, 0), (0x4FC, Status::Mapped, 862, 2),
+    (0x4FD, Status::Valid, 0, 0), (0x4FE, Status::Mapped, 864, 2), (0x4FF, Status::Valid, 0, 0),
+    (0x500, Status::Mapped, 866, 2), (0x501, Status::Valid, 0, 0), (0x502, Status::Mapped, 868, 2),
+    (0x503, Status::Valid, 0, 0), (0x504, Status::Mapped, 870, 2), (0x505, Status::Valid, 0, 0),
+    (0x506, Status::Mapped, 872, 2), (0x507, Status::Valid, 0, 0), (0x508, Status::Mapped, 874, 2),
+    (0x509, Status::Valid, 0, 0), (0x50A, Status::Mapped, 876, 2), (0x50B, Status::Valid, 0, 0),
+    (0x50C, Status::Mapped, 878, 2), (0x50D, Status::Valid, 0, 0), (0x50E, Status::Mapped, 880, 2),
+    (0x50F, Status::Valid, 0, 0), (0x510, Status::Mapped, 882, 2), (0x511, Status::Valid, 0, 0),
+    (0x512, Status::Mapped, 884, 2), (0x513, Status::Valid, 0, 0), (0x514, Status::Mapped, 886, 2),
+    (0x515, Status::Valid, 0, 0), (0x516, Status::Mapped, 888, 2), (0x517, Status::Valid, 0, 0),
+    (0x518, Status::Mapped, 890, 2), (0x519, Status::Valid, 0, 0), (0x51A, Status::Mapped, 892, 2),
+    (0x51B, Status::Valid, 0, 0), (0x51C, Status::Mapped, 894, 2), (0x51D, Status::Valid, 0, 0),
+    (0x51E, Status::Mapped, 896, 2), (0x51F, Status::Valid, 0, 0), (0x520, Status::Mapped, 898, 2),
+    (0x521, Status::Valid, 0, 0), (0x522, Status::Mapped, 900, 2), (0x523, Status::Valid, 0, 0),
+    (0x524, Status::Mapped, 902, 2), (0x525, Status::Valid, 0, 0), (0x526, Status::Mapped, 904, 2),
+    (0x527, Status::Valid, 0, 0), (0x528, Status::Mapped, 906, 2), (0x529, Status::Valid, 0, 0),
+    (0x52A, Status::Mapped, 908, 2), (0x52B, Status::Valid, 0, 0), (0x52C, Status::Mapped, 910, 2),
+    (0x52D, Status::Valid, 0, 0), (0x52E, Status::Mapped, 912, 2), (0x52F, Status::Valid, 0, 0),
+    (0x530, Status::Disallowed, 0, 0), (0x531, Status::Mapped, 914, 2), (0x532, Status::Mapped, 916, 2),
+    (0x533, Status::Mapped, 918, 2), (0x534, Status::Mapped, 920, 2), (0x535, Status::Mapped, 922, 2),
+    (0x536, Status::Mapped, 924, 2), (0x537, Status::Mapped, 926, 2), (0x538, Status::Mapped, 928, 2),
+    (0x539, Status::Mapped, 930, 2), (0x53A, Status::Mapped, 932, 2), (0x53B, Status::Mapped, 934, 2),
+    (0x53C, Status::Mapped, 936, 2), (0x53D, Status::Mapped, 938, 2), (0x53E, Status::Mapped, 940, 2),
+    (0x53F, Status::Mapped, 942, 2), (0x540, Status::Mapped, 944, 2), (0x541, Status::Mapped, 946, 2),
+    (0x542, Status::Mapped, 948, 2), (0x543, Status::Mapped, 950, 2), (0x544, Status::Mapped, 952, 2),
+    (0x545, Status::Mapped, 954, 2), (0x546, Status::Mapped, 956, 2), (0x547, Status::Mapped, 958, 2),
+    (0x548, Status::Mapped, 960, 2), (0x549, Status::Mapped, 962, 2), (0x54A, Status::Mapped, 964, 2),
+    (0x54B, Status::Mapped, 966, 2), (0x54C, Status::Mapped, 968, 2), (0x54D, Status::Mapped, 970, 2),
+    (0x54E, Status::Mapped, 972, 2), (0x54F, Status::Mapped, 974, 2), (0x550, Status::Mapped, 976, 2),
+    (0x551, Status::Mapped, 978, 2), (0x552, Status::Mapped, 980, 2), (0x553, Status::Mapped, 982, 2),
+    (0x554, Status::Mapped, 984, 2), (0x555, Status::Mapped, 986, 2), (0x556, Status::Mapped, 988, 2),
+    (0x557, Status::Disallowed, 0, 0), (0x559, Status::Valid, 0, 0), (0x587, Status::Mapped, 990, 4),
+    (0x588, Status::Valid, 0, 0), (0x58B, Status::Disallowed, 0, 0), (0x58D, Status::Valid, 0, 0),
+    (0x590, Status::Disallowed, 0, 0), (0x591, Status::Valid, 0, 0), (0x5C8, Status::Disallowed, 0, 0),
+    (0x5D0, Status::Valid, 0, 0), (0x5EB, Status::Disallowed, 0, 0), (0x5EF, Status::Valid, 0, 0),
+    (0x5F5, Status::Disallowed, 0, 0), (0x606, Status::Valid, 0, 0), (0x61C, Status::Disallowed, 0, 0),
+    (0x61E, Status::Valid, 0, 0), (0x675, Status::Mapped, 994, 4), (0x676, Status::Mapped, 998, 4),
+    (0x677, Status::Mapped, 1002, 4), (0x678, Status::Mapped, 1006, 4), (0x679, Status::Valid, 0, 0),
+    (0x6DD, Status::Disallowed, 0, 0), (0x6DE, Status::Valid, 0, 0), (0x70E, Status::Disallowed, 0, 0),
+    (0x710, Status::Valid, 0, 0), (0x74B, Status::Disallowed, 0, 0), (0x74D, Status::Valid, 0, 0),
+    (0x7B2, Status::Disallowed, 0, 0), (0x7C0, Status::Valid, 0, 0), (0x7FB, Status::Disallowed, 0, 0),
+    (0x7FD, Status::Valid, 0, 0), (0x82E, Status::Disallowed, 0, 0), (0x830, Status::Valid, 0, 0),
+    (0x83F, Status::Disallowed, 0, 0), (0x840, Status::Valid, 0, 0), (0x85C, Status::Disallowed, 0, 0),
+    (0x85E, Status::Valid, 0, 0), (0x85F, Status::Disallowed, 0, 0), (0x860, Status::Valid, 0, 0),
+    (0x86B, Status::Disallowed, 0, 0), (0x8A0, Status::Valid, 0, 0), (0x8B5, Status::Disallowed, 0, 0),
+    (0x8B6, Status::Valid, 0, 0), (0x8C8, Status::Disallowed, 0, 0), (0x8D3, Status::Valid, 0, 0),
+    (0x8E2, Status::Disallowed, 0, 0), (0x8E3, Status::Valid, 0, 0), (0x958, Status::Mapped, 1010, 6),
+    (0x959, Status::Mapped, 1016, 6), (0x95A, Status::Mapped, 1022, 6), (0x95B, Status::Mapped, 1028, 6),
+    (0x95C, Status::Mapped, 1034, 6), (0x95D, Status::Mapped, 1040, 6), (0x95E, Status::Mapped, 1046, 6),
+    (0x95F, Status::Mapped, 1052, 6), (0x960, Status::Valid, 0, 0), (0x984, Status::Disallowed, 0, 0),
+    (0x985, Status::Valid, 0, 0), (0x98D, Status::Disallowed, 0, 0), (0x98F, Status::Valid, 0, 0),
+    (0x991, Status::Disallowed, 0, 0), (0x993, Status::Valid, 0, 0), (0x9A9, Status::Disallowed, 0, 0),
+    (0x9AA, Status::Valid, 0, 0), (0x9B1, Status::Disallowed, 0, 0), (0x9B2, Status::Valid, 0, 0),
+    (0x9B3, Status::Disallowed, 0, 0), (0x9B6, Status::Valid, 0, 0), (0x9BA, Status::Disallowed, 0, 0),
+    (0x9BC, Status::Valid, 0, 0), (0x9C5, Status::Disallowed, 0, 0), (0x9C7, Status::Valid, 0, 0),
+    (0x9C9, Status::Disallowed, 0, 0), (0x9CB, Status::Valid, 0, 0), (0x9CF, Status::Disallowed, 0, 0),
+    (0x9D7, Status::Valid, 0, 0), (0x9D8, Status::Disallowed, 0, 0), (0x9DC, Status::Mapped, 1058, 6),
+    (0x9DD, Status::Mapped, 1064, 6), (0x9DE, Status::Disallowed, 0, 0), (0x9DF, Status::Mapped, 1070, 6),
+    (0x9E0, Status::Valid, 0, 0), (0x9E4, Status::Disallowed, 0, 0), (0x9E6, Status::Valid, 0, 0),
+    (0x9FF, Status::Disallowed, 0, 0), (0xA01, Status::Valid, 0, 0), (0xA04, Status::Disallowed, 0, 0),
+    (0xA05, Status::Valid, 0, 0), (0xA0B, Status::Disallowed, 0, 0), (0xA0F, Status::Valid, 0, 0),
+    (0xA11, Status::Disallowed, 0, 0), (0xA13, Status::Valid, 0, 0), (0xA29, Status::Disallowed, 0, 0),
+    (0xA2A, Status::Valid, 0, 0), (0xA31, Status::Disallowed, 0, 0), (0xA32, Status::Valid, 0, 0),
+    (0xA33, Status::Mapped, 1076, 6), (0xA34, Status::Disallowed, 0, 0), (0xA35, Status::Valid, 0, 0),
+    (0xA36, Status::Mapped, 1082, 6), (0xA37, Status::Disallowed, 0, 0), (0xA38, Status::Valid, 0, 0),
+    (0xA3A, Status::Disallowed, 0, 0), (0xA3C, Status::Valid, 0, 0), (0xA3D, Status::Disallowed, 0, 0),
+    (0xA3E, Status::Valid, 0, 0), (0xA43, Status::Disallowed, 0, 0), (0xA47, Status::Valid, 0, 0),
+    (0xA49, Status::Disallowed, 0, 0), (0xA4B, Status::Valid, 0, 0), (0xA4E, Status::Disallowed, 0, 0),
+    (0xA51, Status::Valid, 0, 0), (0xA52, Status::Disallowed, 0, 0), (0xA59, Status::Mapped, 1088, 6),
+    (0xA5A, Status::Mapped, 1094, 6), (0xA5B, Status::Mapped, 1100, 6), (0xA5C, Status::Valid, 0, 0),
+    (0xA5D, Status::Disallowed, 0, 0), (0xA5E, Status::Mapped, 1106, 6), (0xA5F, Status::Disallowed, 0, 0),
+    (0xA66, Status::Valid, 0, 0), (0xA77, Status::Disallowed, 0, 0), (0xA81, Status::Valid, 0, 0),
+    (0xA84, Status::Disallowed, 0, 0), (0xA85, Status::Valid, 0, 0), (0xA8E, Status::Disallowed, 0, 0),
+    (0xA8F, Status::Valid, 0, 0), (0xA92, Status::Disallowed, 0, 0), (0xA93, Status::Valid, 0, 0),
+    (0xAA9, Status::Disallowed, 0, 0), (0xAAA, Status::Valid, 0, 0), (0xAB1, Status::Disallowed, 0, 0),
+    (0xAB2, Status::Valid, 0, 0), (0xAB4, Status::Disallowed, 0, 0), (0xAB5, Status::Valid, 0, 0),
+    (0xABA, Status::Disallowed, 0, 0), (0xABC, Status::Valid, 0, 0), (0xAC6, Status::Disallowed, 0, 0),
+    (0xAC7, Status::Valid, 0, 0), (0xACA, Status::Disallowed, 0, 0), (0xACB, Status::Valid, 0, 0),
+    (0xACE, Status::Disallowed, 0, 0), (0xAD0, Status::Valid, 0, 0), (0xAD1, Status::Disallowed, 0, 0),
+    (0xAE0, Status::Valid, 0, 0), (0xAE4, Status::Disallowed, 0, 0), (0xAE6, Status::Valid, 0, 0),
+    (0xAF2, Status::Disallowed, 0, 0), (0xAF9, Status::Valid, 0, 0), (0xB00, Status::Disallowed, 0, 0),
+    (0xB01, Status::Valid, 0, 0), (0xB04, Status::Disallowed, 0, 0), (0xB05, Status::Valid, 0, 0),
+    (0xB0D, Status::Disallowed, 0, 0), (0xB0F, Status::Valid, 0, 0), (0xB11, Status::Disallowed, 0, 0),
+    (0xB13, Status::Valid, 0, 0), (0xB29, Status::Disallowed, 0, 0), (0xB2A, Status::Valid, 0, 0),
+    (0xB31, Status::Disallowed, 0, 0), (0xB32, Status::Valid, 0, 0), (0xB34, Status::Disallowed, 0, 0),
+    (0xB35, Status::Valid, 0, 0), (0xB3A, Status::Disallowed, 0, 0), (0xB3C, Status::Valid, 0, 0),
+    (0xB45, Status::Disallowed, 0, 0), (0xB47, Status::Valid, 0, 0), (0xB49, Status::Disallowed, 0, 0),
+    (0xB4B, Status::Valid, 0, 0), (0xB4E, Status::Disallowed, 0, 0), (0xB55, Status::Valid, 0, 0),
+    (0xB58, Status::Disallowed, 0, 0), (0xB5C, Status::Mapped, 1112, 6), (0xB5D, Status::Mapped, 1118, 6),
+    (0xB5E, Status::Disallowed, 0, 0), (0xB5F, Status::Valid, 0, 0), (0xB64, Status::Disallowed, 0, 0),
+    (0xB66, Status::Valid, 0, 0), (0xB78, Status::Disallowed, 0, 0), (0xB82, Status::Valid, 0, 0),
+    (0xB84, Status::Disallowed, 0, 0), (0xB85, Status::Valid, 0, 0), (0xB8B, Status::Disallowed, 0, 0),
+    (0xB8E, Status::Valid, 0, 0), (0xB91, Status::Disallowed, 0, 0), (0xB92, Status::Valid, 0, 0),
+    (0xB96, Status::Disallowed, 0, 0), (0xB99, Status::Valid, 0, 0), (0xB9B, Status::Disallowed, 0, 0),
+    (0xB9C, Status::Valid, 0, 0), (0xB9D, Status::Disallowed, 0, 0), (0xB9E, Status::Valid, 0, 0),
+    (0xBA0, Status::Disallowed, 0, 0), (0xBA3, Status::Valid, 0, 0), (0xBA5, Status::Disallowed, 0, 0),
+    (0xBA8, Status::Valid, 0, 0), (0xBAB, Status::Disallowed, 0, 0), (0xBAE, Status::Valid, 0, 0),
+    (0xBBA, Status::Disallowed, 0, 0), (0xBBE, Status::Valid, 0, 0), (0xBC3, Status::Disallowed, 0, 0),
+    (0xBC6, Status::Valid, 0, 0), (0xBC9, Status::Disallowed, 0, 0), (0xBCA, Status::Valid, 0, 0),
+    (0xBCE, Status::Disallowed, 0, 0), (0xBD0, Status::Valid, 0, 0), (0xBD1, Status::Disallowed, 0, 0),
+    (0xBD7, Status::Valid, 0, 0), (0xBD8, Status::Disallowed, 0, 0), (0xBE6, Status::Valid, 0, 0),
+    (0xBFB, Status::Disallowed, 0, 0), (0xC00, Status::Valid, 0, 0), (0xC0D, Status::Disallowed, 0, 0),
+    (0xC0E, Status::Valid, 0, 0), (0xC11, Status::Disallowed, 0, 0), (0xC12, Status::Valid, 0, 0),
+    (0xC29, Status::Disallowed, 0, 0), (0xC2A, Status::Valid, 0, 0), (0xC3A, Status::Disallowed, 0, 0),
+    (0xC3D, Status::Valid, 0, 0), (0xC45, Status::Disallowed, 0, 0), (0xC46, Status::Valid, 0, 0),
+    (0xC49, Status::Disallowed, 0, 0), (0xC4A, Status::Valid, 0, 0), (0xC4E, Status::Disallowed, 0, 0),
+    (0xC55, Status::Valid, 0, 0), (0xC57, Status::Disallowed, 0, 0), (0xC58, Status::Valid, 0, 0),
+    (0xC5B, Status::Disallowed, 0, 0), (0xC60, Status::Valid, 0, 0), (0xC64, Status::Disallowed, 0, 0),
+    (0xC66, Status::Valid, 0, 0), (0xC70, Status::Disallowed, 0, 0), (0xC77, Status::Valid, 0, 0),
+    (0xC8D, Status::Disallowed, 0, 0), (0xC8E, Status::Valid, 0, 0), (0xC91, Status::Disallowed, 0, 0),
+    (0xC92, Status::Valid, 0, 0), (0xCA9, Status::Disallowed, 0, 0), (0xCAA, Status::Valid, 0, 0),
+    (0xCB4, Status::Disallowed, 0, 0), (0xCB5, Status::Valid, 0, 0), (0xCBA, Status::Disallowed, 0, 0),
+    (0xCBC, Status::Valid, 0, 0), (0xCC5, Status::Disallowed, 0, 0), (0xCC6, Status::Valid, 0, 0),
+    (0xCC9, Status::Disallowed, 0, 0), (0xCCA, Status::Valid, 0, 0), (0xCCE, Status::Disallowed, 0, 0),
+    (0xCD5, Status::Valid, 0, 0), (0xCD7, Status::Disallowed, 0, 0), (0xCDE, Status::Valid, 0, 0),
+    (0xCDF, Status::Disallowed, 0, 0), (0xCE0, Status::Valid, 0, 0), (0xCE4, Status::Disallowed, 0, 0),
+    (0xCE6, Status::Valid, 0, 0), (0xCF0, Status::Disallowed, 0, 0), (0xCF1, Status::Valid, 0, 0),
+    (0xCF3, Status::Disallowed, 0, 0), (0xD00, Status::Valid, 0, 0), (0xD0D, Status::Disallowed, 0, 0),
+    (0xD0E, Status::Valid, 0, 0), (0xD11, Status::Disallowed, 0, 0), (0xD12, Status::Valid, 0, 0),
+    (0xD45, Status::Disallowed, 0, 0), (0xD46, Status::Valid, 0, 0), (0xD49, Status::Disallowed, 0, 0),
+    (0xD4A, Status::Valid, 0, 0), (0xD50, Status::Disallowed, 0, 0), (0xD54, Status::Valid, 0, 0),
+    (0xD64, Status::Disallowed, 0, 0), (0xD66, Status::Valid, 0, 0), (0xD80, Status::Disallowed, 0, 0),
+    (0xD81, Status::Valid, 0, 0), (0xD84, Status::Disallowed, 0, 0), (0xD85, Status::Valid, 0, 0),
+    (0xD97, Status::Disallowed, 0, 0), (0xD9A, Status::Valid, 0, 0), (0xDB2, Status::Disallowed, 0, 0),
+    (0xDB3, Status::Valid, 0, 0), (0xDBC, Status::Disallowed, 0, 0), (0xDBD, Status::Valid, 0, 0),
+    (0xDBE, Status::Disallowed, 0, 0), (0xDC0, Status::Valid, 0, 0), (0xDC7, Status::Disallowed, 0, 0),
+    (0xDCA, Status::Valid, 0, 0), (0xDCB, Status::Disallowed, 0, 0), (0xDCF, Status::Valid, 0, 0),
+    (0xDD5, Status::Disallowed, 0, 0), (0xDD6, Status::Valid, 0, 0), (0xDD7, Status::Disallowed, 0, 0),
+    (0xDD8, Status::Valid, 0, 0), (0xDE0, Status::Disallowed, 0, 0), (0xDE6, Status::Valid, 0, 0),
+    (0xDF0, Status::Disallowed, 0, 0), (0xDF2, Status::Valid, 0, 0), (0xDF5, Status::Disallowed, 0, 0),
+    (0xE01, Status::Valid, 0, 0), (0xE33, Status::Mapped, 1124, 6), (0xE34, Status::Valid, 0, 0),
+    (0xE3B, Status::Disallowed, 0, 0), (0xE3F, Status::Valid, 0, 0), (0xE5C, Status::Disallowed, 0, 0),
+    (0xE81, Status::Valid, 0, 0), (0xE83, Status::Disallowed, 0, 0), (0xE84, Status::Valid, 0, 0),
+    (0xE85, Status::Disallowed, 0, 0), (0xE86, Status::Valid, 0, 0), (0xE8B, Status::Disallowed, 0, 0),
+    (0xE8C, Status::Valid, 0, 0), (0xEA4, Status::Disallowed, 0, 0), (0xEA5, Status::Valid, 0, 0),
+    (0xEA6, Status::Disallowed, 0, 0), (0xEA7, Status::Valid, 0, 0), (0xEB3, Status::Mapped, 1130, 6),
+    (0xEB4, Status::Valid, 0, 0), (0xEBE, Status::Disallowed, 0, 0), (0xEC0, Status::Valid, 0, 0),
+    (0xEC5, Status::Disallowed, 0, 0), (0xEC6, Status::Valid, 0, 0), (0xEC7, Status::Disallowed, 0, 0),
+    (0xEC8, Status::Valid, 0, 0), (0xECE, Status::Disallowed, 0, 0), (0xED0, Status::Valid, 0, 0),
+    (0xEDA, Status::Disallowed, 0, 0), (0xEDC, Status::Mapped, 1136, 6), (0xEDD, Status::Mapped, 1142, 6),
+    (0xEDE, Status::Valid, 0, 0), (0xEE0, Status::Disallowed, 0, 0), (0xF00, Status::Valid, 0, 0),
+    (0xF0C, Status::Mapped, 1148, 3), (0xF0D, Status::Valid, 0, 0), (0xF43, Status::Mapped, 1151, 6),
+    (0xF44, Status::Valid, 0, 0), (0xF48, Status::Disallowed, 0, 0), (0xF49, Status::Valid, 0, 0),
+    (0xF4D, Status::Mapped, 1157, 6), (0xF4E, Status::Valid, 0, 0), (0xF52, Status::Mapped, 1163, 6),
+    (0xF53, Status::Valid, 0, 0), (0xF57, Status::Mapped, 1169, 6), (0xF58, Status::Valid, 0, 0),
+    (0xF5C, Status::Mapped, 1175, 6), (0xF5D, Status::Valid, 0, 0), (0xF69, Status::Mapped, 1181, 6),
+    (0xF6A, Status::Valid, 0, 0), (0xF6D, Status::Disallowed, 0, 0), (0xF71, Status::Valid, 0, 0),
+    (0xF73, Status::Mapped, 1187, 6), (0xF74, Status::Valid, 0, 0), (0xF75, Status::Mapped, 1193, 6),
+    (0xF76, Status::Mapped, 1199, 6), (0xF77, Status::Mapped, 1205, 9), (0xF78, Status::Mapped, 1214, 6),
+    (0xF79, Status::Mapped, 1220, 9), (0xF7A, Status::Valid, 0, 0), (0xF81, Status::Mapped, 1229, 6),
+    (0xF82, Status::Valid, 0, 0), (0xF93, Status::Mapped, 1235, 6), (0xF94, Status::Valid, 0, 0),
+    (0xF98, Status::Disallowed, 0, 0), (0xF99, Status::Valid, 0, 0), (0xF9D, Status::Mapped, 1241, 6),
+    (0xF9E, Status::Valid, 0, 0), (0xFA2, Status::Mapped, 1247, 6), (0xFA3, Status::Valid, 0, 0),
+    (0xFA7, Status::Mapped, 1253, 6), (0xFA8, Status::Valid, 0, 0), (0xFAC, Status::Mapped, 1259, 6),
+    (0xFAD, Status::Valid, 0, 0), (0xFB9, Status::Mapped, 1265, 6), (0xFBA, Status::Valid, 0, 0),
+    (0xFBD, Status::Disallowed, 0, 0), (0xFBE, Status::Valid, 0, 0), (0xFCD, Status::Disallowed, 0, 0),
+    (0xFCE, Status::Valid, 0, 0), (0xFDB, Status::Disallowed, 0, 0), (0x1000, Status::Valid, 0, 0),
+    (0x10A0, Status::Disallowed, 0, 0), (0x10C7, Status::Mapped, 1271, 3), (0x10C8, Status::Disallowed, 0, 0),
+    (0x10CD, Status::Mapped, 1274, 3), (0x10CE, Status::Disallowed, 0, 0), (0x10D0, Status::Valid, 0, 0),
+    (0x10FC, Status::Mapped, 1277, 3), (0x10FD, Status::Valid, 0, 0), (0x115F, Status::Disallowed, 0, 0),
+    (0x1161, Status::Valid, 0, 0), (0x1249, Status::Disallowed, 0, 0), (0x124A, Status::Valid, 0, 0),
+    (0x124E, Status::Disallowed, 0, 0), (0x1250, Status::Valid, 0, 0), (0x1257, Status::Disallowed, 0, 0),
+    (0x1258, Status::Valid, 0, 0), (0x1259, Status::Disallowed, 0, 0), (0x125A, Status::Valid, 0, 0),
+    (0x125E, Status::Disallowed, 0, 0), (0x1260, Status::Valid, 0, 0), (0x1289, Status::Disallowed, 0, 0),
+    (0x128A, Status::Valid, 0, 0), (0x128E, Status::Disallowed, 0, 0), (0x1290, Status::Valid, 0, 0),
+    (0x12B1, Status::Disallowed, 0, 0), (0x12B2, Status::Valid, 0, 0), (0x12B6, Status::Disallowed, 0, 0),
+    (0x12B8, Status::Valid, 0, 0), (0x12BF, Status::Disallowed, 0, 0), (0x12C0, Status::Valid, 0, 0),
+    (0x12C1, Status::Disallowed, 0, 0), (0x12C2, Status::Valid, 0, 0), (0x12C6, Status::Disallowed, 0, 0),
+    (0x12C8, Status::Valid, 0, 0), (0x12D7, Status::Disallowed, 0, 0), (0x12D8, Status::Valid, 0, 0),
+    (0x1311, Status::Disallowed, 0, 0), (0x1312, Status::Valid, 0, 0), (0x1316, Status::Disallowed, 0, 0),
+    (0x1318, Status::Valid, 0, 0), (0x135B, Status::Disallowed, 0, 0), (0x135D, Status::Valid, 0, 0),
+    (0x137D, Status::Disallowed, 0, 0), (0x1380, Status::Valid, 0, 0), (0x139A, Status::Disallowed, 0, 0),
+    (0x13A0, Status::Valid, 0, 0), (0x13F6, Status::Disallowed, 0, 0), (0x13F8, Status::Mapped, 1280, 3),
+    (0x13F9, Status::Mapped, 1283, 3), (0x13FA, Status::Mapped, 1286, 3), (0x13FB, Status::Mapped, 1289, 3),
+    (0x13FC, Status::Mapped, 1292, 3), (0x13FD, Status::Mapped, 1295, 3), (0x13FE, Status::Disallowed, 0, 0),
+    (0x1400, Status::Valid, 0, 0), (0x1680, Status::Disallowed, 0, 0), (0x1681, Status::Valid, 0, 0),
+    (0x169D, Status::Disallowed, 0, 0), (0x16A0, Status::Valid, 0, 0), (0x16F9, Status::Disallowed, 0, 0),
+    (0x1700, Status::Valid, 0, 0), (0x170D, Status::Disallowed, 0, 0), (0x170E, Status::Valid, 0, 0),
+    (0x1715, Status::Disallowed, 0, 0), (0x1720, Status::Valid, 0, 0), (0x1737, Status::Disallowed, 0, 0),
+    (0x1740, Status::Valid, 0, 0), (0x1754, Status::Disallowed, 0, 0), (0x1760, Status::Valid, 0, 0),
+    (0x176D, Status::Disallowed, 0, 0), (0x176E, Status::Valid, 0, 0), (0x1771, Status::Disallowed, 0, 0),
+    (0x1772, Status::Valid, 0, 0), (0x1774, Status::Disallowed, 0, 0), (0x1780, Status::Valid, 0, 0),
+    (0x17B4, Status::Disallowed, 0, 0), (0x17B6, Status::Valid, 0, 0), (0x17DE, Status::Disallowed, 0, 0),
+    (0x17E0, Status::Valid, 0, 0), (0x17EA, Status::Disallowed, 0, 0), (0x17F0, Status::Valid, 0, 0),
+    (0x17FA, Status::Disallowed, 0, 0), (0x1800, Status::Valid, 0, 0), (0x1806, Status::Disallowed, 0, 0),
+    (0x1807, Status::Valid, 0, 0), (0x180B, Status::Ignored, 0, 0), (0x180E, Status::Disallowed, 0, 0),
+    (0x1810, Status::Valid, 0, 0), (0x181A, Status::Disallowed, 0, 0), (0x1820, Status::Valid, 0, 0),
+    (0x1879, Status::Disallowed, 0, 0), (0x1880, Status::Valid, 0, 0), (0x18AB, Status::Disallowed, 0, 0),
+    (0x18B0, Status::Valid, 0, 0), (0x18F6, Status::Disallowed, 0, 0), (0x1900, Status::Valid, 0, 0),
+    (0x191F, Status::Disallowed, 0, 0), (0x1920, Status::Valid, 0, 0), (0x192C, Status::Disallowed, 0, 0),
+    (0x1930, Status::Valid, 0, 0), (0x193C, Status::Disallowed, 0, 0), (0x1940, Status::Valid, 0, 0),
+    (0x1941, Status::Disallowed, 0, 0), (0x1944, Status::Valid, 0, 0), (0x196E, Status::Disallowed, 0, 0),
+    (0x1970, Status::Valid, 0, 0), (0x1975, Status::Disallowed, 0, 0), (0x1980, Status::Valid, 0, 0),
+    (0x19AC, Status::Disallowed, 0, 0), (0x19B0, Status::Valid, 0, 0), (0x19CA, Status::Disallowed, 0, 0),
+    (0x19D0, Status::Valid, 0, 0), (0x19DB, Status::Disallowed, 0, 0), (0x19DE, Status::Valid, 0, 0),
+    (0x1A1C, Status::Disallowed, 0, 0), (0x1A1E, Status::Valid, 0, 0), (0x1A5F, Status::Disallowed, 0, 0),
+    (0x1A60, Status::Valid, 0, 0), (0x1A7D, Status::Disallowed, 0, 0), (0x1A7F, Status::Valid, 0, 0),
+    (0x1A8A, Status::Disallowed, 0, 0), (0x1A90, Status::Valid, 0, 0), (0x1A9A, Status::Disallowed, 0, 0),
+    (0x1AA0, Status::Valid, 0, 0), (0x1AAE, Status::Disallowed, 0, 0), (0x1AB0, Status::Valid, 0, 0),
+    (0x1AC1, Status::Disallowed, 0, 0), (0x1B00, Status::Valid, 0, 0), (0x1B4C, Status::Disallowed, 0, 0),
+    (0x1B50, Status::Valid, 0, 0), (0x1B7D, Status::Disallowed, 0, 0), (0x1B80, Status::Valid, 0, 0),
+    (0x1BF4, Status::Disallowed, 0, 0), (0x1BFC, Status::Valid, 0, 0), (0x1C38, Status::Disallowed, 0, 0),
+    (0x1C3B, Status::Valid, 0, 0), (0x1C4A, Status::Disallowed, 0, 0), (0x1C4D, Status::Valid, 0, 0),
+    (0x1C80, Status::Mapped, 656, 2), (0x1C81, Status::Mapped, 660, 2), (0x1C82, Status::Mapped, 680, 2),
+    (0x1C83, Status::Mapped, 686, 2), (0x1C84, Status::Mapped, 688, 2), (0x1C86, Status::Mapped, 704, 2),
+    (0x1C87, Status::Mapped, 718, 2), (0x1C88, Status::Mapped, 1298, 3), (0x1C89, Status::Disallowed, 0, 0),
+    (0x1C90, Status::Mapped, 1301, 3), (0x1C91, Status::Mapped, 1304, 3), (0x1C92, Status::Mapped, 1307, 3),
+    (0x1C93, Status::Mapped, 1310, 3), (0x1C94, Status::Mapped, 1313, 3), (0x1C95, Status::Mapped, 1316, 3),
+    (0x1C96, Status::Mapped, 1319, 3), (0x1C97, Status::Mapped, 1322, 3), (0x1C98, Status::Mapped, 1325, 3),
+    (0x1C99, Status::Mapped, 1328, 3), (0x1C9A, Status::Mapped, 1331, 3), (0x1C9B, Status::Mapped, 1334, 3),
+    (0x1C9C, Status::Mapped, 1277, 3), (0x1C9D, Status::Mapped, 1337, 3), (0x1C9E, Status::Mapped, 1340, 3),
+    (0x1C9F, Status::Mapped, 1343, 3), (0x1CA0, Status::Mapped, 1346, 3), (0x1CA1, Status::Mapped, 1349, 3),
+    (0x1CA2, Status::Mapped, 1352, 3), (0x1CA3, Status::Mapped, 1355, 3), (0x1CA4, Status::Mapped, 1358, 3),
+    (0x1CA5, Status::Mapped, 1361, 3), (0x1CA6, Status::Mapped, 1364, 3), (0x1CA7, Status::Mapped, 1367, 3),
+    (0x1CA8, Status::Mapped, 1370, 3), (0x1CA9, Status::Mapped, 1373, 3), (0x1CAA, Status::Mapped, 1376, 3),
+    (0x1CAB, Status::Mapped, 1379, 3), (0x1CAC, Status::Mapped, 1382, 3), (0x1CAD, Status::Mapped, 1385, 3),
+    (0x1CAE, Status::Mapped, 1388, 3), (0x1CAF, Status::Mapped, 1391, 3), (0x1CB0, Status::Mapped, 1394, 3),
+    (0x1CB1, Status::Mapped, 1397, 3), (0x1CB2, Status::Mapped, 1400, 3), (0x1CB3, Status::Mapped, 1403, 3),
+    (0x1CB4, Status::Mapped, 1406, 3), (0x1CB5, Status::Mapped, 1409, 3), (0x1CB6, Status::Mapped, 1412, 3),
+    (0x1CB7, Status::Mapped, 1415, 3), (0x1CB8, Status::Mapped, 1418, 3), (0x1CB9, Status::Mapped, 1421, 3),
+    (0x1CBA, Status::Mapped, 1424, 3), (0x1CBB, Status::Disallowed, 0, 0), (0x1CBD, Status::Mapped, 1427, 3),
+    (0x1CBE, Status::Mapped, 1430, 3), (0x1CBF, Status::Mapped, 1433, 3), (0x1CC0, Status::Valid, 0, 0),
+    (0x1CC8, Status::Disallowed, 0, 0), (0x1CD0, Status::Valid, 0, 0), (0x1CFB, Status::Disallowed, 0, 0),
+    (0x1D00, Status::Valid, 0, 0), (0x1D2C, Status::Mapped, 0, 1), (0x1D2D, Status::Mapped, 71, 2),
+    (0x1D2E, Status::Mapped, 1, 1), (0x1D2F, Status::Valid, 0, 0), (0x1D30, Status::Mapped, 3, 1),
+    (0x1D31, Status::Mapped, 4, 1), (0x1D32, Status::Mapped, 268, 2), (0x1D33, Status::Mapped, 6, 1),
+    (0x1D34, Status::Mapped, 7, 1), (0x1D35, Status::Mapped, 8, 1), (0x1D36, Status::Mapped, 9, 1),
+    (0x1D37, Status::Mapped, 10, 1), (0x1D38, Status::Mapped, 11, 1), (0x1D39, Status::Mapped, 12, 1),
+    (0x1D3A, Status::Mapped, 13, 1), (0x1D3B, Status::Valid, 0, 0), (0x1D3C, Status::Mapped, 14, 1),
+    (0x1D3D, Status::Mapped, 415, 2), (0x1D3E, Status::Mapped, 15, 1), (0x1D3F, Status::Mapped, 17, 1),
+    (0x1D40, Status::Mapped, 19, 1), (0x1D41, Status::Mapped, 20, 1), (0x1D42, Status::Mapped, 22, 1),
+    (0x1D43, Status::Mapped, 0, 1), (0x1D44, Status::Mapped, 1436, 2), (0x1D45, Status::Mapped, 1438, 2),
+    (0x1D46, Status::Mapped, 1440, 3), (0x1D47, Status::Mapped, 1, 1), (0x1D48, Status::Mapped, 3, 1),
+    (0x1D49, Status::Mapped, 4, 1), (0x1D4A, Status::Mapped, 270, 2), (0x1D4B, Status::Mapped, 272, 2),
+    (0x1D4C, Status::Mapped, 1443, 2), (0x1D4D, Status::Mapped, 6, 1), (0x1D4E, Status::Valid, 0, 0),
+    (0x1D4F, Status::Mapped, 10, 1), (0x1D50, Status::Mapped, 12, 1), (0x1D51, Status::Mapped, 198, 2),
+    (0x1D52, Status::Mapped, 14, 1), (0x1D53, Status::Mapped, 258, 2), (0x1D54, Status::Mapped, 1445, 3),
+    (0x1D55, Status::Mapped, 1448, 3), (0x1D56, Status::Mapped, 15, 1), (0x1D57, Status::Mapped, 19, 1),
+    (0x1D58, Status::Mapped, 20, 1), (0x1D59, Status::Mapped, 1451, 3), (0x1D5A, Status::Mapped, 286, 2),
+    (0x1D5B, Status::Mapped, 21, 1), (0x1D5C, Status::Mapped, 1454, 3), (0x1D5D, Status::Mapped, 538, 2),
+    (0x1D5E, Status::Mapped, 540, 2), (0x1D5F, Status::Mapped, 542, 2), (0x1D60, Status::Mapped, 572, 2),
+    (0x1D61, Status::Mapped, 574, 2), (0x1D62, Status::Mapped, 8, 1), (0x1D63, Status::Mapped, 17, 1),
+    (0x1D64, Status::Mapped, 20, 1), (0x1D65, Status::Mapped, 21, 1), (0x1D66, Status::Mapped, 538, 2),
+    (0x1D67, Status::Mapped, 540, 2), (0x1D68, Status::Mapped, 564, 2), (0x1D69, Status::Mapped, 572, 2),
+    (0x1D6A, Status::Mapped, 574, 2), (0x1D6B, Status::Valid, 0, 0), (0x1D78, Status::Mapped, 678, 2),
+    (0x1D79, Status::Valid, 0, 0), (0x1D9B, Status::Mapped, 1457, 2), (0x1D9C, Status::Mapped, 2, 1),
+    (0x1D9D, Status::Mapped, 1459, 2), (0x1D9E, Status::Mapped, 91, 2), (0x1D9F, Status::Mapped, 1443, 2),
+    (0x1DA0, Status::Mapped, 5, 1), (0x1DA1, Status::Mapped, 1461, 2), (0x1DA2, Status::Mapped, 1463, 2),
+    (0x1DA3, Status::Mapped, 1465, 2), (0x1DA4, Status::Mapped, 282, 2), (0x1DA5, Status::Mapped, 280, 2),
+    (0x1DA6, Status::Mapped, 1467, 2), (0x1DA7, Status::Mapped, 1469, 3), (0x1DA8, Status::Mapped, 1472, 2),
+    (0x1DA9, Status::Mapped, 1474, 2), (0x1DAA, Status::Mapped, 1476, 3), (0x1DAB, Status::Mapped, 1479, 2),
+    (0x1DAC, Status::Mapped, 1481, 2), (0x1DAD, Status::Mapped, 1483, 2), (0x1DAE, Status::Mapped, 288, 2),
+    (0x1DAF, Status::Mapped, 1485, 2), (0x1DB0, Status::Mapped, 1487, 2), (0x1DB1, Status::Mapped, 290, 2),
+    (0x1DB2, Status::Mapped, 1489, 2), (0x1DB3, Status::Mapped, 1491, 2), (0x1DB4, Status::Mapped, 302, 2),
+    (0x1DB5, Status::Mapped, 1493, 2), (0x1DB6, Status::Mapped, 447, 2), (0x1DB7, Status::Mapped, 310, 2),
+    (0x1DB8, Status::Mapped, 1495, 3), (0x1DB9, Status::Mapped, 312, 2), (0x1DBA, Status::Mapped, 449, 2),
+    (0x1DBB, Status::Mapped, 25, 1), (0x1DBC, Status::Mapped, 1498, 2), (0x1DBD, Status::Mapped, 1500, 2),
+    (0x1DBE, Status::Mapped, 318, 2), (0x1DBF, Status::Mapped, 550, 2), (0x1DC0, Status::Valid, 0, 0),
+    (0x1DFA, Status::Disallowed, 0, 0), (0x1DFB, Status::Valid, 0, 0), (0x1E00, Status::Mapped, 1502, 3),
+    (0x1E01, Status::Valid, 0, 0), (0x1E02, Status::Mapped, 1505, 3), (0x1E03, Status::Valid, 0, 0),
+    (0x1E04, Status::Mapped, 1508, 3), (0x1E05, Status::Valid, 0, 0), (0x1E06, Status::Mapped, 1511, 3),
+    (0x1E07, Status::Valid, 0, 0), (0x1E08, Status::Mapped, 1514, 3), (0x1E09, Status::Valid, 0, 0),
+    (0x1E0A, Status::Mapped, 1517, 3), (0x1E0B, Status::Valid, 0, 0), (0x1E0C, Status::Mapped, 1520, 3),
+    (0x1E0D, Status::Valid, 0, 0), (0x1E0E, Status::Mapped, 1523, 3), (0x1E0F, Status::Valid, 0, 0),
+    (0x1E10, Status::Mapped, 1526, 3), (0x1E11, Status::Valid, 0, 0), (0x1E12, Status::Mapped, 1529, 3),
+    (0x1E13, Status::Valid, 0, 0), (0x1E14, Status::Mapped, 1532, 3), (0x1E15, Status::Valid, 0, 0),
+    (0x1E16, Status::Mapped, 1535, 3), (0x1E17, Status::Valid, 0, 0), (0x1E18, Status::Mapped, 1538, 3),
+    (0x1E19, Status::Valid, 0, 0), (0x1E1A, Status::Mapped, 1541, 3), (0x1E1B, Status::Valid, 0, 0),
+    (0x1E1C, Status::Mapped, 1544, 3), (0x1E1D, Status::Valid, 0, 0), (0x1E1E, Status::Mapped, 1547, 3),
+    (0x1E1F, Status::Valid, 0, 0), (0x1E20, Status::Mapped, 1550, 3), (0x1E21, Status::Valid, 0, 0),
+    (0x1E22, Status::Mapped, 1553, 3), (0x1E23, Status::Valid, 0, 0), (0x1E24, Status::Mapped, 1556, 3),
+    (0x1E25, Status::Valid, 0, 0), (0x1E26, Status::Mapped, 1559, 3), (0x1E27, Status::Valid, 0, 0),
+    (0x1E28, Status::Mapped, 1562, 3), (0x1E29, Status::Valid, 0, 0), (0x1E2A, Status::Mapped, 1565, 3),
+    (0x1E2B, Status::Valid, 0, 0), (0x1E2C, Status::Mapped, 1568, 3), (0x1E2D, Status::Valid, 0, 0),
+    (0x1E2E, Status::Mapped, 1571, 3), (0x1E2F, Status::Valid, 0, 0), (0x1E30, Status::Mapped, 1574, 3),
+    (0x1E31, Status::Valid, 0, 0), (0x1E32, Status::Mapped, 1577, 3), (0x1E33, Status::Valid, 0, 0),
+    (0x1E34, Status::Mapped, 1580, 3), (0x1E35, Status::Valid, 0, 0), (0x1E36, Status::Mapped, 1583, 3),
+    (0x1E37, Status::Valid, 0, 0), (0x1E38, Status::Mapped, 1586, 3), (0x1E39, Status::Valid, 0, 0),
+    (0x1E3A, Status::Mapped, 1589, 3), (0x1E3B, Status::Valid, 0, 0), (0x1E3C, Status::Mapped, 1592, 3),
+    (0x1E3D, Status::Valid, 0, 0), (0x1E3E, Status::Mapped, 1595, 3), (0x1E3F, Status::Valid, 0, 0),
+    (0x1E40, Status::Mapped, 1598, 3), (0x1E41, Status::Valid, 0, 0), (0x1E42, Status::Mapped, 1601, 3),
+    (0x1E43, Status::Valid, 0, 0), (0x1E44, Status::Mapped, 1604, 3), (0x1E45, Status::Valid, 0, 0),
+    (0x1E46, Status::Mapped, 1607, 3), (0x1E47, Status::Valid, 0, 0), (0x1E48, Status::Mapped, 1610, 3),
+    (0x1E49, Status::Valid, 0, 0), (0x1E4A, Status::Mapped, 1613, 3), (0x1E4B, Status::Valid, 0, 0),
+    (0x1E4C, Status::Mapped, 1616, 3), (0x1E4D, Status::Valid, 0, 0), (0x1E4E, Status::Mapped, 1619, 3),
+    (0x1E4F, Status::Valid, 0, 0), (0x1E50, Status::Mapped, 1622, 3), (0x1E51, Status::Valid, 0, 0),
+    (0x1E52, Status::Mapped, 1625, 3), (0x1E53, Status::Valid, 0, 0), (0x1E54, Status::Mapped, 1628, 3),
+    (0x1E55, Status::Valid, 0, 0), (0x1E56, Status::Mapped, 1631, 3), (0x1E57, Status::Valid, 0, 0),
+    (0x1E58, Status::Mapped, 1634, 3), (0x1E59, Status::Valid, 0, 0), (0x1E5A, Status::Mapped, 1637, 3),
+    (0x1E5B, Status::Valid, 0, 0), (0x1E5C, Status::Mapped, 1640, 3), (0x1E5D, Status::Valid, 0, 0),
+    (0x1E5E, Status::Mapped, 1643, 3), (0x1E5F, Status::Valid, 0, 0), (0x1E60, Status::Mapped, 1646, 3),
+    (0x1E61, Status::Valid, 0, 0), (0x1E62, Status::Mapped, 1649, 3), (0x1E63, Status::Valid, 0, 0),
+    (0x1E64, Status::Mapped, 1652, 3), (0x1E65, Status::Valid, 0, 0), (0x1E66, Status::Mapped, 1655, 3),
+    (0x1E67, Status::Valid, 0, 0), (0x1E68, Status::Mapped, 1658, 3), (0x1E69, Status::Valid, 0, 0),
+    (0x1E6A, Status::Mapped, 1661, 3), (0x1E6B, Status::Valid, 0, 0), (0x1E6C, Status::Mapped, 1664, 3),
+    (0x1E6D, Status::Valid, 0, 0), (0x1E6E, Status::Mapped, 1667, 3), (0x1E6F, Status::Valid, 0, 0),
+    (0x1E70, Status::Mapped, 1670, 3), (0x1E71, Status::Valid, 0, 0), (0x1E72, Status::Mapped, 1673, 3),
+    (0x1E73, Status::Valid, 0, 0), (0x1E74, Status::Mapped, 1676, 3), (0x1E75, Status::Valid, 0, 0),
+    (0x1E76, Status::Mapped, 1679, 3), (0x1E77, Status::Valid, 0, 0), (0x1E78, Status::Mapped, 1682, 3),
+    (0x1E79, Status::Valid, 0, 0), (0x1E7A, Status::Mapped, 1685, 3), (0x1E7B, Status::Valid, 0, 0),
+    (0x1E7C, Status::Mapped, 1688, 3), (0x1E7D, Status::Valid, 0, 0), (0x1E7E, Status::Mapped, 1691, 3),
+    (0x1E7F, Status::Valid, 0, 0), (0x1E80, Status::Mapped, 1694, 3), (0x1E81, Status::Valid, 0, 0),
+    (0x1E82, Status::Mapped, 1697, 3), (0x1E83, Status::Valid, 0, 0), (0x1E84, Status::Mapped, 1700, 3),
+    (0x1E85, Status::Valid, 0, 0), (0x1E86, Status::Mapped, 1703, 3), (0x1E87, Status::Valid, 0, 0),
+    (0x1E88, Status::Mapped, 1706, 3), (0x1E89, Status::Valid, 0, 0), (0x1E8A, Status::Mapped, 1709, 3),
+    (0x1E8B, Status::Valid, 0, 0), (0x1E8C, Status::Mapped, 1712, 3), (0x1E8D, Status::Valid, 0, 0),
+    (0x1E8E, Status::Mapped, 1715, 3), (0x1E8F, Status::Valid, 0, 0), (0x1E90, Status::Mapped, 1718, 3),
+    (0x1E91, Status::Valid, 0, 0), (0x1E92, Status::Mapped, 1721, 3), (0x1E93, Status::Valid, 0, 0),
+    (0x1E94, Status::Mapped, 1724, 3), (0x1E95, Status::Valid, 0, 0), (0x1E9A, Status::Mapped, 1727, 3),
+    (0x1E9B, Status::Mapped, 1646, 3), (0x1E9C, Status::Valid, 0, 0), (0x1E9E, Status::Mapped, 119, 2),
+    (0x1E9F, Status::Valid, 0, 0), (0x1EA0, Status::Mapped, 1730, 3), (0x1EA1, Status::Valid, 0, 0),
+    (0x1EA2, Status::Mapped, 1733, 3), (0x1EA3, Status::Valid, 0, 0), (0x1EA4, Status::Mapped, 1736, 3),
+    (0x1EA5, Status::Valid, 0, 0), (0x1EA6, Status::Mapped, 1739, 3), (0x1EA7, Status::Valid, 0, 0),
+    (0x1EA8, Status::Mapped, 1742, 3), (0x1EA9, Status::Valid, 0, 0), (0x1EAA, Status::Mapped, 1745, 3),
+    (0x1EAB, Status::Valid, 0, 0), (0x1EAC, Status::Mapped, 1748, 3), (0x1EAD, Status::Valid, 0, 0),
+    (0x1EAE, Status::Mapped, 1751, 3), (0x1EAF, Status::Valid, 0, 0), (0x1EB0, Status::Mapped, 1754, 3),
+    (0x1EB1, Status::Valid, 0, 0), (0x1EB2, Status::Mapped, 1757, 3), (0x1EB3, Status::Valid, 0, 0),
+    (0x1EB4, Status::Mapped, 1760, 3), (0x1EB5, Status::Valid, 0, 0), (0x1EB6, Status::Mapped, 1763, 3),
+    (0x1EB7, Status::Valid, 0, 0), (0x1EB8, Status::Mapped, 1766, 3), (0x1EB9, Status::Valid, 0, 0),
+    (0x1EBA, Status::Mapped, 1769, 3), (0x1EBB, Status::Valid, 0, 0), (0x1EBC, Status::Mapped, 1772, 3),
+    (0x1EBD, Status::Valid, 0, 0), (0x1EBE, Status::Mapped, 1775, 3), (0x1EBF, Status::Valid, 0, 0),
+    (0x1EC0, Status::Mapped, 1778, 3), (0x1EC1, Status::Valid, 0, 0), (0x1EC2, Status::Mapped, 1781, 3),
+    (0x1EC3, Status::Valid, 0, 0), (0x1EC4, Status::Mapped, 1784, 3), (0x1EC5, Status::Valid, 0, 0),
+    (0x1EC6, Status::Mapped, 1787, 3), (0x1EC7, Status::Valid, 0, 0), (0x1EC8, Status::Mapped, 1790, 3),
+    (0x1EC9, Status::Valid, 0, 0), (0x1ECA, Status::Mapped, 1793, 3), (0x1ECB, Status::Valid, 0, 0),
+    (0x1ECC, Status::Mapped, 1796, 3), (0x1ECD, Status::Valid, 0, 0), (0x1ECE, Status::Mapped, 1799, 3),
+    (0x1ECF, Status::Valid, 0, 0), (0x1ED0, Status::Mapped, 1802, 3), (0x1ED1, Status::Valid, 0, 0),
+    (0x1ED2, Status::Mapped, 1805, 3), (0x1ED3, Status::Valid, 0, 0), (0x1ED4, Status::Mapped, 1808, 3),
+    (0x1ED5, Status::Valid, 0, 0), (0x1ED6, Status::Mapped, 1811, 3), (0x1ED7, Status::Valid, 0, 0),
+    (0x1ED8, Status::Mapped, 1814, 3), (0x1ED9, Status::Valid, 0, 0), (0x1EDA, Status::Mapped, 1817, 3),
+    (0x1EDB, Status::Valid, 0, 0), (0x1EDC, Status::Mapped, 1820, 3), (0x1EDD, Status::Valid, 0, 0),
+    (0x1EDE, Status::Mapped, 1823, 3), (0x1EDF, Status::Valid, 0, 0), (0x1EE0, Status::Mapped, 1826, 3),
+    (0x1EE1, Status::Valid, 0, 0), (0x1EE2, Status::Mapped, 1829, 3), (0x1EE3, Status::Valid, 0, 0),
+    (0x1EE4, Status::Mapped, 1832, 3), (0x1EE5, Status::Valid, 0, 0), (0x1EE6, Status::Mapped, 1835, 3),
+    (0x1EE7, Status::Valid, 0, 0), (0x1EE8, Status::Mapped, 1838, 3), (0x1EE9, Status::Valid, 0, 0),
+    (0x1EEA, Status::Mapped, 1841, 3), (0x1EEB, Status::Valid, 0, 0), (0x1EEC, Status::Mapped, 1844, 3),
+    (0x1EED, Status::Valid, 0, 0), (0x1EEE, Status::Mapped, 1847, 3), (0x1EEF, Status::Valid, 0, 0),
+    (0x1EF0, Status::Mapped, 1850, 3), (0x1EF1, Status::Valid, 0, 0), (0x1EF2, Status::Mapped, 1853, 3),
+    (0x1EF3, Status::Valid, 0, 0), (0x1EF4, Status::Mapped, 1856, 3), (0x1EF5, Status::Valid, 0, 0),
+    (0x1EF6, Status::Mapped, 1859, 3), (0x1EF7, Status::Valid, 0, 0), (0x1EF8, Status::Mapped, 1862, 3),
+    (0x1EF9, Status::Valid, 0, 0), (0x1EFA, Status::Mapped, 1865, 3), (0x1EFB, Status::Valid, 0, 0),
+    (0x1EFC, Status::Mapped, 1868, 3), (0x1EFD, Status::Valid, 0, 0), (0x1EFE, Status::Mapped, 1871, 3),
+    (0x1EFF, Status::Valid, 0, 0), (0x1F08, Status::Mapped, 1874, 3), (0x1F09, Status::Mapped, 1877, 3),
+    (0x1F0A, Status::Mapped, 1880, 3), (0x1F0B, Status::Mapped, 1883, 3), (0x1F0C, Status::Mapped, 1886, 3),
+    (0x1F0D, Status::Mapped, 1889, 3), (0x1F0E, Status::Mapped, 1892, 3), (0x1F0F, Status::Mapped, 1895, 3),
+    (0x1F10, Status::Valid, 0, 0), (0x1F16, Status::Disallowed, 0, 0), (0x1F18, Status::Mapped, 1898, 3),
+    (0x1F19, Status::Mapped, 1901, 3), (0x1F1A, Status::Mapped, 1904, 3), (0x1F1B, Status::Mapped, 1907, 3),
+    (0x1F1C, Status::Mapped, 1910, 3), (0x1F1D, Status::Mapped, 1913, 3), (0x1F1E, Status::Disallowed, 0, 0),
+    (0x1F20, Status::Valid, 0, 0), (0x1F28, Status::Mapped, 1916, 3), (0x1F29, Status::Mapped, 1919, 3),
+    (0x1F2A, Status::Mapped, 1922, 3), (0x1F2B, Status::Mapped, 1925, 3), (0x1F2C, Status::Mapped, 1928, 3),
+    (0x1F2D, Status::Mapped, 1931, 3), (0x1F2E, Status::Mapped, 1934, 3), (0x1F2F, Status::Mapped, 1937, 3),
+    (0x1F30, Status::Valid, 0, 0), (0x1F38, Status::Mapped, 1940, 3), (0x1F39, Status::Mapped, 1943, 3),
+    (0x1F3A, Status::Mapped, 1946, 3), (0x1F3B, Status::Mapped, 1949, 3), (0x1F3C, Status::Mapped, 1952, 3),
+    (0x1F3D, Status::Mapped, 1955, 3), (0x1F3E, Status::Mapped, 1958, 3), (0x1F3F, Status::Mapped, 1961, 3),
+    (0x1F40, Status::Valid, 0, 0), (0x1F46, Status::Disallowed, 0, 0), (0x1F48, Status::Mapped, 1964, 3),
+    (0x1F49, Status::Mapped, 1967, 3), (0x1F4A, Status::Mapped, 1970, 3), (0x1F4B, Status::Mapped, 1973, 3),
+    (0x1F4C, Status::Mapped, 1976, 3), (0x1F4D, Status::Mapped, 1979, 3), (0x1F4E, Status::Disallowed, 0, 0),
+    (0x1F50, Status::Valid, 0, 0), (0x1F58, Status::Disallowed, 0, 0), (0x1F59, Status::Mapped, 1982, 3),
+    (0x1F5A, Status::Disallowed, 0, 0), (0x1F5B, Status::Mapped, 1985, 3), (0x1F5C, Status::Disallowed, 0, 0),
+    (0x1F5D, Status::Mapped, 1988, 3), (0x1F5E, Status::Disallowed, 0, 0), (0x1F5F, Status::Mapped, 1991, 3),
+    (0x1F60, Status::Valid, 0, 0), (0x1F68, Status::Mapped, 1994, 3), (0x1F69, Status::Mapped, 1997, 3),
+    (0x1F6A, Status::Mapped, 2000, 3), (0x1F6B, Status::Mapped, 2003, 3), (0x1F6C, Status::Mapped, 2006, 3),
+    (0x1F6D, Status::Mapped, 2009, 3), (0x1F6E, Status::Mapped, 2012, 3), (0x1F6F, Status::Mapped, 2015, 3),
+    (0x1F70, Status::Valid, 0, 0), (0x1F71, Status::Mapped, 520, 2), (0x1F72, Status::Valid, 0, 0),
+    (0x1F73, Status::Mapped, 524, 2), (0x1F74, Status::Valid, 0, 0), (0x1F75, Status::Mapped, 526, 2),
+    (0x1F76, Status::Valid, 0, 0), (0x1F77, Status::Mapped, 528, 2), (0x1F78, Status::Valid, 0, 0),
+    (0x1F79, Status::Mapped, 530, 2), (0x1F7A, Status::Valid, 0, 0), (0x1F7B, Status::Mapped, 532, 2),
+    (0x1F7C, Status::Valid, 0, 0), (0x1F7D, Status::Mapped, 534, 2), (0x1F7E, Status::Disallowed, 0, 0),
+    (0x1F80, Status::Mapped, 2018, 5), (0x1F81, Status::Mapped, 2023, 5), (0x1F82, Status::Mapped, 2028, 5),
+    (0x1F83, Status::Mapped, 2033, 5), (0x1F84, Status::Mapped, 2038, 5), (0x1F85, Status::Mapped, 2043, 5),
+    (0x1F86, Status::Mapped, 2048, 5), (0x1F87, Status::Mapped, 2053, 5), (0x1F88, Status::Mapped, 2018, 5),
+    (0x1F89, Status::Mapped, 2023, 5), (0x1F8A, Status::Mapped, 2028, 5), (0x1F8B, Status::Mapped, 2033, 5),
+    (0x1F8C, Status::Mapped, 2038, 5), (0x1F8D, Status::Mapped, 2043, 5), (0x1F8E, Status::Mapped, 2048, 5),
+    (0x1F8F, Status::Mapped, 2053, 5), (0x1F90, Status::Mapped, 2058, 5), (0x1F91, Status::Mapped, 2063, 5),
+    (0x1F92, Status::Mapped, 2068, 5), (0x1F93, Status::Mapped, 2073, 5), (0x1F94, Status::Mapped, 2078, 5),
+    (0x1F95, Status::Mapped, 2083, 5), (0x1F96, Status::Mapped, 2088, 5), (0x1F97, Status::Mapped, 2093, 5),
+    (0x1F98, Status::Mapped, 2058, 5), (0x1F99, Status::Mapped, 2063, 5), (0x1F9A, Status::Mapped, 2068, 5),
+    (0x1F9B, Status::Mapped, 2073, 5), (0x1F9C, Status::Mapped, 2078, 5), (0x1F9D, Status::Mapped, 2083, 5),
+    (0x1F9E, Status::Mapped, 2088, 5), (0x1F9F, Status::Mapped, 2093, 5), (0x1FA0, Status::Mapped, 2098, 5),
+    (0x1FA1, Status::Mapped, 2103, 5), (0x1FA2, Status::Mapped, 2108, 5), (0x1FA3, Status::Mapped, 2113, 5),
+    (0x1FA4, Status::Mapped, 2118, 5), (0x1FA5, Status::Mapped, 2123, 5), (0x1FA6, Status::Mapped, 2128, 5),
+    (0x1FA7, Status::Mapped, 2133, 5), (0x1FA8, Status::Mapped, 2098, 5), (0x1FA9, Status::Mapped, 2103, 5),
+    (0x1FAA, Status::Mapped, 2108, 5), (0x1FAB, Status::Mapped, 2113, 5), (0x1FAC, Status::Mapped, 2118, 5),
+    (0x1FAD, Status::Mapped, 2123, 5), (0x1FAE, Status::Mapped, 2128, 5), (0x1FAF, Status::Mapped, 2133, 5),
+    (0x1FB0, Status::Valid, 0, 0), (0x1FB2, Status::Mapped, 2138, 5), (0x1FB3, Status::Mapped, 2143, 4),
+    (0x1FB4, Status::Mapped, 2147, 4), (0x1FB5, Status::Disallowed, 0, 0), (0x1FB6, Status::Valid, 0, 0),
+    (0x1FB7, Status::Mapped, 2151, 5), (0x1FB8, Status::Mapped, 2156, 3), (0x1FB9, Status::Mapped, 2159, 3),
+    (0x1FBA, Status::Mapped, 2162, 3), (0x1FBB, Status::Mapped, 520, 2), (0x1FBC, Status::Mapped, 2143, 4),
+    (0x1FBD, Status::DisallowedStd3Mapped, 2165, 3), (0x1FBE, Status::Mapped, 499, 2), (0x1FBF, Status::DisallowedStd3Mapped, 2165, 3),
+    (0x1FC0, Status::DisallowedStd3Mapped, 2168, 3), (0x1FC1, Status::DisallowedStd3Mapped, 2171, 5), (0x1FC2, Status::Mapped, 2176, 5),
+    (0x1FC3, Status::Mapped, 2181, 4), (0x1FC4, Status::Mapped, 2185, 4), (0x1FC5, Status::Disallowed, 0, 0),
+    (0x1FC6, Status::Valid, 0, 0), (0x1FC7, Status::Mapped, 2189, 5), (0x1FC8, Status::Mapped, 2194, 3),
+    (0x1FC9, Status::Mapped, 524, 2), (0x1FCA, Status::Mapped, 2197, 3), (0x1FCB, Status::Mapped, 526, 2),
+    (0x1FCC, Status::Mapped, 2181, 4), (0x1FCD, Status::DisallowedStd3Mapped, 2200, 5), (0x1FCE, Status::DisallowedStd3Mapped, 2205, 5),
+    (0x1FCF, Status::DisallowedStd3Mapped, 2210, 5), (0x1FD0, Status::Valid, 0, 0), (0x1FD3, Status::Mapped, 2215, 2),
+    (0x1FD4, Status::Disallowed, 0, 0), (0x1FD6, Status::Valid, 0, 0), (0x1FD8, Status::Mapped, 2217, 3),
+    (0x1FD9, Status::Mapped, 2220, 3), (0x1FDA, Status::Mapped, 2223, 3), (0x1FDB, Status::Mapped, 528, 2),
+    (0x1FDC, Status::Disallowed, 0, 0), (0x1FDD, Status::DisallowedStd3Mapped, 2226, 5), (0x1FDE, Status::DisallowedStd3Mapped, 2231, 5),
+    (0x1FDF, Status::DisallowedStd3Mapped, 2236, 5), (0x1FE0, Status::Valid, 0, 0), (0x1FE3, Status::Mapped, 2241, 2),
+    (0x1FE4, Status::Valid, 0, 0), (0x1FE8, Status::Mapped, 2243, 3), (0x1FE9, Status::Mapped, 2246, 3),
+    (0x1FEA, Status::Mapped, 2249, 3), (0x1FEB, Status::Mapped, 532, 2), (0x1FEC, Status::Mapped, 2252, 3),
+    (0x1FED, Status::DisallowedStd3Mapped, 2255, 5), (0x1FEE, Status::DisallowedStd3Mapped, 515, 5), (0x1FEF, Status::DisallowedStd3Mapped, 2260, 1),
+    (0x1FF0, Status::Disallowed, 0, 0), (0x1FF2, Status::Mapped, 2261, 5), (0x1FF3, Status::Mapped, 2266, 4),
+    (0x1FF4, Status::Mapped, 2270, 4), (0x1FF5, Status::Disallowed, 0, 0), (0x1FF6, Status::Valid, 0, 0),
+    (0x1FF7, Status::Mapped, 2274, 5), (0x1FF8, Status::Mapped, 2279, 3), (0x1FF9, Status::Mapped, 530, 2),
+    (0x1FFA, Status::Mapped, 2282, 3), (0x1FFB, Status::Mapped, 534, 2), (0x1FFC, Status::Mapped, 2266, 4),
+    (0x1FFD, Status::DisallowedStd3Mapped, 35, 3), (0x1FFE, Status::DisallowedStd3Mapped, 2285, 3), (0x1FFF, Status::Disallowed, 0, 0),
+    (0x2000, Status::DisallowedStd3Mapped, 26, 1), (0x200B, Status::Ignored, 0, 0), (0x200C, Status::Deviation, 0, 0),
+    (0x200E, Status::Disallowed, 0, 0), (0x2010, Status::Valid, 0, 0), (0x2011, Status::Mapped, 2288, 3),
+    (0x2012, Status::Valid, 0, 0), (0x2017, Status::DisallowedStd3Mapped, 2291, 3), (0x2018, Status::Valid, 0, 0),
+    (0x2024, Status::Disallowed, 0, 0), (0x2027, Status::Valid, 0, 0), (0x2028, Status::Disallowed, 0, 0),
+    (0x202F, Status::DisallowedStd3Mapped, 26, 1), (0x2030, Status::Valid, 0, 0), (0x2033, Status::Mapped, 2294, 6),
+    (0x2034, Status::Mapped, 2300, 9), (0x2035, Status::Valid, 0, 0), (0x2036, Status::Mapped, 2309, 6),
+    (0x2037, Status::Mapped, 2315, 9), (0x2038, Status::Valid, 0, 0), (0x203C, Status::DisallowedStd3Mapped, 2324, 2),
+    (0x203D, Status::Valid, 0, 0), (0x203E, Status::DisallowedStd3Mapped, 2326, 3), (0x203F, Status::Valid, 0, 0),
+    (0x2047, Status::DisallowedStd3Mapped, 2329, 2), (0x2048, Status::DisallowedStd3Mapped, 2331, 2), (0x2049, Status::DisallowedStd3Mapped, 2333, 2),
+    (0x204A, Status::Valid, 0, 0), (0x2057, Status::Mapped, 2335, 12), (0x2058, Status::Valid, 0, 0),
+    (0x205F, Status::DisallowedStd3Mapped, 26, 1), (0x2060, Status::Ignored, 0, 0), (0x2061, Status::Disallowed, 0, 0),
+    (0x2064, Status::Ignored, 0, 0), (0x2065, Status::Disallowed, 0, 0), (0x2070, Status::Mapped, 2347, 1),
+    (0x2071, Status::Mapped, 8, 1), (0x2072, Status::Disallowed, 0, 0), (0x2074, Status::Mapped, 2348, 1),
+    (0x2075, Status::Mapped, 2349, 1), (0x2076, Status::Mapped, 2350, 1), (0x2077, Status::Mapped, 2351, 1),
+    (0x2078, Status::Mapped, 2352, 1), (0x2079, Status::Mapped, 2353, 1), (0x207A, Status::DisallowedStd3Mapped, 2354, 1),
+    (0x207B, Status::Mapped, 2355, 3), (0x207C, Status::DisallowedStd3Mapped, 2358, 1), (0x207D, Status::DisallowedStd3Mapped, 2359, 1),
+    (0x207E, Status::DisallowedStd3Mapped, 2360, 1), (0x207F, Status::Mapped, 13, 1), (0x2080, Status::Mapped, 2347, 1),
+    (0x2081, Status::Mapped, 43, 1), (0x2082, Status::Mapped, 33, 1), (0x2083, Status::Mapped, 34, 1),
+    (0x2084, Status::Mapped, 2348, 1), (0x2085, Status::Mapped, 2349, 1), (0x2086, Status::Mapped, 2350, 1),
+    (0x2087, Status::Mapped, 2351, 1), (0x2088, Status::Mapped, 2352, 1), (0x2089, Status::Mapped, 2353, 1),
+    (0x208A, Status::DisallowedStd3Mapped, 2354, 1), (0x208B, Status::Mapped, 2355, 3), (0x208C, Status::DisallowedStd3Mapped, 2358, 1),
+    (0x208D, Status::DisallowedStd3Mapped, 2359, 1), (0x208E, Status::DisallowedStd3Mapped, 2360, 1), (0x208F, Status::Disallowed, 0, 0),
+    (0x2090, Status::Mapped, 0, 1), (0x2091, Status::Mapped, 4, 1), (0x2092, Status::Mapped, 14, 1),
+    (0x2093, Status::Mapped, 23, 1), (0x2094, Status::Mapped, 270, 2), (0x2095, Status::Mapped, 7, 1),
+    (0x2096, Status::Mapped, 10, 1), (0x2097, Status::Mapped, 11, 1), (0x2098, Status::Mapped, 12, 1),
+    (0x2099, Status::Mapped, 13, 1), (0x209A, Status::Mapped, 15, 1), (0x209B, Status::Mapped, 18, 1),
+    (0x209C, Status::Mapped, 19, 1), (0x209D, Status::Disallowed, 0, 0), (0x20A0, Status::Valid, 0, 0),
+    (0x20A8, Status::Mapped, 2361, 2), (0x20A9, Status::Valid, 0, 0), (0x20C0, Status::Disallowed, 0, 0),
+    (0x20D0, Status::Valid, 0, 0), (0x20F1, Status::Disallowed, 0, 0), (0x2100, Status::DisallowedStd3Mapped, 2363, 3),
+    (0x2101, Status::DisallowedStd3Mapped, 2366, 3), (0x2102, Status::Mapped, 2, 1), (0x2103, Status::Mapped, 2369, 3),
+    (0x2104, Status::Valid, 0, 0), (0x2105, Status::DisallowedStd3Mapped, 2372, 3), (0x2106, Status::DisallowedStd3Mapped, 2375, 3),
+    (0x2107, Status::Mapped, 272, 2), (0x2108, Status::Valid, 0, 0), (0x2109, Status::Mapped, 2378, 3),
+    (0x210A, Status::Mapped, 6, 1), (0x210B, Status::Mapped, 7, 1), (0x210F, Status::Mapped, 159, 2),
+    (0x2110, Status::Mapped, 8, 1), (0x2112, Status::Mapped, 11, 1), (0x2114, Status::Valid, 0, 0),
+    (0x2115, Status::Mapped, 13, 1), (0x2116, Status::Mapped, 2381, 2), (0x2117, Status::Valid, 0, 0),
+    (0x2119, Status::Mapped, 15, 1), (0x211A, Status::Mapped, 16, 1), (0x211B, Status::Mapped, 17, 1),
+    (0x211E, Status::Valid, 0, 0), (0x2120, Status::Mapped, 2383, 2), (0x2121, Status::Mapped, 2385, 3),
+    (0x2122, Status::Mapped, 2388, 2), (0x2123, Status::Valid, 0, 0), (0x2124, Status::Mapped, 25, 1),
+    (0x2125, Status::Valid, 0, 0), (0x2126, Status::Mapped, 578, 2), (0x2127, Status::Valid, 0, 0),
+    (0x2128, Status::Mapped, 25, 1), (0x2129, Status::Valid, 0, 0), (0x212A, Status::Mapped, 10, 1),
+    (0x212B, Status::Mapped, 69, 2), (0x212C, Status::Mapped, 1, 1), (0x212D, Status::Mapped, 2, 1),
+    (0x212E, Status::Valid, 0, 0), (0x212F, Status::Mapped, 4, 1), (0x2131, Status::Mapped, 5, 1),
+    (0x2132, Status::Disallowed, 0, 0), (0x2133, Status::Mapped, 12, 1), (0x2134, Status::Mapped, 14, 1),
+    (0x2135, Status::Mapped, 2390, 2), (0x2136, Status::Mapped, 2392, 2), (0x2137, Status::Mapped, 2394, 2),
+    (0x2138, Status::Mapped, 2396, 2), (0x2139, Status::Mapped, 8, 1), (0x213A, Status::Valid, 0, 0),
+    (0x213B, Status::Mapped, 2398, 3), (0x213C, Status::Mapped, 562, 2), (0x213D, Status::Mapped, 540, 2),
+    (0x213F, Status::Mapped, 562, 2), (0x2140, Status::Mapped, 2401, 3), (0x2141, Status::Valid, 0, 0),
+    (0x2145, Status::Mapped, 3, 1), (0x2147, Status::Mapped, 4, 1), (0x2148, Status::Mapped, 8, 1),
+    (0x2149, Status::Mapped, 9, 1), (0x214A, Status::Valid, 0, 0), (0x2150, Status::Mapped, 2404, 5),
+    (0x2151, Status::Mapped, 2409, 5), (0x2152, Status::Mapped, 2414, 6), (0x2153, Status::Mapped, 2420, 5),
+    (0x2154, Status::Mapped, 2425, 5), (0x2155, Status::Mapped, 2430, 5), (0x2156, Status::Mapped, 2435, 5),
+    (0x2157, Status::Mapped, 2440, 5), (0x2158, Status::Mapped, 2445, 5), (0x2159, Status::Mapped, 2450, 5),
+    (0x215A, Status::Mapped, 2455, 5), (0x215B, Status::Mapped, 2460, 5), (0x215C, Status::Mapped, 2465, 5),
+    (0x215D, Status::Mapped, 2470, 5), (0x215E, Status::Mapped, 2475, 5), (0x215F, Status::Mapped, 2480, 4),
+    (0x2160, Status::Mapped, 8, 1), (0x2161, Status::Mapped, 2484, 2), (0x2162, Status::Mapped, 2486, 3),
+    (0x2163, Status::Mapped, 2489, 2), (0x2164, Status::Mapped, 21, 1), (0x2165, Status::Mapped, 2491, 2),
+    (0x2166, Status::Mapped, 2493, 3), (0x2167, Status::Mapped, 2496, 4), (0x2168, Status::Mapped, 2500, 2),
+    (0x2169, Status::Mapped, 23, 1), (0x216A, Status::Mapped, 2502, 2), (0x216B, Status::Mapped, 2504, 3),
+    (0x216C, Status::Mapped, 11, 1), (0x216D, Status::Mapped, 2, 1), (0x216E, Status::Mapped, 3, 1),
+    (0x216F, Status::Mapped, 12, 1), (0x2170, Status::Mapped, 8, 1), (0x2171, Status::Mapped, 2484, 2),
+    (0x2172, Status::Mapped, 2486, 3), (0x2173, Status::Mapped, 2489, 2), (0x2174, Status::Mapped, 21, 1),
+    (0x2175, Status::Mapped, 2491, 2), (0x2176, Status::Mapped, 2493, 3), (0x2177, Status::Mapped, 2496, 4),
+    (0x2178, Status::Mapped, 2500, 2), (0x2179, Status::Mapped, 23, 1), (0x217A, Status::Mapped, 2502, 2),
+    (0x217B, Status::Mapped, 2504, 3), (0x217C, Status::Mapped, 11, 1), (0x217D, Status::Mapped, 2, 1),
+    (0x217E, Status::Mapped, 3, 1), (0x217F, Status::Mapped, 12, 1), (0x2180, Status::Valid, 0, 0),
+    (0x2183, Status::Disallowed, 0, 0), (0x2184, Status::Valid, 0, 0), (0x2189, Status::Mapped, 2507, 5),
+    (0x218A, Status::Valid, 0, 0), (0x218C, Status::Disallowed, 0, 0), (0x2190, Status::Valid, 0, 0),
+    (0x222C, Status::Mapped, 2512, 6), (0x222D, Status::Mapped, 2518, 9), (0x222E, Status::Valid, 0, 0),
+    (0x222F, Status::Mapped, 2527, 6), (0x2230, Status::Mapped, 2533, 9), (0x2231, Status::Valid, 0, 0),
+    (0x2260, Status::DisallowedStd3Valid, 0, 0), (0x2261, Status::Valid, 0, 0), (0x226E, Status::DisallowedStd3Valid, 0, 0),
+    (0x2270, Status::Valid, 0, 0), (0x2329, Status::Mapped, 2542, 3), (0x232A, Status::Mapped, 2545, 3),
+    (0x232B, Status::Valid, 0, 0), (0x2427, Status::Disallowed, 0, 0), (0x2440, Status::Valid, 0, 0),
+    (0x244B, Status::Disallowed, 0, 0), (0x2460, Status::Mapped, 43, 1), (0x2461, Status::Mapped, 33, 1),
+    (0x2462, Status::Mapped, 34, 1), (0x2463, Status::Mapped, 2348, 1), (0x2464, Status::Mapped, 2349, 1),
+    (0x2465, Status::Mapped, 2350, 1), (0x2466, Status::Mapped, 2351, 1), (0x2467, Status::Mapped, 2352, 1),
+    (0x2468, Status::Mapped, 2353, 1), (0x2469, Status::Mapped, 2548, 2), (0x246A, Status::Mapped, 2550, 2),
+    (0x246B, Status::Mapped, 2552, 2), (0x246C, Status::Mapped, 2554, 2), (0x246D, Status::Mapped, 2556, 2),
+    (0x246E, Status::Mapped, 2558, 2), (0x246F, Status::Mapped, 2560, 2), (0x2470, Status::Mapped, 2562, 2),
+    (0x2471, Status::Mapped, 2564, 2), (0x2472, Status::Mapped, 2566, 2), (0x2473, Status::Mapped, 2568, 2),
+    (0x2474, Status::DisallowedStd3Mapped, 2570, 3), (0x2475, Status::DisallowedStd3Mapped, 2573, 3), (0x2476, Status::DisallowedStd3Mapped, 2576, 3),
+    (0x2477, Status::DisallowedStd3Mapped, 2579, 3), (0x2478, Status::DisallowedStd3Mapped, 2582, 3), (0x2479, Status::DisallowedStd3Mapped, 2585, 3),
+    (0x247A, Status::DisallowedStd3Mapped, 2588, 3), (0x247B, Status::DisallowedStd3Mapped, 2591, 3), (0x247C, Status::DisallowedStd3Mapped, 2594, 3),
+    (0x247D, Status::DisallowedStd3Mapped, 2597, 4), (0x247E, Status::DisallowedStd3Mapped, 2601, 4), (0x247F, Status::DisallowedStd3Mapped, 2605, 4),
+    (0x2480, Status::DisallowedStd3Mapped, 2609, 4), (0x2481, Status::DisallowedStd3Mapped, 2613, 4), (0x2482, Status::DisallowedStd3Mapped, 2617, 4),
+    (0x2483, Status::DisallowedStd3Mapped, 2621, 4), (0x2484, Status::DisallowedStd3Mapped, 2625, 4), (0x2485, Status::DisallowedStd3Mapped, 2629, 4),
+    (0x2486, Status::DisallowedStd3Mapped, 2633, 4), (0x2487, Status::DisallowedStd3Mapped, 2637, 4), (0x2488, Status::Disallowed, 0, 0),
+    (0x249C, Status::DisallowedStd3Mapped, 2641, 3), (0x249D, Status::DisallowedStd3Mapped, 2644, 3), (0x249E, Status::DisallowedStd3Mapped, 2647, 3),
+    (0x249F, Status::DisallowedStd3Mapped, 2650, 3), (0x24A0, Status::DisallowedStd3Mapped, 2653, 3), (0x24A1, Status::DisallowedStd3Mapped, 2656, 3),
+    (0x24A2, Status::DisallowedStd3Mapped, 2659, 3), (0x24A3, Status::DisallowedStd3Mapped, 2662, 3), (0x24A4, Status::DisallowedStd3Mapped, 2665, 3),
+    (0x24A5, Status::DisallowedStd3Mapped, 2668, 3), (0x24A6, Status::DisallowedStd3Mapped, 2671, 3), (0x24A7, Status::DisallowedStd3Mapped, 2674, 3),
+    (0x24A8, Status::DisallowedStd3Mapped, 2677, 3), (0x24A9, Status::DisallowedStd3Mapped, 2680, 3), (0x24AA, Status::DisallowedStd3Mapped, 2683, 3),
+    (0x24AB, Status::DisallowedStd3Mapped, 2686, 3), (0x24AC, Status::DisallowedStd3Mapped, 2689, 3), (0x24AD, Status::DisallowedStd3Mapped, 2692, 3),
+    (0x24AE, Status::DisallowedStd3Mapped, 2695, 3), (0x24AF, Status::DisallowedStd3Mapped, 2698, 3), (0x24B0, Status::DisallowedStd3Mapped, 2701, 3),
+    (0x24B1, Status::DisallowedStd3Mapped, 2704, 3), (0x24B2, Status::DisallowedStd3Mapped, 2707, 3), (0x24B3, Status::DisallowedStd3Mapped, 2710, 3),
+    (0x24B4, Status::DisallowedStd3Mapped, 2713, 3), (0x24B5, Status::DisallowedStd3Mapped, 2716, 3), (0x24B6, Status::Mapped, 0, 1),
+    (0x24B7, Status::Mapped, 1, 1), (0x24B8, Status::Mapped, 2, 1), (0x24B9, Status::Mapped, 3, 1),
+    (0x24BA, Status::Mapped, 4, 1), (0x24BB, Status::Mapped, 5, 1), (0x24BC, Status::Mapped, 6, 1),
+    (0x24BD, Status::Mapped, 7, 1), (0x24BE, Status::Mapped, 8, 1), (0x24BF, Status::Mapped, 9, 1),
+    (0x24C0, Status::Mapped, 10, 1), (0x24C1, Status::Mapped, 11, 1), (0x24C2, Status::Mapped, 12, 1),
+    (0x24C3, Status::Mapped, 13, 1), (0x24C4, Status::Mapped, 14, 1), (0x24C5, Status::Mapped, 15, 1),
+    (0x24C6, Status::Mapped, 16, 1), (0x24C7, Status::Mapped, 17, 1), (0x24C8, Status::Mapped, 18, 1),
+    (0x24C9, Status::Mapped, 19, 1), (0x24CA, Status::Mapped, 20, 1), (0x24CB, Status::Mapped, 21, 1),
+    (0x24CC, Status::Mapped, 22, 1), (0x24CD, Status::Mapped, 23, 1), (0x24CE, Status::Mapped, 24, 1),
+    (0x24CF, Status::Mapped, 25, 1), (0x24D0, Status::Mapped, 0, 1), (0x24D1, Status::Mapped, 1, 1),
+    (0x24D2, Status::Mapped, 2, 1), (0x24D3, Status::Mapped, 3, 1), (0x24D4, Status::Mapped, 4, 1),
+    (0x24D5, Status::Mapped, 5, 1), (0x24D6, Status::Mapped, 6, 1), (0x24D7, Status::Mapped, 7, 1),
+    (0x24D8, Status::Mapped, 8, 1), (0x24D9, Status::Mapped, 9, 1), (0x24DA, Status::Mapped, 10, 1),
+    (0x24DB, Status::Mapped, 11, 1), (0x24DC, Status::Mapped, 12, 1), (0x24DD, Status::Mapped, 13, 1),
+    (0x24DE, Status::Mapped, 14, 1), (0x24DF, Status::Mapped, 15, 1), (0x24E0, Status::Mapped, 16, 1),
+    (0x24E1, Status::Mapped, 17, 1), (0x24E2, Status::Mapped, 18, 1), (0x24E3, Status::Mapped, 19, 1),
+    (0x24E4, Status::Mapped, 20, 1), (0x24E5, Status::Mapped, 21, 1), (0x24E6, Status::Mapped, 22, 1),
+    (0x24E7, Status::Mapped, 23, 1), (0x24E8, Status::Mapped, 24, 1), (0x24E9, Status::Mapped, 25, 1),
+    (0x24EA, Status::Mapped, 2347, 1), (0x24EB, Status::Valid, 0, 0), (0x2A0C, Status::Mapped, 2719, 12),
+    (0x2A0D, Status::Valid, 0, 0), (0x2A74, Status::DisallowedStd3Mapped, 2731, 3), (0x2A75, Status::DisallowedStd3Mapped, 2734, 2),
+    (0x2A76, Status::DisallowedStd3Mapped, 2736, 3), (0x2A77, Status::Valid, 0, 0), (0x2ADC, Status::Mapped, 2739, 5),
+    (0x2ADD, Status::Valid, 0, 0), (0x2B74, Status::Disallowed, 0, 0), (0x2B76, Status::Valid, 0, 0),
+    (0x2B96, Status::Disallowed, 0, 0), (0x2B97, Status::Valid, 0, 0), (0x2C00, Status::Mapped, 2744, 3),
+    (0x2C01, Status::Mapped, 2747, 3), (0x2C02, Status::Mapped, 2750, 3), (0x2C03, Status::Mapped, 2753, 3),
+    (0x2C04, Status::Mapped, 2756, 3), (0x2C05, Status::Mapped, 2759, 3), (0x2C06, Status::Mapped, 2762, 3),
+    (0x2C07, Status::Mapped, 2765, 3), (0x2C08, Status::Mapped, 2768, 3), (0x2C09, Status::Mapped, 2771, 3),
+    (0x2C0A, Status::Mapped, 2774, 3), (0x2C0B, Status::Mapped, 2777, 3), (0x2C0C, Status::Mapped, 2780, 3),
+    (0x2C0D, Status::Mapped, 2783, 3), (0x2C0E, Status::Mapped, 2786, 3), (0x2C0F, Status::Mapped, 2789, 3),
+    (0x2C10, Status::Mapped, 2792, 3), (0x2C11, Status::Mapped, 2795, 3), (0x2C12, Status::Mapped, 2798, 3),
+    (0x2C13, Status::Mapped, 2801, 3), (0x2C14, Status::Mapped, 2804, 3), (0x2C15, Status::Mapped, 2807, 3),
+    (0x2C16, Status::Mapped, 2810, 3), (0x2C17, Status::Mapped, 2813, 3), (0x2C18, Status::Mapped, 2816, 3),
+    (0x2C19, Status::Mapped, 2819, 3), (0x2C1A, Status::Mapped, 2822, 3), (0x2C1B, Status::Mapped, 2825, 3),
+    (0x2C1C, Status::Mapped, 2828, 3), (0x2C1D, Status::Mapped, 2831, 3), (0x2C1E, Status::Mapped, 2834, 3),
+    (0x2C1F, Status::Mapped, 2837, 3), (0x2C20, Status::Mapped, 2840, 3), (0x2C21, Status::Mapped, 2843, 3),
+    (0x2C22, Status::Mapped, 2846, 3), (0x2C23, Status::Mapped, 2849, 3), (0x2C24, Status::Mapped, 2852, 3),
+    (0x2C25, Status::Mapped, 2855, 3), (0x2C26, Status::Mapped, 2858, 3), (0x2C27, Status::Mapped, 2861, 3),
+    (0x2C28, Status::Mapped, 2864, 3), (0x2C29, Status::Mapped, 2867, 3), (0x2C2A, Status::Mapped, 2870, 3),
+    (0x2C2B, Status::Mapped, 2873, 3), (0x2C2C, Status::Mapped, 2876, 3), (0x2C2D, Status::Mapped, 2879, 3),
+    (0x2C2E, Status::Mapped, 2882, 3), (0x2C2F, Status::Disallowed, 0, 0), (0x2C30, Status::Valid, 0, 0),
+    (0x2C5F, Status::Disallowed, 0, 0), (0x2C60, Status::Mapped, 2885, 3), (0x2C61, Status::Valid, 0, 0),
+    (0x2C62, Status::Mapped, 2888, 2), (0x2C63, Status::Mapped, 2890, 3), (0x2C64, Status::Mapped, 2893, 2),
+    (0x2C65, Status::Valid, 0, 0), (0x2C67, Status::Mapped, 2895, 3), (0x2C68, Status::Valid, 0, 0),
+    (0x2C69, Status::Mapped, 2898, 3), (0x2C6A, Status::Valid, 0, 0), (0x2C6B, Status::Mapped, 2901, 3),
+    (0x2C6C, Status::Valid, 0, 0), (0x2C6D, Status::Mapped, 1438, 2), (0x2C6E, Status::Mapped, 1481, 2),
+    (0x2C6F, Status::Mapped, 1436, 2), (0x2C70, Status::Mapped, 1457, 2), (0x2C71, Status::Valid, 0, 0),
+    (0x2C72, Status::Mapped, 2904, 3), (0x2C73, Status::Valid, 0, 0), (0x2C75, Status::Mapped, 2907, 3),
+    (0x2C76, Status::Valid, 0, 0), (0x2C7C, Status::Mapped, 9, 1), (0x2C7D, Status::Mapped, 21, 1),
+    (0x2C7E, Status::Mapped, 2910, 2), (0x2C7F, Status::Mapped, 2912, 2), (0x2C80, Status::Mapped, 2914, 3),
+    (0x2C81, Status::Valid, 0, 0), (0x2C82, Status::Mapped, 2917, 3), (0x2C83, Status::Valid, 0, 0),
+    (0x2C84, Status::Mapped, 2920, 3), (0x2C85, Status::Valid, 0, 0), (0x2C86, Status::Mapped, 2923, 3),
+    (0x2C87, Status::Valid, 0, 0), (0x2C88, Status::Mapped, 2926, 3), (0x2C89, Status::Valid, 0, 0),
+    (0x2C8A, Status::Mapped, 2929, 3), (0x2C8B, Status::Valid, 0, 0), (0x2C8C, Status::Mapped, 2932, 3),
+    (0x2C8D, Status::Valid, 0, 0), (0x2C8E, Status::Mapped, 2935, 3), (0x2C8F, Status::Valid, 0, 0),
+    (0x2C90, Status::Mapped, 2938, 3), (0x2C91, Status::Valid, 0, 0), (0x2C92, Status::Mapped, 2941, 3),
+    (0x2C93, Status::Valid, 0, 0), (0x2C94, Status::Mapped, 2944, 3), (0x2C95, Status::Valid, 0, 0),
+    (0x2C96, Status::Mapped, 2947, 3), (0x2C97, Status::Valid, 0, 0), (0x2C98, Status::Mapped, 2950, 3),
+    (0x2C99, Status::Valid, 0, 0), (0x2C9A, Status::Mapped, 2953, 3), (0x2C9B, Status::Valid, 0, 0),
+    (0x2C9C, Status::Mapped, 2956, 3), (0x2C9D, Status::Valid, 0, 0), (0x2C9E, Status::Mapped, 2959, 3),
+    (0x2C9F, Status::Valid, 0, 0), (0x2CA0, Status::Mapped, 2962, 3), (0x2CA1, Status::Valid, 0, 0),
+    (0x2CA2, Status::Mapped, 2965, 3), (0x2CA3, Status::Valid, 0, 0), (0x2CA4, Status::Mapped, 2968, 3),
+    (0x2CA5, Status::Valid, 0, 0), (0x2CA6, Status::Mapped, 2971, 3), (0x2CA7, Status::Valid, 0, 0),
+    (0x2CA8, Status::Mapped, 2974, 3), (0x2CA9, Status::Valid, 0, 0), (0x2CAA, Status::Mapped, 2977, 3),
+    (0x2CAB, Status::Valid, 0, 0), (0x2CAC, Status::Mapped, 2980, 3), (0x2CAD, Status::Valid, 0, 0),
+    (0x2CAE, Status::Mapped, 2983, 3), (0x2CAF, Status::Valid, 0, 0), (0x2CB0, Status::Mapped, 2986, 3),
+    (0x2CB1, Status::Valid, 0, 0), (0x2CB2, Status::Mapped, 2989, 3), (0x2CB3, Status::Valid, 0, 0),
+    (0x2CB4, Status::Mapped, 2992, 3), (0x2CB5, Status::Valid, 0, 0), (0x2CB6, Status::Mapped, 2995, 3),
+    (0x2CB7, Status::Valid, 0, 0), (0x2CB8, Status::Mapped, 2998, 3), (0x2CB9, Status::Valid, 0, 0),
+    (0x2CBA, Status::Mapped, 3001, 3), (0x2CBB, Status::Valid, 0, 0), (0x2CBC, Status::Mapped, 3004, 3),
+    (0x2CBD, Status::Valid, 0, 0), (0x2CBE, Status::Mapped, 3007, 3), (0x2CBF, Status::Valid, 0, 0),
+    (0x2CC0, Status::Mapped, 3010, 3), (0x2CC1, Status::Valid, 0, 0), (0x2CC2, Status::Mapped, 3013, 3),
+    (0x2CC3, Status::Valid, 0, 0), (0x2CC4, Status::Mapped, 3016, 3), (0x2CC5, Status::Valid, 0, 0),
+    (0x2CC6, Status::Mapped, 3019, 3), (0x2CC7, Status::Valid, 0, 0), (0x2CC8, Status::Mapped, 3022, 3),
+    (0x2CC9, Status::Valid, 0, 0), (0x2CCA, Status::Mapped, 3025, 3), (0x2CCB, Status::Valid, 0, 0),
+    (0x2CCC, Status::Mapped, 3028, 3), (0x2CCD, Status::Valid, 0, 0), (0x2CCE, Status::Mapped, 3031, 3),
+    (0x2CCF, Status::Valid, 0, 0), (0x2CD0, Status::Mapped, 3034, 3), (0x2CD1, Status::Valid, 0, 0),
+    (0x2CD2, Status::Mapped, 3037, 3), (0x2CD3, Status::Valid, 0, 0), (0x2CD4, Status::Mapped, 3040, 3),
+    (0x2CD5, Status::Valid, 0, 0), (0x2CD6, Status::Mapped, 3043, 3), (0x2CD7, Status::Valid, 0, 0),
+    (0x2CD8, Status::Mapped, 3046, 3), (0x2CD9, Status::Valid, 0, 0), (0x2CDA, Status::Mapped, 3049, 3),
+    (0x2CDB, Status::Valid, 0, 0), (0x2CDC, Status::Mapped, 3052, 3), (0x2CDD, Status::Valid, 0, 0),
+    (0x2CDE, Status::Mapped, 3055, 3), (0x2CDF, Status::Valid, 0, 0), (0x2CE0, Status::Mapped, 3058, 3),
+    (0x2CE1, Status::Valid, 0, 0), (0x2CE2, Status::Mapped, 3061, 3), (0x2CE3, Status::Valid, 0, 0),
+    (0x2CEB, Status::Mapped, 3064, 3), (0x2CEC, Status::Valid, 0, 0), (0x2CED, Status::Mapped, 3067, 3),
+    (0x2CEE, Status::Valid, 0, 0), (0x2CF2, Status::Mapped, 3070, 3), (0x2CF3, Status::Valid, 0, 0),
+    (0x2CF4, Status::Disallowed, 0, 0), (0x2CF9, Status::Valid, 0, 0), (0x2D26, Status::Disallowed, 0, 0),
+    (0x2D27, Status::Valid, 0, 0), (0x2D28, Status::Disallowed, 0, 0), (0x2D2D, Status::Valid, 0, 0),
+    (0x2D2E, Status::Disallowed, 0, 0), (0x2D30, Status::Valid, 0, 0), (0x2D68, Status::Disallowed, 0, 0),
+    (0x2D6F, Status::Mapped, 3073, 3), (0x2D70, Status::Valid, 0, 0), (0x2D71, Status::Disallowed, 0, 0),
+    (0x2D7F, Status::Valid, 0, 0), (0x2D97, Status::Disallowed, 0, 0), (0x2DA0, Status::Valid, 0, 0),
+    (0x2DA7, Status::Disallowed, 0, 0), (0x2DA8, Status::Valid, 0, 0), (0x2DAF, Status::Disallowed, 0, 0),
+    (0x2DB0, Status::Valid, 0, 0), (0x2DB7, Status::Disallowed, 0, 0), (0x2DB8, Status::Valid, 0, 0),
+    (0x2DBF, Status::Disallowed, 0, 0), (0x2DC0, Status::Valid, 0, 0), (0x2DC7, Status::Disallowed, 0, 0),
+    (0x2DC8, Status::Valid, 0, 0), (0x2DCF, Status::Disallowed, 0, 0), (0x2DD0, Status::Valid, 0, 0),
+    (0x2DD7, Status::Disallowed, 0, 0), (0x2DD8, Status::Valid, 0, 0), (0x2DDF, Status::Disallowed, 0, 0),
+    (0x2DE0, Status::Valid, 0, 0), (0x2E53, Status::Disallowed, 0, 0), (0x2E80, Status::Valid, 0, 0),
+    (0x2E9A, Status::Disallowed, 0, 0), (0x2E9B, Status::Valid, 0, 0), (0x2E9F, Status::Mapped, 3076, 3),
+    (0x2EA0, Status::Valid, 0, 0), (0x2EF3, Status::Mapped, 3079, 3), (0x2EF4, Status::Disallowed, 0, 0),
+    (0x2F00, Status::Mapped, 3082, 3), (0x2F01, Status::Mapped, 3085, 3), (0x2F02, Status::Mapped, 3088, 3),
+    (0x2F03, Status::Mapped, 3091, 3), (0x2F04, Status::Mapped, 3094, 3), (0x2F05, Status::Mapped, 3097, 3),
+    (0x2F06, Status::Mapped, 3100, 3), (0x2F07, Status::Mapped, 3103, 3), (0x2F08, Status::Mapped, 3106, 3),
+    (0x2F09, Status::Mapped, 3109, 3), (0x2F0A, Status::Mapped, 3112, 3), (0x2F0B, Status::Mapped, 3115, 3),
+    (0x2F0C, Status::Mapped, 3118, 3), (0x2F0D, Status::Mapped, 3121, 3), (0x2F0E, Status::Mapped, 3124, 3),
+    (0x2F0F, Status::Mapped, 3127, 3), (0x2F10, Status::Mapped, 3130, 3), (0x2F11, Status::Mapped, 3133, 3),
+    (0x2F12, Status::Mapped, 3136, 3), (0x2F13, Status::Mapped, 3139, 3), (0x2F14, Status::Mapped, 3142, 3),
+    (0x2F15, Status::Mapped, 3145, 3), (0x2F16, Status::Mapped, 3148, 3), (0x2F17, Status::Mapped, 3151, 3),
+    (0x2F18, Status::Mapped, 3154, 3), (0x2F19, Status::Mapped, 3157, 3), (0x2F1A, Status::Mapped, 3160, 3),
+    (0x2F1B, Status::Mapped, 3163, 3), (0x2F1C, Status::Mapped, 3166, 3), (0x2F1D, Status::Mapped, 3169, 3),
+    (0x2F1E, Status::Mapped, 3172, 3), (0x2F1F, Status::Mapped, 3175, 3), (0x2F20, Status::Mapped, 3178, 3),
+    (0x2F21, Status::Mapped, 3181, 3), (0x2F22, Status::Mapped, 3184, 3), (0x2F23, Status::Mapped, 3187, 3),
+    (0x2F24, Status::Mapped, 3190, 3), (0x2F25, Status::Mapped, 3193, 3), (0x2F26, Status::Mapped, 3196, 3),
+    (0x2F27, Status::Mapped, 3199, 3), (0x2F28, Status::Mapped, 3202, 3), (0x2F29, Status::Mapped, 3205, 3),
+    (0x2F2A, Status::Mapped, 3208, 3), (0x2F2B, Status::Mapped, 3211, 3), (0x2F2C, Status::Mapped, 3214, 3),
+    (0x2F2D, Status::Mapped, 3217, 3), (0x2F2E, Status::Mapped, 3220, 3), (0x2F2F, Status::Mapped, 3223, 3),
+    (0x2F30, Status::Mapped, 3226, 3), (0x2F31, Status::Mapped, 3229, 3), (0x2F32, Status::Mapped, 3232, 3),
+    (0x2F33, Status::Mapped, 3235, 3), (0x2F34, Status::Mapped, 3238, 3), (0x2F35, Status::Mapped, 3241, 3),
+    (0x2F36, Status::Mapped, 3244, 3), (0x2F37, Status::Mapped, 3247, 3), (0x2F38, Status::Mapped, 3250, 3),
+    (0x2F39, Status::Mapped, 3253, 3), (0x2F3A, Status::Mapped, 3256, 3), (0x2F3B, Status::Mapped, 3259, 3),
+    (0x2F3C, Status::Mapped, 3262, 3), (0x2F3D, Status::Mapped, 3265, 3), (0x2F3E, Status::Mapped, 3268, 3),
+    (0x2F3F, Status::Mapped, 3271, 3), (0x2F40, Status::Mapped, 3274, 3), (0x2F41, Status::Mapped, 3277, 3),
+    (0x2F42, Status::Mapped, 3280, 3), (0x2F43, Status::Mapped, 3283, 3), (0x2F44, Status::Mapped, 3286, 3),
+    (0x2F45, Status::Mapped, 3289, 3), (0x2F46, Status::Mapped, 3292, 3), (0x2F47, Status::Mapped, 3295, 3),
+    (0x2F48, Status::Mapped, 3298, 3), (0x2F49, Status::Mapped, 3301, 3), (0x2F4A, Status::Mapped, 3304, 3),
+    (0x2F4B, Status::Mapped, 3307, 3), (0x2F4C, Status::Mapped, 3310, 3), (0x2F4D, Status::Mapped, 3313, 3),
+    (0x2F4E, Status::Mapped, 3316, 3), (0x2F4F, Status::Mapped, 3319, 3), (0x2F50, Status::Mapped, 3322, 3),
+    (0x2F51, Status::Mapped, 3325, 3), (0x2F52, Status::Mapped, 3328, 3), (0x2F53, Status::Mapped, 3331, 3),
+    (0x2F54, Status::Mapped, 3334, 3), (0x2F55, Status::Mapped, 3337, 3), (0x2F56, Status::Mapped, 3340, 3),
+    (0x2F57, Status::Mapped, 3343, 3), (0x2F58, Status::Mapped, 3346, 3), (0x2F59, Status::Mapped, 3349, 3),
+    (0x2F5A, Status::Mapped, 3352, 3), (0x2F5B, Status::Mapped, 3355, 3), (0x2F5C, Status::Mapped, 3358, 3),
+    (0x2F5D, Status::Mapped, 3361, 3), (0x2F5E, Status::Mapped, 3364, 3), (0x2F5F, Status::Mapped, 3367, 3),
+    (0x2F60, Status::Mapped, 3370, 3), (0x2F61, Status::Mapped, 3373, 3), (0x2F62, Status::Mapped, 3376, 3),
+    (0x2F63, Status::Mapped, 3379, 3), (0x2F64, Status::Mapped, 3382, 3), (0x2F65, Status::Mapped, 3385, 3),
+    (0x2F66, Status::Mapped, 3388, 3), (0x2F67, Status::Mapped, 3391, 3), (0x2F68, Status::Mapped, 3394, 3),
+    (0x2F69, Status::Mapped, 3397, 3), (0x2F6A, Status::Mapped, 3400, 3), (0x2F6B, Status::Mapped, 3403, 3),
+    (0x2F6C, Status::Mapped, 3406, 3), (0x2F6D, Status::Mapped, 3409, 3), (0x2F6E, Status::Mapped, 3412, 3),
+    (0x2F6F, Status::Mapped, 3415, 3), (0x2F70, Status::Mapped, 3418, 3), (0x2F71, Status::Mapped, 3421, 3),
+    (0x2F72, Status::Mapped, 3424, 3), (0x2F73, Status::Mapped, 3427, 3), (0x2F74, Status::Mapped, 3430, 3),
+    (0x2F75, Status::Mapped, 3433, 3), (0x2F76, Status::Mapped, 3436, 3), (0x2F77, Status::Mapped, 3439, 3),
+    (0x2F78, Status::Mapped, 3442, 3), (0x2F79, Status::Mapped, 3445, 3), (0x2F7A, Status::Mapped, 3448, 3),
+    (0x2F7B, Status::Mapped, 3451, 3), (0x2F7C, Status::Mapped, 3454, 3), (0x2F7D, Status::Mapped, 3457, 3),
+    (0x2F7E, Status::Mapped, 3460, 3), (0x2F7F, Status::Mapped, 3463, 3), (0x2F80, Status::Mapped, 3466, 3),
+    (0x2F81, Status::Mapped, 3469, 3), (0x2F82, Status::Mapped, 3472, 3), (0x2F83, Status::Mapped, 3475, 3),
+    (0x2F84, Status::Mapped, 3478, 3), (0x2F85, Status::Mapped, 3481, 3), (0x2F86, Status::Mapped, 3484, 3),
+    (0x2F87, Status::Mapped, 3487, 3), (0x2F88, Status::Mapped, 3490, 3), (0x2F89, Status::Mapped, 3493, 3),
+    (0x2F8A, Status::Mapped, 3496, 3), (0x2F8B, Status::Mapped, 3499, 3), (0x2F8C, Status::Mapped, 3502, 3),
+    (0x2F8D, Status::Mapped, 3505, 3), (0x2F8E, Status::Mapped, 3508, 3), (0x2F8F, Status::Mapped, 3511, 3),
+    (0x2F90, Status::Mapped, 3514, 3), (0x2F91, Status::Mapped, 3517, 3), (0x2F92, Status::Mapped, 3520, 3),
+    (0x2F93, Status::Mapped, 3523, 3), (0x2F94, Status::Mapped, 3526, 3), (0x2F95, Status::Mapped, 3529, 3),
+    (0x2F96, Status::Mapped, 3532, 3), (0x2F97, Status::Mapped, 3535, 3), (0x2F98, Status::Mapped, 3538, 3),
+    (0x2F99, Status::Mapped, 3541, 3), (0x2F9A, Status::Mapped, 3544, 3), (0x2F9B, Status::Mapped, 3547, 3),
+    (0x2F9C, Status::Mapped, 3550, 3), (0x2F9D, Status::Mapped, 3553, 3), (0x2F9E, Status::Mapped, 3556, 3),
+    (0x2F9F, Status::Mapped, 3559, 3), (0x2FA0, Status::Mapped, 3562, 3), (0x2FA1, Status::Mapped, 3565, 3),
+    (0x2FA2, Status::Mapped, 3568, 3), (0x2FA3, Status::Mapped, 3571, 3), (0x2FA4, Status::Mapped, 3574, 3),
+    (0x2FA5, Status::Mapped, 3577, 3), (0x2FA6, Status::Mapped, 3580, 3), (0x2FA7, Status::Mapped, 3583, 3),
+    (0x2FA8, Status::Mapped, 3586, 3), (0x2FA9, Status::Mapped, 3589, 3), (0x2FAA, Status::Mapped, 3592, 3),
+    (0x2FAB, Status::Mapped, 3595, 3), (0x2FAC, Status::Mapped, 3598, 3), (0x2FAD, Status::Mapped, 3601, 3),
+    (0x2FAE, Status::Mapped, 3604, 3), (0x2FAF, Status::Mapped, 3607, 3), (0x2FB0, Status::Mapped, 3610, 3),
+    (0x2FB1, Status::Mapped, 3613, 3), (0x2FB2, Status::Mapped, 3616, 3), (0x2FB3, Status::Mapped, 3619, 3),
+    (0x2FB4, Status::Mapped, 3622, 3), (0x2FB5, Status::Mapped, 3625, 3), (0x2FB6, Status::Mapped, 3628, 3),
+    (0x2FB7, Status::Mapped, 3631, 3), (0x2FB8, Status::Mapped, 3634, 3), (0x2FB9, Status::Mapped, 3637, 3),
+    (0x2FBA, Status::Mapped, 3640, 3), (0x2FBB, Status::Mapped, 3643, 3), (0x2FBC, Status::Mapped, 3646, 3),
+    (0x2FBD, Status::Mapped, 3649, 3), (0x2FBE, Status::Mapped, 3652, 3), (0x2FBF, Status::Mapped, 3655, 3),
+    (0x2FC0, Status::Mapped, 3658, 3), (0x2FC1, Status::Mapped, 3661, 3), (0x2FC2, Status::Mapped, 3664, 3),
+    (0x2FC3, Status::Mapped, 3667, 3), (0x2FC4, Status::Mapped, 3670, 3), (0x2FC5, Status::Mapped, 3673, 3),
+    (0x2FC6, Status::Mapped, 3676, 3), (0x2FC7, Status::Mapped, 3679, 3), (0x2FC8, Status::Mapped, 3682, 3),
+    (0x2FC9, Status::Mapped, 3685, 3), (0x2FCA, Status::Mapped, 3688, 3), (0x2FCB, Status::Mapped, 3691, 3),
+    (0x2FCC, Status::Mapped, 3694, 3), (0x2FCD, Status::Mapped, 3697, 3), (0x2FCE, Status::Mapped, 3700, 3),
+    (0x2FCF, Status::Mapped, 3703, 3), (0x2FD0, Status::Mapped, 3706, 3), (0x2FD1, Status::Mapped, 3709, 3),
+    (0x2FD2, Status::Mapped, 3712, 3), (0x2FD3, Status::Mapped, 3715, 3), (0x2FD4, Status::Mapped, 3718, 3),
+    (0x2FD5, Status::Mapped, 3721, 3), (0x2FD6, Status::Disallowed, 0, 0), (0x3000, Status::DisallowedStd3Mapped, 26, 1),
+    (0x3001, Status::Valid, 0, 0), (0x3002, Status::Mapped, 3724, 1), (0x3003, Status::Valid, 0, 0),
+    (0x3036, Status::Mapped, 3725, 3), (0x3037, Status::Valid, 0, 0), (0x3038, Status::Mapped, 3151, 3),
+    (0x3039, Status::Mapped, 3728, 3), (0x303A, Status::Mapped, 3731, 3), (0x303B, Status::Valid, 0, 0),
+    (0x3040, Status::Disallowed, 0, 0), (0x3041, Status::Valid, 0, 0), (0x3097, Status::Disallowed, 0, 0),
+    (0x3099, Status::Valid, 0, 0), (0x309B, Status::DisallowedStd3Mapped, 3734, 4), (0x309C, Status::DisallowedStd3Mapped, 3738, 4),
+    (0x309D, Status::Valid, 0, 0), (0x309F, Status::Mapped, 3742, 6), (0x30A0, Status::Valid, 0, 0),
+    (0x30FF, Status::Mapped, 3748, 6), (0x3100, Status::Disallowed, 0, 0), (0x3105, Status::Valid, 0, 0),
+    (0x3130, Status::Disallowed, 0, 0), (0x3131, Status::Mapped, 3754, 3), (0x3132, Status::Mapped, 3757, 3),
+    (0x3133, Status::Mapped, 3760, 3), (0x3134, Status::Mapped, 3763, 3), (0x3135, Status::Mapped, 3766, 3),
+    (0x3136, Status::Mapped, 3769, 3), (0x3137, Status::Mapped, 3772, 3), (0x3138, Status::Mapped, 3775, 3),
+    (0x3139, Status::Mapped, 3778, 3), (0x313A, Status::Mapped, 3781, 3), (0x313B, Status::Mapped, 3784, 3),
+    (0x313C, Status::Mapped, 3787, 3), (0x313D, Status::Mapped, 3790, 3), (0x313E, Status::Mapped, 3793, 3),
+    (0x313F, Status::Mapped, 3796, 3), (0x3140, Status::Mapped, 3799, 3), (0x3141, Status::Mapped, 3802, 3),
+    (0x3142, Status::Mapped, 3805, 3), (0x3143, Status::Mapped, 3808, 3), (0x3144, Status::Mapped, 3811, 3),
+    (0x3145, Status::Mapped, 3814, 3), (0x3146, Status::Mapped, 3817, 3), (0x3147, Status::Mapped, 3820, 3),
+    (0x3148, Status::Mapped, 3823, 3), (0x3149, Status::Mapped, 3826, 3), (0x314A, Status::Mapped, 3829, 3),
+    (0x314B, Status::Mapped, 3832, 3), (0x314C, Status::Mapped, 3835, 3), (0x314D, Status::Mapped, 3838, 3),
+    (0x314E, Status::Mapped, 3841, 3), (0x314F, Status::Mapped, 3844, 3), (0x3150, Status::Mapped, 3847, 3),
+    (0x3151, Status::Mapped, 3850, 3), (0x3152, Status::Mapped, 3853, 3), (0x3153, Status::Mapped, 3856, 3),
+    (0x3154, Status::Mapped, 3859, 3), (0x3155, Status::Mapped, 3862, 3), (0x3156, Status::Mapped, 3865, 3),
+    (0x3157, Status::Mapped, 3868, 3), (0x3158, Status::Mapped, 3871, 3), (0x3159, Status::Mapped, 3874, 3),
+    (0x315A, Status::Mapped, 3877, 3), (0x315B, Status::Mapped, 3880, 3), (0x315C, Status::Mapped, 3883, 3),
+    (0x315D, Status::Mapped, 3886, 3), (0x315E, Status::Mapped, 3889, 3), (0x315F, Status::Mapped, 3892, 3),
+    (0x3160, Status::Mapped, 3895, 3), (0x3161, Status::Mapped, 3898, 3), (0x3162, Status::Mapped, 3901, 3),
+    (0x3163, Status::Mapped, 3904, 3), (0x3164, Status::Disallowed, 0, 0), (0x3165, Status::Mapped, 3907, 3),
+    (0x3166, Status::Mapped, 3910, 3), (0x3167, Status::Mapped, 3913, 3), (0x3168, Status::Mapped, 3916, 3),
+    (0x3169, Status::Mapped, 3919, 3), (0x316A, Status::Mapped, 3922, 3), (0x316B, Status::Mapped, 3925, 3),
+    (0x316C, Status::Mapped, 3928, 3), (0x316D, Status::Mapped, 3931, 3), (0x316E, Status::Mapped, 3934, 3),
+    (0x316F, Status::Mapped, 3937, 3), (0x3170, Status::Mapped, 3940, 3), (0x3171, Status::Mapped, 3943, 3),
+    (0x3172, Status::Mapped, 3946, 3), (0x3173, Status::Mapped, 3949, 3), (0x3174, Status::Mapped, 3952, 3),
+    (0x3175, Status::Mapped, 3955, 3), (0x3176, Status::Mapped, 3958, 3), (0x3177, Status::Mapped, 3961, 3),
+    (0x3178, Status::Mapped, 3964, 3), (0x3179, Status::Mapped, 3967, 3), (0x317A, Status::Mapped, 3970, 3),
+    (0x317B, Status::Mapped, 3973, 3), (0x317C, Status::Mapped, 3976, 3), (0x317D, Status::Mapped, 3979, 3),
+    (0x317E, Status::Mapped, 3982, 3), (0x317F, Status::Mapped, 3985, 3), (0x3180, Status::Mapped, 3988, 3),
+    (0x3181, Status::Mapped, 3991, 3), (0x3182, Status::Mapped, 3994, 3), (0x3183, Status::Mapped, 3997, 3),
+    (0x3184, Status::Mapped, 4000, 3), (0x3185, Status::Mapped, 4003, 3), (0x3186, Status::Mapped, 4006, 3),
+    (0x3187, Status::Mapped, 4009, 3), (0x3188, Status::Mapped, 4012, 3), (0x3189, Status::Mapped, 4015, 3),
+    (0x318A, Status::Mapped, 4018, 3), (0x318B, Status::Mapped, 4021, 3), (0x318C, Status::Mapped, 4024, 3),
+    (0x318D, Status::Mapped, 4027, 3), (0x318E, Status::Mapped, 4030, 3), (0x318F, Status::Disallowed, 0, 0),
+    (0x3190, Status::Valid, 0, 0), (0x3192, Status::Mapped, 3082, 3), (0x3193, Status::Mapped, 3100, 3),
+    (0x3194, Status::Mapped, 4033, 3), (0x3195, Status::Mapped, 4036, 3), (0x3196, Status::Mapped, 4039, 3),
+    (0x3197, Status::Mapped, 4042, 3), (0x3198, Status::Mapped, 4045, 3), (0x3199, Status::Mapped, 4048, 3),
+    (0x319A, Status::Mapped, 3094, 3), (0x319B, Status::Mapped, 4051, 3), (0x319C, Status::Mapped, 4054, 3),
+    (0x319D, Status::Mapped, 4057, 3), (0x319E, Status::Mapped, 4060, 3), (0x319F, Status::Mapped, 3106, 3),
+    (0x31A0, Status::Valid, 0, 0), (0x31E4, Status::Disallowed, 0, 0), (0x31F0, Status::Valid, 0, 0),
+    (0x3200, Status::DisallowedStd3Mapped, 4063, 5), (0x3201, Status::DisallowedStd3Mapped, 4068, 5), (0x3202, Status::DisallowedStd3Mapped, 4073, 5),
+    (0x3203, Status::DisallowedStd3Mapped, 4078, 5), (0x3204, Status::DisallowedStd3Mapped, 4083, 5), (0x3205, Status::DisallowedStd3Mapped, 4088, 5),
+    (0x3206, Status::DisallowedStd3Mapped, 4093, 5), (0x3207, Status::DisallowedStd3Mapped, 4098, 5), (0x3208, Status::DisallowedStd3Mapped, 4103, 5),
+    (0x3209, Status::DisallowedStd3Mapped, 4108, 5), (0x320A, Status::DisallowedStd3Mapped, 4113, 5), (0x320B, Status::DisallowedStd3Mapped, 4118, 5),
+    (0x320C, Status::DisallowedStd3Mapped, 4123, 5), (0x320D, Status::DisallowedStd3Mapped, 4128, 5), (0x320E, Status::DisallowedStd3Mapped, 4133, 5),
+    (0x320F, Status::DisallowedStd3Mapped, 4138, 5), (0x3210, Status::DisallowedStd3Mapped, 4143, 5), (0x3211, Status::DisallowedStd3Mapped, 4148, 5),
+    (0x3212, Status::DisallowedStd3Mapped, 4153, 5), (0x3213, Status::DisallowedStd3Mapped, 4158, 5), (0x3214, Status::DisallowedStd3Mapped, 4163, 5),
+    (0x3215, Status::DisallowedStd3Mapped, 4168, 5), (0x3216, Status::DisallowedStd3Mapped, 4173, 5), (0x3217, Status::DisallowedStd3Mapped, 4178, 5),
+    (0x3218, Status::DisallowedStd3Mapped, 4183, 5), (0x3219, Status::DisallowedStd3Mapped, 4188, 5), (0x321A, Status::DisallowedStd3Mapped, 4193, 5),
+    (0x321B, Status::DisallowedStd3Mapped, 4198, 5), (0x321C, Status::DisallowedStd3Mapped, 4203, 5), (0x321D, Status::DisallowedStd3Mapped, 4208, 8),
+    (0x321E, Status::DisallowedStd3Mapped, 4216, 8), (0x321F, Status::Disallowed, 0, 0), (0x3220, Status::DisallowedStd3Mapped, 4224, 5),
+    (0x3221, Status::DisallowedStd3Mapped, 4229, 5), (0x3222, Status::DisallowedStd3Mapped, 4234, 5), (0x3223, Status::DisallowedStd3Mapped, 4239, 5),
+    (0x3224, Status::DisallowedStd3Mapped, 4244, 5), (0x3225, Status::DisallowedStd3Mapped, 4249, 5), (0x3226, Status::DisallowedStd3Mapped, 4254, 5),
+    (0x3227, Status::DisallowedStd3Mapped, 4259, 5), (0x3228, Status::DisallowedStd3Mapped, 4264, 5), (0x3229, Status::DisallowedStd3Mapped, 4269, 5),
+    (0x322A, Status::DisallowedStd3Mapped, 4274, 5), (0x322B, Status::DisallowedStd3Mapped, 4279, 5), (0x322C, Status::DisallowedStd3Mapped, 4284, 5),
+    (0x322D, Status::DisallowedStd3Mapped, 4289, 5), (0x322E, Status::DisallowedStd3Mapped, 4294, 5), (0x322F, Status::DisallowedStd3Mapped, 4299, 5),
+    (0x3230, Status::DisallowedStd3Mapped, 4304, 5), (0x3231, Status::DisallowedStd3Mapped, 4309, 5), (0x3232, Status::DisallowedStd3Mapped, 4314, 5),
+    (0x3233, Status::DisallowedStd3Mapped, 4319, 5), (0x3234, Status::DisallowedStd3Mapped, 4324, 5), (0x3235, Status::DisallowedStd3Mapped, 4329, 5),
+    (0x3236, Status::DisallowedStd3Mapped, 4334, 5), (0x3237, Status::DisallowedStd3Mapped, 4339, 5), (0x3238, Status::DisallowedStd3Mapped, 4344, 5),
+    (0x3239, Status::DisallowedStd3Mapped, 4349, 5), (0x323A, Status::DisallowedStd3Mapped, 4354, 5), (0x323B, Status::DisallowedStd3Mapped, 4359, 5),
+    (0x323C, Status::DisallowedStd3Mapped, 4364, 5), (0x323D, Status::DisallowedStd3Mapped, 4369, 5), (0x323E, Status::DisallowedStd3Mapped, 4374, 5),
+    (0x323F, Status::DisallowedStd3Mapped, 4379, 5), (0x3240, Status::DisallowedStd3Mapped, 4384, 5), (0x3241, Status::DisallowedStd3Mapped, 4389, 5),
+    (0x3242, Status::DisallowedStd3Mapped, 4394, 5), (0x3243, Status::DisallowedStd3Mapped, 4399, 5), (0x3244, Status::Mapped, 4404, 3),
+    (0x3245, Status::Mapped, 4407, 3), (0x3246, Status::Mapped, 3280, 3), (0x3247, Status::Mapped, 4410, 3),
+    (0x3248, Status::Valid, 0, 0), (0x3250, Status::Mapped, 4413, 3), (0x3251, Status::Mapped, 4416, 2),
+    (0x3252, Status::Mapped, 4418, 2), (0x3253, Status::Mapped, 4420, 2), (0x3254, Status::Mapped, 4422, 2),
+    (0x3255, Status::Mapped, 4424, 2), (0x3256, Status::Mapped, 4426, 2), (0x3257, Status::Mapped, 4428, 2),
+    (0x3258, Status::Mapped, 4430, 2), (0x3259, Status::Mapped, 4432, 2), (0x325A, Status::Mapped, 4434, 2),
+    (0x325B, Status::Mapped, 4436, 2), (0x325C, Status::Mapped, 4438, 2), (0x325D, Status::Mapped, 4440, 2),
+    (0x325E, Status::Mapped, 4442, 2), (0x325F, Status::Mapped, 4444, 2), (0x3260, Status::Mapped, 3754, 3),
+    (0x3261, Status::Mapped, 3763, 3), (0x3262, Status::Mapped, 3772, 3), (0x3263, Status::Mapped, 3778, 3),
+    (0x3264, Status::Mapped, 3802, 3), (0x3265, Status::Mapped, 3805, 3), (0x3266, Status::Mapped, 3814, 3),
+    (0x3267, Status::Mapped, 3820, 3), (0x3268, Status::Mapped, 3823, 3), (0x3269, Status::Mapped, 3829, 3),
+    (0x326A, Status::Mapped, 3832, 3), (0x326B, Status::Mapped, 3835, 3), (0x326C, Status::Mapped, 3838, 3),
+    (0x326D, Status::Mapped, 3841, 3), (0x326E, Status::Mapped, 4446, 3), (0x326F, Status::Mapped, 4449, 3),
+    (0x3270, Status::Mapped, 4452, 3), (0x3271, Status::Mapped, 4455, 3), (0x3272, Status::Mapped, 4458, 3),
+    (0x3273, Status::Mapped, 4461, 3), (0x3274, Status::Mapped, 4464, 3), (0x3275, Status::Mapped, 4467, 3),
+    (0x3276, Status::Mapped, 4470, 3), (0x3277, Status::Mapped, 4473, 3), (0x3278, Status::Mapped, 4476, 3),
+    (0x3279, Status::Mapped, 4479, 3), (0x327A, Status::Mapped, 4482, 3), (0x327B, Status::Mapped, 4485, 3),
+    (0x327C, Status::Mapped, 4488, 6), (0x327D, Status::Mapped, 4494, 6), (0x327E, Status::Mapped, 4500, 3),
+    (0x327F, Status::Valid, 0, 0), (0x3280, Status::Mapped, 3082, 3), (0x3281, Status::Mapped, 3100, 3),
+    (0x3282, Status::Mapped, 4033, 3), (0x3283, Status::Mapped, 4036, 3), (0x3284, Status::Mapped, 4503, 3),
+    (0x3285, Status::Mapped, 4506, 3), (0x3286, Status::Mapped, 4509, 3), (0x3287, Status::Mapped, 3115, 3),
+    (0x3288, Status::Mapped, 4512, 3), (0x3289, Status::Mapped, 3151, 3), (0x328A, Status::Mapped, 3301, 3),
+    (0x328B, Status::Mapped, 3337, 3), (0x328C, Status::Mapped, 3334, 3), (0x328D, Status::Mapped, 3304, 3),
+    (0x328E, Status::Mapped, 3580, 3), (0x328F, Status::Mapped, 3175, 3), (0x3290, Status::Mapped, 3295, 3),
+    (0x3291, Status::Mapped, 4515, 3), (0x3292, Status::Mapped, 4518, 3), (0x3293, Status::Mapped, 4521, 3),
+    (0x3294, Status::Mapped, 4524, 3), (0x3295, Status::Mapped, 4527, 3), (0x3296, Status::Mapped, 4530, 3),
+    (0x3297, Status::Mapped, 4533, 3), (0x3298, Status::Mapped, 4536, 3), (0x3299, Status::Mapped, 4539, 3),
+    (0x329A, Status::Mapped, 4542, 3), (0x329B, Status::Mapped, 3193, 3), (0x329C, Status::Mapped, 4545, 3),
+    (0x329D, Status::Mapped, 4548, 3), (0x329E, Status::Mapped, 4551, 3), (0x329F, Status::Mapped, 4554, 3),
+    (0x32A0, Status::Mapped, 4557, 3), (0x32A1, Status::Mapped, 4560, 3), (0x32A2, Status::Mapped, 4563, 3),
+    (0x32A3, Status::Mapped, 4566, 3), (0x32A4, Status::Mapped, 4039, 3), (0x32A5, Status::Mapped, 4042, 3),
+    (0x32A6, Status::Mapped, 4045, 3), (0x32A7, Status::Mapped, 4569, 3), (0x32A8, Status::Mapped, 4572, 3),
+    (0x32A9, Status::Mapped, 4575, 3), (0x32AA, Status::Mapped, 4578, 3), (0x32AB, Status::Mapped, 4581, 3),
+    (0x32AC, Status::Mapped, 4584, 3), (0x32AD, Status::Mapped, 4587, 3), (0x32AE, Status::Mapped, 4590, 3),
+    (0x32AF, Status::Mapped, 4593, 3), (0x32B0, Status::Mapped, 4596, 3), (0x32B1, Status::Mapped, 4599, 2),
+    (0x32B2, Status::Mapped, 4601, 2), (0x32B3, Status::Mapped, 4603, 2), (0x32B4, Status::Mapped, 4605, 2),
+    (0x32B5, Status::Mapped, 4607, 2), (0x32B6, Status::Mapped, 4609, 2), (0x32B7, Status::Mapped, 4611, 2),
+    (0x32B8, Status::Mapped, 4613, 2), (0x32B9, Status::Mapped, 4615, 2), (0x32BA, Status::Mapped, 4617, 2),
+    (0x32BB, Status::Mapped, 4619, 2), (0x32BC, Status::Mapped, 4621, 2), (0x32BD, Status::Mapped, 4623, 2),
+    (0x32BE, Status::Mapped, 4625, 2), (0x32BF, Status::Mapped, 4627, 2), (0x32C0, Status::Mapped, 4629, 4),
+    (0x32C1, Status::Mapped, 4633, 4), (0x32C2, Status::Mapped, 4637, 4), (0x32C3, Status::Mapped, 4641, 4),
+    (0x32C4, Status::Mapped, 4645, 4), (0x32C5, Status::Mapped, 4649, 4), (0x32C6, Status::Mapped, 4653, 4),
+    (0x32C7, Status::Mapped, 4657, 4), (0x32C8, Status::Mapped, 4661, 4), (0x32C9, Status::Mapped, 4665, 5),
+    (0x32CA, Status::Mapped, 4670, 5), (0x32CB, Status::Mapped, 4675, 5), (0x32CC, Status::Mapped, 4680, 2),
+    (0x32CD, Status::Mapped, 4682, 3), (0x32CE, Status::Mapped, 4685, 2), (0x32CF, Status::Mapped, 4687, 3),
+    (0x32D0, Status::Mapped, 4690, 3), (0x32D1, Status::Mapped, 4693, 3), (0x32D2, Status::Mapped, 4696, 3),
+    (0x32D3, Status::Mapped, 4699, 3), (0x32D4, Status::Mapped, 4702, 3), (0x32D5, Status::Mapped, 4705, 3),
+    (0x32D6, Status::Mapped, 4708, 3), (0x32D7, Status::Mapped, 4711, 3), (0x32D8, Status::Mapped, 4714, 3),
+    (0x32D9, Status::Mapped, 4717, 3), (0x32DA, Status::Mapped, 4720, 3), (0x32DB, Status::Mapped, 4723, 3),
+    (0x32DC, Status::Mapped, 4726, 3), (0x32DD, Status::Mapped, 4729, 3), (0x32DE, Status::Mapped, 4732, 3),
+    (0x32DF, Status::Mapped, 4735, 3), (0x32E0, Status::Mapped, 4738, 3), (0x32E1, Status::Mapped, 4741, 3),
+    (0x32E2, Status::Mapped, 4744, 3), (0x32E3, Status::Mapped, 4747, 3), (0x32E4, Status::Mapped, 4750, 3),
+    (0x32E5, Status::Mapped, 4753, 3), (0x32E6, Status::Mapped, 4756, 3), (0x32E7, Status::Mapped, 4759, 3),
+    (0x32E8, Status::Mapped, 4762, 3), (0x32E9, Status::Mapped, 4765, 3), (0x32EA, Status::Mapped, 4768, 3),
+    (0x32EB, Status::Mapped, 4771, 3), (0x32EC, Status::Mapped, 4774, 3), (0x32ED, Status::Mapped, 4777, 3),
+    (0x32EE, Status::Mapped, 4780, 3), (0x32EF, Status::Mapped, 4783, 3), (0x32F0, Status::Mapped, 4786, 3),
+    (0x32F1, Status::Mapped, 4789, 3), (0x32F2, Status::Mapped, 4792, 3), (0x32F3, Status::Mapped, 4795, 3),
+    (0x32F4, Status::Mapped, 4798, 3), (0x32F5, Status::Mapped, 4801, 3), (0x32F6, Status::Mapped, 4804, 3),
+    (0x32F7, Status::Mapped, 4807, 3), (0x32F8, Status::Mapped, 4810, 3), (0x32F9, Status::Mapped, 4813, 3),
+    (0x32FA, Status::Mapped, 4816, 3), (0x32FB, Status::Mapped, 4819, 3), (0x32FC, Status::Mapped, 4822, 3),
+    (0x32FD, Status::Mapped, 4825, 3), (0x32FE, Status::Mapped, 4828, 3), (0x32FF, Status::Mapped, 4831, 6),
+    (0x3300, Status::Mapped, 4837, 12), (0x3301, Status::Mapped, 4849, 12), (0x3302, Status::Mapped, 4861, 12),
+    (0x3303, Status::Mapped, 4873, 9), (0x3304, Status::Mapped, 4882, 12), (0x3305, Status::Mapped, 4894, 9),
+    (0x3306, Status::Mapped, 4903, 9), (0x3307, Status::Mapped, 4912, 15), (0x3308, Status::Mapped, 4927, 12),
+    (0x3309, Status::Mapped, 4939, 9), (0x330A, Status::Mapped, 4948, 9), (0x330B, Status::Mapped, 4957, 9),
+    (0x330C, Status::Mapped, 4966, 12), (0x330D, Status::Mapped, 4978, 12), (0x330E, Status::Mapped, 4990, 9),
+    (0x330F, Status::Mapped, 4999, 9), (0x3310, Status::Mapped, 5008, 6), (0x3311, Status::Mapped, 5014, 9),
+    (0x3312, Status::Mapped, 5023, 12), (0x3313, Status::Mapped, 5035, 12), (0x3314, Status::Mapped, 5047, 6),
+    (0x3315, Status::Mapped, 5053, 15), (0x3316, Status::Mapped, 5068, 18), (0x3317, Status::Mapped, 5086, 15),
+    (0x3318, Status::Mapped, 5101, 9), (0x3319, Status::Mapped, 5110, 15), (0x331A, Status::Mapped, 5125, 15),
+    (0x331B, Status::Mapped, 5140, 12), (0x331C, Status::Mapped, 5152, 9), (0x331D, Status::Mapped, 5161, 9),
+    (0x331E, Status::Mapped, 5170, 9), (0x331F, Status::Mapped, 5179, 12), (0x3320, Status::Mapped, 5191, 15),
+    (0x3321, Status::Mapped, 5206, 12), (0x3322, Status::Mapped, 5218, 9), (0x3323, Status::Mapped, 5227, 9),
+    (0x3324, Status::Mapped, 5236, 9), (0x3325, Status::Mapped, 5245, 6), (0x3326, Status::Mapped, 5251, 6),
+    (0x3327, Status::Mapped, 5257, 6), (0x3328, Status::Mapped, 5263, 6), (0x3329, Status::Mapped, 5269, 9),
+    (0x332A, Status::Mapped, 5278, 9), (0x332B, Status::Mapped, 5287, 15), (0x332C, Status::Mapped, 5302, 9),
+    (0x332D, Status::Mapped, 5311, 12), (0x332E, Status::Mapped, 5323, 15), (0x332F, Status::Mapped, 5338, 9),
+    (0x3330, Status::Mapped, 5347, 6), (0x3331, Status::Mapped, 5353, 6), (0x3332, Status::Mapped, 5359, 15),
+    (0x3333, Status::Mapped, 5374, 12), (0x3334, Status::Mapped, 5386, 15), (0x3335, Status::Mapped, 5401, 9),
+    (0x3336, Status::Mapped, 5410, 15), (0x3337, Status::Mapped, 5425, 6), (0x3338, Status::Mapped, 5431, 9),
+    (0x3339, Status::Mapped, 5440, 9), (0x333A, Status::Mapped, 5449, 9), (0x333B, Status::Mapped, 5458, 9),
+    (0x333C, Status::Mapped, 5467, 9), (0x333D, Status::Mapped, 5476, 12), (0x333E, Status::Mapped, 5488, 9),
+    (0x333F, Status::Mapped, 5497, 6), (0x3340, Status::Mapped, 5503, 9), (0x3341, Status::Mapped, 5512, 9),
+    (0x3342, Status::Mapped, 5521, 9), (0x3343, Status::Mapped, 5530, 12), (0x3344, Status::Mapped, 5542, 9),
+    (0x3345, Status::Mapped, 5551, 9), (0x3346, Status::Mapped, 5560, 9), (0x3347, Status::Mapped, 5569, 15),
+    (0x3348, Status::Mapped, 5584, 12), (0x3349, Status::Mapped, 5596, 6), (0x334A, Status::Mapped, 5602, 15),
+    (0x334B, Status::Mapped, 5617, 6), (0x334C, Status::Mapped, 5623, 12), (0x334D, Status::Mapped, 5635, 12),
+    (0x334E, Status::Mapped, 5647, 9), (0x334F, Status::Mapped, 5656, 9), (0x3350, Status::Mapped, 5665, 9),
+    (0x3351, Status::Mapped, 5674, 12), (0x3352, Status::Mapped, 5686, 6), (0x3353, Status::Mapped, 5692, 9),
+    (0x3354, Status::Mapped, 5701, 12), (0x3355, Status::Mapped, 5713, 6), (0x3356, Status::Mapped, 5719, 15),
+    (0x3357, Status::Mapped, 5734, 9), (0x3358, Status::Mapped, 5743, 4), (0x3359, Status::Mapped, 5747, 4),
+    (0x335A, Status::Mapped, 5751, 4), (0x335B, Status::Mapped, 5755, 4), (0x335C, Status::Mapped, 5759, 4),
+    (0x335D, Status::Mapped, 5763, 4), (0x335E, Status::Mapped, 5767, 4), (0x335F, Status::Mapped, 5771, 4),
+    (0x3360, Status::Mapped, 5775, 4), (0x3361, Status::Mapped, 5779, 4), (0x3362, Status::Mapped, 5783, 5),
+    (0x3363, Status::Mapped, 5788, 5), (0x3364, Status::Mapped, 5793, 5), (0x3365, Status::Mapped, 5798, 5),
+    (0x3366, Status::Mapped, 5803, 5), (0x3367, Status::Mapped, 5808, 5), (0x3368, Status::Mapped, 5813, 5),
+    (0x3369, Status::Mapped, 5818, 5), (0x336A, Status::Mapped, 5823, 5), (0x336B, Status::Mapped, 5828, 5),
+    (0x336C, Status::Mapped, 5833, 5), (0x336D, Status::Mapped, 5838, 5), (0x336E, Status::Mapped, 5843, 5),
+    (0x336F, Status::Mapped, 5848, 5), (0x3370, Status::Mapped, 5853, 5), (0x3371, Status::Mapped, 5858, 3),
+    (0x3372, Status::Mapped, 5861, 2), (0x3373, Status::Mapped, 5863, 2), (0x3374, Status::Mapped, 5865, 3),
+    (0x3375, Status::Mapped, 5868, 2), (0x3376, Status::Mapped, 5870, 2), (0x3377, Status::Mapped, 5872, 2),
+    (0x3378, Status::Mapped, 5874, 3), (0x3379, Status::Mapped, 5877, 3), (0x337A, Status::Mapped, 5880, 2),
+    (0x337B, Status::Mapped, 5882, 6), (0x337C, Status::Mapped, 5888, 6), (0x337D, Status::Mapped, 5894, 6),
+    (0x337E, Status::Mapped, 5900, 6), (0x337F, Status::Mapped, 5906, 12), (0x3380, Status::Mapped, 5918, 2),
+    (0x3381, Status::Mapped, 5920, 2), (0x3382, Status::Mapped, 5922, 3), (0x3383, Status::Mapped, 5925, 2),
+    (0x3384, Status::Mapped, 5927, 2), (0x3385, Status::Mapped, 5929, 2), (0x3386, Status::Mapped, 5931, 2),
+    (0x3387, Status::Mapped, 5933, 2), (0x3388, Status::Mapped, 5935, 3), (0x3389, Status::Mapped, 5938, 4),
+    (0x338A, Status::Mapped, 5942, 2), (0x338B, Status::Mapped, 5944, 2), (0x338C, Status::Mapped, 5946, 3),
+    (0x338D, Status::Mapped, 5949, 3), (0x338E, Status::Mapped, 5952, 2), (0x338F, Status::Mapped, 5954, 2),
+    (0x3390, Status::Mapped, 5956, 2), (0x3391, Status::Mapped, 5958, 3), (0x3392, Status::Mapped, 5961, 3),
+    (0x3393, Status::Mapped, 5964, 3), (0x3394, Status::Mapped, 5967, 3), (0x3395, Status::Mapped, 5970, 3),
+    (0x3396, Status::Mapped, 5973, 2), (0x3397, Status::Mapped, 5975, 2), (0x3398, Status::Mapped, 5977, 2),
+    (0x3399, Status::Mapped, 5979, 2), (0x339A, Status::Mapped, 5981, 2), (0x339B, Status::Mapped, 5983, 3),
+    (0x339C, Status::Mapped, 5986, 2), (0x339D, Status::Mapped, 5988, 2), (0x339E, Status::Mapped, 5990, 2),
+    (0x339F, Status::Mapped, 5992, 3), (0x33A0, Status::Mapped, 5995, 3), (0x33A1, Status::Mapped, 5998, 2),
+    (0x33A2, Status::Mapped, 6000, 3), (0x33A3, Status::Mapped, 6003, 3), (0x33A4, Status::Mapped, 6006, 3),
+    (0x33A5, Status::Mapped, 6009, 2), (0x33A6, Status::Mapped, 6011, 3), (0x33A7, Status::Mapped, 6014, 5),
+    (0x33A8, Status::Mapped, 6019, 6), (0x33A9, Status::Mapped, 5918, 2), (0x33AA, Status::Mapped, 6025, 3),
+    (0x33AB, Status::Mapped, 6028, 3), (0x33AC, Status::Mapped, 6031, 3), (0x33AD, Status::Mapped, 6034, 3),
+    (0x33AE, Status::Mapped, 6037, 7), (0x33AF, Status::Mapped, 6044, 8), (0x33B0, Status::Mapped, 6052, 2),
+    (0x33B1, Status::Mapped, 6054, 2), (0x33B2, Status::Mapped, 6056, 3), (0x33B3, Status::Mapped, 6059, 2),
+    (0x33B4, Status::Mapped, 6061, 2), (0x33B5, Status::Mapped, 6063, 2), (0x33B6, Status::Mapped, 6065, 3),
+    (0x33B7, Status::Mapped, 6068, 2), (0x33B8, Status::Mapped, 6070, 2), (0x33B9, Status::Mapped, 6068, 2),
+    (0x33BA, Status::Mapped, 6072, 2), (0x33BB, Status::Mapped, 6074, 2), (0x33BC, Status::Mapped, 6076, 3),
+    (0x33BD, Status::Mapped, 6079, 2), (0x33BE, Status::Mapped, 6081, 2), (0x33BF, Status::Mapped, 6079, 2),
+    (0x33C0, Status::Mapped, 6083, 3), (0x33C1, Status::Mapped, 6086, 3), (0x33C2, Status::Disallowed, 0, 0),
+    (0x33C3, Status::Mapped, 6089, 2), (0x33C4, Status::Mapped, 6091, 2), (0x33C5, Status::Mapped, 6093, 2),
+    (0x33C6, Status::Mapped, 6095, 6), (0x33C7, Status::Disallowed, 0, 0), (0x33C8, Status::Mapped, 6101, 2),
+    (0x33C9, Status::Mapped, 6103, 2), (0x33CA, Status::Mapped, 6105, 2), (0x33CB, Status::Mapped, 6107, 2),
+    (0x33CC, Status::Mapped, 6109, 2), (0x33CD, Status::Mapped, 6111, 2), (0x33CE, Status::Mapped, 5990, 2),
+    (0x33CF, Status::Mapped, 6113, 2), (0x33D0, Status::Mapped, 6115, 2), (0x33D1, Status::Mapped, 6117, 2),
+    (0x33D2, Status::Mapped, 6119, 3), (0x33D3, Status::Mapped, 6122, 2), (0x33D4, Status::Mapped, 5931, 2),
+    (0x33D5, Status::Mapped, 6124, 3), (0x33D6, Status::Mapped, 6127, 3), (0x33D7, Status::Mapped, 6130, 2),
+    (0x33D8, Status::Disallowed, 0, 0), (0x33D9, Status::Mapped, 6132, 3), (0x33DA, Status::Mapped, 6135, 2),
+    (0x33DB, Status::Mapped, 6137, 2), (0x33DC, Status::Mapped, 6139, 2), (0x33DD, Status::Mapped, 6141, 2),
+    (0x33DE, Status::Mapped, 6143, 5), (0x33DF, Status::Mapped, 6148, 5), (0x33E0, Status::Mapped, 6153, 4),
+    (0x33E1, Status::Mapped, 6157, 4), (0x33E2, Status::Mapped, 6161, 4), (0x33E3, Status::Mapped, 6165, 4),
+    (0x33E4, Status::Mapped, 6169, 4), (0x33E5, Status::Mapped, 6173, 4), (0x33E6, Status::Mapped, 6177, 4),
+    (0x33E7, Status::Mapped, 6181, 4), (0x33E8, Status::Mapped, 6185, 4), (0x33E9, Status::Mapped, 6189, 5),
+    (0x33EA, Status::Mapped, 6194, 5), (0x33EB, Status::Mapped, 6199, 5), (0x33EC, Status::Mapped, 6204, 5),
+    (0x33ED, Status::Mapped, 6209, 5), (0x33EE, Status::Mapped, 6214, 5), (0x33EF, Status::Mapped, 6219, 5),
+    (0x33F0, Status::Mapped, 6224, 5), (0x33F1, Status::Mapped, 6229, 5), (0x33F2, Status::Mapped, 6234, 5),
+    (0x33F3, Status::Mapped, 6239, 5), (0x33F4, Status::Mapped, 6244, 5), (0x33F5, Status::Mapped, 6249, 5),
+    (0x33F6, Status::Mapped, 6254, 5), (0x33F7, Status::Mapped, 6259, 5), (0x33F8, Status::Mapped, 6264, 5),
+    (0x33F9, Status::Mapped, 6269, 5), (0x33FA, Status::Mapped, 6274, 5), (0x33FB, Status::Mapped, 6279, 5),
+    (0x33FC, Status::Mapped, 6284, 5), (0x33FD, Status::Mapped, 6289, 5), (0x33FE, Status::Mapped, 6294, 5),
+    (0x33FF, Status::Mapped, 6299, 3), (0x3400, Status::Valid, 0, 0), (0x9FFD, Status::Disallowed, 0, 0),
+    (0xA000, Status::Valid, 0, 0), (0xA48D, Status::Disallowed, 0, 0), (0xA490, Status::Valid, 0, 0),
+    (0xA4C7, Status::Disallowed, 0, 0), (0xA4D0, Status::Valid, 0, 0), (0xA62C, Status::Disallowed, 0, 0),
+    (0xA640, Status::Mapped, 6302, 3), (0xA641, Status::Valid, 0, 0), (0xA642, Status::Mapped, 6305, 3),
+    (0xA643, Status::Valid, 0, 0), (0xA644, Status::Mapped, 6308, 3), (0xA645, Status::Valid, 0, 0),
+    (0xA646, Status::Mapped, 6311, 3), (0xA647, Status::Valid, 0, 0), (0xA648, Status::Mapped, 6314, 3),
+    (0xA649, Status::Valid, 0, 0), (0xA64A, Status::Mapped, 1298, 3), (0xA64B, Status::Valid, 0, 0),
+    (0xA64C, Status::Mapped, 6317, 3), (0xA64D, Status::Valid, 0, 0), (0xA64E, Status::Mapped, 6320, 3),
+    (0xA64F, Status::Valid, 0, 0), (0xA650, Status::Mapped, 6323, 3), (0xA651, Status::Valid, 0, 0),
+    (0xA652, Status::Mapped, 6326, 3), (0xA653, Status::Valid, 0, 0), (0xA654, Status::Mapped, 6329, 3),
+    (0xA655, Status::Valid, 0, 0), (0xA656, Status::Mapped, 6332, 3), (0xA657, Status::Valid, 0, 0),
+    (0xA658, Status::Mapped, 6335, 3), (0xA659, Status::Valid, 0, 0), (0xA65A, Status::Mapped, 6338, 3),
+    (0xA65B, Status::Valid, 0, 0), (0xA65C, Status::Mapped, 6341, 3), (0xA65D, Status::Valid, 0, 0),
+    (0xA65E, Status::Mapped, 6344, 3), (0xA65F, Status::Valid, 0, 0), (0xA660, Status::Mapped, 6347, 3),
+    (0xA661, Status::Valid, 0, 0), (0xA662, Status::Mapped, 6350, 3), (0xA663, Status::Valid, 0, 0),
+    (0xA664, Status::Mapped, 6353, 3), (0xA665, Status::Valid, 0, 0), (0xA666, Status::Mapped, 6356, 3),
+    (0xA667, Status::Valid, 0, 0), (0xA668, Status::Mapped, 6359, 3), (0xA669, Status::Valid, 0, 0),
+    (0xA66A, Status::Mapped, 6362, 3), (0xA66B, Status::Valid, 0, 0), (0xA66C, Status::Mapped, 6365, 3),
+    (0xA66D, Status::Valid, 0, 0), (0xA680, Status::Mapped, 6368, 3), (0xA681, Status::Valid, 0, 0),
+    (0xA682, Status::Mapped, 6371, 3), (0xA683, Status::Valid, 0, 0), (0xA684, Status::Mapped, 6374, 3),
+    (0xA685, Status::Valid, 0, 0), (0xA686, Status::Mapped, 6377, 3), (0xA687, Status::Valid, 0, 0),
+    (0xA688, Status::Mapped, 6380, 3), (0xA689, Status::Valid, 0, 0), (0xA68A, Status::Mapped, 6383, 3),
+    (0xA68B, Status::Valid, 0, 0), (0xA68C, Status::Mapped, 6386, 3), (0xA68D, Status::Valid, 0, 0),
+    (0xA68E, Status::Mapped, 6389, 3), (0xA68F, Status::Valid, 0, 0), (0xA690, Status::Mapped, 6392, 3),
+    (0xA691, Status::Valid, 0, 0), (0xA692, Status::Mapped, 6395, 3), (0xA693, Status::Valid, 0, 0),
+    (0xA694, Status::Mapped, 6398, 3), (0xA695, Status::Valid, 0, 0), (0xA696, Status::Mapped, 6401, 3),
+    (0xA697, Status::Valid, 0, 0), (0xA698, Status::Mapped, 6404, 3), (0xA699, Status::Valid, 0, 0),
+    (0xA69A, Status::Mapped, 6407, 3), (0xA69B, Status::Valid, 0, 0), (0xA69C, Status::Mapped, 704, 2),
+    (0xA69D, Status::Mapped, 708, 2), (0xA69E, Status::Valid, 0, 0), (0xA6F8, Status::Disallowed, 0, 0),
+    (0xA700, Status::Valid, 0, 0), (0xA722, Status::Mapped, 6410, 3), (0xA723, Status::Valid, 0, 0),
+    (0xA724, Status::Mapped, 6413, 3), (0xA725, Status::Valid, 0, 0), (0xA726, Status::Mapped, 6416, 3),
+    (0xA727, Status::Valid, 0, 0), (0xA728, Status::Mapped, 6419, 3), (0xA729, Status::Valid, 0, 0),
+    (0xA72A, Status::Mapped, 6422, 3), (0xA72B, Status::Valid, 0, 0), (0xA72C, Status::Mapped, 6425, 3),
+    (0xA72D, Status::Valid, 0, 0), (0xA72E, Status::Mapped, 6428, 3), (0xA72F, Status::Valid, 0, 0),
+    (0xA732, Status::Mapped, 6431, 3), (0xA733, Status::Valid, 0, 0), (0xA734, Status::Mapped, 6434, 3),
+    (0xA735, Status::Valid, 0, 0), (0xA736, Status::Mapped, 6437, 3), (0xA737, Status::Valid, 0, 0),
+    (0xA738, Status::Mapped, 6440, 3), (0xA739, Status::Valid, 0, 0), (0xA73A, Status::Mapped, 6443, 3),
+    (0xA73B, Status::Valid, 0, 0), (0xA73C, Status::Mapped, 6446, 3), (0xA73D, Status::Valid, 0, 0),
+    (0xA73E, Status::Mapped, 6449, 3), (0xA73F, Status::Valid, 0, 0), (0xA740, Status::Mapped, 6452, 3),
+    (0xA741, Status::Valid, 0, 0), (0xA742, Status::Mapped, 6455, 3), (0xA743, Status::Valid, 0, 0),
+    (0xA744, Status::Mapped, 6458, 3), (0xA745, Status::Valid, 0, 0), (0xA746, Status::Mapped, 6461, 3),
+    (0xA747, Status::Valid, 0, 0), (0xA748, Status::Mapped, 6464, 3), (0xA749, Status::Valid, 0, 0),
+    (0xA74A, Status::Mapped, 6467, 3), (0xA74B, Status::Valid, 0, 0), (0xA74C, Status::Mapped, 6470, 3),
+    (0xA74D, Status::Valid, 0, 0), (0xA74E, Status::Mapped, 6473, 3), (0xA74F, Status::Valid, 0, 0),
+    (0xA750, Status::Mapped, 6476, 3), (0xA751, Status::Valid, 0, 0), (0xA752, Status::Mapped, 6479, 3),
+    (0xA753, Status::Valid, 0, 0), (0xA754, Status::Mapped, 6482, 3), (0xA755, Status::Valid, 0, 0),
+    (0xA756, Status::Mapped, 6485, 3), (0xA757, Status::Valid, 0, 0), (0xA758, Status::Mapped, 6488, 3),
+    (0xA759, Status::Valid, 0, 0), (0xA75A, Status::Mapped, 6491, 3), (0xA75B, Status::Valid, 0, 0),
+    (0xA75C, Status::Mapped, 6494, 3), (0xA75D, Status::Valid, 0, 0), (0xA75E, Status::Mapped, 6497, 3),
+    (0xA75F, Status::Valid, 0, 0), (0xA760, Status::Mapped, 6500, 3), (0xA761, Status::Valid, 0, 0),
+    (0xA762, Status::Mapped, 6503, 3), (0xA763, Status::Valid, 0, 0), (0xA764, Status::Mapped, 6506, 3),
+    (0xA765, Status::Valid, 0, 0), (0xA766, Status::Mapped, 6509, 3), (0xA767, Status::Valid, 0, 0),
+    (0xA768, Status::Mapped, 6512, 3), (0xA769, Status::Valid, 0, 0), (0xA76A, Status::Mapped, 6515, 3),
+    (0xA76B, Status::Valid, 0, 0), (0xA76C, Status::Mapped, 6518, 3), (0xA76D, Status::Valid, 0, 0),
+    (0xA76E, Status::Mapped, 6521, 3), (0xA76F, Status::Valid, 0, 0), (0xA770, Status::Mapped, 6521, 3),
+    (0xA771, Status::Valid, 0, 0), (0xA779, Status::Mapped, 6524, 3), (0xA77A, Status::Valid, 0, 0),
+    (0xA77B, Status::Mapped, 6527, 3), (0xA77C, Status::Valid, 0, 0), (0xA77D, Status::Mapped, 6530, 3),
+    (0xA77E, Status::Mapped, 6533, 3), (0xA77F, Status::Valid, 0, 0), (0xA780, Status::Mapped, 6536, 3),
+    (0xA781, Status::Valid, 0, 0), (0xA782, Status::Mapped, 6539, 3), (0xA783, Status::Valid, 0, 0),
+    (0xA784, Status::Mapped, 6542, 3), (0xA785, Status::Valid, 0, 0), (0xA786, Status::Mapped, 6545, 3),
+    (0xA787, Status::Valid, 0, 0), (0xA78B, Status::Mapped, 6548, 3), (0xA78C, Status::Valid, 0, 0),
+    (0xA78D, Status::Mapped, 1465, 2), (0xA78E, Status::Valid, 0, 0), (0xA790, Status::Mapped, 6551, 3),
+    (0xA791, Status::Valid, 0, 0), (0xA792, Status::Mapped, 6554, 3), (0xA793, Status::Valid, 0, 0),
+    (0xA796, Status::Mapped, 6557, 3), (0xA797, Status::Valid, 0, 0), (0xA798, Status::Mapped, 6560, 3),
+    (0xA799, Status::Valid, 0, 0), (0xA79A, Status::Mapped, 6563, 3), (0xA79B, Status::Valid, 0, 0),
+    (0xA79C, Status::Mapped, 6566, 3), (0xA79D, Status::Valid, 0, 0), (0xA79E, Status::Mapped, 6569, 3),
+    (0xA79F, Status::Valid, 0, 0), (0xA7A0, Status::Mapped, 6572, 3), (0xA7A1, Status::Valid, 0, 0),
+    (0xA7A2, Status::Mapped, 6575, 3), (0xA7A3, Status::Valid, 0, 0), (0xA7A4, Status::Mapped, 6578, 3),
+    (0xA7A5, Status::Valid, 0, 0), (0xA7A6, Status::Mapped, 6581, 3), (0xA7A7, Status::Valid, 0, 0),
+    (0xA7A8, Status::Mapped, 6584, 3), (0xA7A9, Status::Valid, 0, 0), (0xA7AA, Status::Mapped, 461, 2),
+    (0xA7AB, Status::Mapped, 1443, 2), (0xA7AC, Status::Mapped, 1463, 2), (0xA7AD, Status::Mapped, 6587, 2),
+    (0xA7AE, Status::Mapped, 1467, 2), (0xA7AF, Status::Valid, 0, 0), (0xA7B0, Status::Mapped, 6589, 2),
+    (0xA7B1, Status::Mapped, 6591, 2), (0xA7B2, Status::Mapped, 1472, 2), (0xA7B3, Status::Mapped, 6593, 3),
+    (0xA7B4, Status::Mapped, 6596, 3), (0xA7B5, Status::Valid, 0, 0), (0xA7B6, Status::Mapped, 6599, 3),
+    (0xA7B7, Status::Valid, 0, 0), (0xA7B8, Status::Mapped, 6602, 3), (0xA7B9, Status::Valid, 0, 0),
+    (0xA7BA, Status::Mapped, 6605, 3), (0xA7BB, Status::Valid, 0, 0), (0xA7BC, Status::Mapped, 6608, 3),
+    (0xA7BD, Status::Valid, 0, 0), (0xA7BE, Status::Mapped, 6611, 3), (0xA7BF, Status::Valid, 0, 0),
+    (0xA7C0, Status::Disallowed, 0, 0), (0xA7C2, Status::Mapped, 6614, 3), (0xA7C3, Status::Valid, 0, 0),
+    (0xA7C4, Status::Mapped, 6617, 3), (0xA7C5, Status::Mapped, 1491, 2), (0xA7C6, Status::Mapped, 6620, 3),
+    (0xA7C7, Status::Mapped, 6623, 3), (0xA7C8, Status::Valid, 0, 0), (0xA7C9, Status::Mapped, 6626, 3),
+    (0xA7CA, Status::Valid, 0, 0), (0xA7CB, Status::Disallowed, 0, 0), (0xA7F5, Status::Mapped, 6629, 3),
+    (0xA7F6, Status::Valid, 0, 0), (0xA7F8, Status::Mapped, 159, 2), (0xA7F9, Status::Mapped, 206, 2),
+    (0xA7FA, Status::Valid, 0, 0), (0xA82D, Status::Disallowed, 0, 0), (0xA830, Status::Valid, 0, 0),
+    (0xA83A, Status::Disallowed, 0, 0), (0xA840, Status::Valid, 0, 0), (0xA878, Status::Disallowed, 0, 0),
+    (0xA880, Status::Valid, 0, 0), (0xA8C6, Status::Disallowed, 0, 0), (0xA8CE, Status::Valid, 0, 0),
+    (0xA8DA, Status::Disallowed, 0, 0), (0xA8E0, Status::Valid, 0, 0), (0xA954, Status::Disallowed, 0, 0),
+    (0xA95F, Status::Valid, 0, 0), (0xA97D, Status::Disallowed, 0, 0), (0xA980, Status::Valid, 0, 0),
+    (0xA9CE, Status::Disallowed, 0, 0), (0xA9CF, Status::Valid, 0, 0), (0xA9DA, Status::Disallowed, 0, 0),
+    (0xA9DE, Status::Valid, 0, 0), (0xA9FF, Status::Disallowed, 0, 0), (0xAA00, Status::Valid, 0, 0),
+    (0xAA37, Status::Disallowed, 0, 0), (0xAA40, Status::Valid, 0, 0), (0xAA4E, Status::Disallowed, 0, 0),
+    (0xAA50, Status::Valid, 0, 0), (0xAA5A, Status::Disallowed, 0, 0), (0xAA5C, Status::Valid, 0, 0),
+    (0xAAC3, Status::Disallowed, 0, 0), (0xAADB, Status::Valid, 0, 0), (0xAAF7, Status::Disallowed, 0, 0),
+    (0xAB01, Status::Valid, 0, 0), (0xAB07, Status::Disallowed, 0, 0), (0xAB09, Status::Valid, 0, 0),
+    (0xAB0F, Status::Disallowed, 0, 0), (0xAB11, Status::Valid, 0, 0), (0xAB17, Status::Disallowed, 0, 0),
+    (0xAB20, Status::Valid, 0, 0), (0xAB27, Status::Disallowed, 0, 0), (0xAB28, Status::Valid, 0, 0),
+    (0xAB2F, Status::Disallowed, 0, 0), (0xAB30, Status::Valid, 0, 0), (0xAB5C, Status::Mapped, 6416, 3),
+    (0xAB5D, Status::Mapped, 6632, 3), (0xAB5E, Status::Mapped, 2888, 2), (0xAB5F, Status::Mapped, 6635, 3),
+    (0xAB60, Status::Valid, 0, 0), (0xAB69, Status::Mapped, 6638, 2), (0xAB6A, Status::Valid, 0, 0),
+    (0xAB6C, Status::Disallowed, 0, 0), (0xAB70, Status::Mapped, 6640, 3), (0xAB71, Status::Mapped, 6643, 3),
+    (0xAB72, Status::Mapped, 6646, 3), (0xAB73, Status::Mapped, 6649, 3), (0xAB74, Status::Mapped, 6652, 3),
+    (0xAB75, Status::Mapped, 6655, 3), (0xAB76, Status::Mapped, 6658, 3), (0xAB77, Status::Mapped, 6661, 3),
+    (0xAB78, Status::Mapped, 6664, 3), (0xAB79, Status::Mapped, 6667, 3), (0xAB7A, Status::Mapped, 6670, 3),
+    (0xAB7B, Status::Mapped, 6673, 3), (0xAB7C, Status::Mapped, 6676, 3), (0xAB7D, Status::Mapped, 6679, 3),
+    (0xAB7E, Status::Mapped, 6682, 3), (0xAB7F, Status::Mapped, 6685, 3), (0xAB80, Status::Mapped, 6688, 3),
+    (0xAB81, Status::Mapped, 6691, 3), (0xAB82, Status::Mapped, 6694, 3), (0xAB83, Status::Mapped, 6697, 3),
+    (0xAB84, Status::Mapped, 6700, 3), (0xAB85, Status::Mapped, 6703, 3), (0xAB86, Status::Mapped, 6706, 3),
+    (0xAB87, Status::Mapped, 6709, 3), (0xAB88, Status::Mapped, 6712, 3), (0xAB89, Status::Mapped, 6715, 3),
+    (0xAB8A, Status::Mapped, 6718, 3), (0xAB8B, Status::Mapped, 6721, 3), (0xAB8C, Status::Mapped, 6724, 3),
+    (0xAB8D, Status::Mapped, 6727, 3), (0xAB8E, Status::Mapped, 6730, 3), (0xAB8F, Status::Mapped, 6733, 3),
+    (0xAB90, Status::Mapped, 6736, 3), (0xAB91, Status::Mapped, 6739, 3), (0xAB92, Status::Mapped, 6742, 3),
+    (0xAB93, Status::Mapped, 6745, 3), (0xAB94, Status::Mapped, 6748, 3), (0xAB95, Status::Mapped, 6751, 3),
+    (0xAB96, Status::Mapped, 6754, 3), (0xAB97, Status::Mapped, 6757, 3), (0xAB98, Status::Mapped, 6760, 3),
+    (0xAB99, Status::Mapped, 6763, 3), (0xAB9A, Status::Mapped, 6766, 3), (0xAB9B, Status::Mapped, 6769, 3),
+    (0xAB9C, Status::Mapped, 6772, 3), (0xAB9D, Status::Mapped, 6775, 3), (0xAB9E, Status::Mapped, 6778, 3),
+    (0xAB9F, Status::Mapped, 6781, 3), (0xABA0, Status::Mapped, 6784, 3), (0xABA1, Status::Mapped, 6787, 3),
+    (0xABA2, Status::Mapped, 6790, 3), (0xABA3, Status::Mapped, 6793, 3), (0xABA4, Status::Mapped, 6796, 3),
+    (0xABA5, Status::Mapped, 6799, 3), (0xABA6, Status::Mapped, 6802, 3), (0xABA7, Status::Mapped, 6805, 3),
+    (0xABA8, Status::Mapped, 6808, 3), (0xABA9, Status::Mapped, 6811, 3), (0xABAA, Status::Mapped, 6814, 3),
+    (0xABAB, Status::Mapped, 6817, 3), (0xABAC, Status::Mapped, 6820, 3), (0xABAD, Status::Mapped, 6823, 3),
+    (0xABAE, Status::Mapped, 6826, 3), (0xABAF, Status::Mapped, 6829, 3), (0xABB0, Status::Mapped, 6832, 3),
+    (0xABB1, Status::Mapped, 6835, 3), (0xABB2, Status::Mapped, 6838, 3), (0xABB3, Status::Mapped, 6841, 3),
+    (0xABB4, Status::Mapped, 6844, 3), (0xABB5, Status::Mapped, 6847, 3), (0xABB6, Status::Mapped, 6850, 3),
+    (0xABB7, Status::Mapped, 6853, 3), (0xABB8, Status::Mapped, 6856, 3), (0xABB9, Status::Mapped, 6859, 3),
+    (0xABBA, Status::Mapped, 6862, 3), (0xABBB, Status::Mapped, 6865, 3), (0xABBC, Status::Mapped, 6868, 3),
+    (0xABBD, Status::Mapped, 6871, 3), (0xABBE, Status::Mapped, 6874, 3), (0xABBF, Status::Mapped, 6877, 3),
+    (0xABC0, Status::Valid, 0, 0), (0xABEE, Status::Disallowed, 0, 0), (0xABF0, Status::Valid, 0, 0),
+    (0xABFA, Status::Disallowed, 0, 0), (0xAC00, Status::Valid, 0, 0), (0xD7A4, Status::Disallowed, 0, 0),
+    (0xD7B0, Status::Valid, 0, 0), (0xD7C7, Status::Disallowed, 0, 0), (0xD7CB, Status::Valid, 0, 0),
+    (0xD7FC, Status::Disallowed, 0, 0), (0xF900, Status::Mapped, 6880, 3), (0xF901, Status::Mapped, 6883, 3),
+    (0xF902, Status::Mapped, 3556, 3), (0xF903, Status::Mapped, 6886, 3), (0xF904, Status::Mapped, 6889, 3),
+    (0xF905, Status::Mapped, 6892, 3), (0xF906, Status::Mapped, 6895, 3), (0xF907, Status::Mapped, 3718, 3),
+    (0xF909, Status::Mapped, 6898, 3), (0xF90A, Status::Mapped, 3580, 3), (0xF90B, Status::Mapped, 6901, 3),
+    (0xF90C, Status::Mapped, 6904, 3), (0xF90D, Status::Mapped, 6907, 3), (0xF90E, Status::Mapped, 6910, 3),
+    (0xF90F, Status::Mapped, 6913, 3), (0xF910, Status::Mapped, 6916, 3), (0xF911, Status::Mapped, 6919, 3),
+    (0xF912, Status::Mapped, 6922, 3), (0xF913, Status::Mapped, 6925, 3), (0xF914, Status::Mapped, 6928, 3),
+    (0xF915, Status::Mapped, 6931, 3), (0xF916, Status::Mapped, 6934, 3), (0xF917, Status::Mapped, 6937, 3),
+    (0xF918, Status::Mapped, 6940, 3), (0xF919, Status::Mapped, 6943, 3), (0xF91A, Status::Mapped, 6946, 3),
+    (0xF91B, Status::Mapped, 6949, 3), (0xF91C, Status::Mapped, 6952, 3), (0xF91D, Status::Mapped, 6955, 3),
+    (0xF91E, Status::Mapped, 6958, 3), (0xF91F, Status::Mapped, 6961, 3), (0xF920, Status::Mapped, 6964, 3),
+    (0xF921, Status::Mapped, 6967, 3), (0xF922, Status::Mapped, 6970, 3), (0xF923, Status::Mapped, 6973, 3),
+    (0xF924, Status::Mapped, 6976, 3), (0xF925, Status::Mapped, 6979, 3), (0xF926, Status::Mapped, 6982, 3),
+    (0xF927, Status::Mapped, 6985, 3), (0xF928, Status::Mapped, 6988, 3), (0xF929, Status::Mapped, 6991, 3),
+    (0xF92A, Status::Mapped, 6994, 3), (0xF92B, Status::Mapped, 6997, 3), (0xF92C, Status::Mapped, 7000, 3),
+    (0xF92D, Status::Mapped, 7003, 3), (0xF92E, Status::Mapped, 7006, 3), (0xF92F, Status::Mapped, 7009, 3),
+    (0xF930, Status::Mapped, 7012, 3), (0xF931, Status::Mapped, 7015, 3), (0xF932, Status::Mapped, 7018, 3),
+    (0xF933, Status::Mapped, 7021, 3), (0xF934, Status::Mapped, 3454, 3), (0xF935, Status::Mapped, 7024, 3),
+    (0xF936, Status::Mapped, 7027, 3), (0xF937, Status::Mapped, 7030, 3), (0xF938, Status::Mapped, 7033, 3),
+    (0xF939, Status::Mapped, 7036, 3), (0xF93A, Status::Mapped, 7039, 3), (0xF93B, Status::Mapped, 7042, 3),
+    (0xF93C, Status::Mapped, 7045, 3), (0xF93D, Status::Mapped, 7048, 3), (0xF93E, Status::Mapped, 7051, 3),
+    (0xF93F, Status::Mapped, 7054, 3), (0xF940, Status::Mapped, 3673, 3), (0xF941, Status::Mapped, 7057, 3),
+    (0xF942, Status::Mapped, 7060, 3), (0xF943, Status::Mapped, 7063, 3), (0xF944, Status::Mapped, 7066, 3),
+    (0xF945, Status::Mapped, 7069, 3), (0xF946, Status::Mapped, 7072, 3), (0xF947, Status::Mapped, 7075, 3),
+    (0xF948, Status::Mapped, 7078, 3), (0xF949, Status::Mapped, 7081, 3), (0xF94A, Status::Mapped, 7084, 3),
+    (0xF94B, Status::Mapped, 7087, 3), (0xF94C, Status::Mapped, 7090, 3), (0xF94D, Status::Mapped, 7093, 3),
+    (0xF94E, Status::Mapped, 7096, 3), (0xF94F, Status::Mapped, 7099, 3), (0xF950, Status::Mapped, 7102, 3),
+    (0xF951, Status::Mapped, 7105, 3), (0xF952, Status::Mapped, 7108, 3), (0xF953, Status::Mapped, 7111, 3),
+    (0xF954, Status::Mapped, 7114, 3), (0xF955, Status::Mapped, 7117, 3), (0xF956, Status::Mapped, 7120, 3),
+    (0xF957, Status::Mapped, 7123, 3), (0xF958, Status::Mapped, 7126, 3), (0xF959, Status::Mapped, 7129, 3),
+    (0xF95A, Status::Mapped, 7132, 3), (0xF95B, Status::Mapped, 7135, 3), (0xF95C, Status::Mapped, 6928, 3),
+    (0xF95D, Status::Mapped, 7138, 3), (0xF95E, Status::Mapped, 7141, 3), (0xF95F, Status::Mapped, 7144, 3),
+    (0xF960, Status::Mapped, 7147, 3), (0xF961, Status::Mapped, 7150, 3), (0xF962, Status::Mapped, 7153, 3),
+    (0xF963, Status::Mapped, 7156, 3), (0xF964, Status::Mapped, 7159, 3), (0xF965, Status::Mapped, 7162, 3),
+    (0xF966, Status::Mapped, 7165, 3), (0xF967, Status::Mapped, 7168, 3), (0xF968, Status::Mapped, 7171, 3),
+    (0xF969, Status::Mapped, 7174, 3), (0xF96A, Status::Mapped, 7177, 3), (0xF96B, Status::Mapped, 7180, 3),
+    (0xF96C, Status::Mapped, 7183, 3), (0xF96D, Status::Mapped, 7186, 3), (0xF96E, Status::Mapped, 7189, 3),
+    (0xF96F, Status::Mapped, 7192, 3), (0xF970, Status::Mapped, 7195, 3), (0xF971, Status::Mapped, 3562, 3),
+    (0xF972, Status::Mapped, 7198, 3), (0xF973, Status::Mapped, 7201, 3), (0xF974, Status::Mapped, 7204, 3),
+    (0xF975, Status::Mapped, 7207, 3), (0xF976, Status::Mapped, 7210, 3), (0xF977, Status::Mapped, 7213, 3),
+    (0xF978, Status::Mapped, 7216, 3), (0xF979, Status::Mapped, 7219, 3), (0xF97A, Status::Mapped, 7222, 3),
+    (0xF97B, Status::Mapped, 7225, 3), (0xF97C, Status::Mapped, 7228, 3), (0xF97D, Status::Mapped, 7231, 3),
+    (0xF97E, Status::Mapped, 7234, 3), (0xF97F, Status::Mapped, 7237, 3), (0xF980, Status::Mapped, 7240, 3),
+    (0xF981, Status::Mapped, 3193, 3), (0xF982, Status::Mapped, 7243, 3), (0xF983, Status::Mapped, 7246, 3),
+    (0xF984, Status::Mapped, 7249, 3), (0xF985, Status::Mapped, 7252, 3), (0xF986, Status::Mapped, 7255, 3),
+    (0xF987, Status::Mapped, 7258, 3), (0xF988, Status::Mapped, 7261, 3), (0xF989, Status::Mapped, 7264, 3),
+    (0xF98A, Status::Mapped, 3136, 3), (0xF98B, Status::Mapped, 7267, 3), (0xF98C, Status::Mapped, 7270, 3),
+    (0xF98D, Status::Mapped, 7273, 3), (0xF98E, Status::Mapped, 7276, 3), (0xF98F, Status::Mapped, 7279, 3),
+    (0xF990, Status::Mapped, 7282, 3), (0xF991, Status::Mapped, 7285, 3), (0xF992, Status::Mapped, 7288, 3),
+    (0xF993, Status::Mapped, 7291, 3), (0xF994, Status::Mapped, 7294, 3), (0xF995, Status::Mapped, 7297, 3),
+    (0xF996, Status::Mapped, 7300, 3), (0xF997, Status::Mapped, 7303, 3), (0xF998, Status::Mapped, 7306, 3),
+    (0xF999, Status::Mapped, 7309, 3), (0xF99A, Status::Mapped, 7312, 3), (0xF99B, Status::Mapped, 7315, 3),
+    (0xF99C, Status::Mapped, 7318, 3), (0xF99D, Status::Mapped, 7321, 3), (0xF99E, Status::Mapped, 7324, 3),
+    (0xF99F, Status::Mapped, 7327, 3), (0xF9A0, Status::Mapped, 7330, 3), (0xF9A1, Status::Mapped, 7192, 3),
+    (0xF9A2, Status::Mapped, 7333, 3), (0xF9A3, Status::Mapped, 7336, 3), (0xF9A4, Status::Mapped, 7339, 3),
+    (0xF9A5, Status::Mapped, 7342, 3), (0xF9A6, Status::Mapped, 7345, 3), (0xF9A7, Status::Mapped, 7348, 3),
+    (0xF9A8, Status::Mapped, 7351, 3), (0xF9A9, Status::Mapped, 7354, 3), (0xF9AA, Status::Mapped, 7144, 3),
+    (0xF9AB, Status::Mapped, 7357, 3), (0xF9AC, Status::Mapped, 7360, 3), (0xF9AD, Status::Mapped, 7363, 3),
+    (0xF9AE, Status::Mapped, 7366, 3), (0xF9AF, Status::Mapped, 7369, 3), (0xF9B0, Status::Mapped, 7372, 3),
+    (0xF9B1, Status::Mapped, 7375, 3), (0xF9B2, Status::Mapped, 7378, 3), (0xF9B3, Status::Mapped, 7381, 3),
+    (0xF9B4, Status::Mapped, 7384, 3), (0xF9B5, Status::Mapped, 7387, 3), (0xF9B6, Status::Mapped, 7390, 3),
+    (0xF9B7, Status::Mapped, 7393, 3), (0xF9B8, Status::Mapped, 7396, 3), (0xF9B9, Status::Mapped, 7399, 3),
+    (0xF9BA, Status::Mapped, 7402, 3), (0xF9BB, Status::Mapped, 7405, 3), (0xF9BC, Status::Mapped, 7408, 3),
+    (0xF9BD, Status::Mapped, 7411, 3), (0xF9BE, Status::Mapped, 7414, 3), (0xF9BF, Status::Mapped, 6928, 3),
+    (0xF9C0, Status::Mapped, 7417, 3), (0xF9C1, Status::Mapped, 7420, 3), (0xF9C2, Status::Mapped, 7423, 3),
+    (0xF9C3, Status::Mapped, 7426, 3), (0xF9C4, Status::Mapped, 3715, 3), (0xF9C5, Status::Mapped, 7429, 3),
+    (0xF9C6, Status::Mapped, 7432, 3), (0xF9C7, Status::Mapped, 7435, 3), (0xF9C8, Status::Mapped, 7438, 3),
+    (0xF9C9, Status::Mapped, 7441, 3), (0xF9CA, Status::Mapped, 7444, 3), (0xF9CB, Status::Mapped, 7447, 3),
+    (0xF9CC, Status::Mapped, 7450, 3), (0xF9CD, Status::Mapped, 7453, 3), (0xF9CE, Status::Mapped, 7456, 3),
+    (0xF9CF, Status::Mapped, 7459, 3), (0xF9D0, Status::Mapped, 7462, 3), (0xF9D1, Status::Mapped, 4506, 3),
+    (0xF9D2, Status::Mapped, 7465, 3), (0xF9D3, Status::Mapped, 7468, 3), (0xF9D4, Status::Mapped, 7471, 3),
+    (0xF9D5, Status::Mapped, 7474, 3), (0xF9D6, Status::Mapped, 7477, 3), (0xF9D7, Status::Mapped, 7480, 3),
+    (0xF9D8, Status::Mapped, 7483, 3), (0xF9D9, Status::Mapped, 7486, 3), (0xF9DA, Status::Mapped, 7489, 3),
+    (0xF9DB, Status::Mapped, 7150, 3), (0xF9DC, Status::Mapped, 7492, 3), (0xF9DD, Status::Mapped, 7495, 3),
+    (0xF9DE, Status::Mapped, 7498, 3), (0xF9DF, Status::Mapped, 7501, 3), (0xF9E0, Status::Mapped, 7504, 3),
+    (0xF9E1, Status::Mapped, 7507, 3), (0xF9E2, Status::Mapped, 7510, 3), (0xF9E3, Status::Mapped, 7513, 3),
+    (0xF9E4, Status::Mapped, 7516, 3), (0xF9E5, Status::Mapped, 7519, 3), (0xF9E6, Status::Mapped, 7522, 3),
+    (0xF9E7, Status::Mapped, 7525, 3), (0xF9E8, Status::Mapped, 7528, 3), (0xF9E9, Status::Mapped, 3577, 3),
+    (0xF9EA, Status::Mapped, 7531, 3), (0xF9EB, Status::Mapped, 7534, 3), (0xF9EC, Status::Mapped, 7537, 3),
+    (0xF9ED, Status::Mapped, 7540, 3), (0xF9EE, Status::Mapped, 7543, 3), (0xF9EF, Status::Mapped, 7546, 3),
+    (0xF9F0, Status::Mapped, 7549, 3), (0xF9F1, Status::Mapped, 7552, 3), (0xF9F2, Status::Mapped, 7555, 3),
+    (0xF9F3, Status::Mapped, 7558, 3), (0xF9F4, Status::Mapped, 7561, 3), (0xF9F5, Status::Mapped, 7564, 3),
+    (0xF9F6, Status::Mapped, 7567, 3), (0xF9F7, Status::Mapped, 3430, 3), (0xF9F8, Status::Mapped, 7570, 3),
+    (0xF9F9, Status::Mapped, 7573, 3), (0xF9FA, Status::Mapped, 7576, 3), (0xF9FB, Status::Mapped, 7579, 3),
+    (0xF9FC, Status::Mapped, 7582, 3), (0xF9FD, Status::Mapped, 7585, 3), (0xF9FE, Status::Mapped, 7588, 3),
+    (0xF9FF, Status::Mapped, 7591, 3), (0xFA00, Status::Mapped, 7594, 3), (0xFA01, Status::Mapped, 7597, 3),
+    (0xFA02, Status::Mapped, 7600, 3), (0xFA03, Status::Mapped, 7603, 3), (0xFA04, Status::Mapped, 7606, 3),
+    (0xFA05, Status::Mapped, 7609, 3), (0xFA06, Status::Mapped, 7612, 3), (0xFA07, Status::Mapped, 7615, 3),
+    (0xFA08, Status::Mapped, 3511, 3), (0xFA09, Status::Mapped, 7618, 3), (0xFA0A, Status::Mapped, 3520, 3),
+    (0xFA0B, Status::Mapped, 7621, 3), (0xFA0C, Status::Mapped, 7624, 3), (0xFA0D, Status::Mapped, 7627, 3),
+    (0xFA0E, Status::Valid, 0, 0), (0xFA10, Status::Mapped, 7630, 3), (0xFA11, Status::Valid, 0, 0),
+    (0xFA12, Status::Mapped, 7633, 3), (0xFA13, Status::Valid, 0, 0), (0xFA15, Status::Mapped, 7636, 3),
+    (0xFA16, Status::Mapped, 7639, 3), (0xFA17, Status::Mapped, 7642, 3), (0xFA18, Status::Mapped, 7645, 3),
+    (0xFA19, Status::Mapped, 7648, 3), (0xFA1A, Status::Mapped, 7651, 3), (0xFA1B, Status::Mapped, 7654, 3),
+    (0xFA1C, Status::Mapped, 7657, 3), (0xFA1D, Status::Mapped, 7660, 3), (0xFA1E, Status::Mapped, 3451, 3),
+    (0xFA1F, Status::Valid, 0, 0), (0xFA20, Status::Mapped, 7663, 3), (0xFA21, Status::Valid, 0, 0),
+    (0xFA22, Status::Mapped, 7666, 3), (0xFA23, Status::Valid, 0, 0), (0xFA25, Status::Mapped, 7669, 3),
+    (0xFA26, Status::Mapped, 7672, 3), (0xFA27, Status::Valid, 0, 0), (0xFA2A, Status::Mapped, 7675, 3),
+    (0xFA2B, Status::Mapped, 7678, 3), (0xFA2C, Status::Mapped, 7681, 3), (0xFA2D, Status::Mapped, 7684, 3),
+    (0xFA2E, Status::Mapped, 7687, 3), (0xFA2F, Status::Mapped, 7690, 3), (0xFA30, Status::Mapped, 7693, 3),
+    (0xFA31, Status::Mapped, 7696, 3), (0xFA32, Status::Mapped, 7699, 3), (0xFA33, Status::Mapped, 7702, 3),
+    (0xFA34, Status::Mapped, 7705, 3), (0xFA35, Status::Mapped, 7708, 3), (0xFA36, Status::Mapped, 7711, 3),
+    (0xFA37, Status::Mapped, 7714, 3), (0xFA38, Status::Mapped, 7717, 3), (0xFA39, Status::Mapped, 7720, 3),
+    (0xFA3A, Status::Mapped, 7723, 3), (0xFA3B, Status::Mapped, 7726, 3), (0xFA3C, Status::Mapped, 3214, 3),
+    (0xFA3D, Status::Mapped, 7729, 3), (0xFA3E, Status::Mapped, 7732, 3), (0xFA3F, Status::Mapped, 7735, 3),
+    (0xFA40, Status::Mapped, 7738, 3), (0xFA41, Status::Mapped, 7741, 3), (0xFA42, Status::Mapped, 7744, 3),
+    (0xFA43, Status::Mapped, 7747, 3), (0xFA44, Status::Mapped, 7750, 3), (0xFA45, Status::Mapped, 7753, 3),
+    (0xFA46, Status::Mapped, 7756, 3), (0xFA47, Status::Mapped, 7759, 3), (0xFA48, Status::Mapped, 7762, 3),
+    (0xFA49, Status::Mapped, 7765, 3), (0xFA4A, Status::Mapped, 7768, 3), (0xFA4B, Status::Mapped, 7771, 3),
+    (0xFA4C, Status::Mapped, 4521, 3), (0xFA4D, Status::Mapped, 7774, 3), (0xFA4E, Status::Mapped, 7777, 3),
+    (0xFA4F, Status::Mapped, 7780, 3), (0xFA50, Status::Mapped, 7783, 3), (0xFA51, Status::Mapped, 4533, 3),
+    (0xFA52, Status::Mapped, 7786, 3), (0xFA53, Status::Mapped, 7789, 3), (0xFA54, Status::Mapped, 7792, 3),
+    (0xFA55, Status::Mapped, 7795, 3), (0xFA56, Status::Mapped, 7798, 3), (0xFA57, Status::Mapped, 7300, 3),
+    (0xFA58, Status::Mapped, 7801, 3), (0xFA59, Status::Mapped, 7804, 3), (0xFA5A, Status::Mapped, 7807, 3),
+    (0xFA5B, Status::Mapped, 7810, 3), (0xFA5C, Status::Mapped, 7813, 3), (0xFA5D, Status::Mapped, 7816, 3),
+    (0xFA5F, Status::Mapped, 7819, 3), (0xFA60, Status::Mapped, 7822, 3), (0xFA61, Status::Mapped, 7825, 3),
+    (0xFA62, Status::Mapped, 7828, 3), (0xFA63, Status::Mapped, 7831, 3), (0xFA64, Status::Mapped, 7834, 3),
+    (0xFA65, Status::Mapped, 7837, 3), (0xFA66, Status::Mapped, 7840, 3), (0xFA67, Status::Mapped, 7669, 3),
+    (0xFA68, Status::Mapped, 7843, 3), (0xFA69, Status::Mapped, 7846, 3), (0xFA6A, Status::Mapped, 7849, 3),
+    (0xFA6B, Status::Mapped, 7852, 3), (0xFA6C, Status::Mapped, 7855, 4), (0xFA6D, Status::Mapped, 7859, 3),
+    (0xFA6E, Status::Disallowed, 0, 0), (0xFA70, Status::Mapped, 7862, 3), (0xFA71, Status::Mapped, 7865, 3),
+    (0xFA72, Status::Mapped, 7868, 3), (0xFA73, Status::Mapped, 7871, 3), (0xFA74, Status::Mapped, 7874, 3),
+    (0xFA75, Status::Mapped, 7877, 3), (0xFA76, Status::Mapped, 7880, 3), (0xFA77, Status::Mapped, 7883, 3),
+    (0xFA78, Status::Mapped, 7711, 3), (0xFA79, Status::Mapped, 7886, 3), (0xFA7A, Status::Mapped, 7889, 3),
+    (0xFA7B, Status::Mapped, 7892, 3), (0xFA7C, Status::Mapped, 7630, 3), (0xFA7D, Status::Mapped, 7895, 3),
+    (0xFA7E, Status::Mapped, 7898, 3), (0xFA7F, Status::Mapped, 7901, 3), (0xFA80, Status::Mapped, 7904, 3),
+    (0xFA81, Status::Mapped, 7907, 3), (0xFA82, Status::Mapped, 7910, 3), (0xFA83, Status::Mapped, 7913, 3),
+    (0xFA84, Status::Mapped, 7916, 3), (0xFA85, Status::Mapped, 7919, 3), (0xFA86, Status::Mapped, 7922, 3),
+    (0xFA87, Status::Mapped, 7925, 3), (0xFA88, Status::Mapped, 7928, 3), (0xFA89, Status::Mapped, 7735, 3),
+    (0xFA8A, Status::Mapped, 7931, 3), (0xFA8B, Status::Mapped, 7738, 3), (0xFA8C, Status::Mapped, 7934, 3),
+    (0xFA8D, Status::Mapped, 7937, 3), (0xFA8E, Status::Mapped, 7940, 3), (0xFA8F, Status::Mapped, 7943, 3),
+    (0xFA90, Status::Mapped, 7946, 3), (0xFA91, Status::Mapped, 7633, 3), (0xFA92, Status::Mapped, 6991, 3),
+    (0xFA93, Status::Mapped, 7949, 3), (0xFA94, Status::Mapped, 7952, 3), (0xFA95, Status::Mapped, 3313, 3),
+    (0xFA96, Status::Mapped, 7195, 3), (0xFA97, Status::Mapped, 7444, 3), (0xFA98, Status::Mapped, 7955, 3),
+    (0xFA99, Status::Mapped, 7958, 3), (0xFA9A, Status::Mapped, 7759, 3), (0xFA9B, Status::Mapped, 7961, 3),
+    (0xFA9C, Status::Mapped, 7762, 3), (0xFA9D, Status::Mapped, 7964, 3), (0xFA9E, Status::Mapped, 7967, 3),
+    (0xFA9F, Status::Mapped, 7970, 3), (0xFAA0, Status::Mapped, 7639, 3), (0xFAA1, Status::Mapped, 7973, 3),
+    (0xFAA2, Status::Mapped, 7976, 3), (0xFAA3, Status::Mapped, 7979, 3), (0xFAA4, Status::Mapped, 7982, 3),
+    (0xFAA5, Status::Mapped, 7985, 3), (0xFAA6, Status::Mapped, 7642, 3), (0xFAA7, Status::Mapped, 7988, 3),
+    (0xFAA8, Status::Mapped, 7991, 3), (0xFAA9, Status::Mapped, 7994, 3), (0xFAAA, Status::Mapped, 7997, 3),
+    (0xFAAB, Status::Mapped, 8000, 3), (0xFAAC, Status::Mapped, 8003, 3), (0xFAAD, Status::Mapped, 7798, 3),
+    (0xFAAE, Status::Mapped, 8006, 3), (0xFAAF, Status::Mapped, 8009, 3), (0xFAB0, Status::Mapped, 7300, 3),
+    (0xFAB1, Status::Mapped, 8012, 3), (0xFAB2, Status::Mapped, 7810, 3), (0xFAB3, Status::Mapped, 8015, 3),
+    (0xFAB4, Status::Mapped, 8018, 3), (0xFAB5, Status::Mapped, 8021, 3), (0xFAB6, Status::Mapped, 8024, 3),
+    (0xFAB7, Status::Mapped, 8027, 3), (0xFAB8, Status::Mapped, 7825, 3), (0xFAB9, Status::Mapped, 8030, 3),
+    (0xFABA, Status::Mapped, 7666, 3), (0xFABB, Status::Mapped, 8033, 3), (0xFABC, Status::Mapped, 7828, 3),
+    (0xFABD, Status::Mapped, 7138, 3), (0xFABE, Status::Mapped, 8036, 3), (0xFABF, Status::Mapped, 7831, 3),
+    (0xFAC0, Status::Mapped, 8039, 3), (0xFAC1, Status::Mapped, 7837, 3), (0xFAC2, Status::Mapped, 8042, 3),
+    (0xFAC3, Status::Mapped, 8045, 3), (0xFAC4, Status::Mapped, 8048, 3), (0xFAC5, Status::Mapped, 8051, 3),
+    (0xFAC6, Status::Mapped, 8054, 3), (0xFAC7, Status::Mapped, 7843, 3), (0xFAC8, Status::Mapped, 7657, 3),
+    (0xFAC9, Status::Mapped, 8057, 3), (0xFACA, Status::Mapped, 7846, 3), (0xFACB, Status::Mapped, 8060, 3),
+    (0xFACC, Status::Mapped, 7849, 3), (0xFACD, Status::Mapped, 8063, 3), (0xFACE, Status::Mapped, 3718, 3),
+    (0xFACF, Status::Mapped, 8066, 4), (0xFAD0, Status::Mapped, 8070, 4), (0xFAD1, Status::Mapped, 8074, 4),
+    (0xFAD2, Status::Mapped, 8078, 3), (0xFAD3, Status::Mapped, 8081, 3), (0xFAD4, Status::Mapped, 8084, 3),
+    (0xFAD5, Status::Mapped, 8087, 4), (0xFAD6, Status::Mapped, 8091, 4), (0xFAD7, Status::Mapped, 8095, 4),
+    (0xFAD8, Status::Mapped, 8099, 3), (0xFAD9, Status::Mapped, 8102, 3), (0xFADA, Status::Disallowed, 0, 0),
+    (0xFB00, Status::Mapped, 8105, 2), (0xFB01, Status::Mapped, 8107, 2), (0xFB02, Status::Mapped, 8109, 2),
+    (0xFB03, Status::Mapped, 8111, 3), (0xFB04, Status::Mapped, 8114, 3), (0xFB05, Status::Mapped, 8117, 2),
+    (0xFB07, Status::Disallowed, 0, 0), (0xFB13, Status::Mapped, 8119, 4), (0xFB14, Status::Mapped, 8123, 4),
+    (0xFB15, Status::Mapped, 8127, 4), (0xFB16, Status::Mapped, 8131, 4), (0xFB17, Status::Mapped, 8135, 4),
+    (0xFB18, Status::Disallowed, 0, 0), (0xFB1D, Status::Mapped, 8139, 4), (0xFB1E, Status::Valid, 0, 0),
+    (0xFB1F, Status::Mapped, 8143, 4), (0xFB20, Status::Mapped, 8147, 2), (0xFB21, Status::Mapped, 2390, 2),
+    (0xFB22, Status::Mapped, 2396, 2), (0xFB23, Status::Mapped, 8149, 2), (0xFB24, Status::Mapped, 8151, 2),
+    (0xFB25, Status::Mapped, 8153, 2), (0xFB26, Status::Mapped, 8155, 2), (0xFB27, Status::Mapped, 8157, 2),
+    (0xFB28, Status::Mapped, 8159, 2), (0xFB29, Status::DisallowedStd3Mapped, 2354, 1), (0xFB2A, Status::Mapped, 8161, 4),
+    (0xFB2B, Status::Mapped, 8165, 4), (0xFB2C, Status::Mapped, 8169, 6), (0xFB2D, Status::Mapped, 8175, 6),
+    (0xFB2E, Status::Mapped, 8181, 4), (0xFB2F, Status::Mapped, 8185, 4), (0xFB30, Status::Mapped, 8189, 4),
+    (0xFB31, Status::Mapped, 8193, 4), (0xFB32, Status::Mapped, 8197, 4), (0xFB33, Status::Mapped, 8201, 4),
+    (0xFB34, Status::Mapped, 8205, 4), (0xFB35, Status::Mapped, 8209, 4), (0xFB36, Status::Mapped, 8213, 4),
+    (0xFB37, Status::Disallowed, 0, 0), (0xFB38, Status::Mapped, 8217, 4), (0xFB39, Status::Mapped, 8221, 4),
+    (0xFB3A, Status::Mapped, 8225, 4), (0xFB3B, Status::Mapped, 8229, 4), (0xFB3C, Status::Mapped, 8233, 4),
+    (0xFB3D, Status::Disallowed, 0, 0), (0xFB3E, Status::Mapped, 8237, 4), (0xFB3F, Status::Disallowed, 0, 0),
+    (0xFB40, Status::Mapped, 8241, 4), (0xFB41, Status::Mapped, 8245, 4), (0xFB42, Status::Disallowed, 0, 0),
+    (0xFB43, Status::Mapped, 8249, 4), (0xFB44, Status::Mapped, 8253, 4), (0xFB45, Status::Disallowed, 0, 0),
+    (0xFB46, Status::Mapped, 8257, 4), (0xFB47, Status::Mapped, 8261, 4), (0xFB48, Status::Mapped, 8265, 4),
+    (0xFB49, Status::Mapped, 8269, 4), (0xFB4A, Status::Mapped, 8273, 4), (0xFB4B, Status::Mapped, 8277, 4),
+    (0xFB4C, Status::Mapped, 8281, 4), (0xFB4D, Status::Mapped, 8285, 4), (0xFB4E, Status::Mapped, 8289, 4),
+    (0xFB4F, Status::Mapped, 8293, 4), (0xFB50, Status::Mapped, 8297, 2), (0xFB52, Status::Mapped, 8299, 2),
+    (0xFB56, Status::Mapped, 8301, 2), (0xFB5A, Status::Mapped, 8303, 2), (0xFB5E, Status::Mapped, 8305, 2),
+    (0xFB62, Status::Mapped, 8307, 2), (0xFB66, Status::Mapped, 8309, 2), (0xFB6A, Status::Mapped, 8311, 2),
+    (0xFB6E, Status::Mapped, 8313, 2), (0xFB72, Status::Mapped, 8315, 2), (0xFB76, Status::Mapped, 8317, 2),
+    (0xFB7A, Status::Mapped, 8319, 2), (0xFB7E, Status::Mapped, 8321, 2), (0xFB82, Status::Mapped, 8323, 2),
+    (0xFB84, Status::Mapped, 8325, 2), (0xFB86, Status::Mapped, 8327, 2), (0xFB88, Status::Mapped, 8329, 2),
+    (0xFB8A, Status::Mapped, 8331, 2), (0xFB8C, Status::Mapped, 8333, 2), (0xFB8E, Status::Mapped, 8335, 2),
+    (0xFB92, Status::Mapped, 8337, 2), (0xFB96, Status::Mapped, 8339, 2), (0xFB9A, Status::Mapped, 8341, 2),
+    (0xFB9E, Status::Mapped, 8343, 2), (0xFBA0, Status::Mapped, 8345, 2), (0xFBA4, Status::Mapped, 8347, 2),
+    (0xFBA6, Status::Mapped, 8349, 2), (0xFBAA, Status::Mapped, 8351, 2), (0xFBAE, Status::Mapped, 8353, 2),
+    (0xFBB0, Status::Mapped, 8355, 2), (0xFBB2, Status::Valid, 0, 0), (0xFBC2, Status::Disallowed, 0, 0),
+    (0xFBD3, Status::Mapped, 8357, 2), (0xFBD7, Status::Mapped, 8359, 2), (0xFBD9, Status::Mapped, 8361, 2),
+    (0xFBDB, Status::Mapped, 8363, 2), (0xFBDD, Status::Mapped, 1002, 4), (0xFBDE, Status::Mapped, 8365, 2),
+    (0xFBE0, Status::Mapped, 8367, 2), (0xFBE2, Status::Mapped, 8369, 2), (0xFBE4, Status::Mapped, 8371, 2),
+    (0xFBE8, Status::Mapped, 8373, 2), (0xFBEA, Status::Mapped, 8375, 4), (0xFBEC, Status::Mapped, 8379, 4),
+    (0xFBEE, Status::Mapped, 8383, 4), (0xFBF0, Status::Mapped, 8387, 4), (0xFBF2, Status::Mapped, 8391, 4),
+    (0xFBF4, Status::Mapped, 8395, 4), (0xFBF6, Status::Mapped, 8399, 4), (0xFBF9, Status::Mapped, 8403, 4),
+    (0xFBFC, Status::Mapped, 8407, 2), (0xFC00, Status::Mapped, 8409, 4), (0xFC01, Status::Mapped, 8413, 4),
+    (0xFC02, Status::Mapped, 8417, 4), (0xFC03, Status::Mapped, 8403, 4), (0xFC04, Status::Mapped, 8421, 4),
+    (0xFC05, Status::Mapped, 8425, 4), (0xFC06, Status::Mapped, 8429, 4), (0xFC07, Status::Mapped, 8433, 4),
+    (0xFC08, Status::Mapped, 8437, 4), (0xFC09, Status::Mapped, 8441, 4), (0xFC0A, Status::Mapped, 8445, 4),
+    (0xFC0B, Status::Mapped, 8449, 4), (0xFC0C, Status::Mapped, 8453, 4), (0xFC0D, Status::Mapped, 8457, 4),
+    (0xFC0E, Status::Mapped, 8461, 4), (0xFC0F, Status::Mapped, 8465, 4), (0xFC10, Status::Mapped, 8469, 4),
+    (0xFC11, Status::Mapped, 8473, 4), (0xFC12, Status::Mapped, 8477, 4), (0xFC13, Status::Mapped, 8481, 4),
+    (0xFC14, Status::Mapped, 8485, 4), (0xFC15, Status::Mapped, 8489, 4), (0xFC16, Status::Mapped, 8493, 4),
+    (0xFC17, Status::Mapped, 8497, 4), (0xFC18, Status::Mapped, 8501, 4), (0xFC19, Status::Mapped, 8505, 4),
+    (0xFC1A, Status::Mapped, 8509, 4), (0xFC1B, Status::Mapped, 8513, 4), (0xFC1C, Status::Mapped, 8517, 4),
+    (0xFC1D, Status::Mapped, 8521, 4), (0xFC1E, Status::Mapped, 8525, 4), (0xFC1F, Status::Mapped, 8529, 4),
+    (0xFC20, Status::Mapped, 8533, 4), (0xFC21, Status::Mapped, 8537, 4), (0xFC22, Status::Mapped, 8541, 4),
+    (0xFC23, Status::Mapped, 8545, 4), (0xFC24, Status::Mapped, 8549, 4), (0xFC25, Status::Mapped, 8553, 4),
+    (0xFC26, Status::Mapped, 8557, 4), (0xFC27, Status::Mapped, 8561, 4), (0xFC28, Status::Mapped, 8565, 4),
+    (0xFC29, Status::Mapped, 8569, 4), (0xFC2A, Status::Mapped, 8573, 4), (0xFC2B, Status::Mapped, 8577, 4),
+    (0xFC2C, Status::Mapped, 8581, 4), (0xFC2D, Status::Mapped, 8585, 4), (0xFC2E, Status::Mapped, 8589, 4),
+    (0xFC2F, Status::Mapped, 8593, 4), (0xFC30, Status::Mapped, 8597, 4), (0xFC31, Status::Mapped, 8601, 4),
+    (0xFC32, Status::Mapped, 8605, 4), (0xFC33, Status::Mapped, 8609, 4), (0xFC34, Status::Mapped, 8613, 4),
+    (0xFC35, Status::Mapped, 8617, 4), (0xFC36, Status::Mapped, 8621, 4), (0xFC37, Status::Mapped, 8625, 4),
+    (0xFC38, Status::Mapped, 8629, 4), (0xFC39, Status::Mapped, 8633, 4), (0xFC3A, Status::Mapped, 8637, 4),
+    (0xFC3B, Status::Mapped, 8641, 4), (0xFC3C, Status::Mapped, 8645, 4), (0xFC3D, Status::Mapped, 8649, 4),
+    (0xFC3E, Status::Mapped, 8653, 4), (0xFC3F, Status::Mapped, 8657, 4), (0xFC40, Status::Mapped, 8661, 4),
+    (0xFC41, Status::Mapped, 8665, 4), (0xFC42, Status::Mapped, 8669, 4), (0xFC43, Status::Mapped, 8673, 4),
+    (0xFC44, Status::Mapped, 8677, 4), (0xFC45, Status::Mapped, 8681, 4), (0xFC46, Status::Mapped, 8685, 4),
+    (0xFC47, Status::Mapped, 8689, 4), (0xFC48, Status::Mapped, 8693, 4), (0xFC49, Status::Mapped, 8697, 4),
+    (0xFC4A, Status::Mapped, 8701, 4), (0xFC4B, Status::Mapped, 8705, 4), (0xFC4C, Status::Mapped, 8709, 4),
+    (0xFC4D, Status::Mapped, 8713, 4), (0xFC4E, Status::Mapped, 8717, 4), (0xFC4F, Status::Mapped, 8721, 4),
+    (0xFC50, Status::Mapped, 8725, 4), (0xFC51, Status::Mapped, 8729, 4), (0xFC52, Status::Mapped, 8733, 4),
+    (0xFC53, Status::Mapped, 8737, 4), (0xFC54, Status::Mapped, 8741, 4), (0xFC55, Status::Mapped, 8745, 4),
+    (0xFC56, Status::Mapped, 8749, 4), (0xFC57, Status::Mapped, 8753, 4), (0xFC58, Status::Mapped, 8757, 4),
+    (0xFC59, Status::Mapped, 8761, 4), (0xFC5A, Status::Mapped, 8765, 4), (0xFC5B, Status::Mapped, 8769, 4),
+    (0xFC5C, Status::Mapped, 8773, 4), (0xFC5D, Status::Mapped, 8777, 4), (0xFC5E, Status::DisallowedStd3Mapped, 8781, 5),
+    (0xFC5F, Status::DisallowedStd3Mapped, 8786, 5), (0xFC60, Status::DisallowedStd3Mapped, 8791, 5), (0xFC61, Status::DisallowedStd3Mapped, 8796, 5),
+    (0xFC62, Status::DisallowedStd3Mapped, 8801, 5), (0xFC63, Status::DisallowedStd3Mapped, 8806, 5), (0xFC64, Status::Mapped, 8811, 4),
+    (0xFC65, Status::Mapped, 8815, 4), (0xFC66, Status::Mapped, 8417, 4), (0xFC67, Status::Mapped, 8819, 4),
+    (0xFC68, Status::Mapped, 8403, 4), (0xFC69, Status::Mapped, 8421, 4), (0xFC6A, Status::Mapped, 8823, 4),
+    (0xFC6B, Status::Mapped, 8827, 4), (0xFC6C, Status::Mapped, 8437, 4), (0xFC6D, Status::Mapped, 8831, 4),
+    (0xFC6E, Status::Mapped, 8441, 4), (0xFC6F, Status::Mapped, 8445, 4), (0xFC70, Status::Mapped, 8835, 4),
+    (0xFC71, Status::Mapped, 8839, 4), (0xFC72, Status::Mapped, 8461, 4), (0xFC73, Status::Mapped, 8843, 4),
+    (0xFC74, Status::Mapped, 8465, 4), (0xFC75, Status::Mapped, 8469, 4), (0xFC76, Status::Mapped, 8847, 4),
+    (0xFC77, Status::Mapped, 8851, 4), (0xFC78, Status::Mapped, 8477, 4), (0xFC79, Status::Mapped, 8855, 4),
+    (0xFC7A, Status::Mapped, 8481, 4), (0xFC7B, Status::Mapped, 8485, 4), (0xFC7C, Status::Mapped, 8601, 4),
+    (0xFC7D, Status::Mapped, 8605, 4), (0xFC7E, Status::Mapped, 8617, 4), (0xFC7F, Status::Mapped, 8621, 4),
+    (0xFC80, Status::Mapped, 8625, 4), (0xFC81, Status::Mapped, 8641, 4), (0xFC82, Status::Mapped, 8645, 4),
+    (0xFC83, Status::Mapped, 8649, 4), (0xFC84, Status::Mapped, 8653, 4), (0xFC85, Status::Mapped, 8669, 4),
+    (0xFC86, Status::Mapped, 8673, 4), (0xFC87, Status::Mapped, 8677, 4), (0xFC88, Status::Mapped, 8859, 4),
+    (0xFC89, Status::Mapped, 8693, 4), (0xFC8A, Status::Mapped, 8863, 4), (0xFC8B, Status::Mapped, 8867, 4),
+    (0xFC8C, Status::Mapped, 8717, 4), (0xFC8D, Status::Mapped, 8871, 4), (0xFC8E, Status::Mapped, 8721, 4),
+    (0xFC8F, Status::Mapped, 8725, 4), (0xFC90, Status::Mapped, 8777, 4), (0xFC91, Status::Mapped, 8875, 4),
+    (0xFC92, Status::Mapped, 8879, 4), (0xFC93, Status::Mapped, 8757, 4), (0xFC94, Status::Mapped, 8883, 4),
+    (0xFC95, Status::Mapped, 8761, 4), (0xFC96, Status::Mapped, 8765, 4), (0xFC97, Status::Mapped, 8409, 4),
+    (0xFC98, Status::Mapped, 8413, 4), (0xFC99, Status::Mapped, 8887, 4), (0xFC9A, Status::Mapped, 8417, 4),
+    (0xFC9B, Status::Mapped, 8891, 4), (0xFC9C, Status::Mapped, 8425, 4), (0xFC9D, Status::Mapped, 8429, 4),
+    (0xFC9E, Status::Mapped, 8433, 4), (0xFC9F, Status::Mapped, 8437, 4), (0xFCA0, Status::Mapped, 8895, 4),
+    (0xFCA1, Status::Mapped, 8449, 4), (0xFCA2, Status::Mapped, 8453, 4), (0xFCA3, Status::Mapped, 8457, 4),
+    (0xFCA4, Status::Mapped, 8461, 4), (0xFCA5, Status::Mapped, 8899, 4), (0xFCA6, Status::Mapped, 8477, 4),
+    (0xFCA7, Status::Mapped, 8489, 4), (0xFCA8, Status::Mapped, 8493, 4), (0xFCA9, Status::Mapped, 8497, 4),
+    (0xFCAA, Status::Mapped, 8501, 4), (0xFCAB, Status::Mapped, 8505, 4), (0xFCAC, Status::Mapped, 8513, 4),
+    (0xFCAD, Status::Mapped, 8517, 4), (0xFCAE, Status::Mapped, 8521, 4), (0xFCAF, Status::Mapped, 8525, 4),
+    (0xFCB0, Status::Mapped, 8529, 4), (0xFCB1, Status::Mapped, 8533, 4), (0xFCB2, Status::Mapped, 8903, 4),
+    (0xFCB3, Status::Mapped, 8537, 4), (0xFCB4, Status::Mapped, 8541, 4), (0xFCB5, Status::Mapped, 8545, 4),
+    (0xFCB6, Status::Mapped, 8549, 4), (0xFCB7, Status::Mapped, 8553, 4), (0xFCB8, Status::Mapped, 8557, 4),
+    (0xFCB9, Status::Mapped, 8565, 4), (0xFCBA, Status::Mapped, 8569, 4), (0xFCBB, Status::Mapped, 8573, 4),
+    (0xFCBC, Status::Mapped, 8577, 4), (0xFCBD, Status::Mapped, 8581, 4), (0xFCBE, Status::Mapped, 8585, 4),
+    (0xFCBF, Status::Mapped, 8589, 4), (0xFCC0, Status::Mapped, 8593, 4), (0xFCC1, Status::Mapped, 8597, 4),
+    (0xFCC2, Status::Mapped, 8609, 4), (0xFCC3, Status::Mapped, 8613, 4), (0xFCC4, Status::Mapped, 8629, 4),
+    (0xFCC5, Status::Mapped, 8633, 4), (0xFCC6, Status::Mapped, 8637, 4), (0xFCC7, Status::Mapped, 8641, 4),
+    (0xFCC8, Status::Mapped, 8645, 4), (0xFCC9, Status::Mapped, 8657, 4), (0xFCCA, Status::Mapped, 8661, 4),
+    (0xFCCB, Status::Mapped, 8665, 4), (0xFCCC, Status::Mapped, 8669, 4), (0xFCCD, Status::Mapped, 8907, 4),
+    (0xFCCE, Status::Mapped, 8681, 4), (0xFCCF, Status::Mapped, 8685, 4), (0xFCD0, Status::Mapped, 8689, 4),
+    (0xFCD1, Status::Mapped, 8693, 4), (0xFCD2, Status::Mapped, 8705, 4), (0xFCD3, Status::Mapped, 8709, 4),
+    (0xFCD4, Status::Mapped, 8713, 4), (0xFCD5, Status::Mapped, 8717, 4), (0xFCD6, Status::Mapped, 8911, 4),
+    (0xFCD7, Status::Mapped, 8729, 4), (0xFCD8, Status::Mapped, 8733, 4), (0xFCD9, Status::Mapped, 8915, 4),
+    (0xFCDA, Status::Mapped, 8745, 4), (0xFCDB, Status::Mapped, 8749, 4), (0xFCDC, Status::Mapped, 8753, 4),
+    (0xFCDD, Status::Mapped, 8757, 4), (0xFCDE, Status::Mapped, 8919, 4), (0xFCDF, Status::Mapped, 8417, 4),
+    (0xFCE0, Status::Mapped, 8891, 4), (0xFCE1, Status::Mapped, 8437, 4), (0xFCE2, Status::Mapped, 8895, 4),
+    (0xFCE3, Status::Mapped, 8461, 4), (0xFCE4, Status::Mapped, 8899, 4), (0xFCE5, Status::Mapped, 8477, 4),
+    (0xFCE6, Status::Mapped, 8923, 4), (0xFCE7, Status::Mapped, 8529, 4), (0xFCE8, Status::Mapped, 8927, 4),
+    (0xFCE9, Status::Mapped, 8931, 4), (0xFCEA, Status::Mapped, 8935, 4), (0xFCEB, Status::Mapped, 8641, 4),
+    (0xFCEC, Status::Mapped, 8645, 4), (0xFCED, Status::Mapped, 8669, 4), (0xFCEE, Status::Mapped, 8717, 4),
+    (0xFCEF, Status::Mapped, 8911, 4), (0xFCF0, Status::Mapped, 8757, 4), (0xFCF1, Status::Mapped, 8919, 4),
+    (0xFCF2, Status::Mapped, 8939, 6), (0xFCF3, Status::Mapped, 8945, 6), (0xFCF4, Status::Mapped, 8951, 6),
+    (0xFCF5, Status::Mapped, 8957, 4), (0xFCF6, Status::Mapped, 8961, 4), (0xFCF7, Status::Mapped, 8965, 4),
+    (0xFCF8, Status::Mapped, 8969, 4), (0xFCF9, Status::Mapped, 8973, 4), (0xFCFA, Status::Mapped, 8977, 4),
+    (0xFCFB, Status::Mapped, 8981, 4), (0xFCFC, Status::Mapped, 8985, 4), (0xFCFD, Status::Mapped, 8989, 4),
+    (0xFCFE, Status::Mapped, 8993, 4), (0xFCFF, Status::Mapped, 8997, 4), (0xFD00, Status::Mapped, 9001, 4),
+    (0xFD01, Status::Mapped, 9005, 4), (0xFD02, Status::Mapped, 9009, 4), (0xFD03, Status::Mapped, 9013, 4),
+    (0xFD04, Status::Mapped, 9017, 4), (0xFD05, Status::Mapped, 9021, 4), (0xFD06, Status::Mapped, 9025, 4),
+    (0xFD07, Status::Mapped, 9029, 4), (0xFD08, Status::Mapped, 9033, 4), (0xFD09, Status::Mapped, 9037, 4),
+    (0xFD0A, Status::Mapped, 9041, 4), (0xFD0B, Status::Mapped, 9045, 4), (0xFD0C, Status::Mapped, 8931, 4),
+    (0xFD0D, Status::Mapped, 9049, 4), (0xFD0E, Status::Mapped, 9053, 4), (0xFD0F, Status::Mapped, 9057, 4),
+    (0xFD10, Status::Mapped, 9061, 4), (0xFD11, Status::Mapped, 8957, 4), (0xFD12, Status::Mapped, 8961, 4),
+    (0xFD13, Status::Mapped, 8965, 4), (0xFD14, Status::Mapped, 8969, 4), (0xFD15, Status::Mapped, 8973, 4),
+    (0xFD16, Status::Mapped, 8977, 4), (0xFD17, Status::Mapped, 8981, 4), (0xFD18, Status::Mapped, 8985, 4),
+    (0xFD19, Status::Mapped, 8989, 4), (0xFD1A, Status::Mapped, 8993, 4), (0xFD1B, Status::Mapped, 8997, 4),
+    (0xFD1C, Status::Mapped, 9001, 4), (0xFD1D, Status::Mapped, 9005, 4), (0xFD1E, Status::Mapped, 9009, 4),
+    (0xFD1F, Status::Mapped, 9013, 4), (0xFD20, Status::Mapped, 9017, 4), (0xFD21, Status::Mapped, 9021, 4),
+    (0xFD22, Status::Mapped, 9025, 4), (0xFD23, Status::Mapped, 9029, 4), (0xFD24, Status::Mapped, 9033, 4),
+    (0xFD25, Status::Mapped, 9037, 4), (0xFD26, Status::Mapped, 9041, 4), (0xFD27, Status::Mapped, 9045, 4),
+    (0xFD28, Status::Mapped, 8931, 4), (0xFD29, Status::Mapped, 9049, 4), (0xFD2A, Status::Mapped, 9053, 4),
+    (0xFD2B, Status::Mapped, 9057, 4), (0xFD2C, Status::Mapped, 9061, 4), (0xFD2D, Status::Mapped, 9037, 4),
+    (0xFD2E, Status::Mapped, 9041, 4), (0xFD2F, Status::Mapped, 9045, 4), (0xFD30, Status::Mapped, 8931, 4),
+    (0xFD31, Status::Mapped, 8927, 4), (0xFD32, Status::Mapped, 8935, 4), (0xFD33, Status::Mapped, 8561, 4),
+    (0xFD34, Status::Mapped, 8517, 4), (0xFD35, Status::Mapped, 8521, 4), (0xFD36, Status::Mapped, 8525, 4),
+    (0xFD37, Status::Mapped, 9037, 4), (0xFD38, Status::Mapped, 9041, 4), (0xFD39, Status::Mapped, 9045, 4),
+    (0xFD3A, Status::Mapped, 8561, 4), (0xFD3B, Status::Mapped, 8565, 4), (0xFD3C, Status::Mapped, 9065, 4),
+    (0xFD3E, Status::Valid, 0, 0), (0xFD40, Status::Disallowed, 0, 0), (0xFD50, Status::Mapped, 9069, 6),
+    (0xFD51, Status::Mapped, 9075, 6), (0xFD53, Status::Mapped, 9081, 6), (0xFD54, Status::Mapped, 9087, 6),
+    (0xFD55, Status::Mapped, 9093, 6), (0xFD56, Status::Mapped, 9099, 6), (0xFD57, Status::Mapped, 9105, 6),
+    (0xFD58, Status::Mapped, 9111, 6), (0xFD5A, Status::Mapped, 9117, 6), (0xFD5B, Status::Mapped, 9123, 6),
+    (0xFD5C, Status::Mapped, 9129, 6), (0xFD5D, Status::Mapped, 9135, 6), (0xFD5E, Status::Mapped, 9141, 6),
+    (0xFD5F, Status::Mapped, 9147, 6), (0xFD61, Status::Mapped, 9153, 6), (0xFD62, Status::Mapped, 9159, 6),
+    (0xFD64, Status::Mapped, 9165, 6), (0xFD66, Status::Mapped, 9171, 6), (0xFD67, Status::Mapped, 9177, 6),
+    (0xFD69, Status::Mapped, 9183, 6), (0xFD6A, Status::Mapped, 9189, 6), (0xFD6C, Status::Mapped, 9195, 6),
+    (0xFD6E, Status::Mapped, 9201, 6), (0xFD6F, Status::Mapped, 9207, 6), (0xFD71, Status::Mapped, 9213, 6),
+    (0xFD73, Status::Mapped, 9219, 6), (0xFD74, Status::Mapped, 9225, 6), (0xFD75, Status::Mapped, 9231, 6),
+    (0xFD76, Status::Mapped, 9237, 6), (0xFD78, Status::Mapped, 9243, 6), (0xFD79, Status::Mapped, 9249, 6),
+    (0xFD7A, Status::Mapped, 9255, 6), (0xFD7B, Status::Mapped, 9261, 6), (0xFD7C, Status::Mapped, 9267, 6),
+    (0xFD7E, Status::Mapped, 9273, 6), (0xFD7F, Status::Mapped, 9279, 6), (0xFD80, Status::Mapped, 9285, 6),
+    (0xFD81, Status::Mapped, 9291, 6), (0xFD82, Status::Mapped, 9297, 6), (0xFD83, Status::Mapped, 9303, 6),
+    (0xFD85, Status::Mapped, 9309, 6), (0xFD87, Status::Mapped, 9315, 6), (0xFD89, Status::Mapped, 9321, 6),
+    (0xFD8A, Status::Mapped, 9327, 6), (0xFD8B, Status::Mapped, 9333, 6), (0xFD8C, Status::Mapped, 9339, 6),
+    (0xFD8D, Status::Mapped, 9345, 6), (0xFD8E, Status::Mapped, 9351, 6), (0xFD8F, Status::Mapped, 9357, 6),
+    (0xFD90, Status::Disallowed, 0, 0), (0xFD92, Status::Mapped, 9363, 6), (0xFD93, Status::Mapped, 9369, 6),
+    (0xFD94, Status::Mapped, 9375, 6), (0xFD95, Status::Mapped, 9381, 6), (0xFD96, Status::Mapped, 9387, 6),
+    (0xFD97, Status::Mapped, 9393, 6), (0xFD99, Status::Mapped, 9399, 6), (0xFD9A, Status::Mapped, 9405, 6),
+    (0xFD9B, Status::Mapped, 9411, 6), (0xFD9C, Status::Mapped, 9417, 6), (0xFD9E, Status::Mapped, 9423, 6),
+    (0xFD9F, Status::Mapped, 9429, 6), (0xFDA0, Status::Mapped, 9435, 6), (0xFDA1, Status::Mapped, 9441, 6),
+    (0xFDA2, Status::Mapped, 9447, 6), (0xFDA3, Status::Mapped, 9453, 6), (0xFDA4, Status::Mapped, 9459, 6),
+    (0xFDA5, Status::Mapped, 9465, 6), (0xFDA6, Status::Mapped, 9471, 6), (0xFDA7, Status::Mapped, 9477, 6),
+    (0xFDA8, Status::Mapped, 9483, 6), (0xFDA9, Status::Mapped, 9489, 6), (0xFDAA, Status::Mapped, 9495, 6),
+    (0xFDAB, Status::Mapped, 9501, 6), (0xFDAC, Status::Mapped, 9507, 6), (0xFDAD, Status::Mapped, 9513, 6),
+    (0xFDAE, Status::Mapped, 9519, 6), (0xFDAF, Status::Mapped, 9525, 6), (0xFDB0, Status::Mapped, 9531, 6),
+    (0xFDB1, Status::Mapped, 9537, 6), (0xFDB2, Status::Mapped, 9543, 6), (0xFDB3, Status::Mapped, 9549, 6),
+    (0xFDB4, Status::Mapped, 9273, 6), (0xFDB5, Status::Mapped, 9285, 6), (0xFDB6, Status::Mapped, 9555, 6),
+    (0xFDB7, Status::Mapped, 9561, 6), (0xFDB8, Status::Mapped, 9567, 6), (0xFDB9, Status::Mapped, 9573, 6),
+    (0xFDBA, Status::Mapped, 9579, 6), (0xFDBB, Status::Mapped, 9585, 6), (0xFDBC, Status::Mapped, 9579, 6),
+    (0xFDBD, Status::Mapped, 9567, 6), (0xFDBE, Status::Mapped, 9591, 6), (0xFDBF, Status::Mapped, 9597, 6),
+    (0xFDC0, Status::Mapped, 9603, 6), (0xFDC1, Status::Mapped, 9609, 6), (0xFDC2, Status::Mapped, 9615, 6),
+    (0xFDC3, Status::Mapped, 9585, 6), (0xFDC4, Status::Mapped, 9231, 6), (0xFDC5, Status::Mapped, 9171, 6),
+    (0xFDC6, Status::Mapped, 9621, 6), (0xFDC7, Status::Mapped, 9627, 6), (0xFDC8, Status::Disallowed, 0, 0),
+    (0xFDF0, Status::Mapped, 9633, 6), (0xFDF1, Status::Mapped, 9639, 6), (0xFDF2, Status::Mapped, 9645, 8),
+    (0xFDF3, Status::Mapped, 9653, 8), (0xFDF4, Status::Mapped, 9661, 8), (0xFDF5, Status::Mapped, 9669, 8),
+    (0xFDF6, Status::Mapped, 9677, 8), (0xFDF7, Status::Mapped, 9685, 8), (0xFDF8, Status::Mapped, 9693, 8),
+    (0xFDF9, Status::Mapped, 9701, 6), (0xFDFA, Status::DisallowedStd3Mapped, 9707, 33), (0xFDFB, Status::DisallowedStd3Mapped, 9740, 15),
+    (0xFDFC, Status::Mapped, 9755, 8), (0xFDFD, Status::Valid, 0, 0), (0xFDFE, Status::Disallowed, 0, 0),
+    (0xFE00, Status::Ignored, 0, 0), (0xFE10, Status::DisallowedStd3Mapped, 9763, 1), (0xFE11, Status::Mapped, 9764, 3),
+    (0xFE12, Status::Disallowed, 0, 0), (0xFE13, Status::DisallowedStd3Mapped, 9767, 1), (0xFE14, Status::DisallowedStd3Mapped, 512, 1),
+    (0xFE15, Status::DisallowedStd3Mapped, 9768, 1), (0xFE16, Status::DisallowedStd3Mapped, 9769, 1), (0xFE17, Status::Mapped, 9770, 3),
+    (0xFE18, Status::Mapped, 9773, 3), (0xFE19, Status::Disallowed, 0, 0), (0xFE20, Status::Valid, 0, 0),
+    (0xFE30, Status::Disallowed, 0, 0), (0xFE31, Status::Mapped, 9776, 3), (0xFE32, Status::Mapped, 9779, 3),
+    (0xFE33, Status::DisallowedStd3Mapped, 9782, 1), (0xFE35, Status::DisallowedStd3Mapped, 2359, 1), (0xFE36, Status::DisallowedStd3Mapped, 2360, 1),
+    (0xFE37, Status::DisallowedStd3Mapped, 9783, 1), (0xFE38, Status::DisallowedStd3Mapped, 9784, 1), (0xFE39, Status::Mapped, 9785, 3),
+    (0xFE3A, Status::Mapped, 9788, 3), (0xFE3B, Status::Mapped, 9791, 3), (0xFE3C, Status::Mapped, 9794, 3),
+    (0xFE3D, Status::Mapped, 9797, 3), (0xFE3E, Status::Mapped, 9800, 3), (0xFE3F, Status::Mapped, 2542, 3),
+    (0xFE40, Status::Mapped, 2545, 3), (0xFE41, Status::Mapped, 9803, 3), (0xFE42, Status::Mapped, 9806, 3),
+    (0xFE43, Status::Mapped, 9809, 3), (0xFE44, Status::Mapped, 9812, 3), (0xFE45, Status::Valid, 0, 0),
+    (0xFE47, Status::DisallowedStd3Mapped, 9815, 1), (0xFE48, Status::DisallowedStd3Mapped, 9816, 1), (0xFE49, Status::DisallowedStd3Mapped, 2326, 3),
+    (0xFE4D, Status::DisallowedStd3Mapped, 9782, 1), (0xFE50, Status::DisallowedStd3Mapped, 9763, 1), (0xFE51, Status::Mapped, 9764, 3),
+    (0xFE52, Status::Disallowed, 0, 0), (0xFE54, Status::DisallowedStd3Mapped, 512, 1), (0xFE55, Status::DisallowedStd3Mapped, 9767, 1),
+    (0xFE56, Status::DisallowedStd3Mapped, 9769, 1), (0xFE57, Status::DisallowedStd3Mapped, 9768, 1), (0xFE58, Status::Mapped, 9776, 3),
+    (0xFE59, Status::DisallowedStd3Mapped, 2359, 1), (0xFE5A, Status::DisallowedStd3Mapped, 2360, 1), (0xFE5B, Status::DisallowedStd3Mapped, 9783, 1),
+    (0xFE5C, Status::DisallowedStd3Mapped, 9784, 1), (0xFE5D, Status::Mapped, 9785, 3), (0xFE5E, Status::Mapped, 9788, 3),
+    (0xFE5F, Status::DisallowedStd3Mapped, 9817, 1), (0xFE60, Status::DisallowedStd3Mapped, 9818, 1), (0xFE61, Status::DisallowedStd3Mapped, 9819, 1),
+    (0xFE62, Status::DisallowedStd3Mapped, 2354, 1), (0xFE63, Status::Mapped, 9820, 1), (0xFE64, Status::DisallowedStd3Mapped, 9821, 1),
+    (0xFE65, Status::DisallowedStd3Mapped, 9822, 1), (0xFE66, Status::DisallowedStd3Mapped, 2358, 1), (0xFE67, Status::Disallowed, 0, 0),
+    (0xFE68, Status::DisallowedStd3Mapped, 9823, 1), (0xFE69, Status::DisallowedStd3Mapped, 9824, 1), (0xFE6A, Status::DisallowedStd3Mapped, 9825, 1),
+    (0xFE6B, Status::DisallowedStd3Mapped, 9826, 1), (0xFE6C, Status::Disallowed, 0, 0), (0xFE70, Status::DisallowedStd3Mapped, 9827, 3),
+    (0xFE71, Status::Mapped, 9830, 4), (0xFE72, Status::DisallowedStd3Mapped, 9834, 3), (0xFE73, Status::Valid, 0, 0),
+    (0xFE74, Status::DisallowedStd3Mapped, 9837, 3), (0xFE75, Status::Disallowed, 0, 0), (0xFE76, Status::DisallowedStd3Mapped, 9840, 3),
+    (0xFE77, Status::Mapped, 9843, 4), (0xFE78, Status::DisallowedStd3Mapped, 9847, 3), (0xFE79, Status::Mapped, 9850, 4),
+    (0xFE7A, Status::DisallowedStd3Mapped, 9854, 3), (0xFE7B, Status::Mapped, 9857, 4), (0xFE7C, Status::DisallowedStd3Mapped, 9861, 3),
+    (0xFE7D, Status::Mapped, 9864, 4), (0xFE7E, Status::DisallowedStd3Mapped, 9868, 3), (0xFE7F, Status::Mapped, 9871, 4),
+    (0xFE80, Status::Mapped, 9875, 2), (0xFE81, Status::Mapped, 9877, 2), (0xFE83, Status::Mapped, 9879, 2),
+    (0xFE85, Status::Mapped, 9881, 2), (0xFE87, Status::Mapped, 9883, 2), (0xFE89, Status::Mapped, 9885, 2),
+    (0xFE8D, Status::Mapped, 9887, 2), (0xFE8F, Status::Mapped, 9889, 2), (0xFE93, Status::Mapped, 9891, 2),
+    (0xFE95, Status::Mapped, 9893, 2), (0xFE99, Status::Mapped, 9895, 2), (0xFE9D, Status::Mapped, 9897, 2),
+    (0xFEA1, Status::Mapped, 9899, 2), (0xFEA5, Status::Mapped, 9901, 2), (0xFEA9, Status::Mapped, 9903, 2),
+    (0xFEAB, Status::Mapped, 9905, 2), (0xFEAD, Status::Mapped, 9907, 2), (0xFEAF, Status::Mapped, 9909, 2),
+    (0xFEB1, Status::Mapped, 9911, 2), (0xFEB5, Status::Mapped, 9913, 2), (0xFEB9, Status::Mapped, 9915, 2),
+    (0xFEBD, Status::Mapped, 9917, 2), (0xFEC1, Status::Mapped, 9919, 2), (0xFEC5, Status::Mapped, 9921, 2),
+    (0xFEC9, Status::Mapped, 9923, 2), (0xFECD, Status::Mapped, 9925, 2), (0xFED1, Status::Mapped, 9927, 2),
+    (0xFED5, Status::Mapped, 9929, 2), (0xFED9, Status::Mapped, 9931, 2), (0xFEDD, Status::Mapped, 9933, 2),
+    (0xFEE1, Status::Mapped, 9935, 2), (0xFEE5, Status::Mapped, 9937, 2), (0xFEE9, Status::Mapped, 9939, 2),
+    (0xFEED, Status::Mapped, 9941, 2), (0xFEEF, Status::Mapped, 8373, 2), (0xFEF1, Status::Mapped, 9943, 2),
+    (0xFEF5, Status::Mapped, 9945, 4), (0xFEF7, Status::Mapped, 9949, 4), (0xFEF9, Status::Mapped, 9953, 4),
+    (0xFEFB, Status::Mapped, 9957, 4), (0xFEFD, Status::Disallowed, 0, 0), (0xFEFF, Status::Ignored, 0, 0),
+    (0xFF00, Status::Disallowed, 0, 0), (0xFF01, Status::DisallowedStd3Mapped, 9768, 1), (0xFF02, Status::DisallowedStd3Mapped, 9961, 1),
+    (0xFF03, Status::DisallowedStd3Mapped, 9817, 1), (0xFF04, Status::DisallowedStd3Mapped, 9824, 1), (0xFF05, Status::DisallowedStd3Mapped, 9825, 1),
+    (0xFF06, Status::DisallowedStd3Mapped, 9818, 1), (0xFF07, Status::DisallowedStd3Mapped, 9962, 1), (0xFF08, Status::DisallowedStd3Mapped, 2359, 1),
+    (0xFF09, Status::DisallowedStd3Mapped, 2360, 1), (0xFF0A, Status::DisallowedStd3Mapped, 9819, 1), (0xFF0B, Status::DisallowedStd3Mapped, 2354, 1),
+    (0xFF0C, Status::DisallowedStd3Mapped, 9763, 1), (0xFF0D, Status::Mapped, 9820, 1), (0xFF0E, Status::Mapped, 3724, 1),
+    (0xFF0F, Status::DisallowedStd3Mapped, 9963, 1), (0xFF10, Status::Mapped, 2347, 1), (0xFF11, Status::Mapped, 43, 1),
+    (0xFF12, Status::Mapped, 33, 1), (0xFF13, Status::Mapped, 34, 1), (0xFF14, Status::Mapped, 2348, 1),
+    (0xFF15, Status::Mapped, 2349, 1), (0xFF16, Status::Mapped, 2350, 1), (0xFF17, Status::Mapped, 2351, 1),
+    (0xFF18, Status::Mapped, 2352, 1), (0xFF19, Status::Mapped, 2353, 1), (0xFF1A, Status::DisallowedStd3Mapped, 9767, 1),
+    (0xFF1B, Status::DisallowedStd3Mapped, 512, 1), (0xFF1C, Status::DisallowedStd3Mapped, 9821, 1), (0xFF1D, Status::DisallowedStd3Mapped, 2358, 1),
+    (0xFF1E, Status::DisallowedStd3Mapped, 9822, 1), (0xFF1F, Status::DisallowedStd3Mapped, 9769, 1), (0xFF20, Status::DisallowedStd3Mapped, 9826, 1),
+    (0xFF21, Status::Mapped, 0, 1), (0xFF22, Status::Mapped, 1, 1), (0xFF23, Status::Mapped, 2, 1),
+    (0xFF24, Status::Mapped, 3, 1), (0xFF25, Status::Mapped, 4, 1), (0xFF26, Status::Mapped, 5, 1),
+    (0xFF27, Status::Mapped, 6, 1), (0xFF28, Status::Mapped, 7, 1), (0xFF29, Status::Mapped, 8, 1),
+    (0xFF2A, Status::Mapped, 9, 1), (0xFF2B, Status::Mapped, 10, 1), (0xFF2C, Status::Mapped, 11, 1),
+    (0xFF2D, Status::Mapped, 12, 1), (0xFF2E, Status::Mapped, 13, 1), (0xFF2F, Status::Mapped, 14, 1),
+    (0xFF30, Status::Mapped, 15, 1), (0xFF31, Status::Mapped, 16, 1), (0xFF32, Status::Mapped, 17, 1),
+    (0xFF33, Status::Mapped, 18, 1), (0xFF34, Status::Mapped, 19, 1), (0xFF35, Status::Mapped, 20, 1),
+    (0xFF36, Status::Mapped, 21, 1), (0xFF37, Status::Mapped, 22, 1), (0xFF38, Status::Mapped, 23, 1),
+    (0xFF39, Status::Mapped, 24, 1), (0xFF3A, Status::Mapped, 25, 1), (0xFF3B, Status::DisallowedStd3Mapped, 9815, 1),
+    (0xFF3C, Status::DisallowedStd3Mapped, 9823, 1), (0xFF3D, Status::DisallowedStd3Mapped, 9816, 1), (0xFF3E, Status::DisallowedStd3Mapped, 9964, 1),
+    (0xFF3F, Status::DisallowedStd3Mapped, 9782, 1), (0xFF40, Status::DisallowedStd3Mapped, 2260, 1), (0xFF41, Status::Mapped, 0, 1),
+    (0xFF42, Status::Mapped, 1, 1), (0xFF43, Status::Mapped, 2, 1), (0xFF44, Status::Mapped, 3, 1),
+    (0xFF45, Status::Mapped, 4, 1), (0xFF46, Status::Mapped, 5, 1), (0xFF47, Status::Mapped, 6, 1),
+    (0xFF48, Status::Mapped, 7, 1), (0xFF49, Status::Mapped, 8, 1), (0xFF4A, Status::Mapped, 9, 1),
+    (0xFF4B, Status::Mapped, 10, 1), (0xFF4C, Status::Mapped, 11, 1), (0xFF4D, Status::Mapped, 12, 1),
+    (0xFF4E, Status::Mapped, 13, 1), (0xFF4F, Status::Mapped, 14, 1), (0xFF50, Status::Mapped, 15, 1),
+    (0xFF51, Status::Mapped, 16, 1), (0xFF52, Status::Mapped, 17, 1), (0xFF53, Status::Mapped, 18, 1),
+    (0xFF54, Status::Mapped, 19, 1), (0xFF55, Status::Mapped, 20, 1), (0xFF56, Status::Mapped, 21, 1),
+    (0xFF57, Status::Mapped, 22, 1), (0xFF58, Status::Mapped, 23, 1), (0xFF59, Status::Mapped, 24, 1),
+    (0xFF5A, Status::Mapped, 25, 1), (0xFF5B, Status::DisallowedStd3Mapped, 9783, 1), (0xFF5C, Status::DisallowedStd3Mapped, 9965, 1),
+    (0xFF5D, Status::DisallowedStd3Mapped, 9784, 1), (0xFF5E, Status::DisallowedStd3Mapped, 9966, 1), (0xFF5F, Status::Mapped, 9967, 3),
+    (0xFF60, Status::Mapped, 9970, 3), (0xFF61, Status::Mapped, 3724, 1), (0xFF62, Status::Mapped, 9803, 3),
+    (0xFF63, Status::Mapped, 9806, 3), (0xFF64, Status::Mapped, 9764, 3), (0xFF65, Status::Mapped, 9973, 3),
+    (0xFF66, Status::Mapped, 4828, 3), (0xFF67, Status::Mapped, 9976, 3), (0xFF68, Status::Mapped, 9979, 3),
+    (0xFF69, Status::Mapped, 9982, 3), (0xFF6A, Status::Mapped, 9985, 3), (0xFF6B, Status::Mapped, 9988, 3),
+    (0xFF6C, Status::Mapped, 9991, 3), (0xFF6D, Status::Mapped, 9994, 3), (0xFF6E, Status::Mapped, 9997, 3),
+    (0xFF6F, Status::Mapped, 10000, 3), (0xFF70, Status::Mapped, 10003, 3), (0xFF71, Status::Mapped, 4690, 3),
+    (0xFF72, Status::Mapped, 4693, 3), (0xFF73, Status::Mapped, 4696, 3), (0xFF74, Status::Mapped, 4699, 3),
+    (0xFF75, Status::Mapped, 4702, 3), (0xFF76, Status::Mapped, 4705, 3), (0xFF77, Status::Mapped, 4708, 3),
+    (0xFF78, Status::Mapped, 4711, 3), (0xFF79, Status::Mapped, 4714, 3), (0xFF7A, Status::Mapped, 4717, 3),
+    (0xFF7B, Status::Mapped, 4720, 3), (0xFF7C, Status::Mapped, 4723, 3), (0xFF7D, Status::Mapped, 4726, 3),
+    (0xFF7E, Status::Mapped, 4729, 3), (0xFF7F, Status::Mapped, 4732, 3), (0xFF80, Status::Mapped, 4735, 3),
+    (0xFF81, Status::Mapped, 4738, 3), (0xFF82, Status::Mapped, 4741, 3), (0xFF83, Status::Mapped, 4744, 3),
+    (0xFF84, Status::Mapped, 4747, 3), (0xFF85, Status::Mapped, 4750, 3), (0xFF86, Status::Mapped, 4753, 3),
+    (0xFF87, Status::Mapped, 4756, 3), (0xFF88, Status::Mapped, 4759, 3), (0xFF89, Status::Mapped, 4762, 3),
+    (0xFF8A, Status::Mapped, 4765, 3), (0xFF8B, Status::Mapped, 4768, 3), (0xFF8C, Status::Mapped, 4771, 3),
+    (0xFF8D, Status::Mapped, 4774, 3), (0xFF8E, Status::Mapped, 4777, 3), (0xFF8F, Status::Mapped, 4780, 3),
+    (0xFF90, Status::Mapped, 4783, 3), (0xFF91, Status::Mapped, 4786, 3), (0xFF92, Status::Mapped, 4789, 3),
+    (0xFF93, Status::Mapped, 4792, 3), (0xFF94, Status::Mapped, 4795, 3), (0xFF95, Status::Mapped, 4798, 3),
+    (0xFF96, Status::Mapped, 4801, 3), (0xFF97, Status::Mapped, 4804, 3), (0xFF98, Status::Mapped, 4807, 3),
+    (0xFF99, Status::Mapped, 4810, 3), (0xFF9A, Status::Mapped, 4813, 3), (0xFF9B, Status::Mapped, 4816, 3),
+    (0xFF9C, Status::Mapped, 4819, 3), (0xFF9D, Status::Mapped, 10006, 3), (0xFF9E, Status::Mapped, 10009, 3),
+    (0xFF9F, Status::Mapped, 10012, 3), (0xFFA0, Status::Disallowed, 0, 0), (0xFFA1, Status::Mapped, 3754, 3),
+    (0xFFA2, Status::Mapped, 3757, 3), (0xFFA3, Status::Mapped, 3760, 3), (0xFFA4, Status::Mapped, 3763, 3),
+    (0xFFA5, Status::Mapped, 3766, 3), (0xFFA6, Status::Mapped, 3769, 3), (0xFFA7, Status::Mapped, 3772, 3),
+    (0xFFA8, Status::Mapped, 3775, 3), (0xFFA9, Status::Mapped, 3778, 3), (0xFFAA, Status::Mapped, 3781, 3),
+    (0xFFAB, Status::Mapped, 3784, 3), (0xFFAC, Status::Mapped, 3787, 3), (0xFFAD, Status::Mapped, 3790, 3),
+    (0xFFAE, Status::Mapped, 3793, 3), (0xFFAF, Status::Mapped, 3796, 3), (0xFFB0, Status::Mapped, 3799, 3),
+    (0xFFB1, Status::Mapped, 3802, 3), (0xFFB2, Status::Mapped, 3805, 3), (0xFFB3, Status::Mapped, 3808, 3),
+    (0xFFB4, Status::Mapped, 3811, 3), (0xFFB5, Status::Mapped, 3814, 3), (0xFFB6, Status::Mapped, 3817, 3),
+    (0xFFB7, Status::Mapped, 3820, 3), (0xFFB8, Status::Mapped, 3823, 3), (0xFFB9, Status::Mapped, 3826, 3),
+    (0xFFBA, Status::Mapped, 3829, 3), (0xFFBB, Status::Mapped, 3832, 3), (0xFFBC, Status::Mapped, 3835, 3),
+    (0xFFBD, Status::Mapped, 3838, 3), (0xFFBE, Status::Mapped, 3841, 3), (0xFFBF, Status::Disallowed, 0, 0),
+    (0xFFC2, Status::Mapped, 3844, 3), (0xFFC3, Status::Mapped, 3847, 3), (0xFFC4, Status::Mapped, 3850, 3),
+    (0xFFC5, Status::Mapped, 3853, 3), (0xFFC6, Status::Mapped, 3856, 3), (0xFFC7, Status::Mapped, 3859, 3),
+    (0xFFC8, Status::Disallowed, 0, 0), (0xFFCA, Status::Mapped, 3862, 3), (0xFFCB, Status::Mapped, 3865, 3),
+    (0xFFCC, Status::Mapped, 3868, 3), (0xFFCD, Status::Mapped, 3871, 3), (0xFFCE, Status::Mapped, 3874, 3),
+    (0xFFCF, Status::Mapped, 3877, 3), (0xFFD0, Status::Disallowed, 0, 0), (0xFFD2, Status::Mapped, 3880, 3),
+    (0xFFD3, Status::Mapped, 3883, 3), (0xFFD4, Status::Mapped, 3886, 3), (0xFFD5, Status::Mapped, 3889, 3),
+    (0xFFD6, Status::Mapped, 3892, 3), (0xFFD7, Status::Mapped, 3895, 3), (0xFFD8, Status::Disallowed, 0, 0),
+    (0xFFDA, Status::Mapped, 3898, 3), (0xFFDB, Status::Mapped, 3901, 3), (0xFFDC, Status::Mapped, 3904, 3),
+    (0xFFDD, Status::Disallowed, 0, 0), (0xFFE0, Status::Mapped, 10015, 2), (0xFFE1, Status::Mapped, 10017, 2),
+    (0xFFE2, Status::Mapped, 10019, 2), (0xFFE3, Status::DisallowedStd3Mapped, 30, 3), (0xFFE4, Status::Mapped, 10021, 2),
+    (0xFFE5, Status::Mapped, 10023, 2), (0xFFE6, Status::Mapped, 10025, 3), (0xFFE7, Status::Disallowed, 0, 0),
+    (0xFFE8, Status::Mapped, 10028, 3), (0xFFE9, Status::Mapped, 10031, 3), (0xFFEA, Status::Mapped, 10034, 3),
+    (0xFFEB, Status::Mapped, 10037, 3), (0xFFEC, Status::Mapped, 10040, 3), (0xFFED, Status::Mapped, 10043, 3),
+    (0xFFEE, Status::Mapped, 10046, 3), (0xFFEF, Status::Disallowed, 0, 0), (0x10000, Status::Valid, 0, 0),
+    (0x1000C, Status::Disallowed, 0, 0), (0x1000D, Status::Valid, 0, 0), (0x10027, Status::Disallowed, 0, 0),
+    (0x10028, Status::Valid, 0, 0), (0x1003B, Status::Disallowed, 0, 0), (0x1003C, Status::Valid, 0, 0),
+    (0x1003E, Status::Disallowed, 0, 0), (0x1003F, Status::Valid, 0, 0), (0x1004E, Status::Disallowed, 0, 0),
+    (0x10050, Status::Valid, 0, 0), (0x1005E, Status::Disallowed, 0, 0), (0x10080, Status::Valid, 0, 0),
+    (0x100FB, Status::Disallowed, 0, 0), (0x10100, Status::Valid, 0, 0), (0x10103, Status::Disallowed, 0, 0),
+    (0x10107, Status::Valid, 0, 0), (0x10134, Status::Disallowed, 0, 0), (0x10137, Status::Valid, 0, 0),
+    (0x1018F, Status::Disallowed, 0, 0), (0x10190, Status::Valid, 0, 0), (0x1019D, Status::Disallowed, 0, 0),
+    (0x101A0, Status::Valid, 0, 0), (0x101A1, Status::Disallowed, 0, 0), (0x101D0, Status::Valid, 0, 0),
+    (0x101FE, Status::Disallowed, 0, 0), (0x10280, Status::Valid, 0, 0), (0x1029D, Status::Disallowed, 0, 0),
+    (0x102A0, Status::Valid, 0, 0), (0x102D1, Status::Disallowed, 0, 0), (0x102E0, Status::Valid, 0, 0),
+    (0x102FC, Status::Disallowed, 0, 0), (0x10300, Status::Valid, 0, 0), (0x10324, Status::Disallowed, 0, 0),
+    (0x1032D, Status::Valid, 0, 0), (0x1034B, Status::Disallowed, 0, 0), (0x10350, Status::Valid, 0, 0),
+    (0x1037B, Status::Disallowed, 0, 0), (0x10380, Status::Valid, 0, 0), (0x1039E, Status::Disallowed, 0, 0),
+    (0x1039F, Status::Valid, 0, 0), (0x103C4, Status::Disallowed, 0, 0), (0x103C8, Status::Valid, 0, 0),
+    (0x103D6, Status::Disallowed, 0, 0), (0x10400, Status::Mapped, 10049, 4), (0x10401, Status::Mapped, 10053, 4),
+    (0x10402, Status::Mapped, 10057, 4), (0x10403, Status::Mapped, 10061, 4), (0x10404, Status::Mapped, 10065, 4),
+    (0x10405, Status::Mapped, 10069, 4), (0x10406, Status::Mapped, 10073, 4), (0x10407, Status::Mapped, 10077, 4),
+    (0x10408, Status::Mapped, 10081, 4), (0x10409, Status::Mapped, 10085, 4), (0x1040A, Status::Mapped, 10089, 4),
+    (0x1040B, Status::Mapped, 10093, 4), (0x1040C, Status::Mapped, 10097, 4), (0x1040D, Status::Mapped, 10101, 4),
+    (0x1040E, Status::Mapped, 10105, 4), (0x1040F, Status::Mapped, 10109, 4), (0x10410, Status::Mapped, 10113, 4),
+    (0x10411, Status::Mapped, 10117, 4), (0x10412, Status::Mapped, 10121, 4), (0x10413, Status::Mapped, 10125, 4),
+    (0x10414, Status::Mapped, 10129, 4), (0x10415, Status::Mapped, 10133, 4), (0x10416, Status::Mapped, 10137, 4),
+    (0x10417, Status::Mapped, 10141, 4), (0x10418, Status::Mapped, 10145, 4), (0x10419, Status::Mapped, 10149, 4),
+    (0x1041A, Status::Mapped, 10153, 4), (0x1041B, Status::Mapped, 10157, 4), (0x1041C, Status::Mapped, 10161, 4),
+    (0x1041D, Status::Mapped, 10165, 4), (0x1041E, Status::Mapped, 10169, 4), (0x1041F, Status::Mapped, 10173, 4),
+    (0x10420, Status::Mapped, 10177, 4), (0x10421, Status::Mapped, 10181, 4), (0x10422, Status::Mapped, 10185, 4),
+    (0x10423, Status::Mapped, 10189, 4), (0x10424, Status::Mapped, 10193, 4), (0x10425, Status::Mapped, 10197, 4),
+    (0x10426, Status::Mapped, 10201, 4), (0x10427, Status::Mapped, 10205, 4), (0x10428, Status::Valid, 0, 0),
+    (0x1049E, Status::Disallowed, 0, 0), (0x104A0, Status::Valid, 0, 0), (0x104AA, Status::Disallowed, 0, 0),
+    (0x104B0, Status::Mapped, 10209, 4), (0x104B1, Status::Mapped, 10213, 4), (0x104B2, Status::Mapped, 10217, 4),
+    (0x104B3, Status::Mapped, 10221, 4), (0x104B4, Status::Mapped, 10225, 4), (0x104B5, Status::Mapped, 10229, 4),
+    (0x104B6, Status::Mapped, 10233, 4), (0x104B7, Status::Mapped, 10237, 4), (0x104B8, Status::Mapped, 10241, 4),
+    (0x104B9, Status::Mapped, 10245, 4), (0x104BA, Status::Mapped, 10249, 4), (0x104BB, Status::Mapped, 10253, 4),
+    (0x104BC, Status::Mapped, 10257, 4), (0x104BD, Status::Mapped, 10261, 4), (0x104BE, Status::Mapped, 10265, 4),
+    (0x104BF, Status::Mapped, 10269, 4), (0x104C0, Status::Mapped, 10273, 4), (0x104C1, Status::Mapped, 10277, 4),
+    (0x104C2, Status::Mapped, 10281, 4), (0x104C3, Status::Mapped, 10285, 4), (0x104C4, Status::Mapped, 10289, 4),
+    (0x104C5, Status::Mapped, 10293, 4), (0x104C6, Status::Mapped, 10297, 4), (0x104C7, Status::Mapped, 10301, 4),
+    (0x104C8, Status::Mapped, 10305, 4), (0x104C9, Status::Mapped, 10309, 4), (0x104CA, Status::Mapped, 10313, 4),
+    (0x104CB, Status::Mapped, 10317, 4), (0x104CC, Status::Mapped, 10321, 4), (0x104CD, Status::Mapped, 10325, 4),
+    (0x104CE, Status::Mapped, 10329, 4), (0x104CF, Status::Mapped, 10333, 4), (0x104D0, Status::Mapped, 10337, 4),
+    (0x104D1, Status::Mapped, 10341, 4), (0x104D2, Status::Mapped, 10345, 4), (0x104D3, Status::Mapped, 10349, 4),
+    (0x104D4, Status::Disallowed, 0, 0), (0x104D8, Status::Valid, 0, 0), (0x104FC, Status::Disallowed, 0, 0),
+    (0x10500, Status::Valid, 0, 0), (0x10528, Status::Disallowed, 0, 0), (0x10530, Status::Valid, 0, 0),
+    (0x10564, Status::Disallowed, 0, 0), (0x1056F, Status::Valid, 0, 0), (0x10570, Status::Disallowed, 0, 0),
+    (0x10600, Status::Valid, 0, 0), (0x10737, Status::Disallowed, 0, 0), (0x10740, Status::Valid, 0, 0),
+    (0x10756, Status::Disallowed, 0, 0), (0x10760, Status::Valid, 0, 0), (0x10768, Status::Disallowed, 0, 0),
+    (0x10800, Status::Valid, 0, 0), (0x10806, Status::Disallowed, 0, 0), (0x10808, Status::Valid, 0, 0),
+    (0x10809, Status::Disallowed, 0, 0), (0x1080A, Status::Valid, 0, 0), (0x10836, Status::Disallowed, 0, 0),
+    (0x10837, Status::Valid, 0, 0), (0x10839, Status::Disallowed, 0, 0), (0x1083C, Status::Valid, 0, 0),
+    (0x1083D, Status::Disallowed, 0, 0), (0x1083F, Status::Valid, 0, 0), (0x10856, Status::Disallowed, 0, 0),
+    (0x10857, Status::Valid, 0, 0), (0x1089F, Status::Disallowed, 0, 0), (0x108A7, Status::Valid, 0, 0),
+    (0x108B0, Status::Disallowed, 0, 0), (0x108E0, Status::Valid, 0, 0), (0x108F3, Status::Disallowed, 0, 0),
+    (0x108F4, Status::Valid, 0, 0), (0x108F6, Status::Disallowed, 0, 0), (0x108FB, Status::Valid, 0, 0),
+    (0x1091C, Status::Disallowed, 0, 0), (0x1091F, Status::Valid, 0, 0), (0x1093A, Status::Disallowed, 0, 0),
+    (0x1093F, Status::Valid, 0, 0), (0x10940, Status::Disallowed, 0, 0), (0x10980, Status::Valid, 0, 0),
+    (0x109B8, Status::Disallowed, 0, 0), (0x109BC, Status::Valid, 0, 0), (0x109D0, Status::Disallowed, 0, 0),
+    (0x109D2, Status::Valid, 0, 0), (0x10A04, Status::Disallowed, 0, 0), (0x10A05, Status::Valid, 0, 0),
+    (0x10A07, Status::Disallowed, 0, 0), (0x10A0C, Status::Valid, 0, 0), (0x10A14, Status::Disallowed, 0, 0),
+    (0x10A15, Status::Valid, 0, 0), (0x10A18, Status::Disallowed, 0, 0), (0x10A19, Status::Valid, 0, 0),
+    (0x10A36, Status::Disallowed, 0, 0), (0x10A38, Status::Valid, 0, 0), (0x10A3B, Status::Disallowed, 0, 0),
+    (0x10A3F, Status::Valid, 0, 0), (0x10A49, Status::Disallowed, 0, 0), (0x10A50, Status::Valid, 0, 0),
+    (0x10A59, Status::Disallowed, 0, 0), (0x10A60, Status::Valid, 0, 0), (0x10AA0, Status::Disallowed, 0, 0),
+    (0x10AC0, Status::Valid, 0, 0), (0x10AE7, Status::Disallowed, 0, 0), (0x10AEB, Status::Valid, 0, 0),
+    (0x10AF7, Status::Disallowed, 0, 0), (0x10B00, Status::Valid, 0, 0), (0x10B36, Status::Disallowed, 0, 0),
+    (0x10B39, Status::Valid, 0, 0), (0x10B56, Status::Disallowed, 0, 0), (0x10B58, Status::Valid, 0, 0),
+    (0x10B73, Status::Disallowed, 0, 0), (0x10B78, Status::Valid, 0, 0), (0x10B92, Status::Disallowed, 0, 0),
+    (0x10B99, Status::Valid, 0, 0), (0x10B9D, Status::Disallowed, 0, 0), (0x10BA9, Status::Valid, 0, 0),
+    (0x10BB0, Status::Disallowed, 0, 0), (0x10C00, Status::Valid, 0, 0), (0x10C49, Status::Disallowed, 0, 0),
+    (0x10C80, Status::Mapped, 10353, 4), (0x10C81, Status::Mapped, 10357, 4), (0x10C82, Status::Mapped, 10361, 4),
+    (0x10C83, Status::Mapped, 10365, 4), (0x10C84, Status::Mapped, 10369, 4), (0x10C85, Status::Mapped, 10373, 4),
+    (0x10C86, Status::Mapped, 10377, 4), (0x10C87, Status::Mapped, 10381, 4), (0x10C88, Status::Mapped, 10385, 4),
+    (0x10C89, Status::Mapped, 10389, 4), (0x10C8A, Status::Mapped, 10393, 4), (0x10C8B, Status::Mapped, 10397, 4),
+    (0x10C8C, Status::Mapped, 10401, 4), (0x10C8D, Status::Mapped, 10405, 4), (0x10C8E, Status::Mapped, 10409, 4),
+    (0x10C8F, Status::Mapped, 10413, 4), (0x10C90, Status::Mapped, 10417, 4), (0x10C91, Status::Mapped, 10421, 4),
+    (0x10C92, Status::Mapped, 10425, 4), (0x10C93, Status::Mapped, 10429, 4), (0x10C94, Status::Mapped, 10433, 4),
+    (0x10C95, Status::Mapped, 10437, 4), (0x10C96, Status::Mapped, 10441, 4), (0x10C97, Status::Mapped, 10445, 4),
+    (0x10C98, Status::Mapped, 10449, 4), (0x10C99, Status::Mapped, 10453, 4), (0x10C9A, Status::Mapped, 10457, 4),
+    (0x10C9B, Status::Mapped, 10461, 4), (0x10C9C, Status::Mapped, 10465, 4), (0x10C9D, Status::Mapped, 10469, 4),
+    (0x10C9E, Status::Mapped, 10473, 4), (0x10C9F, Status::Mapped, 10477, 4), (0x10CA0, Status::Mapped, 10481, 4),
+    (0x10CA1, Status::Mapped, 10485, 4), (0x10CA2, Status::Mapped, 10489, 4), (0x10CA3, Status::Mapped, 10493, 4),
+    (0x10CA4, Status::Mapped, 10497, 4), (0x10CA5, Status::Mapped, 10501, 4), (0x10CA6, Status::Mapped, 10505, 4),
+    (0x10CA7, Status::Mapped, 10509, 4), (0x10CA8, Status::Mapped, 10513, 4), (0x10CA9, Status::Mapped, 10517, 4),
+    (0x10CAA, Status::Mapped, 10521, 4), (0x10CAB, Status::Mapped, 10525, 4), (0x10CAC, Status::Mapped, 10529, 4),
+    (0x10CAD, Status::Mapped, 10533, 4), (0x10CAE, Status::Mapped, 10537, 4), (0x10CAF, Status::Mapped, 10541, 4),
+    (0x10CB0, Status::Mapped, 10545, 4), (0x10CB1, Status::Mapped, 10549, 4), (0x10CB2, Status::Mapped, 10553, 4),
+    (0x10CB3, Status::Disallowed, 0, 0), (0x10CC0, Status::Valid, 0, 0), (0x10CF3, Status::Disallowed, 0, 0),
+    (0x10CFA, Status::Valid, 0, 0), (0x10D28, Status::Disallowed, 0, 0), (0x10D30, Status::Valid, 0, 0),
+    (0x10D3A, Status::Disallowed, 0, 0), (0x10E60, Status::Valid, 0, 0), (0x10E7F, Status::Disallowed, 0, 0),
+    (0x10E80, Status::Valid, 0, 0), (0x10EAA, Status::Disallowed, 0, 0), (0x10EAB, Status::Valid, 0, 0),
+    (0x10EAE, Status::Disallowed, 0, 0), (0x10EB0, Status::Valid, 0, 0), (0x10EB2, Status::Disallowed, 0, 0),
+    (0x10F00, Status::Valid, 0, 0), (0x10F28, Status::Disallowed, 0, 0), (0x10F30, Status::Valid, 0, 0),
+    (0x10F5A, Status::Disallowed, 0, 0), (0x10FB0, Status::Valid, 0, 0), (0x10FCC, Status::Disallowed, 0, 0),
+    (0x10FE0, Status::Valid, 0, 0), (0x10FF7, Status::Disallowed, 0, 0), (0x11000, Status::Valid, 0, 0),
+    (0x1104E, Status::Disallowed, 0, 0), (0x11052, Status::Valid, 0, 0), (0x11070, Status::Disallowed, 0, 0),
+    (0x1107F, Status::Valid, 0, 0), (0x110BD, Status::Disallowed, 0, 0), (0x110BE, Status::Valid, 0, 0),
+    (0x110C2, Status::Disallowed, 0, 0), (0x110D0, Status::Valid, 0, 0), (0x110E9, Status::Disallowed, 0, 0),
+    (0x110F0, Status::Valid, 0, 0), (0x110FA, Status::Disallowed, 0, 0), (0x11100, Status::Valid, 0, 0),
+    (0x11135, Status::Disallowed, 0, 0), (0x11136, Status::Valid, 0, 0), (0x11148, Status::Disallowed, 0, 0),
+    (0x11150, Status::Valid, 0, 0), (0x11177, Status::Disallowed, 0, 0), (0x11180, Status::Valid, 0, 0),
+    (0x111E0, Status::Disallowed, 0, 0), (0x111E1, Status::Valid, 0, 0), (0x111F5, Status::Disallowed, 0, 0),
+    (0x11200, Status::Valid, 0, 0), (0x11212, Status::Disallowed, 0, 0), (0x11213, Status::Valid, 0, 0),
+    (0x1123F, Status::Disallowed, 0, 0), (0x11280, Status::Valid, 0, 0), (0x11287, Status::Disallowed, 0, 0),
+    (0x11288, Status::Valid, 0, 0), (0x11289, Status::Disallowed, 0, 0), (0x1128A, Status::Valid, 0, 0),
+    (0x1128E, Status::Disallowed, 0, 0), (0x1128F, Status::Valid, 0, 0), (0x1129E, Status::Disallowed, 0, 0),
+    (0x1129F, Status::Valid, 0, 0), (0x112AA, Status::Disallowed, 0, 0), (0x112B0, Status::Valid, 0, 0),
+    (0x112EB, Status::Disallowed, 0, 0), (0x112F0, Status::Valid, 0, 0), (0x112FA, Status::Disallowed, 0, 0),
+    (0x11300, Status::Valid, 0, 0), (0x11304, Status::Disallowed, 0, 0), (0x11305, Status::Valid, 0, 0),
+    (0x1130D, Status::Disallowed, 0, 0), (0x1130F, Status::Valid, 0, 0), (0x11311, Status::Disallowed, 0, 0),
+    (0x11313, Status::Valid, 0, 0), (0x11329, Status::Disallowed, 0, 0), (0x1132A, Status::Valid, 0, 0),
+    (0x11331, Status::Disallowed, 0, 0), (0x11332, Status::Valid, 0, 0), (0x11334, Status::Disallowed, 0, 0),
+    (0x11335, Status::Valid, 0, 0), (0x1133A, Status::Disallowed, 0, 0), (0x1133B, Status::Valid, 0, 0),
+    (0x11345, Status::Disallowed, 0, 0), (0x11347, Status::Valid, 0, 0), (0x11349, Status::Disallowed, 0, 0),
+    (0x1134B, Status::Valid, 0, 0), (0x1134E, Status::Disallowed, 0, 0), (0x11350, Status::Valid, 0, 0),
+    (0x11351, Status::Disallowed, 0, 0), (0x11357, Status::Valid, 0, 0), (0x11358, Status::Disallowed, 0, 0),
+    (0x1135D, Status::Valid, 0, 0), (0x11364, Status::Disallowed, 0, 0), (0x11366, Status::Valid, 0, 0),
+    (0x1136D, Status::Disallowed, 0, 0), (0x11370, Status::Valid, 0, 0), (0x11375, Status::Disallowed, 0, 0),
+    (0x11400, Status::Valid, 0, 0), (0x1145C, Status::Disallowed, 0, 0), (0x1145D, Status::Valid, 0, 0),
+    (0x11462, Status::Disallowed, 0, 0), (0x11480, Status::Valid, 0, 0), (0x114C8, Status::Disallowed, 0, 0),
+    (0x114D0, Status::Valid, 0, 0), (0x114DA, Status::Disallowed, 0, 0), (0x11580, Status::Valid, 0, 0),
+    (0x115B6, Status::Disallowed, 0, 0), (0x115B8, Status::Valid, 0, 0), (0x115DE, Status::Disallowed, 0, 0),
+    (0x11600, Status::Valid, 0, 0), (0x11645, Status::Disallowed, 0, 0), (0x11650, Status::Valid, 0, 0),
+    (0x1165A, Status::Disallowed, 0, 0), (0x11660, Status::Valid, 0, 0), (0x1166D, Status::Disallowed, 0, 0),
+    (0x11680, Status::Valid, 0, 0), (0x116B9, Status::Disallowed, 0, 0), (0x116C0, Status::Valid, 0, 0),
+    (0x116CA, Status::Disallowed, 0, 0), (0x11700, Status::Valid, 0, 0), (0x1171B, Status::Disallowed, 0, 0),
+    (0x1171D, Status::Valid, 0, 0), (0x1172C, Status::Disallowed, 0, 0), (0x11730, Status::Valid, 0, 0),
+    (0x11740, Status::Disallowed, 0, 0), (0x11800, Status::Valid, 0, 0), (0x1183C, Status::Disallowed, 0, 0),
+    (0x118A0, Status::Mapped, 10557, 4), (0x118A1, Status::Mapped, 10561, 4), (0x118A2, Status::Mapped, 10565, 4),
+    (0x118A3, Status::Mapped, 10569, 4), (0x118A4, Status::Mapped, 10573, 4), (0x118A5, Status::Mapped, 10577, 4),
+    (0x118A6, Status::Mapped, 10581, 4), (0x118A7, Status::Mapped, 10585, 4), (0x118A8, Status::Mapped, 10589, 4),
+    (0x118A9, Status::Mapped, 10593, 4), (0x118AA, Status::Mapped, 10597, 4), (0x118AB, Status::Mapped, 10601, 4),
+    (0x118AC, Status::Mapped, 10605, 4), (0x118AD, Status::Mapped, 10609, 4), (0x118AE, Status::Mapped, 10613, 4),
+    (0x118AF, Status::Mapped, 10617, 4), (0x118B0, Status::Mapped, 10621, 4), (0x118B1, Status::Mapped, 10625, 4),
+    (0x118B2, Status::Mapped, 10629, 4), (0x118B3, Status::Mapped, 10633, 4), (0x118B4, Status::Mapped, 10637, 4),
+    (0x118B5, Status::Mapped, 10641, 4), (0x118B6, Status::Mapped, 10645, 4), (0x118B7, Status::Mapped, 10649, 4),
+    (0x118B8, Status::Mapped, 10653, 4), (0x118B9, Status::Mapped, 10657, 4), (0x118BA, Status::Mapped, 10661, 4),
+    (0x118BB, Status::Mapped, 10665, 4), (0x118BC, Status::Mapped, 10669, 4), (0x118BD, Status::Mapped, 10673, 4),
+    (0x118BE, Status::Mapped, 10677, 4), (0x118BF, Status::Mapped, 10681, 4), (0x118C0, Status::Valid, 0, 0),
+    (0x118F3, Status::Disallowed, 0, 0), (0x118FF, Status::Valid, 0, 0), (0x11907, Status::Disallowed, 0, 0),
+    (0x11909, Status::Valid, 0, 0), (0x1190A, Status::Disallowed, 0, 0), (0x1190C, Status::Valid, 0, 0),
+    (0x11914, Status::Disallowed, 0, 0), (0x11915, Status::Valid, 0, 0), (0x11917, Status::Disallowed, 0, 0),
+    (0x11918, Status::Valid, 0, 0), (0x11936, Status::Disallowed, 0, 0), (0x11937, Status::Valid, 0, 0),
+    (0x11939, Status::Disallowed, 0, 0), (0x1193B, Status::Valid, 0, 0), (0x11947, Status::Disallowed, 0, 0),
+    (0x11950, Status::Valid, 0, 0), (0x1195A, Status::Disallowed, 0, 0), (0x119A0, Status::Valid, 0, 0),
+    (0x119A8, Status::Disallowed, 0, 0), (0x119AA, Status::Valid, 0, 0), (0x119D8, Status::Disallowed, 0, 0),
+    (0x119DA, Status::Valid, 0, 0), (0x119E5, Status::Disallowed, 0, 0), (0x11A00, Status::Valid, 0, 0),
+    (0x11A48, Status::Disallowed, 0, 0), (0x11A50, Status::Valid, 0, 0), (0x11AA3, Status::Disallowed, 0, 0),
+    (0x11AC0, Status::Valid, 0, 0), (0x11AF9, Status::Disallowed, 0, 0), (0x11C00, Status::Valid, 0, 0),
+    (0x11C09, Status::Disallowed, 0, 0), (0x11C0A, Status::Valid, 0, 0), (0x11C37, Status::Disallowed, 0, 0),
+    (0x11C38, Status::Valid, 0, 0), (0x11C46, Status::Disallowed, 0, 0), (0x11C50, Status::Valid, 0, 0),
+    (0x11C6D, Status::Disallowed, 0, 0), (0x11C70, Status::Valid, 0, 0), (0x11C90, Status::Disallowed, 0, 0),
+    (0x11C92, Status::Valid, 0, 0), (0x11CA8, Status::Disallowed, 0, 0), (0x11CA9, Status::Valid, 0, 0),
+    (0x11CB7, Status::Disallowed, 0, 0), (0x11D00, Status::Valid, 0, 0), (0x11D07, Status::Disallowed, 0, 0),
+    (0x11D08, Status::Valid, 0, 0), (0x11D0A, Status::Disallowed, 0, 0), (0x11D0B, Status::Valid, 0, 0),
+    (0x11D37, Status::Disallowed, 0, 0), (0x11D3A, Status::Valid, 0, 0), (0x11D3B, Status::Disallowed, 0, 0),
+    (0x11D3C, Status::Valid, 0, 0), (0x11D3E, Status::Disallowed, 0, 0), (0x11D3F, Status::Valid, 0, 0),
+    (0x11D48, Status::Disallowed, 0, 0), (0x11D50, Status::Valid, 0, 0), (0x11D5A, Status::Disallowed, 0, 0),
+    (0x11D60, Status::Valid, 0, 0), (0x11D66, Status::Disallowed, 0, 0), (0x11D67, Status::Valid, 0, 0),
+    (0x11D69, Status::Disallowed, 0, 0), (0x11D6A, Status::Valid, 0, 0), (0x11D8F, Status::Disallowed, 0, 0),
+    (0x11D90, Status::Valid, 0, 0), (0x11D92, Status::Disallowed, 0, 0), (0x11D93, Status::Valid, 0, 0),
+    (0x11D99, Status::Disallowed, 0, 0), (0x11DA0, Status::Valid, 0, 0), (0x11DAA, Status::Disallowed, 0, 0),
+    (0x11EE0, Status::Valid, 0, 0), (0x11EF9, Status::Disallowed, 0, 0), (0x11FB0, Status::Valid, 0, 0),
+    (0x11FB1, Status::Disallowed, 0, 0), (0x11FC0, Status::Valid, 0, 0), (0x11FF2, Status::Disallowed, 0, 0),
+    (0x11FFF, Status::Valid, 0, 0), (0x1239A, Status::Disallowed, 0, 0), (0x12400, Status::Valid, 0, 0),
+    (0x1246F, Status::Disallowed, 0, 0), (0x12470, Status::Valid, 0, 0), (0x12475, Status::Disallowed, 0, 0),
+    (0x12480, Status::Valid, 0, 0), (0x12544, Status::Disallowed, 0, 0), (0x13000, Status::Valid, 0, 0),
+    (0x1342F, Status::Disallowed, 0, 0), (0x14400, Status::Valid, 0, 0), (0x14647, Status::Disallowed, 0, 0),
+    (0x16800, Status::Valid, 0, 0), (0x16A39, Status::Disallowed, 0, 0), (0x16A40, Status::Valid, 0, 0),
+    (0x16A5F, Status::Disallowed, 0, 0), (0x16A60, Status::Valid, 0, 0), (0x16A6A, Status::Disallowed, 0, 0),
+    (0x16A6E, Status::Valid, 0, 0), (0x16A70, Status::Disallowed, 0, 0), (0x16AD0, Status::Valid, 0, 0),
+    (0x16AEE, Status::Disallowed, 0, 0), (0x16AF0, Status::Valid, 0, 0), (0x16AF6, Status::Disallowed, 0, 0),
+    (0x16B00, Status::Valid, 0, 0), (0x16B46, Status::Disallowed, 0, 0), (0x16B50, Status::Valid, 0, 0),
+    (0x16B5A, Status::Disallowed, 0, 0), (0x16B5B, Status::Valid, 0, 0), (0x16B62, Status::Disallowed, 0, 0),
+    (0x16B63, Status::Valid, 0, 0), (0x16B78, Status::Disallowed, 0, 0), (0x16B7D, Status::Valid, 0, 0),
+    (0x16B90, Status::Disallowed, 0, 0), (0x16E40, Status::Mapped, 10685, 4), (0x16E41, Status::Mapped, 10689, 4),
+    (0x16E42, Status::Mapped, 10693, 4), (0x16E43, Status::Mapped, 10697, 4), (0x16E44, Status::Mapped, 10701, 4),
+    (0x16E45, Status::Mapped, 10705, 4), (0x16E46, Status::Mapped, 10709, 4), (0x16E47, Status::Mapped, 10713, 4),
+    (0x16E48, Status::Mapped, 10717, 4), (0x16E49, Status::Mapped, 10721, 4), (0x16E4A, Status::Mapped, 10725, 4),
+    (0x16E4B, Status::Mapped, 10729, 4), (0x16E4C, Status::Mapped, 10733, 4), (0x16E4D, Status::Mapped, 10737, 4),
+    (0x16E4E, Status::Mapped, 10741, 4), (0x16E4F, Status::Mapped, 10745, 4), (0x16E50, Status::Mapped, 10749, 4),
+    (0x16E51, Status::Mapped, 10753, 4), (0x16E52, Status::Mapped, 10757, 4), (0x16E53, Status::Mapped, 10761, 4),
+    (0x16E54, Status::Mapped, 10765, 4), (0x16E55, Status::Mapped, 10769, 4), (0x16E56, Status::Mapped, 10773, 4),
+    (0x16E57, Status::Mapped, 10777, 4), (0x16E58, Status::Mapped, 10781, 4), (0x16E59, Status::Mapped, 10785, 4),
+    (0x16E5A, Status::Mapped, 10789, 4), (0x16E5B, Status::Mapped, 10793, 4), (0x16E5C, Status::Mapped, 10797, 4),
+    (0x16E5D, Status::Mapped, 10801, 4), (0x16E5E, Status::Mapped, 10805, 4), (0x16E5F, Status::Mapped, 10809, 4),
+    (0x16E60, Status::Valid, 0, 0), (0x16E9B, Status::Disallowed, 0, 0), (0x16F00, Status::Valid, 0, 0),
+    (0x16F4B, Status::Disallowed, 0, 0), (0x16F4F, Status::Valid, 0, 0), (0x16F88, Status::Disallowed, 0, 0),
+    (0x16F8F, Status::Valid, 0, 0), (0x16FA0, Status::Disallowed, 0, 0), (0x16FE0, Status::Valid, 0, 0),
+    (0x16FE5, Status::Disallowed, 0, 0), (0x16FF0, Status::Valid, 0, 0), (0x16FF2, Status::Disallowed, 0, 0),
+    (0x17000, Status::Valid, 0, 0), (0x187F8, Status::Disallowed, 0, 0), (0x18800, Status::Valid, 0, 0),
+    (0x18CD6, Status::Disallowed, 0, 0), (0x18D00, Status::Valid, 0, 0), (0x18D09, Status::Disallowed, 0, 0),
+    (0x1B000, Status::Valid, 0, 0), (0x1B11F, Status::Disallowed, 0, 0), (0x1B150, Status::Valid, 0, 0),
+    (0x1B153, Status::Disallowed, 0, 0), (0x1B164, Status::Valid, 0, 0), (0x1B168, Status::Disallowed, 0, 0),
+    (0x1B170, Status::Valid, 0, 0), (0x1B2FC, Status::Disallowed, 0, 0), (0x1BC00, Status::Valid, 0, 0),
+    (0x1BC6B, Status::Disallowed, 0, 0), (0x1BC70, Status::Valid, 0, 0), (0x1BC7D, Status::Disallowed, 0, 0),
+    (0x1BC80, Status::Valid, 0, 0), (0x1BC89, Status::Disallowed, 0, 0), (0x1BC90, Status::Valid, 0, 0),
+    (0x1BC9A, Status::Disallowed, 0, 0), (0x1BC9C, Status::Valid, 0, 0), (0x1BCA0, Status::Ignored, 0, 0),
+    (0x1BCA4, Status::Disallowed, 0, 0), (0x1D000, Status::Valid, 0, 0), (0x1D0F6, Status::Disallowed, 0, 0),
+    (0x1D100, Status::Valid, 0, 0), (0x1D127, Status::Disallowed, 0, 0), (0x1D129, Status::Valid, 0, 0),
+    (0x1D15E, Status::Mapped, 10813, 8), (0x1D15F, Status::Mapped, 10821, 8), (0x1D160, Status::Mapped, 10829, 12),
+    (0x1D161, Status::Mapped, 10841, 12), (0x1D162, Status::Mapped, 10853, 12), (0x1D163, Status::Mapped, 10865, 12),
+    (0x1D164, Status::Mapped, 10877, 12), (0x1D165, Status::Valid, 0, 0), (0x1D173, Status::Disallowed, 0, 0),
+    (0x1D17B, Status::Valid, 0, 0), (0x1D1BB, Status::Mapped, 10889, 8), (0x1D1BC, Status::Mapped, 10897, 8),
+    (0x1D1BD, Status::Mapped, 10905, 12), (0x1D1BE, Status::Mapped, 10917, 12), (0x1D1BF, Status::Mapped, 10929, 12),
+    (0x1D1C0, Status::Mapped, 10941, 12), (0x1D1C1, Status::Valid, 0, 0), (0x1D1E9, Status::Disallowed, 0, 0),
+    (0x1D200, Status::Valid, 0, 0), (0x1D246, Status::Disallowed, 0, 0), (0x1D2E0, Status::Valid, 0, 0),
+    (0x1D2F4, Status::Disallowed, 0, 0), (0x1D300, Status::Valid, 0, 0), (0x1D357, Status::Disallowed, 0, 0),
+    (0x1D360, Status::Valid, 0, 0), (0x1D379, Status::Disallowed, 0, 0), (0x1D400, Status::Mapped, 0, 1),
+    (0x1D401, Status::Mapped, 1, 1), (0x1D402, Status::Mapped, 2, 1), (0x1D403, Status::Mapped, 3, 1),
+    (0x1D404, Status::Mapped, 4, 1), (0x1D405, Status::Mapped, 5, 1), (0x1D406, Status::Mapped, 6, 1),
+    (0x1D407, Status::Mapped, 7, 1), (0x1D408, Status::Mapped, 8, 1), (0x1D409, Status::Mapped, 9, 1),
+    (0x1D40A, Status::Mapped, 10, 1), (0x1D40B, Status::Mapped, 11, 1), (0x1D40C, Status::Mapped, 12, 1),
+    (0x1D40D, Status::Mapped, 13, 1), (0x1D40E, Status::Mapped, 14, 1), (0x1D40F, Status::Mapped, 15, 1),
+    (0x1D410, Status::Mapped, 16, 1), (0x1D411, Status::Mapped, 17, 1), (0x1D412, Status::Mapped, 18, 1),
+    (0x1D413, Status::Mapped, 19, 1), (0x1D414, Status::Mapped, 20, 1), (0x1D415, Status::Mapped, 21, 1),
+    (0x1D416, Status::Mapped, 22, 1), (0x1D417, Status::Mapped, 23, 1), (0x1D418, Status::Mapped, 24, 1),
+    (0x1D419, Status::Mapped, 25, 1), (0x1D41A, Status::Mapped, 0, 1), (0x1D41B, Status::Mapped, 1, 1),
+    (0x1D41C, Status::Mapped, 2, 1), (0x1D41D, Status::Mapped, 3, 1), (0x1D41E, Status::Mapped, 4, 1),
+    (0x1D41F, Status::Mapped, 5, 1), (0x1D420, Status::Mapped, 6, 1), (0x1D421, Status::Mapped, 7, 1),
+    (0x1D422, Status::Mapped, 8, 1), (0x1D423, Status::Mapped, 9, 1), (0x1D424, Status::Mapped, 10, 1),
+    (0x1D425, Status::Mapped, 11, 1), (0x1D426, Status::Mapped, 12, 1), (0x1D427, Status::Mapped, 13, 1),
+    (0x1D428, Status::Mapped, 14, 1), (0x1D429, Status::Mapped, 15, 1), (0x1D42A, Status::Mapped, 16, 1),
+    (0x1D42B, Status::Mapped, 17, 1), (0x1D42C, Status::Mapped, 18, 1), (0x1D42D, Status::Mapped, 19, 1),
+    (0x1D42E, Status::Mapped, 20, 1), (0x1D42F, Status::Mapped, 21, 1), (0x1D430, Status::Mapped, 22, 1),
+    (0x1D431, Status::Mapped, 23, 1), (0x1D432, Status::Mapped, 24, 1), (0x1D433, Status::Mapped, 25, 1),
+    (0x1D434, Status::Mapped, 0, 1), (0x1D435, Status::Mapped, 1, 1), (0x1D436, Status::Mapped, 2, 1),
+    (0x1D437, Status::Mapped, 3, 1), (0x1D438, Status::Mapped, 4, 1), (0x1D439, Status::Mapped, 5, 1),
+    (0x1D43A, Status::Mapped, 6, 1), (0x1D43B, Status::Mapped, 7, 1), (0x1D43C, Status::Mapped, 8, 1),
+    (0x1D43D, Status::Mapped, 9, 1), (0x1D43E, Status::Mapped, 10, 1), (0x1D43F, Status::Mapped, 11, 1),
+    (0x1D440, Status::Mapped, 12, 1), (0x1D441, Status::Mapped, 13, 1), (0x1D442, Status::Mapped, 14, 1),
+    (0x1D443, Status::Mapped, 15, 1), (0x1D444, Status::Mapped, 16, 1), (0x1D445, Status::Mapped, 17, 1),
+    (0x1D446, Status::Mapped, 18, 1), (0x1D447, Status::Mapped, 19, 1), (0x1D448, Status::Mapped, 20, 1),
+    (0x1D449, Status::Mapped, 21, 1), (0x1D44A, Status::Mapped, 22, 1), (0x1D44B, Status::Mapped, 23, 1),
+    (0x1D44C, Status::Mapped, 24, 1), (0x1D44D, Status::Mapped, 25, 1), (0x1D44E, Status::Mapped, 0, 1),
+    (0x1D44F, Status::Mapped, 1, 1), (0x1D450, Status::Mapped, 2, 1), (0x1D451, Status::Mapped, 3, 1),
+    (0x1D452, Status::Mapped, 4, 1), (0x1D453, Status::Mapped, 5, 1), (0x1D454, Status::Mapped, 6, 1),
+    (0x1D455, Status::Disallowed, 0, 0), (0x1D456, Status::Mapped, 8, 1), (0x1D457, Status::Mapped, 9, 1),
+    (0x1D458, Status::Mapped, 10, 1), (0x1D459, Status::Mapped, 11, 1), (0x1D45A, Status::Mapped, 12, 1),
+    (0x1D45B, Status::Mapped, 13, 1), (0x1D45C, Status::Mapped, 14, 1), (0x1D45D, Status::Mapped, 15, 1),
+    (0x1D45E, Status::Mapped, 16, 1), (0x1D45F, Status::Mapped, 17, 1), (0x1D460, Status::Mapped, 18, 1),
+    (0x1D461, Status::Mapped, 19, 1), (0x1D462, Status::Mapped, 20, 1), (0x1D463, Status::Mapped, 21, 1),
+    (0x1D464, Status::Mapped, 22, 1), (0x1D465, Status::Mapped, 23, 1), (0x1D466, Status::Mapped, 24, 1),
+    (0x1D467, Status::Mapped, 25, 1), (0x1D468, Status::Mapped, 0, 1), (0x1D469, Status::Mapped, 1, 1),
+    (0x1D46A, Status::Mapped, 2, 1), (0x1D46B, Status::Mapped, 3, 1), (0x1D46C, Status::Mapped, 4, 1),
+    (0x1D46D, Status::Mapped, 5, 1), (0x1D46E, Status::Mapped, 6, 1), (0x1D46F, Status::Mapped, 7, 1),
+    (0x1D470, Status::Mapped, 8, 1), (0x1D471, Status::Mapped, 9, 1), (0x1D472, Status::Mapped, 10, 1),
+    (0x1D473, Status::Mapped, 11, 1), (0x1D474, Status::Mapped, 12, 1), (0x1D475, Status::Mapped, 13, 1),
+    (0x1D476, Status::Mapped, 14, 1), (0x1D477, Status::Mapped, 15, 1), (0x1D478, Status::Mapped, 16, 1),
+    (0x1D479, Status::Mapped, 17, 1), (0x1D47A, Status::Mapped, 18, 1), (0x1D47B, Status::Mapped, 19, 1),
+    (0x1D47C, Status::Mapped, 20, 1), (0x1D47D, Status::Mapped, 21, 1), (0x1D47E, Status::Mapped, 22, 1),
+    (0x1D47F, Status::Mapped, 23, 1), (0x1D480, Status::Mapped, 24, 1), (0x1D481, Status::Mapped, 25, 1),
+    (0x1D482, Status::Mapped, 0, 1), (0x1D483, Status::Mapped, 1, 1), (0x1D484, Status::Mapped, 2, 1),
+    (0x1D485, Status::Mapped, 3, 1), (0x1D486, Status::Mapped, 4, 1), (0x1D487, Status::Mapped, 5, 1),
+    (0x1D488, Status::Mapped, 6, 1), (0x1D489, Status::Mapped, 7, 1), (0x1D48A, Status::Mapped, 8, 1),
+    (0x1D48B, Status::Mapped, 9, 1), (0x1D48C, Status::Mapped, 10, 1), (0x1D48D, Status::Mapped, 11, 1),
+    (0x1D48E, Status::Mapped, 12, 1), (0x1D48F, Status::Mapped, 13, 1), (0x1D490, Status::Mapped, 14, 1),
+    (0x1D491, Status::Mapped, 15, 1), (0x1D492, Status::Mapped, 16, 1), (0x1D493, Status::Mapped, 17, 1),
+    (0x1D494, Status::Mapped, 18, 1), (0x1D495, Status::Mapped, 19, 1), (0x1D496, Status::Mapped, 20, 1),
+    (0x1D497, Status::Mapped, 21, 1), (0x1D498, Status::Mapped, 22, 1), (0x1D499, Status::Mapped, 23, 1),
+    (0x1D49A, Status::Mapped, 24, 1), (0x1D49B, Status::Mapped, 25, 1), (0x1D49C, Status::Mapped, 0, 1),
+    (0x1D49D, Status::Disallowed, 0, 0), (0x1D49E, Status::Mapped, 2, 1), (0x1D49F, Status::Mapped, 3, 1),
+    (0x1D4A0, Status::Disallowed, 0, 0), (0x1D4A2, Status::Mapped, 6, 1), (0x1D4A3, Status::Disallowed, 0, 0),
+    (0x1D4A5, Status::Mapped, 9, 1), (0x1D4A6, Status::Mapped, 10, 1), (0x1D4A7, Status::Disallowed, 0, 0),
+    (0x1D4A9, Status::Mapped, 13, 1), (0x1D4AA, Status::Mapped, 14, 1), (0x1D4AB, Status::Mapped, 15, 1),
+    (0x1D4AC, Status::Mapped, 16, 1), (0x1D4AD, Status::Disallowed, 0, 0), (0x1D4AE, Status::Mapped, 18, 1),
+    (0x1D4AF, Status::Mapped, 19, 1), (0x1D4B0, Status::Mapped, 20, 1), (0x1D4B1, Status::Mapped, 21, 1),
+    (0x1D4B2, Status::Mapped, 22, 1), (0x1D4B3, Status::Mapped, 23, 1), (0x1D4B4, Status::Mapped, 24, 1),
+    (0x1D4B5, Status::Mapped, 25, 1), (0x1D4B6, Status::Mapped, 0, 1), (0x1D4B7, Status::Mapped, 1, 1),
+    (0x1D4B8, Status::Mapped, 2, 1), (0x1D4B9, Status::Mapped, 3, 1), (0x1D4BA, Status::Disallowed, 0, 0),
+    (0x1D4BB, Status::Mapped, 5, 1), (0x1D4BC, Status::Disallowed, 0, 0), (0x1D4BD, Status::Mapped, 7, 1),
+    (0x1D4BE, Status::Mapped, 8, 1), (0x1D4BF, Status::Mapped, 9, 1), (0x1D4C0, Status::Mapped, 10, 1),
+    (0x1D4C1, Status::Mapped, 11, 1), (0x1D4C2, Status::Mapped, 12, 1), (0x1D4C3, Status::Mapped, 13, 1),
+    (0x1D4C4, Status::Disallowed, 0, 0), (0x1D4C5, Status::Mapped, 15, 1), (0x1D4C6, Status::Mapped, 16, 1),
+    (0x1D4C7, Status::Mapped, 17, 1), (0x1D4C8, Status::Mapped, 18, 1), (0x1D4C9, Status::Mapped, 19, 1),
+    (0x1D4CA, Status::Mapped, 20, 1), (0x1D4CB, Status::Mapped, 21, 1), (0x1D4CC, Status::Mapped, 22, 1),
+    (0x1D4CD, Status::Mapped, 23, 1), (0x1D4CE, Status::Mapped, 24, 1), (0x1D4CF, Status::Mapped, 25, 1),
+    (0x1D4D0, Status::Mapped, 0, 1), (0x1D4D1, Status::Mapped, 1, 1), (0x1D4D2, Status::Mapped, 2, 1),
+    (0x1D4D3, Status::Mapped, 3, 1), (0x1D4D4, Status::Mapped, 4, 1), (0x1D4D5, Status::Mapped, 5, 1),
+    (0x1D4D6, Status::Mapped, 6, 1), (0x1D4D7, Status::Mapped, 7, 1), (0x1D4D8, Status::Mapped, 8, 1),
+    (0x1D4D9, Status::Mapped, 9, 1), (0x1D4DA, Status::Mapped, 10, 1), (0x1D4DB, Status::Mapped, 11, 1),
+    (0x1D4DC, Status::Mapped, 12, 1), (0x1D4DD, Status::Mapped, 13, 1), (0x1D4DE, Status::Mapped, 14, 1),
+    (0x1D4DF, Status::Mapped, 15, 1), (0x1D4E0, Status::Mapped, 16, 1), (0x1D4E1, Status::Mapped, 17, 1),
+    (0x1D4E2, Status::Mapped, 18, 1), (0x1D4E3, Status::Mapped, 19, 1), (0x1D4E4, Status::Mapped, 20, 1),
+    (0x1D4E5, Status::Mapped, 21, 1), (0x1D4E6, Status::Mapped, 22, 1), (0x1D4E7, Status::Mapped, 23, 1),
+    (0x1D4E8, Status::Mapped, 24, 1), (0x1D4E9, Status::Mapped, 25, 1), (0x1D4EA, Status::Mapped, 0, 1),
+    (0x1D4EB, Status::Mapped, 1, 1), (0x1D4EC, Status::Mapped, 2, 1), (0x1D4ED, Status::Mapped, 3, 1),
+    (0x1D4EE, Status::Mapped, 4, 1), (0x1D4EF, Status::Mapped, 5, 1), (0x1D4F0, Status::Mapped, 6, 1),
+    (0x1D4F1, Status::Mapped, 7, 1), (0x1D4F2, Status::Mapped, 8, 1), (0x1D4F3, Status::Mapped, 9, 1),
+    (0x1D4F4, Status::Mapped, 10, 1), (0x1D4F5, Status::Mapped, 11, 1), (0x1D4F6, Status::Mapped, 12, 1),
+    (0x1D4F7, Status::Mapped, 13, 1), (0x1D4F8, Status::Mapped, 14, 1), (0x1D4F9, Status::Mapped, 15, 1),
+    (0x1D4FA, Status::Mapped, 16, 1), (0x1D4FB, Status::Mapped, 17, 1), (0x1D4FC, Status::Mapped, 18, 1),
+    (0x1D4FD, Status::Mapped, 19, 1), (0x1D4FE, Status::Mapped, 20, 1), (0x1D4FF, Status::Mapped, 21, 1),
+    (0x1D500, Status::Mapped, 22, 1), (0x1D501, Status::Mapped, 23, 1), (0x1D502, Status::Mapped, 24, 1),
+    (0x1D503, Status::Mapped, 25, 1), (0x1D504, Status::Mapped, 0, 1), (0x1D505, Status::Mapped, 1, 1),
+    (0x1D506, Status::Disallowed, 0, 0), (0x1D507, Status::Mapped, 3, 1), (0x1D508, Status::Mapped, 4, 1),
+    (0x1D509, Status::Mapped, 5, 1), (0x1D50A, Status::Mapped, 6, 1), (0x1D50B, Status::Disallowed, 0, 0),
+    (0x1D50D, Status::Mapped, 9, 1), (0x1D50E, Status::Mapped, 10, 1), (0x1D50F, Status::Mapped, 11, 1),
+    (0x1D510, Status::Mapped, 12, 1), (0x1D511, Status::Mapped, 13, 1), (0x1D512, Status::Mapped, 14, 1),
+    (0x1D513, Status::Mapped, 15, 1), (0x1D514, Status::Mapped, 16, 1), (0x1D515, Status::Disallowed, 0, 0),
+    (0x1D516, Status::Mapped, 18, 1), (0x1D517, Status::Mapped, 19, 1), (0x1D518, Status::Mapped, 20, 1),
+    (0x1D519, Status::Mapped, 21, 1), (0x1D51A, Status::Mapped, 22, 1), (0x1D51B, Status::Mapped, 23, 1),
+    (0x1D51C, Status::Mapped, 24, 1), (0x1D51D, Status::Disallowed, 0, 0), (0x1D51E, Status::Mapped, 0, 1),
+    (0x1D51F, Status::Mapped, 1, 1), (0x1D520, Status::Mapped, 2, 1), (0x1D521, Status::Mapped, 3, 1),
+    (0x1D522, Status::Mapped, 4, 1), (0x1D523, Status::Mapped, 5, 1), (0x1D524, Status::Mapped, 6, 1),
+    (0x1D525, Status::Mapped, 7, 1), (0x1D526, Status::Mapped, 8, 1), (0x1D527, Status::Mapped, 9, 1),
+    (0x1D528, Status::Mapped, 10, 1), (0x1D529, Status::Mapped, 11, 1), (0x1D52A, Status::Mapped, 12, 1),
+    (0x1D52B, Status::Mapped, 13, 1), (0x1D52C, Status::Mapped, 14, 1), (0x1D52D, Status::Mapped, 15, 1),
+    (0x1D52E, Status::Mapped, 16, 1), (0x1D52F, Status::Mapped, 17, 1), (0x1D530, Status::Mapped, 18, 1),
+    (0x1D531, Status::Mapped, 19, 1), (0x1D532, Status::Mapped, 20, 1), (0x1D533, Status::Mapped, 21, 1),
+    (0x1D534, Status::Mapped, 22, 1), (0x1D535, Status::Mapped, 23, 1), (0x1D536, Status::Mapped, 24, 1),
+    (0x1D537, Status::Mapped, 25, 1), (0x1D538, Status::Mapped, 0, 1), (0x1D539, Status::Mapped, 1, 1),
+    (0x1D53A, Status::Disallowed, 0, 0), (0x1D53B, Status::Mapped, 3, 1), (0x1D53C, Status::Mapped, 4, 1),
+    (0x1D53D, Status::Mapped, 5, 1), (0x1D53E, Status::Mapped, 6, 1), (0x1D53F, Status::Disallowed, 0, 0),
+    (0x1D540, Status::Mapped, 8, 1), (0x1D541, Status::Mapped, 9, 1), (0x1D542, Status::Mapped, 10, 1),
+    (0x1D543, Status::Mapped, 11, 1), (0x1D544, Status::Mapped, 12, 1), (0x1D545, Status::Disallowed, 0, 0),
+    (0x1D546, Status::Mapped, 14, 1), (0x1D547, Status::Disallowed, 0, 0), (0x1D54A, Status::Mapped, 18, 1),
+    (0x1D54B, Status::Mapped, 19, 1), (0x1D54C, Status::Mapped, 20, 1), (0x1D54D, Status::Mapped, 21, 1),
+    (0x1D54E, Status::Mapped, 22, 1), (0x1D54F, Status::Mapped, 23, 1), (0x1D550, Status::Mapped, 24, 1),
+    (0x1D551, Status::Disallowed, 0, 0), (0x1D552, Status::Mapped, 0, 1), (0x1D553, Status::Mapped, 1, 1),
+    (0x1D554, Status::Mapped, 2, 1), (0x1D555, Status::Mapped, 3, 1), (0x1D556, Status::Mapped, 4, 1),
+    (0x1D557, Status::Mapped, 5, 1), (0x1D558, Status::Mapped, 6, 1), (0x1D559, Status::Mapped, 7, 1),
+    (0x1D55A, Status::Mapped, 8, 1), (0x1D55B, Status::Mapped, 9, 1), (0x1D55C, Status::Mapped, 10, 1),
+    (0x1D55D, Status::Mapped, 11, 1), (0x1D55E, Status::Mapped, 12, 1), (0x1D55F, Status::Mapped, 13, 1),
+    (0x1D560, Status::Mapped, 14, 1), (0x1D561, Status::Mapped, 15, 1), (0x1D562, Status::Mapped, 16, 1),
+    (0x1D563, Status::Mapped, 17, 1), (0x1D564, Status::Mapped, 18, 1), (0x1D565, Status::Mapped, 19, 1),
+    (0x1D566, Status::Mapped, 20, 1), (0x1D567, Status::Mapped, 21, 1), (0x1D568, Status::Mapped, 22, 1),
+    (0x1D569, Status::Mapped, 23, 1), (0x1D56A, Status::Mapped, 24, 1), (0x1D56B, Status::Mapped, 25, 1),
+    (0x1D56C, Status::Mapped, 0, 1), (0x1D56D, Status::Mapped, 1, 1), (0x1D56E, Status::Mapped, 2, 1),
+    (0x1D56F, Status::Mapped, 3, 1), (0x1D570, Status::Mapped, 4, 1), (0x1D571, Status::Mapped, 5, 1),
+    (0x1D572, Status::Mapped, 6, 1), (0x1D573, Status::Mapped, 7, 1), (0x1D574, Status::Mapped, 8, 1),
+    (0x1D575, Status::Mapped, 9, 1), (0x1D576, Status::Mapped, 10, 1), (0x1D577, Status::Mapped, 11, 1),
+    (0x1D578, Status::Mapped, 12, 1), (0x1D579, Status::Mapped, 13, 1), (0x1D57A, Status::Mapped, 14, 1),
+    (0x1D57B, Status::Mapped, 15, 1), (0x1D57C, Status::Mapped, 16, 1), (0x1D57D, Status::Mapped, 17, 1),
+    (0x1D57E, Status::Mapped, 18, 1), (0x1D57F, Status::Mapped, 19, 1), (0x1D580, Status::Mapped, 20, 1),
+    (0x1D581, Status::Mapped, 21, 1), (0x1D582, Status::Mapped, 22, 1), (0x1D583, Status::Mapped, 23, 1),
+    (0x1D584, Status::Mapped, 24, 1), (0x1D585, Status::Mapped, 25, 1), (0x1D586, Status::Mapped, 0, 1),
+    (0x1D587, Status::Mapped, 1, 1), (0x1D588, Status::Mapped, 2, 1), (0x1D589, Status::Mapped, 3, 1),
+    (0x1D58A, Status::Mapped, 4, 1), (0x1D58B, Status::Mapped, 5, 1), (0x1D58C, Status::Mapped, 6, 1),
+    (0x1D58D, Status::Mapped, 7, 1), (0x1D58E, Status::Mapped, 8, 1), (0x1D58F, Status::Mapped, 9, 1),
+    (0x1D590, Status::Mapped, 10, 1), (0x1D591, Status::Mapped, 11, 1), (0x1D592, Status::Mapped, 12, 1),
+    (0x1D593, Status::Mapped, 13, 1), (0x1D594, Status::Mapped, 14, 1), (0x1D595, Status::Mapped, 15, 1),
+    (0x1D596, Status::Mapped, 16, 1), (0x1D597, Status::Mapped, 17, 1), (0x1D598, Status::Mapped, 18, 1),
+    (0x1D599, Status::Mapped, 19, 1), (0x1D59A, Status::Mapped, 20, 1), (0x1D59B, Status::Mapped, 21, 1),
+    (0x1D59C, Status::Mapped, 22, 1), (0x1D59D, Status::Mapped, 23, 1), (0x1D59E, Status::Mapped, 24, 1),
+    (0x1D59F, Status::Mapped, 25, 1), (0x1D5A0, Status::Mapped, 0, 1), (0x1D5A1, Status::Mapped, 1, 1),
+    (0x1D5A2, Status::Mapped, 2, 1), (0x1D5A3, Status::Mapped, 3, 1), (0x1D5A4, Status::Mapped, 4, 1),
+    (0x1D5A5, Status::Mapped, 5, 1), (0x1D5A6, Status::Mapped, 6, 1), (0x1D5A7, Status::Mapped, 7, 1),
+    (0x1D5A8, Status::Mapped, 8, 1), (0x1D5A9, Status::Mapped, 9, 1), (0x1D5AA, Status::Mapped, 10, 1),
+    (0x1D5AB, Status::Mapped, 11, 1), (0x1D5AC, Status::Mapped, 12, 1), (0x1D5AD, Status::Mapped, 13, 1),
+    (0x1D5AE, Status::Mapped, 14, 1), (0x1D5AF, Status::Mapped, 15, 1), (0x1D5B0, Status::Mapped, 16, 1),
+    (0x1D5B1, Status::Mapped, 17, 1), (0x1D5B2, Status::Mapped, 18, 1), (0x1D5B3, Status::Mapped, 19, 1),
+    (0x1D5B4, Status::Mapped, 20, 1), (0x1D5B5, Status::Mapped, 21, 1), (0x1D5B6, Status::Mapped, 22, 1),
+    (0x1D5B7, Status::Mapped, 23, 1), (0x1D5B8, Status::Mapped, 24, 1), (0x1D5B9, Status::Mapped, 25, 1),
+    (0x1D5BA, Status::Mapped, 0, 1), (0x1D5BB, Status::Mapped, 1, 1), (0x1D5BC, Status::Mapped, 2, 1),
+    (0x1D5BD, Status::Mapped, 3, 1), (0x1D5BE, Status::Mapped, 4, 1), (0x1D5BF, Status::Mapped, 5, 1),
+    (0x1D5C0, Status::Mapped, 6, 1), (0x1D5C1, Status::Mapped, 7, 1), (0x1D5C2, Status::Mapped, 8, 1),
+    (0x1D5C3, Status::Mapped, 9, 1), (0x1D5C4, Status::Mapped, 10, 1), (0x1D5C5, Status::Mapped, 11, 1),
+    (0x1D5C6, Status::Mapped, 12, 1), (0x1D5C7, Status::Mapped, 13, 1), (0x1D5C8, Status::Mapped, 14, 1),
+    (0x1D5C9, Status::Mapped, 15, 1), (0x1D5CA, Status::Mapped, 16, 1), (0x1D5CB, Status::Mapped, 17, 1),
+    (0x1D5CC, Status::Mapped, 18, 1), (0x1D5CD, Status::Mapped, 19, 1), (0x1D5CE, Status::Mapped, 20, 1),
+    (0x1D5CF, Status::Mapped, 21, 1), (0x1D5D0, Status::Mapped, 22, 1), (0x1D5D1, Status::Mapped, 23, 1),
+    (0x1D5D2, Status::Mapped, 24, 1), (0x1D5D3, Status::Mapped, 25, 1), (0x1D5D4, Status::Mapped, 0, 1),
+    (0x1D5D5, Status::Mapped, 1, 1), (0x1D5D6, Status::Mapped, 2, 1), (0x1D5D7, Status::Mapped, 3, 1),
+    (0x1D5D8, Status::Mapped, 4, 1), (0x1D5D9, Status::Mapped, 5, 1), (0x1D5DA, Status::Mapped, 6, 1),
+    (0x1D5DB, Status::Mapped, 7, 1), (0x1D5DC, Status::Mapped, 8, 1), (0x1D5DD, Status::Mapped, 9, 1),
+    (0x1D5DE, Status::Mapped, 10, 1), (0x1D5DF, Status::Mapped, 11, 1), (0x1D5E0, Status::Mapped, 12, 1),
+    (0x1D5E1, Status::Mapped, 13, 1), (0x1D5E2, Status::Mapped, 14, 1), (0x1D5E3, Status::Mapped, 15, 1),
+    (0x1D5E4, Status::Mapped, 16, 1), (0x1D5E5, Status::Mapped, 17, 1), (0x1D5E6, Status::Mapped, 18, 1),
+    (0x1D5E7, Status::Mapped, 19, 1), (0x1D5E8, Status::Mapped, 20, 1), (0x1D5E9, Status::Mapped, 21, 1),
+    (0x1D5EA, Status::Mapped, 22, 1), (0x1D5EB, Status::Mapped, 23, 1), (0x1D5EC, Status::Mapped, 24, 1),
+    (0x1D5ED, Status::Mapped, 25, 1), (0x1D5EE, Status::Mapped, 0, 1), (0x1D5EF, Status::Mapped, 1, 1),
+    (0x1D5F0, Status::Mapped, 2, 1), (0x1D5F1, Status::Mapped, 3, 1), (0x1D5F2, Status::Mapped, 4, 1),
+    (0x1D5F3, Status::Mapped, 5, 1), (0x1D5F4, Status::Mapped, 6, 1), (0x1D5F5, Status::Mapped, 7, 1),
+    (0x1D5F6, Status::Mapped, 8, 1), (0x1D5F7, Status::Mapped, 9, 1), (0x1D5F8, Status::Mapped, 10, 1),
+    (0x1D5F9, Status::Mapped, 11, 1), (0x1D5FA, Status::Mapped, 12, 1), (0x1D5FB, Status::Mapped, 13, 1),
+    (0x1D5FC, Status::Mapped, 14, 1), (0x1D5FD, Status::Mapped, 15, 1), (0x1D5FE, Status::Mapped, 16, 1),
+    (0x1D5FF, Status::Mapped, 17, 1), (0x1D600, Status::Mapped, 18, 1), (0x1D601, Status::Mapped, 19, 1),
+    (0x1D602, Status::Mapped, 20, 1), (0x1D603, Status::Mapped, 21, 1), (0x1D604, Status::Mapped, 22, 1),
+    (0x1D605, Status::Mapped, 23, 1), (0x1D606, Status::Mapped, 24, 1), (0x1D607, Status::Mapped, 25, 1),
+    (0x1D608, Status::Mapped, 0, 1), (0x1D609, Status::Mapped, 1, 1), (0x1D60A, Status::Mapped, 2, 1),
+    (0x1D60B, Status::Mapped, 3, 1), (0x1D60C, Status::Mapped, 4, 1), (0x1D60D, Status::Mapped, 5, 1),
+    (0x1D60E, Status::Mapped, 6, 1), (0x1D60F, Status::Mapped, 7, 1), (0x1D610, Status::Mapped, 8, 1),
+    (0x1D611, Status::Mapped, 9, 1), (0x1D612, Status::Mapped, 10, 1), (0x1D613, Status::Mapped, 11, 1),
+    (0x1D614, Status::Mapped, 12, 1), (0x1D615, Status::Mapped, 13, 1), (0x1D616, Status::Mapped, 14, 1),
+    (0x1D617, Status::Mapped, 15, 1), (0x1D618, Status::Mapped, 16, 1), (0x1D619, Status::Mapped, 17, 1),
+    (0x1D61A, Status::Mapped, 18, 1), (0x1D61B, Status::Mapped, 19, 1), (0x1D61C, Status::Mapped, 20, 1),
+    (0x1D61D, Status::Mapped, 21, 1), (0x1D61E, Status::Mapped, 22, 1), (0x1D61F, Status::Mapped, 23, 1),
+    (0x1D620, Status::Mapped, 24, 1), (0x1D621, Status::Mapped, 25, 1), (0x1D622, Status::Mapped, 0, 1),
+    (0x1D623, Status::Mapped, 1, 1), (0x1D624, Status::Mapped, 2, 1), (0x1D625, Status::Mapped, 3, 1),
+    (0x1D626, Status::Mapped, 4, 1), (0x1D627, Status::Mapped, 5, 1), (0x1D628, Status::Mapped, 6, 1),
+    (0x1D629, Status::Mapped, 7, 1), (0x1D62A, Status::Mapped, 8, 1), (0x1D62B, Status::Mapped, 9, 1),
+    (0x1D62C, Status::Mapped, 10, 1), (0x1D62D, Status::Mapped, 11, 1), (0x1D62E, Status::Mapped, 12, 1),
+    (0x1D62F, Status::Mapped, 13, 1), (0x1D630, Status::Mapped, 14, 1), (0x1D631, Status::Mapped, 15, 1),
+    (0x1D632, Status::Mapped, 16, 1), (0x1D633, Status::Mapped, 17, 1), (0x1D634, Status::Mapped, 18, 1),
+    (0x1D635, Status::Mapped, 19, 1), (0x1D636, Status::Mapped, 20, 1), (0x1D637, Status::Mapped, 21, 1),
+    (0x1D638, Status::Mapped, 22, 1), (0x1D639, Status::Mapped, 23, 1), (0x1D63A, Status::Mapped, 24, 1),
+    (0x1D63B, Status::Mapped, 25, 1), (0x1D63C, Status::Mapped, 0, 1), (0x1D63D, Status::Mapped, 1, 1),
+    (0x1D63E, Status::Mapped, 2, 1), (0x1D63F, Status::Mapped, 3, 1), (0x1D640, Status::Mapped, 4, 1),
+    (0x1D641, Status::Mapped, 5, 1), (0x1D642, Status::Mapped, 6, 1), (0x1D643, Status::Mapped, 7, 1),
+    (0x1D644, Status::Mapped, 8, 1), (0x1D645, Status::Mapped, 9, 1), (0x1D646, Status::Mapped, 10, 1),
+    (0x1D647, Status::Mapped, 11, 1), (0x1D648, Status::Mapped, 12, 1), (0x1D649, Status::Mapped, 13, 1),
+    (0x1D64A, Status::Mapped, 14, 1), (0x1D64B, Status::Mapped, 15, 1), (0x1D64C, Status::Mapped, 16, 1),
+    (0x1D64D, Status::Mapped, 17, 1), (0x1D64E, Status::Mapped, 18, 1), (0x1D64F, Status::Mapped, 19, 1),
+    (0x1D650, Status::Mapped, 20, 1), (0x1D651, Status::Mapped, 21, 1), (0x1D652, Status::Mapped, 22, 1),
+    (0x1D653, Status::Mapped, 23, 1), (0x1D654, Status::Mapped, 24, 1), (0x1D655, Status::Mapped, 25, 1),
+    (0x1D656, Status::Mapped, 0, 1), (0x1D657, Status::Mapped, 1, 1), (0x1D658, Status::Mapped, 2, 1),
+    (0x1D659, Status::Mapped, 3, 1), (0x1D65A, Status::Mapped, 4, 1), (0x1D65B, Status::Mapped, 5, 1),
+    (0x1D65C, Status::Mapped, 6, 1), (0x1D65D, Status::Mapped, 7, 1), (0x1D65E, Status::Mapped, 8, 1),
+    (0x1D65F, Status::Mapped, 9, 1), (0x1D660, Status::Mapped, 10, 1), (0x1D661, Status::Mapped, 11, 1),
+    (0x1D662, Status::Mapped, 12, 1), (0x1D663, Status::Mapped, 13, 1), (0x1D664, Status::Mapped, 14, 1),
+    (0x1D665, Status::Mapped, 15, 1), (0x1D666, Status::Mapped, 16, 1), (0x1D667, Status::Mapped, 17, 1),
+    (0x1D668, Status::Mapped, 18, 1), (0x1D669, Status::Mapped, 19, 1), (0x1D66A, Status::Mapped, 20, 1),
+    (0x1D66B, Status::Mapped, 21, 1), (0x1D66C, Status::Mapped, 22, 1), (0x1D66D, Status::Mapped, 23, 1),
+    (0x1D66E, Status::Mapped, 24, 1), (0x1D66F, Status::Mapped, 25, 1), (0x1D670, Status::Mapped, 0, 1),
+    (0x1D671, Status::Mapped, 1, 1), (0x1D672, Status::Mapped, 2, 1), (0x1D673, Status::Mapped, 3, 1),
+    (0x1D674, Status::Mapped, 4, 1), (0x1D675, Status::Mapped, 5, 1), (0x1D676, Status::Mapped, 6, 1),
+    (0x1D677, Status::Mapped, 7, 1), (0x1D678, Status::Mapped, 8, 1), (0x1D679, Status::Mapped, 9, 1),
+    (0x1D67A, Status::Mapped, 10, 1), (0x1D67B, Status::Mapped, 11, 1), (0x1D67C, Status::Mapped, 12, 1),
+    (0x1D67D, Status::Mapped, 13, 1), (0x1D67E, Status::Mapped, 14, 1), (0x1D67F, Status::Mapped, 15, 1),
+    (0x1D680, Status::Mapped, 16, 1), (0x1D681, Status::Mapped, 17, 1), (0x1D682, Status::Mapped, 18, 1),
+    (0x1D683, Status::Mapped, 19, 1), (0x1D684, Status::Mapped, 20, 1), (0x1D685, Status::Mapped, 21, 1),
+    (0x1D686, Status::Mapped, 22, 1), (0x1D687, Status::Mapped, 23, 1), (0x1D688, Status::Mapped, 24, 1),
+    (0x1D689, Status::Mapped, 25, 1), (0x1D68A, Status::Mapped, 0, 1), (0x1D68B, Status::Mapped, 1, 1),
+    (0x1D68C, Status::Mapped, 2, 1), (0x1D68D, Status::Mapped, 3, 1), (0x1D68E, Status::Mapped, 4, 1),
+    (0x1D68F, Status::Mapped, 5, 1), (0x1D690, Status::Mapped, 6, 1), (0x1D691, Status::Mapped, 7, 1),
+    (0x1D692, Status::Mapped, 8, 1), (0x1D693, Status::Mapped, 9, 1), (0x1D694, Status::Mapped, 10, 1),
+    (0x1D695, Status::Mapped, 11, 1), (0x1D696, Status::Mapped, 12, 1), (0x1D697, Status::Mapped, 13, 1),
+    (0x1D698, Status::Mapped, 14, 1), (0x1D699, Status::Mapped, 15, 1), (0x1D69A, Status::Mapped, 16, 1),
+    (0x1D69B, Status::Mapped, 17, 1), (0x1D69C, Status::Mapped, 18, 1), (0x1D69D, Status::Mapped, 19, 1),
+    (0x1D69E, Status::Mapped, 20, 1), (0x1D69F, Status::Mapped, 21, 1), (0x1D6A0, Status::Mapped, 22, 1),
+    (0x1D6A1, Status::Mapped, 23, 1), (0x1D6A2, Status::Mapped, 24, 1), (0x1D6A3, Status::Mapped, 25, 1),
+    (0x1D6A4, Status::Mapped, 10953, 2), (0x1D6A5, Status::Mapped, 10955, 2), (0x1D6A6, Status::Disallowed, 0, 0),
+    (0x1D6A8, Status::Mapped, 536, 2), (0x1D6A9, Status::Mapped, 538, 2), (0x1D6AA, Status::Mapped, 540, 2),
+    (0x1D6AB, Status::Mapped, 542, 2), (0x1D6AC, Status::Mapped, 544, 2), (0x1D6AD, Status::Mapped, 546, 2),
+    (0x1D6AE, Status::Mapped, 548, 2), (0x1D6AF, Status::Mapped, 550, 2), (0x1D6B0, Status::Mapped, 499, 2),
+    (0x1D6B1, Status::Mapped, 552, 2), (0x1D6B2, Status::Mapped, 554, 2), (0x1D6B3, Status::Mapped, 38, 2),
+    (0x1D6B4, Status::Mapped, 556, 2), (0x1D6B5, Status::Mapped, 558, 2), (0x1D6B6, Status::Mapped, 560, 2),
+    (0x1D6B7, Status::Mapped, 562, 2), (0x1D6B8, Status::Mapped, 564, 2), (0x1D6B9, Status::Mapped, 550, 2),
+    (0x1D6BA, Status::Mapped, 566, 2), (0x1D6BB, Status::Mapped, 568, 2), (0x1D6BC, Status::Mapped, 570, 2),
+    (0x1D6BD, Status::Mapped, 572, 2), (0x1D6BE, Status::Mapped, 574, 2), (0x1D6BF, Status::Mapped, 576, 2),
+    (0x1D6C0, Status::Mapped, 578, 2), (0x1D6C1, Status::Mapped, 10957, 3), (0x1D6C2, Status::Mapped, 536, 2),
+    (0x1D6C3, Status::Mapped, 538, 2), (0x1D6C4, Status::Mapped, 540, 2), (0x1D6C5, Status::Mapped, 542, 2),
+    (0x1D6C6, Status::Mapped, 544, 2), (0x1D6C7, Status::Mapped, 546, 2), (0x1D6C8, Status::Mapped, 548, 2),
+    (0x1D6C9, Status::Mapped, 550, 2), (0x1D6CA, Status::Mapped, 499, 2), (0x1D6CB, Status::Mapped, 552, 2),
+    (0x1D6CC, Status::Mapped, 554, 2), (0x1D6CD, Status::Mapped, 38, 2), (0x1D6CE, Status::Mapped, 556, 2),
+    (0x1D6CF, Status::Mapped, 558, 2), (0x1D6D0, Status::Mapped, 560, 2), (0x1D6D1, Status::Mapped, 562, 2),
+    (0x1D6D2, Status::Mapped, 564, 2), (0x1D6D3, Status::Mapped, 566, 2), (0x1D6D5, Status::Mapped, 568, 2),
+    (0x1D6D6, Status::Mapped, 570, 2), (0x1D6D7, Status::Mapped, 572, 2), (0x1D6D8, Status::Mapped, 574, 2),
+    (0x1D6D9, Status::Mapped, 576, 2), (0x1D6DA, Status::Mapped, 578, 2), (0x1D6DB, Status::Mapped, 10960, 3),
+    (0x1D6DC, Status::Mapped, 544, 2), (0x1D6DD, Status::Mapped, 550, 2), (0x1D6DE, Status::Mapped, 552, 2),
+    (0x1D6DF, Status::Mapped, 572, 2), (0x1D6E0, Status::Mapped, 564, 2), (0x1D6E1, Status::Mapped, 562, 2),
+    (0x1D6E2, Status::Mapped, 536, 2), (0x1D6E3, Status::Mapped, 538, 2), (0x1D6E4, Status::Mapped, 540, 2),
+    (0x1D6E5, Status::Mapped, 542, 2), (0x1D6E6, Status::Mapped, 544, 2), (0x1D6E7, Status::Mapped, 546, 2),
+    (0x1D6E8, Status::Mapped, 548, 2), (0x1D6E9, Status::Mapped, 550, 2), (0x1D6EA, Status::Mapped, 499, 2),
+    (0x1D6EB, Status::Mapped, 552, 2), (0x1D6EC, Status::Mapped, 554, 2), (0x1D6ED, Status::Mapped, 38, 2),
+    (0x1D6EE, Status::Mapped, 556, 2), (0x1D6EF, Status::Mapped, 558, 2), (0x1D6F0, Status::Mapped, 560, 2),
+    (0x1D6F1, Status::Mapped, 562, 2), (0x1D6F2, Status::Mapped, 564, 2), (0x1D6F3, Status::Mapped, 550, 2),
+    (0x1D6F4, Status::Mapped, 566, 2), (0x1D6F5, Status::Mapped, 568, 2), (0x1D6F6, Status::Mapped, 570, 2),
+    (0x1D6F7, Status::Mapped, 572, 2), (0x1D6F8, Status::Mapped, 574, 2), (0x1D6F9, Status::Mapped, 576, 2),
+    (0x1D6FA, Status::Mapped, 578, 2), (0x1D6FB, Status::Mapped, 10957, 3), (0x1D6FC, Status::Mapped, 536, 2),
+    (0x1D6FD, Status::Mapped, 538, 2), (0x1D6FE, Status::Mapped, 540, 2), (0x1D6FF, Status::Mapped, 542, 2),
+    (0x1D700, Status::Mapped, 544, 2), (0x1D701, Status::Mapped, 546, 2), (0x1D702, Status::Mapped, 548, 2),
+    (0x1D703, Status::Mapped, 550, 2), (0x1D704, Status::Mapped, 499, 2), (0x1D705, Status::Mapped, 552, 2),
+    (0x1D706, Status::Mapped, 554, 2), (0x1D707, Status::Mapped, 38, 2), (0x1D708, Status::Mapped, 556, 2),
+    (0x1D709, Status::Mapped, 558, 2), (0x1D70A, Status::Mapped, 560, 2), (0x1D70B, Status::Mapped, 562, 2),
+    (0x1D70C, Status::Mapped, 564, 2), (0x1D70D, Status::Mapped, 566, 2), (0x1D70F, Status::Mapped, 568, 2),
+    (0x1D710, Status::Mapped, 570, 2), (0x1D711, Status::Mapped, 572, 2), (0x1D712, Status::Mapped, 574, 2),
+    (0x1D713, Status::Mapped, 576, 2), (0x1D714, Status::Mapped, 578, 2), (0x1D715, Status::Mapped, 10960, 3),
+    (0x1D716, Status::Mapped, 544, 2), (0x1D717, Status::Mapped, 550, 2), (0x1D718, Status::Mapped, 552, 2),
+    (0x1D719, Status::Mapped, 572, 2), (0x1D71A, Status::Mapped, 564, 2), (0x1D71B, Status::Mapped, 562, 2),
+    (0x1D71C, Status::Mapped, 536, 2), (0x1D71D, Status::Mapped, 538, 2), (0x1D71E, Status::Mapped, 540, 2),
+    (0x1D71F, Status::Mapped, 542, 2), (0x1D720, Status::Mapped, 544, 2), (0x1D721, Status::Mapped, 546, 2),
+    (0x1D722, Status::Mapped, 548, 2), (0x1D723, Status::Mapped, 550, 2), (0x1D724, Status::Mapped, 499, 2),
+    (0x1D725, Status::Mapped, 552, 2), (0x1D726, Status::Mapped, 554, 2), (0x1D727, Status::Mapped, 38, 2),
+    (0x1D728, Status::Mapped, 556, 2), (0x1D729, Status::Mapped, 558, 2), (0x1D72A, Status::Mapped, 560, 2),
+    (0x1D72B, Status::Mapped, 562, 2), (0x1D72C, Status::Mapped, 564, 2), (0x1D72D, Status::Mapped, 550, 2),
+    (0x1D72E, Status::Mapped, 566, 2), (0x1D72F, Status::Mapped, 568, 2), (0x1D730, Status::Mapped, 570, 2),
+    (0x1D731, Status::Mapped, 572, 2), (0x1D732, Status::Mapped, 574, 2), (0x1D733, Status::Mapped, 576, 2),
+    (0x1D734, Status::Mapped, 578, 2), (0x1D735, Status::Mapped, 10957, 3), (0x1D736, Status::Mapped, 536, 2),
+    (0x1D737, Status::Mapped, 538, 2), (0x1D738, Status::Mapped, 540, 2), (0x1D739, Status::Mapped, 542, 2),
+    (0x1D73A, Status::Mapped, 544, 2), (0x1D73B, Status::Mapped, 546, 2), (0x1D73C, Status::Mapped, 548, 2),
+    (0x1D73D, Status::Mapped, 550, 2), (0x1D73E, Status::Mapped, 499, 2), (0x1D73F, Status::Mapped, 552, 2),
+    (0x1D740, Status::Mapped, 554, 2), (0x1D741, Status::Mapped, 38, 2), (0x1D742, Status::Mapped, 556, 2),
+    (0x1D743, Status::Mapped, 558, 2), (0x1D744, Status::Mapped, 560, 2), (0x1D745, Status::Mapped, 562, 2),
+    (0x1D746, Status::Mapped, 564, 2), (0x1D747, Status::Mapped, 566, 2), (0x1D749, Status::Mapped, 568, 2),
+    (0x1D74A, Status::Mapped, 570, 2), (0x1D74B, Status::Mapped, 572, 2), (0x1D74C, Status::Mapped, 574, 2),
+    (0x1D74D, Status::Mapped, 576, 2), (0x1D74E, Status::Mapped, 578, 2), (0x1D74F, Status::Mapped, 10960, 3),
+    (0x1D750, Status::Mapped, 544, 2), (0x1D751, Status::Mapped, 550, 2), (0x1D752, Status::Mapped, 552, 2),
+    (0x1D753, Status::Mapped, 572, 2), (0x1D754, Status::Mapped, 564, 2), (0x1D755, Status::Mapped, 562, 2),
+    (0x1D756, Status::Mapped, 536, 2), (0x1D757, Status::Mapped, 538, 2), (0x1D758, Status::Mapped, 540, 2),
+    (0x1D759, Status::Mapped, 542, 2), (0x1D75A, Status::Mapped, 544, 2), (0x1D75B, Status::Mapped, 546, 2),
+    (0x1D75C, Status::Mapped, 548, 2), (0x1D75D, Status::Mapped, 550, 2), (0x1D75E, Status::Mapped, 499, 2),
+    (0x1D75F, Status::Mapped, 552, 2), (0x1D760, Status::Mapped, 554, 2), (0x1D761, Status::Mapped, 38, 2),
+    (0x1D762, Status::Mapped, 556, 2), (0x1D763, Status::Mapped, 558, 2), (0x1D764, Status::Mapped, 560, 2),
+    (0x1D765, Status::Mapped, 562, 2), (0x1D766, Status::Mapped, 564, 2), (0x1D767, Status::Mapped, 550, 2),
+    (0x1D768, Status::Mapped, 566, 2), (0x1D769, Status::Mapped, 568, 2), (0x1D76A, Status::Mapped, 570, 2),
+    (0x1D76B, Status::Mapped, 572, 2), (0x1D76C, Status::Mapped, 574, 2), (0x1D76D, Status::Mapped, 576, 2),
+    (0x1D76E, Status::Mapped, 578, 2), (0x1D76F, Status::Mapped, 10957, 3), (0x1D770, Status::Mapped, 536, 2),
+    (0x1D771, Status::Mapped, 538, 2), (0x1D772, Status::Mapped, 540, 2), (0x1D773, Status::Mapped, 542, 2),
+    (0x1D774, Status::Mapped, 544, 2), (0x1D775, Status::Mapped, 546, 2), (0x1D776, Status::Mapped, 548, 2),
+    (0x1D777, Status::Mapped, 550, 2), (0x1D778, Status::Mapped, 499, 2), (0x1D779, Status::Mapped, 552, 2),
+    (0x1D77A, Status::Mapped, 554, 2), (0x1D77B, Status::Mapped, 38, 2), (0x1D77C, Status::Mapped, 556, 2),
+    (0x1D77D, Status::Mapped, 558, 2), (0x1D77E, Status::Mapped, 560, 2), (0x1D77F, Status::Mapped, 562, 2),
+    (0x1D780, Status::Mapped, 564, 2), (0x1D781, Status::Mapped, 566, 2), (0x1D783, Status::Mapped, 568, 2),
+    (0x1D784, Status::Mapped, 570, 2), (0x1D785, Status::Mapped, 572, 2), (0x1D786, Status::Mapped, 574, 2),
+    (0x1D787, Status::Mapped, 576, 2), (0x1D788, Status::Mapped, 578, 2), (0x1D789, Status::Mapped, 10960, 3),
+    (0x1D78A, Status::Mapped, 544, 2), (0x1D78B, Status::Mapped, 550, 2), (0x1D78C, Status::Mapped, 552, 2),
+    (0x1D78D, Status::Mapped, 572, 2), (0x1D78E, Status::Mapped, 564, 2), (0x1D78F, Status::Mapped, 562, 2),
+    (0x1D790, Status::Mapped, 536, 2), (0x1D791, Status::Mapped, 538, 2), (0x1D792, Status::Mapped, 540, 2),
+    (0x1D793, Status::Mapped, 542, 2), (0x1D794, Status::Mapped, 544, 2), (0x1D795, Status::Mapped, 546, 2),
+    (0x1D796, Status::Mapped, 548, 2), (0x1D797, Status::Mapped, 550, 2), (0x1D798, Status::Mapped, 499, 2),
+    (0x1D799, Status::Mapped, 552, 2), (0x1D79A, Status::Mapped, 554, 2), (0x1D79B, Status::Mapped, 38, 2),
+    (0x1D79C, Status::Mapped, 556, 2), (0x1D79D, Status::Mapped, 558, 2), (0x1D79E, Status::Mapped, 560, 2),
+    (0x1D79F, Status::Mapped, 562, 2), (0x1D7A0, Status::Mapped, 564, 2), (0x1D7A1, Status::Mapped, 550, 2),
+    (0x1D7A2, Status::Mapped, 566, 2), (0x1D7A3, Status::Mapped, 568, 2), (0x1D7A4, Status::Mapped, 570, 2),
+    (0x1D7A5, Status::Mapped, 572, 2), (0x1D7A6, Status::Mapped, 574, 2), (0x1D7A7, Status::Mapped, 576, 2),
+    (0x1D7A8, Status::Mapped, 578, 2), (0x1D7A9, Status::Mapped, 10957, 3), (0x1D7AA, Status::Mapped, 536, 2),
+    (0x1D7AB, Status::Mapped, 538, 2), (0x1D7AC, Status::Mapped, 540, 2), (0x1D7AD, Status::Mapped, 542, 2),
+    (0x1D7AE, Status::Mapped, 544, 2), (0x1D7AF, Status::Mapped, 546, 2), (0x1D7B0, Status::Mapped, 548, 2),
+    (0x1D7B1, Status::Mapped, 550, 2), (0x1D7B2, Status::Mapped, 499, 2), (0x1D7B3, Status::Mapped, 552, 2),
+    (0x1D7B4, Status::Mapped, 554, 2), (0x1D7B5, Status::Mapped, 38, 2), (0x1D7B6, Status::Mapped, 556, 2),
+    (0x1D7B7, Status::Mapped, 558, 2), (0x1D7B8, Status::Mapped, 560, 2), (0x1D7B9, Status::Mapped, 562, 2),
+    (0x1D7BA, Status::Mapped, 564, 2), (0x1D7BB, Status::Mapped, 566, 2), (0x1D7BD, Status::Mapped, 568, 2),
+    (0x1D7BE, Status::Mapped, 570, 2), (0x1D7BF, Status::Mapped, 572, 2), (0x1D7C0, Status::Mapped, 574, 2),
+    (0x1D7C1, Status::Mapped, 576, 2), (0x1D7C2, Status::Mapped, 578, 2), (0x1D7C3, Status::Mapped, 10960, 3),
+    (0x1D7C4, Status::Mapped, 544, 2), (0x1D7C5, Status::Mapped, 550, 2), (0x1D7C6, Status::Mapped, 552, 2),
+    (0x1D7C7, Status::Mapped, 572, 2), (0x1D7C8, Status::Mapped, 564, 2), (0x1D7C9, Status::Mapped, 562, 2),
+    (0x1D7CA, Status::Mapped, 590, 2), (0x1D7CC, Status::Disallowed, 0, 0), (0x1D7CE, Status::Mapped, 2347, 1),
+    (0x1D7CF, Status::Mapped, 43, 1), (0x1D7D0, Status::Mapped, 33, 1), (0x1D7D1, Status::Mapped, 34, 1),
+    (0x1D7D2, Status::Mapped, 2348, 1), (0x1D7D3, Status::Mapped, 2349, 1), (0x1D7D4, Status::Mapped, 2350, 1),
+    (0x1D7D5, Status::Mapped, 2351, 1), (0x1D7D6, Status::Mapped, 2352, 1), (0x1D7D7, Status::Mapped, 2353, 1),
+    (0x1D7D8, Status::Mapped, 2347, 1), (0x1D7D9, Status::Mapped, 43, 1), (0x1D7DA, Status::Mapped, 33, 1),
+    (0x1D7DB, Status::Mapped, 34, 1), (0x1D7DC, Status::Mapped, 2348, 1), (0x1D7DD, Status::Mapped, 2349, 1),
+    (0x1D7DE, Status::Mapped, 2350, 1), (0x1D7DF, Status::Mapped, 2351, 1), (0x1D7E0, Status::Mapped, 2352, 1),
+    (0x1D7E1, Status::Mapped, 2353, 1), (0x1D7E2, Status::Mapped, 2347, 1), (0x1D7E3, Status::Mapped, 43, 1),
+    (0x1D7E4, Status::Mapped, 33, 1), (0x1D7E5, Status::Mapped, 34, 1), (0x1D7E6, Status::Mapped, 2348, 1),
+    (0x1D7E7, Status::Mapped, 2349, 1), (0x1D7E8, Status::Mapped, 2350, 1), (0x1D7E9, Status::Mapped, 2351, 1),
+    (0x1D7EA, Status::Mapped, 2352, 1), (0x1D7EB, Status::Mapped, 2353, 1), (0x1D7EC, Status::Mapped, 2347, 1),
+    (0x1D7ED, Status::Mapped, 43, 1), (0x1D7EE, Status::Mapped, 33, 1), (0x1D7EF, Status::Mapped, 34, 1),
+    (0x1D7F0, Status::Mapped, 2348, 1), (0x1D7F1, Status::Mapped, 2349, 1), (0x1D7F2, Status::Mapped, 2350, 1),
+    (0x1D7F3, Status::Mapped, 2351, 1), (0x1D7F4, Status::Mapped, 2352, 1), (0x1D7F5, Status::Mapped, 2353, 1),
+    (0x1D7F6, Status::Mapped, 2347, 1), (0x1D7F7, Status::Mapped, 43, 1), (0x1D7F8, Status::Mapped, 33, 1),
+    (0x1D7F9, Status::Mapped, 34, 1), (0x1D7FA, Status::Mapped, 2348, 1), (0x1D7FB, Status::Mapped, 2349, 1),
+    (0x1D7FC, Status::Mapped, 2350, 1), (0x1D7FD, Status::Mapped, 2351, 1), (0x1D7FE, Status::Mapped, 2352, 1),
+    (0x1D7FF, Status::Mapped, 2353, 1), (0x1D800, Status::Valid, 0, 0), (0x1DA8C, Status::Disallowed, 0, 0),
+    (0x1DA9B, Status::Valid, 0, 0), (0x1DAA0, Status::Disallowed, 0, 0), (0x1DAA1, Status::Valid, 0, 0),
+    (0x1DAB0, Status::Disallowed, 0, 0), (0x1E000, Status::Valid, 0, 0), (0x1E007, Status::Disallowed, 0, 0),
+    (0x1E008, Status::Valid, 0, 0), (0x1E019, Status::Disallowed, 0, 0), (0x1E01B, Status::Valid, 0, 0),
+    (0x1E022, Status::Disallowed, 0, 0), (0x1E023, Status::Valid, 0, 0), (0x1E025, Status::Disallowed, 0, 0),
+    (0x1E026, Status::Valid, 0, 0), (0x1E02B, Status::Disallowed, 0, 0), (0x1E100, Status::Valid, 0, 0),
+    (0x1E12D, Status::Disallowed, 0, 0), (0x1E130, Status::Valid, 0, 0), (0x1E13E, Status::Disallowed, 0, 0),
+    (0x1E140, Status::Valid, 0, 0), (0x1E14A, Status::Disallowed, 0, 0), (0x1E14E, Status::Valid, 0, 0),
+    (0x1E150, Status::Disallowed, 0, 0), (0x1E2C0, Status::Valid, 0, 0), (0x1E2FA, Status::Disallowed, 0, 0),
+    (0x1E2FF, Status::Valid, 0, 0), (0x1E300, Status::Disallowed, 0, 0), (0x1E800, Status::Valid, 0, 0),
+    (0x1E8C5, Status::Disallowed, 0, 0), (0x1E8C7, Status::Valid, 0, 0), (0x1E8D7, Status::Disallowed, 0, 0),
+    (0x1E900, Status::Mapped, 10963, 4), (0x1E901, Status::Mapped, 10967, 4), (0x1E902, Status::Mapped, 10971, 4),
+    (0x1E903, Status::Mapped, 10975, 4), (0x1E904, Status::Mapped, 10979, 4), (0x1E905, Status::Mapped, 10983, 4),
+    (0x1E906, Status::Mapped, 10987, 4), (0x1E907, Status::Mapped, 10991, 4), (0x1E908, Status::Mapped, 10995, 4),
+    (0x1E909, Status::Mapped, 10999, 4), (0x1E90A, Status::Mapped, 11003, 4), (0x1E90B, Status::Mapped, 11007, 4),
+    (0x1E90C, Status::Mapped, 11011, 4), (0x1E90D, Status::Mapped, 11015, 4), (0x1E90E, Status::Mapped, 11019, 4),
+    (0x1E90F, Status::Mapped, 11023, 4), (0x1E910, Status::Mapped, 11027, 4), (0x1E911, Status::Mapped, 11031, 4),
+    (0x1E912, Status::Mapped, 11035, 4), (0x1E913, Status::Mapped, 11039, 4), (0x1E914, Status::Mapped, 11043, 4),
+    (0x1E915, Status::Mapped, 11047, 4), (0x1E916, Status::Mapped, 11051, 4), (0x1E917, Status::Mapped, 11055, 4),
+    (0x1E918, Status::Mapped, 11059, 4), (0x1E919, Status::Mapped, 11063, 4), (0x1E91A, Status::Mapped, 11067, 4),
+    (0x1E91B, Status::Mapped, 11071, 4), (0x1E91C, Status::Mapped, 11075, 4), (0x1E91D, Status::Mapped, 11079, 4),
+    (0x1E91E, Status::Mapped, 11083, 4), (0x1E91F, Status::Mapped, 11087, 4), (0x1E920, Status::Mapped, 11091, 4),
+    (0x1E921, Status::Mapped, 11095, 4), (0x1E922, Status::Valid, 0, 0), (0x1E94C, Status::Disallowed, 0, 0),
+    (0x1E950, Status::Valid, 0, 0), (0x1E95A, Status::Disallowed, 0, 0), (0x1E95E, Status::Valid, 0, 0),
+    (0x1E960, Status::Disallowed, 0, 0), (0x1EC71, Status::Valid, 0, 0), (0x1ECB5, Status::Disallowed, 0, 0),
+    (0x1ED01, Status::Valid, 0, 0), (0x1ED3E, Status::Disallowed, 0, 0), (0x1EE00, Status::Mapped, 9887, 2),
+    (0x1EE01, Status::Mapped, 9889, 2), (0x1EE02, Status::Mapped, 9897, 2), (0x1EE03, Status::Mapped, 9903, 2),
+    (0x1EE04, Status::Disallowed, 0, 0), (0x1EE05, Status::Mapped, 9941, 2), (0x1EE06, Status::Mapped, 9909, 2),
+    (0x1EE07, Status::Mapped, 9899, 2), (0x1EE08, Status::Mapped, 9919, 2), (0x1EE09, Status::Mapped, 9943, 2),
+    (0x1EE0A, Status::Mapped, 9931, 2), (0x1EE0B, Status::Mapped, 9933, 2), (0x1EE0C, Status::Mapped, 9935, 2),
+    (0x1EE0D, Status::Mapped, 9937, 2), (0x1EE0E, Status::Mapped, 9911, 2), (0x1EE0F, Status::Mapped, 9923, 2),
+    (0x1EE10, Status::Mapped, 9927, 2), (0x1EE11, Status::Mapped, 9915, 2), (0x1EE12, Status::Mapped, 9929, 2),
+    (0x1EE13, Status::Mapped, 9907, 2), (0x1EE14, Status::Mapped, 9913, 2), (0x1EE15, Status::Mapped, 9893, 2),
+    (0x1EE16, Status::Mapped, 9895, 2), (0x1EE17, Status::Mapped, 9901, 2), (0x1EE18, Status::Mapped, 9905, 2),
+    (0x1EE19, Status::Mapped, 9917, 2), (0x1EE1A, Status::Mapped, 9921, 2), (0x1EE1B, Status::Mapped, 9925, 2),
+    (0x1EE1C, Status::Mapped, 11099, 2), (0x1EE1D, Status::Mapped, 8343, 2), (0x1EE1E, Status::Mapped, 11101, 2),
+    (0x1EE1F, Status::Mapped, 11103, 2), (0x1EE20, Status::Disallowed, 0, 0), (0x1EE21, Status::Mapped, 9889, 2),
+    (0x1EE22, Status::Mapped, 9897, 2), (0x1EE23, Status::Disallowed, 0, 0), (0x1EE24, Status::Mapped, 9939, 2),
+    (0x1EE25, Status::Disallowed, 0, 0), (0x1EE27, Status::Mapped, 9899, 2), (0x1EE28, Status::Disallowed, 0, 0),
+    (0x1EE29, Status::Mapped, 9943, 2), (0x1EE2A, Status::Mapped, 9931, 2), (0x1EE2B, Status::Mapped, 9933, 2),
+    (0x1EE2C, Status::Mapped, 9935, 2), (0x1EE2D, Status::Mapped, 9937, 2), (0x1EE2E, Status::Mapped, 9911, 2),
+    (0x1EE2F, Status::Mapped, 9923, 2), (0x1EE30, Status::Mapped, 9927, 2), (0x1EE31, Status::Mapped, 9915, 2),
+    (0x1EE32, Status::Mapped, 9929, 2), (0x1EE33, Status::Disallowed, 0, 0), (0x1EE34, Status::Mapped, 9913, 2),
+    (0x1EE35, Status::Mapped, 9893, 2), (0x1EE36, Status::Mapped, 9895, 2), (0x1EE37, Status::Mapped, 9901, 2),
+    (0x1EE38, Status::Disallowed, 0, 0), (0x1EE39, Status::Mapped, 9917, 2), (0x1EE3A, Status::Disallowed, 0, 0),
+    (0x1EE3B, Status::Mapped, 9925, 2), (0x1EE3C, Status::Disallowed, 0, 0), (0x1EE42, Status::Mapped, 9897, 2),
+    (0x1EE43, Status::Disallowed, 0, 0), (0x1EE47, Status::Mapped, 9899, 2), (0x1EE48, Status::Disallowed, 0, 0),
+    (0x1EE49, Status::Mapped, 9943, 2), (0x1EE4A, Status::Disallowed, 0, 0), (0x1EE4B, Status::Mapped, 9933, 2),
+    (0x1EE4C, Status::Disallowed, 0, 0), (0x1EE4D, Status::Mapped, 9937, 2), (0x1EE4E, Status::Mapped, 9911, 2),
+    (0x1EE4F, Status::Mapped, 9923, 2), (0x1EE50, Status::Disallowed, 0, 0), (0x1EE51, Status::Mapped, 9915, 2),
+    (0x1EE52, Status::Mapped, 9929, 2), (0x1EE53, Status::Disallowed, 0, 0), (0x1EE54, Status::Mapped, 9913, 2),
+    (0x1EE55, Status::Disallowed, 0, 0), (0x1EE57, Status::Mapped, 9901, 2), (0x1EE58, Status::Disallowed, 0, 0),
+    (0x1EE59, Status::Mapped, 9917, 2), (0x1EE5A, Status::Disallowed, 0, 0), (0x1EE5B, Status::Mapped, 9925, 2),
+    (0x1EE5C, Status::Disallowed, 0, 0), (0x1EE5D, Status::Mapped, 8343, 2), (0x1EE5E, Status::Disallowed, 0, 0),
+    (0x1EE5F, Status::Mapped, 11103, 2), (0x1EE60, Status::Disallowed, 0, 0), (0x1EE61, Status::Mapped, 9889, 2),
+    (0x1EE62, Status::Mapped, 9897, 2), (0x1EE63, Status::Disallowed, 0, 0), (0x1EE64, Status::Mapped, 9939, 2),
+    (0x1EE65, Status::Disallowed, 0, 0), (0x1EE67, Status::Mapped, 9899, 2), (0x1EE68, Status::Mapped, 9919, 2),
+    (0x1EE69, Status::Mapped, 9943, 2), (0x1EE6A, Status::Mapped, 9931, 2), (0x1EE6B, Status::Disallowed, 0, 0),
+    (0x1EE6C, Status::Mapped, 9935, 2), (0x1EE6D, Status::Mapped, 9937, 2), (0x1EE6E, Status::Mapped, 9911, 2),
+    (0x1EE6F, Status::Mapped, 9923, 2), (0x1EE70, Status::Mapped, 9927, 2), (0x1EE71, Status::Mapped, 9915, 2),
+    (0x1EE72, Status::Mapped, 9929, 2), (0x1EE73, Status::Disallowed, 0, 0), (0x1EE74, Status::Mapped, 9913, 2),
+    (0x1EE75, Status::Mapped, 9893, 2), (0x1EE76, Status::Mapped, 9895, 2), (0x1EE77, Status::Mapped, 9901, 2),
+    (0x1EE78, Status::Disallowed, 0, 0), (0x1EE79, Status::Mapped, 9917, 2), (0x1EE7A, Status::Mapped, 9921, 2),
+    (0x1EE7B, Status::Mapped, 9925, 2), (0x1EE7C, Status::Mapped, 11099, 2), (0x1EE7D, Status::Disallowed, 0, 0),
+    (0x1EE7E, Status::Mapped, 11101, 2), (0x1EE7F, Status::Disallowed, 0, 0), (0x1EE80, Status::Mapped, 9887, 2),
+    (0x1EE81, Status::Mapped, 9889, 2), (0x1EE82, Status::Mapped, 9897, 2), (0x1EE83, Status::Mapped, 9903, 2),
+    (0x1EE84, Status::Mapped, 9939, 2), (0x1EE85, Status::Mapped, 9941, 2), (0x1EE86, Status::Mapped, 9909, 2),
+    (0x1EE87, Status::Mapped, 9899, 2), (0x1EE88, Status::Mapped, 9919, 2), (0x1EE89, Status::Mapped, 9943, 2),
+    (0x1EE8A, Status::Disallowed, 0, 0), (0x1EE8B, Status::Mapped, 9933, 2), (0x1EE8C, Status::Mapped, 9935, 2),
+    (0x1EE8D, Status::Mapped, 9937, 2), (0x1EE8E, Status::Mapped, 9911, 2), (0x1EE8F, Status::Mapped, 9923, 2),
+    (0x1EE90, Status::Mapped, 9927, 2), (0x1EE91, Status::Mapped, 9915, 2), (0x1EE92, Status::Mapped, 9929, 2),
+    (0x1EE93, Status::Mapped, 9907, 2), (0x1EE94, Status::Mapped, 9913, 2), (0x1EE95, Status::Mapped, 9893, 2),
+    (0x1EE96, Status::Mapped, 9895, 2), (0x1EE97, Status::Mapped, 9901, 2), (0x1EE98, Status::Mapped, 9905, 2),
+    (0x1EE99, Status::Mapped, 9917, 2), (0x1EE9A, Status::Mapped, 9921, 2), (0x1EE9B, Status::Mapped, 9925, 2),
+    (0x1EE9C, Status::Disallowed, 0, 0), (0x1EEA1, Status::Mapped, 9889, 2), (0x1EEA2, Status::Mapped, 9897, 2),
+    (0x1EEA3, Status::Mapped, 9903, 2), (0x1EEA4, Status::Disallowed, 0, 0), (0x1EEA5, Status::Mapped, 9941, 2),
+    (0x1EEA6, Status::Mapped, 9909, 2), (0x1EEA7, Status::Mapped, 9899, 2), (0x1EEA8, Status::Mapped, 9919, 2),
+    (0x1EEA9, Status::Mapped, 9943, 2), (0x1EEAA, Status::Disallowed, 0, 0), (0x1EEAB, Status::Mapped, 9933, 2),
+    (0x1EEAC, Status::Mapped, 9935, 2), (0x1EEAD, Status::Mapped, 9937, 2), (0x1EEAE, Status::Mapped, 9911, 2),
+    (0x1EEAF, Status::Mapped, 9923, 2), (0x1EEB0, Status::Mapped, 9927, 2), (0x1EEB1, Status::Mapped, 9915, 2),
+    (0x1EEB2, Status::Mapped, 9929, 2), (0x1EEB3, Status::Mapped, 9907, 2), (0x1EEB4, Status::Mapped, 9913, 2),
+    (0x1EEB5, Status::Mapped, 9893, 2), (0x1EEB6, Status::Mapped, 9895, 2), (0x1EEB7, Status::Mapped, 9901, 2),
+    (0x1EEB8, Status::Mapped, 9905, 2), (0x1EEB9, Status::Mapped, 9917, 2), (0x1EEBA, Status::Mapped, 9921, 2),
+    (0x1EEBB, Status::Mapped, 9925, 2), (0x1EEBC, Status::Disallowed, 0, 0), (0x1EEF0, Status::Valid, 0, 0),
+    (0x1EEF2, Status::Disallowed, 0, 0), (0x1F000, Status::Valid, 0, 0), (0x1F02C, Status::Disallowed, 0, 0),
+    (0x1F030, Status::Valid, 0, 0), (0x1F094, Status::Disallowed, 0, 0), (0x1F0A0, Status::Valid, 0, 0),
+    (0x1F0AF, Status::Disallowed, 0, 0), (0x1F0B1, Status::Valid, 0, 0), (0x1F0C0, Status::Disallowed, 0, 0),
+    (0x1F0C1, Status::Valid, 0, 0), (0x1F0D0, Status::Disallowed, 0, 0), (0x1F0D1, Status::Valid, 0, 0),
+    (0x1F0F6, Status::Disallowed, 0, 0), (0x1F101, Status::DisallowedStd3Mapped, 11105, 2), (0x1F102, Status::DisallowedStd3Mapped, 11107, 2),
+    (0x1F103, Status::DisallowedStd3Mapped, 11109, 2), (0x1F104, Status::DisallowedStd3Mapped, 11111, 2), (0x1F105, Status::DisallowedStd3Mapped, 11113, 2),
+    (0x1F106, Status::DisallowedStd3Mapped, 11115, 2), (0x1F107, Status::DisallowedStd3Mapped, 11117, 2), (0x1F108, Status::DisallowedStd3Mapped, 11119, 2),
+    (0x1F109, Status::DisallowedStd3Mapped, 11121, 2), (0x1F10A, Status::DisallowedStd3Mapped, 11123, 2), (0x1F10B, Status::Valid, 0, 0),
+    (0x1F110, Status::DisallowedStd3Mapped, 2641, 3), (0x1F111, Status::DisallowedStd3Mapped, 2644, 3), (0x1F112, Status::DisallowedStd3Mapped, 2647, 3),
+    (0x1F113, Status::DisallowedStd3Mapped, 2650, 3), (0x1F114, Status::DisallowedStd3Mapped, 2653, 3), (0x1F115, Status::DisallowedStd3Mapped, 2656, 3),
+    (0x1F116, Status::DisallowedStd3Mapped, 2659, 3), (0x1F117, Status::DisallowedStd3Mapped, 2662, 3), (0x1F118, Status::DisallowedStd3Mapped, 2665, 3),
+    (0x1F119, Status::DisallowedStd3Mapped, 2668, 3), (0x1F11A, Status::DisallowedStd3Mapped, 2671, 3), (0x1F11B, Status::DisallowedStd3Mapped, 2674, 3),
+    (0x1F11C, Status::DisallowedStd3Mapped, 2677, 3), (0x1F11D, Status::DisallowedStd3Mapped, 2680, 3), (0x1F11E, Status::DisallowedStd3Mapped, 2683, 3),
+    (0x1F11F, Status::DisallowedStd3Mapped, 2686, 3), (0x1F120, Status::DisallowedStd3Mapped, 2689, 3), (0x1F121, Status::DisallowedStd3Mapped, 2692, 3),
+    (0x1F122, Status::DisallowedStd3Mapped, 2695, 3), (0x1F123, Status::DisallowedStd3Mapped, 2698, 3), (0x1F124, Status::DisallowedStd3Mapped, 2701, 3),
+    (0x1F125, Status::DisallowedStd3Mapped, 2704, 3), (0x1F126, Status::DisallowedStd3Mapped, 2707, 3), (0x1F127, Status::DisallowedStd3Mapped, 2710, 3),
+    (0x1F128, Status::DisallowedStd3Mapped, 2713, 3), (0x1F129, Status::DisallowedStd3Mapped, 2716, 3), (0x1F12A, Status::Mapped, 11125, 7),
+    (0x1F12B, Status::Mapped, 2, 1), (0x1F12C, Status::Mapped, 17, 1), (0x1F12D, Status::Mapped, 6093, 2),
+    (0x1F12E, Status::Mapped, 11132, 2), (0x1F12F, Status::Valid, 0, 0), (0x1F130, Status::Mapped, 0, 1),
+    (0x1F131, Status::Mapped, 1, 1), (0x1F132, Status::Mapped, 2, 1), (0x1F133, Status::Mapped, 3, 1),
+    (0x1F134, Status::Mapped, 4, 1), (0x1F135, Status::Mapped, 5, 1), (0x1F136, Status::Mapped, 6, 1),
+    (0x1F137, Status::Mapped, 7, 1), (0x1F138, Status::Mapped, 8, 1), (0x1F139, Status::Mapped, 9, 1),
+    (0x1F13A, Status::Mapped, 10, 1), (0x1F13B, Status::Mapped, 11, 1), (0x1F13C, Status::Mapped, 12, 1),
+    (0x1F13D, Status::Mapped, 13, 1), (0x1F13E, Status::Mapped, 14, 1), (0x1F13F, Status::Mapped, 15, 1),
+    (0x1F140, Status::Mapped, 16, 1), (0x1F141, Status::Mapped, 17, 1), (0x1F142, Status::Mapped, 18, 1),
+    (0x1F143, Status::Mapped, 19, 1), (0x1F144, Status::Mapped, 20, 1), (0x1F145, Status::Mapped, 21, 1),
+    (0x1F146, Status::Mapped, 22, 1), (0x1F147, Status::Mapped, 23, 1), (0x1F148, Status::Mapped, 24, 1),
+    (0x1F149, Status::Mapped, 25, 1), (0x1F14A, Status::Mapped, 11134, 2), (0x1F14B, Status::Mapped, 6068, 2),
+    (0x1F14C, Status::Mapped, 11136, 2), (0x1F14D, Status::Mapped, 119, 2), (0x1F14E, Status::Mapped, 11138, 3),
+    (0x1F14F, Status::Mapped, 11141, 2), (0x1F150, Status::Valid, 0, 0), (0x1F16A, Status::Mapped, 11143, 2),
+    (0x1F16B, Status::Mapped, 11145, 2), (0x1F16C, Status::Mapped, 11147, 2), (0x1F16D, Status::Valid, 0, 0),
+    (0x1F190, Status::Mapped, 11149, 2), (0x1F191, Status::Valid, 0, 0), (0x1F1AE, Status::Disallowed, 0, 0),
+    (0x1F1E6, Status::Valid, 0, 0), (0x1F200, Status::Mapped, 11151, 6), (0x1F201, Status::Mapped, 11157, 6),
+    (0x1F202, Status::Mapped, 4720, 3), (0x1F203, Status::Disallowed, 0, 0), (0x1F210, Status::Mapped, 3271, 3),
+    (0x1F211, Status::Mapped, 11163, 3), (0x1F212, Status::Mapped, 11166, 3), (0x1F213, Status::Mapped, 11169, 3),
+    (0x1F214, Status::Mapped, 3100, 3), (0x1F215, Status::Mapped, 11172, 3), (0x1F216, Status::Mapped, 11175, 3),
+    (0x1F217, Status::Mapped, 4057, 3), (0x1F218, Status::Mapped, 11178, 3), (0x1F219, Status::Mapped, 11181, 3),
+    (0x1F21A, Status::Mapped, 11184, 3), (0x1F21B, Status::Mapped, 7414, 3), (0x1F21C, Status::Mapped, 11187, 3),
+    (0x1F21D, Status::Mapped, 11190, 3), (0x1F21E, Status::Mapped, 11193, 3), (0x1F21F, Status::Mapped, 11196, 3),
+    (0x1F220, Status::Mapped, 11199, 3), (0x1F221, Status::Mapped, 11202, 3), (0x1F222, Status::Mapped, 3379, 3),
+    (0x1F223, Status::Mapped, 11205, 3), (0x1F224, Status::Mapped, 11208, 3), (0x1F225, Status::Mapped, 11211, 3),
+    (0x1F226, Status::Mapped, 11214, 3), (0x1F227, Status::Mapped, 11217, 3), (0x1F228, Status::Mapped, 11220, 3),
+    (0x1F229, Status::Mapped, 3082, 3), (0x1F22A, Status::Mapped, 4033, 3), (0x1F22B, Status::Mapped, 11223, 3),
+    (0x1F22C, Status::Mapped, 4569, 3), (0x1F22D, Status::Mapped, 4042, 3), (0x1F22E, Status::Mapped, 4572, 3),
+    (0x1F22F, Status::Mapped, 11226, 3), (0x1F230, Status::Mapped, 3547, 3), (0x1F231, Status::Mapped, 11229, 3),
+    (0x1F232, Status::Mapped, 11232, 3), (0x1F233, Status::Mapped, 11235, 3), (0x1F234, Status::Mapped, 11238, 3),
+    (0x1F235, Status::Mapped, 11241, 3), (0x1F236, Status::Mapped, 4518, 3), (0x1F237, Status::Mapped, 3301, 3),
+    (0x1F238, Status::Mapped, 11244, 3), (0x1F239, Status::Mapped, 11247, 3), (0x1F23A, Status::Mapped, 11250, 3),
+    (0x1F23B, Status::Mapped, 11253, 3), (0x1F23C, Status::Disallowed, 0, 0), (0x1F240, Status::Mapped, 11256, 9),
+    (0x1F241, Status::Mapped, 11265, 9), (0x1F242, Status::Mapped, 11274, 9), (0x1F243, Status::Mapped, 11283, 9),
+    (0x1F244, Status::Mapped, 11292, 9), (0x1F245, Status::Mapped, 11301, 9), (0x1F246, Status::Mapped, 11310, 9),
+    (0x1F247, Status::Mapped, 11319, 9), (0x1F248, Status::Mapped, 11328, 9), (0x1F249, Status::Disallowed, 0, 0),
+    (0x1F250, Status::Mapped, 11337, 3), (0x1F251, Status::Mapped, 11340, 3), (0x1F252, Status::Disallowed, 0, 0),
+    (0x1F260, Status::Valid, 0, 0), (0x1F266, Status::Disallowed, 0, 0), (0x1F300, Status::Valid, 0, 0),
+    (0x1F6D8, Status::Disallowed, 0, 0), (0x1F6E0, Status::Valid, 0, 0), (0x1F6ED, Status::Disallowed, 0, 0),
+    (0x1F6F0, Status::Valid, 0, 0), (0x1F6FD, Status::Disallowed, 0, 0), (0x1F700, Status::Valid, 0, 0),
+    (0x1F774, Status::Disallowed, 0, 0), (0x1F780, Status::Valid, 0, 0), (0x1F7D9, Status::Disallowed, 0, 0),
+    (0x1F7E0, Status::Valid, 0, 0), (0x1F7EC, Status::Disallowed, 0, 0), (0x1F800, Status::Valid, 0, 0),
+    (0x1F80C, Status::Disallowed, 0, 0), (0x1F810, Status::Valid, 0, 0), (0x1F848, Status::Disallowed, 0, 0),
+    (0x1F850, Status::Valid, 0, 0), (0x1F85A, Status::Disallowed, 0, 0), (0x1F860, Status::Valid, 0, 0),
+    (0x1F888, Status::Disallowed, 0, 0), (0x1F890, Status::Valid, 0, 0), (0x1F8AE, Status::Disallowed, 0, 0),
+    (0x1F8B0, Status::Valid, 0, 0), (0x1F8B2, Status::Disallowed, 0, 0), (0x1F900, Status::Valid, 0, 0),
+    (0x1F979, Status::Disallowed, 0, 0), (0x1F97A, Status::Valid, 0, 0), (0x1F9CC, Status::Disallowed, 0, 0),
+    (0x1F9CD, Status::Valid, 0, 0), (0x1FA54, Status::Disallowed, 0, 0), (0x1FA60, Status::Valid, 0, 0),
+    (0x1FA6E, Status::Disallowed, 0, 0), (0x1FA70, Status::Valid, 0, 0), (0x1FA75, Status::Disallowed, 0, 0),
+    (0x1FA78, Status::Valid, 0, 0), (0x1FA7B, Status::Disallowed, 0, 0), (0x1FA80, Status::Valid, 0, 0),
+    (0x1FA87, Status::Disallowed, 0, 0), (0x1FA90, Status::Valid, 0, 0), (0x1FAA9, Status::Disallowed, 0, 0),
+    (0x1FAB0, Status::Valid, 0, 0), (0x1FAB7, Status::Disallowed, 0, 0), (0x1FAC0, Status::Valid, 0, 0),
+    (0x1FAC3, Status::Disallowed, 0, 0), (0x1FAD0, Status::Valid, 0, 0), (0x1FAD7, Status::Disallowed, 0, 0),
+    (0x1FB00, Status::Valid, 0, 0), (0x1FB93, Status::Disallowed, 0, 0), (0x1FB94, Status::Valid, 0, 0),
+    (0x1FBCB, Status::Disallowed, 0, 0), (0x1FBF0, Status::Mapped, 2347, 1), (0x1FBF1, Status::Mapped, 43, 1),
+    (0x1FBF2, Status::Mapped, 33, 1), (0x1FBF3, Status::Mapped, 34, 1), (0x1FBF4, Status::Mapped, 2348, 1),
+    (0x1FBF5, Status::Mapped, 2349, 1), (0x1FBF6, Status::Mapped, 2350, 1), (0x1FBF7, Status::Mapped, 2351, 1),
+    (0x1FBF8, Status::Mapped, 2352, 1), (0x1FBF9, Status::Mapped, 2353, 1), (0x1FBFA, Status::Disallowed, 0, 0),
+    (0x20000, Status::Valid, 0, 0), (0x2A6DE, Status::Disallowed, 0, 0), (0x2A700, Status::Valid, 0, 0),
+    (0x2B735, Status::Disallowed, 0, 0), (0x2B740, Status::Valid, 0, 0), (0x2B81E, Status::Disallowed, 0, 0),
+    (0x2B820, Status::Valid, 0, 0), (0x2CEA2, Status::Disallowed, 0, 0), (0x2CEB0, Status::Valid, 0, 0),
+    (0x2EBE1, Status::Disallowed, 0, 0), (0x2F800, Status::Mapped, 11343, 3), (0x2F801, Status::Mapped, 11346, 3),
+    (0x2F802, Status::Mapped, 11349, 3), (0x2F803, Status::Mapped, 11352, 4), (0x2F804, Status::Mapped, 11356, 3),
+    (0x2F805, Status::Mapped, 7693, 3), (0x2F806, Status::Mapped, 11359, 3), (0x2F807, Status::Mapped, 11362, 3),
+    (0x2F808, Status::Mapped, 11365, 3), (0x2F809, Status::Mapped, 11368, 3), (0x2F80A, Status::Mapped, 7696, 3),
+    (0x2F80B, Status::Mapped, 11371, 3), (0x2F80C, Status::Mapped, 11374, 3), (0x2F80D, Status::Mapped, 11377, 4),
+    (0x2F80E, Status::Mapped, 7699, 3), (0x2F80F, Status::Mapped, 11381, 3), (0x2F810, Status::Mapped, 11384, 3),
+    (0x2F811, Status::Mapped, 11387, 3), (0x2F812, Status::Mapped, 11390, 4), (0x2F813, Status::Mapped, 11394, 3),
+    (0x2F814, Status::Mapped, 11397, 3), (0x2F815, Status::Mapped, 11193, 3), (0x2F816, Status::Mapped, 11400, 4),
+    (0x2F817, Status::Mapped, 11404, 3), (0x2F818, Status::Mapped, 11407, 3), (0x2F819, Status::Mapped, 11410, 3),
+    (0x2F81A, Status::Mapped, 11413, 3), (0x2F81B, Status::Mapped, 7865, 3), (0x2F81C, Status::Mapped, 11416, 4),
+    (0x2F81D, Status::Mapped, 3130, 3), (0x2F81E, Status::Mapped, 11420, 3), (0x2F81F, Status::Mapped, 11423, 3),
+    (0x2F820, Status::Mapped, 11426, 3), (0x2F821, Status::Mapped, 11429, 3), (0x2F822, Status::Mapped, 11247, 3),
+    (0x2F823, Status::Mapped, 11432, 3), (0x2F824, Status::Mapped, 11435, 3), (0x2F825, Status::Mapped, 7880, 3),
+    (0x2F826, Status::Mapped, 7702, 3), (0x2F827, Status::Mapped, 7705, 3), (0x2F828, Status::Mapped, 7883, 3),
+    (0x2F829, Status::Mapped, 11438, 3), (0x2F82A, Status::Mapped, 11441, 3), (0x2F82B, Status::Mapped, 7156, 3),
+    (0x2F82C, Status::Mapped, 11444, 3), (0x2F82D, Status::Mapped, 7708, 3), (0x2F82E, Status::Mapped, 11447, 3),
+    (0x2F82F, Status::Mapped, 11450, 3), (0x2F830, Status::Mapped, 11453, 3), (0x2F831, Status::Mapped, 11456, 3),
+    (0x2F834, Status::Mapped, 11459, 4), (0x2F835, Status::Mapped, 11463, 3), (0x2F836, Status::Mapped, 11466, 3),
+    (0x2F837, Status::Mapped, 11469, 3), (0x2F838, Status::Mapped, 11472, 4), (0x2F839, Status::Mapped, 11476, 3),
+    (0x2F83A, Status::Mapped, 11479, 3), (0x2F83B, Status::Mapped, 11482, 3), (0x2F83C, Status::Mapped, 11485, 3),
+    (0x2F83D, Status::Mapped, 11488, 3), (0x2F83E, Status::Mapped, 11491, 3), (0x2F83F, Status::Mapped, 11494, 3),
+    (0x2F840, Status::Mapped, 11497, 3), (0x2F841, Status::Mapped, 11500, 3), (0x2F842, Status::Mapped, 11503, 3),
+    (0x2F843, Status::Mapped, 11506, 3), (0x2F844, Status::Mapped, 11509, 3), (0x2F845, Status::Mapped, 11512, 3),
+    (0x2F847, Status::Mapped, 7889, 3), (0x2F848, Status::Mapped, 11515, 3), (0x2F849, Status::Mapped, 11518, 3),
+    (0x2F84A, Status::Mapped, 11521, 3), (0x2F84B, Status::Mapped, 11524, 3), (0x2F84C, Status::Mapped, 7714, 3),
+    (0x2F84D, Status::Mapped, 11527, 3), (0x2F84E, Status::Mapped, 11530, 3), (0x2F84F, Status::Mapped, 11533, 3),
+    (0x2F850, Status::Mapped, 7594, 3), (0x2F851, Status::Mapped, 11536, 3), (0x2F852, Status::Mapped, 11539, 3),
+    (0x2F853, Status::Mapped, 11542, 3), (0x2F854, Status::Mapped, 11545, 3), (0x2F855, Status::Mapped, 11548, 3),
+    (0x2F856, Status::Mapped, 11551, 3), (0x2F857, Status::Mapped, 11554, 3), (0x2F858, Status::Mapped, 11557, 3),
+    (0x2F859, Status::Mapped, 11560, 4), (0x2F85A, Status::Mapped, 11564, 3), (0x2F85B, Status::Mapped, 11567, 3),
+    (0x2F85C, Status::Mapped, 11570, 3), (0x2F85D, Status::Mapped, 11172, 3), (0x2F85E, Status::Mapped, 11573, 3),
+    (0x2F85F, Status::Mapped, 11576, 3), (0x2F860, Status::Mapped, 11579, 4), (0x2F861, Status::Mapped, 11583, 4),
+    (0x2F862, Status::Mapped, 11587, 3), (0x2F863, Status::Mapped, 11590, 3), (0x2F864, Status::Mapped, 11593, 3),
+    (0x2F865, Status::Mapped, 11596, 3), (0x2F866, Status::Mapped, 11599, 3), (0x2F867, Status::Mapped, 11602, 3),
+    (0x2F868, Status::Disallowed, 0, 0), (0x2F869, Status::Mapped, 11605, 3), (0x2F86A, Status::Mapped, 11608, 3),
+    (0x2F86C, Status::Mapped, 11611, 4), (0x2F86D, Status::Mapped, 11615, 3), (0x2F86E, Status::Mapped, 11618, 3),
+    (0x2F86F, Status::Mapped, 7144, 3), (0x2F870, Status::Mapped, 11621, 3), (0x2F871, Status::Mapped, 11624, 4),
+    (0x2F872, Status::Mapped, 11628, 3), (0x2F873, Status::Mapped, 11631, 3), (0x2F874, Status::Disallowed, 0, 0),
+    (0x2F875, Status::Mapped, 3208, 3), (0x2F876, Status::Mapped, 11634, 3), (0x2F877, Status::Mapped, 11637, 3),
+    (0x2F878, Status::Mapped, 3214, 3), (0x2F879, Status::Mapped, 11640, 3), (0x2F87A, Status::Mapped, 11643, 3),
+    (0x2F87B, Status::Mapped, 11646, 4), (0x2F87C, Status::Mapped, 11650, 3), (0x2F87D, Status::Mapped, 11653, 4),
+    (0x2F87E, Status::Mapped, 11657, 3), (0x2F87F, Status::Mapped, 11660, 3), (0x2F880, Status::Mapped, 11663, 3),
+    (0x2F881, Status::Mapped, 11666, 3), (0x2F882, Status::Mapped, 11669, 3), (0x2F883, Status::Mapped, 11672, 3),
+    (0x2F884, Status::Mapped, 11675, 3), (0x2F885, Status::Mapped, 11678, 3), (0x2F886, Status::Mapped, 11681, 3),
+    (0x2F887, Status::Mapped, 11684, 3), (0x2F888, Status::Mapped, 11687, 3), (0x2F889, Status::Mapped, 11690, 4),
+    (0x2F88A, Status::Mapped, 11694, 3), (0x2F88B, Status::Mapped, 11697, 3), (0x2F88C, Status::Mapped, 11700, 3),
+    (0x2F88D, Status::Mapped, 11703, 3), (0x2F88E, Status::Mapped, 6988, 3), (0x2F88F, Status::Mapped, 11706, 4),
+    (0x2F890, Status::Mapped, 3244, 3), (0x2F891, Status::Mapped, 11710, 4), (0x2F893, Status::Mapped, 11714, 3),
+    (0x2F894, Status::Mapped, 11717, 3), (0x2F896, Status::Mapped, 11720, 3), (0x2F897, Status::Mapped, 11723, 4),
+    (0x2F898, Status::Mapped, 11727, 4), (0x2F899, Status::Mapped, 11731, 3), (0x2F89A, Status::Mapped, 11734, 3),
+    (0x2F89B, Status::Mapped, 11737, 3), (0x2F89C, Status::Mapped, 11740, 3), (0x2F89D, Status::Mapped, 11743, 3),
+    (0x2F89E, Status::Mapped, 11746, 3), (0x2F89F, Status::Mapped, 11749, 3), (0x2F8A0, Status::Mapped, 11752, 3),
+    (0x2F8A1, Status::Mapped, 11755, 3), (0x2F8A2, Status::Mapped, 11758, 3), (0x2F8A3, Status::Mapped, 7729, 3),
+    (0x2F8A4, Status::Mapped, 11761, 4), (0x2F8A5, Status::Mapped, 11765, 3), (0x2F8A6, Status::Mapped, 11768, 3),
+    (0x2F8A7, Status::Mapped, 11771, 3), (0x2F8A8, Status::Mapped, 7925, 3), (0x2F8A9, Status::Mapped, 11771, 3),
+    (0x2F8AA, Status::Mapped, 11774, 3), (0x2F8AB, Status::Mapped, 7735, 3), (0x2F8AC, Status::Mapped, 11777, 3),
+    (0x2F8AD, Status::Mapped, 11780, 3), (0x2F8AE, Status::Mapped, 11783, 3), (0x2F8AF, Status::Mapped, 11786, 3),
+    (0x2F8B0, Status::Mapped, 7738, 3), (0x2F8B1, Status::Mapped, 6907, 3), (0x2F8B2, Status::Mapped, 11789, 3),
+    (0x2F8B3, Status::Mapped, 11792, 3), (0x2F8B4, Status::Mapped, 11795, 3), (0x2F8B5, Status::Mapped, 11798, 3),
+    (0x2F8B6, Status::Mapped, 11801, 3), (0x2F8B7, Status::Mapped, 11804, 3), (0x2F8B8, Status::Mapped, 11807, 4),
+    (0x2F8B9, Status::Mapped, 11811, 3), (0x2F8BA, Status::Mapped, 11814, 3), (0x2F8BB, Status::Mapped, 11817, 3),
+    (0x2F8BC, Status::Mapped, 11820, 3), (0x2F8BD, Status::Mapped, 11823, 3), (0x2F8BE, Status::Mapped, 11826, 4),
+    (0x2F8BF, Status::Mapped, 11830, 3), (0x2F8C0, Status::Mapped, 11833, 3), (0x2F8C1, Status::Mapped, 11836, 3),
+    (0x2F8C2, Status::Mapped, 11839, 3), (0x2F8C3, Status::Mapped, 11842, 3), (0x2F8C4, Status::Mapped, 11845, 3),
+    (0x2F8C5, Status::Mapped, 11848, 3), (0x2F8C6, Status::Mapped, 11851, 3), (0x2F8C7, Status::Mapped, 11854, 3),
+    (0x2F8C8, Status::Mapped, 7741, 3), (0x2F8C9, Status::Mapped, 11857, 3), (0x2F8CA, Status::Mapped, 11860, 4),
+    (0x2F8CB, Status::Mapped, 11864, 3), (0x2F8CC, Status::Mapped, 11867, 3), (0x2F8CD, Status::Mapped, 11870, 3),
+    (0x2F8CE, Status::Mapped, 11873, 3), (0x2F8CF, Status::Mapped, 7747, 3), (0x2F8D0, Status::Mapped, 11876, 3),
+    (0x2F8D1, Status::Mapped, 11879, 3), (0x2F8D2, Status::Mapped, 11882, 3), (0x2F8D3, Status::Mapped, 11885, 3),
+    (0x2F8D4, Status::Mapped, 11888, 3), (0x2F8D5, Status::Mapped, 11891, 3), (0x2F8D6, Status::Mapped, 11894, 3),
+    (0x2F8D7, Status::Mapped, 11897, 3), (0x2F8D8, Status::Mapped, 6991, 3), (0x2F8D9, Status::Mapped, 7949, 3),
+    (0x2F8DA, Status::Mapped, 11900, 3), (0x2F8DB, Status::Mapped, 11903, 3), (0x2F8DC, Status::Mapped, 11906, 3),
+    (0x2F8DD, Status::Mapped, 11909, 4), (0x2F8DE, Status::Mapped, 11913, 3), (0x2F8DF, Status::Mapped, 11916, 3),
+    (0x2F8E0, Status::Mapped, 11919, 3), (0x2F8E1, Status::Mapped, 11922, 3), (0x2F8E2, Status::Mapped, 7750, 3),
+    (0x2F8E3, Status::Mapped, 11925, 4), (0x2F8E4, Status::Mapped, 11929, 3), (0x2F8E5, Status::Mapped, 11932, 3),
+    (0x2F8E6, Status::Mapped, 11935, 3), (0x2F8E7, Status::Mapped, 8078, 3), (0x2F8E8, Status::Mapped, 11938, 3),
+    (0x2F8E9, Status::Mapped, 11941, 3), (0x2F8EA, Status::Mapped, 11944, 3), (0x2F8EB, Status::Mapped, 11947, 3),
+    (0x2F8EC, Status::Mapped, 11950, 4), (0x2F8ED, Status::Mapped, 11954, 3), (0x2F8EE, Status::Mapped, 11957, 3),
+    (0x2F8EF, Status::Mapped, 11960, 3), (0x2F8F0, Status::Mapped, 11963, 4), (0x2F8F1, Status::Mapped, 11967, 3),
+    (0x2F8F2, Status::Mapped, 11970, 3), (0x2F8F3, Status::Mapped, 11973, 3), (0x2F8F4, Status::Mapped, 11976, 3),
+    (0x2F8F5, Status::Mapped, 7195, 3), (0x2F8F6, Status::Mapped, 11979, 3), (0x2F8F7, Status::Mapped, 11982, 4),
+    (0x2F8F8, Status::Mapped, 11986, 4), (0x2F8F9, Status::Mapped, 11990, 4), (0x2F8FA, Status::Mapped, 11994, 3),
+    (0x2F8FB, Status::Mapped, 11997, 4), (0x2F8FC, Status::Mapped, 12001, 3), (0x2F8FD, Status::Mapped, 12004, 3),
+    (0x2F8FE, Status::Mapped, 12007, 3), (0x2F8FF, Status::Mapped, 12010, 3), (0x2F900, Status::Mapped, 12013, 3),
+    (0x2F901, Status::Mapped, 7753, 3), (0x2F902, Status::Mapped, 7444, 3), (0x2F903, Status::Mapped, 12016, 3),
+    (0x2F904, Status::Mapped, 12019, 3), (0x2F905, Status::Mapped, 12022, 3), (0x2F906, Status::Mapped, 12025, 4),
+    (0x2F907, Status::Mapped, 12029, 3), (0x2F908, Status::Mapped, 12032, 3), (0x2F909, Status::Mapped, 12035, 3),
+    (0x2F90A, Status::Mapped, 12038, 3), (0x2F90B, Status::Mapped, 7958, 3), (0x2F90C, Status::Mapped, 12041, 3),
+    (0x2F90D, Status::Mapped, 12044, 4), (0x2F90E, Status::Mapped, 12048, 3), (0x2F90F, Status::Mapped, 12051, 3),
+    (0x2F910, Status::Mapped, 12054, 4), (0x2F911, Status::Mapped, 12058, 4), (0x2F912, Status::Mapped, 12062, 3),
+    (0x2F913, Status::Mapped, 12065, 3), (0x2F914, Status::Mapped, 7961, 3), (0x2F915, Status::Mapped, 12068, 3),
+    (0x2F916, Status::Mapped, 12071, 3), (0x2F917, Status::Mapped, 12074, 3), (0x2F918, Status::Mapped, 12077, 3),
+    (0x2F919, Status::Mapped, 12080, 3), (0x2F91A, Status::Mapped, 12083, 3), (0x2F91B, Status::Mapped, 12086, 4),
+    (0x2F91C, Status::Mapped, 12090, 3), (0x2F91D, Status::Mapped, 12093, 4), (0x2F91E, Status::Mapped, 12097, 3),
+    (0x2F91F, Status::Disallowed, 0, 0), (0x2F920, Status::Mapped, 12100, 3), (0x2F921, Status::Mapped, 7967, 3),
+    (0x2F922, Status::Mapped, 12103, 3), (0x2F923, Status::Mapped, 12106, 4), (0x2F924, Status::Mapped, 12110, 3),
+    (0x2F925, Status::Mapped, 12113, 3), (0x2F926, Status::Mapped, 12116, 4), (0x2F927, Status::Mapped, 12120, 4),
+    (0x2F928, Status::Mapped, 12124, 3), (0x2F929, Status::Mapped, 12127, 3), (0x2F92A, Status::Mapped, 12130, 3),
+    (0x2F92B, Status::Mapped, 12133, 3), (0x2F92C, Status::Mapped, 12136, 3), (0x2F92E, Status::Mapped, 12139, 3),
+    (0x2F92F, Status::Mapped, 12142, 3), (0x2F930, Status::Mapped, 7973, 3), (0x2F931, Status::Mapped, 12145, 3),
+    (0x2F932, Status::Mapped, 12148, 3), (0x2F933, Status::Mapped, 12151, 3), (0x2F934, Status::Mapped, 12154, 3),
+    (0x2F935, Status::Mapped, 12157, 4), (0x2F936, Status::Mapped, 12161, 3), (0x2F937, Status::Mapped, 12164, 4),
+    (0x2F938, Status::Mapped, 7153, 3), (0x2F939, Status::Mapped, 12168, 4), (0x2F93A, Status::Mapped, 12172, 3),
+    (0x2F93B, Status::Mapped, 12175, 4), (0x2F93C, Status::Mapped, 12179, 4), (0x2F93D, Status::Mapped, 12183, 4),
+    (0x2F93E, Status::Mapped, 12187, 3), (0x2F93F, Status::Mapped, 12190, 3), (0x2F940, Status::Mapped, 7991, 3),
+    (0x2F941, Status::Mapped, 12193, 4), (0x2F942, Status::Mapped, 12197, 4), (0x2F943, Status::Mapped, 12201, 4),
+    (0x2F944, Status::Mapped, 12205, 4), (0x2F945, Status::Mapped, 12209, 3), (0x2F946, Status::Mapped, 12212, 3),
+    (0x2F948, Status::Mapped, 7994, 3), (0x2F949, Status::Mapped, 8084, 3), (0x2F94A, Status::Mapped, 12215, 3),
+    (0x2F94B, Status::Mapped, 12218, 3), (0x2F94C, Status::Mapped, 12221, 3), (0x2F94D, Status::Mapped, 12224, 4),
+    (0x2F94E, Status::Mapped, 12228, 3), (0x2F94F, Status::Mapped, 7042, 3), (0x2F950, Status::Mapped, 8000, 3),
+    (0x2F951, Status::Mapped, 12231, 3), (0x2F952, Status::Mapped, 12234, 4), (0x2F953, Status::Mapped, 7783, 3),
+    (0x2F954, Status::Mapped, 12238, 4), (0x2F955, Status::Mapped, 12242, 4), (0x2F956, Status::Mapped, 7654, 3),
+    (0x2F957, Status::Mapped, 12246, 3), (0x2F958, Status::Mapped, 12249, 3), (0x2F959, Status::Mapped, 7792, 3),
+    (0x2F95A, Status::Mapped, 12252, 3), (0x2F95B, Status::Mapped, 12255, 3), (0x2F95C, Status::Mapped, 12258, 4),
+    (0x2F95D, Status::Mapped, 12262, 4), (0x2F95F, Status::Disallowed, 0, 0), (0x2F960, Status::Mapped, 12266, 3),
+    (0x2F961, Status::Mapped, 12269, 4), (0x2F962, Status::Mapped, 12273, 3), (0x2F963, Status::Mapped, 12276, 3),
+    (0x2F964, Status::Mapped, 12279, 3), (0x2F965, Status::Mapped, 12282, 4), (0x2F966, Status::Mapped, 12286, 3),
+    (0x2F967, Status::Mapped, 12289, 3), (0x2F968, Status::Mapped, 12292, 3), (0x2F969, Status::Mapped, 12295, 3),
+    (0x2F96A, Status::Mapped, 12298, 3), (0x2F96B, Status::Mapped, 12301, 4), (0x2F96C, Status::Mapped, 12305, 3),
+    (0x2F96D, Status::Mapped, 12308, 3), (0x2F96E, Status::Mapped, 12311, 3), (0x2F96F, Status::Mapped, 12314, 3),
+    (0x2F970, Status::Mapped, 12317, 3), (0x2F971, Status::Mapped, 12320, 3), (0x2F972, Status::Mapped, 12323, 4),
+    (0x2F973, Status::Mapped, 12327, 4), (0x2F974, Status::Mapped, 12331, 3), (0x2F975, Status::Mapped, 12334, 4),
+    (0x2F976, Status::Mapped, 12338, 3), (0x2F977, Status::Mapped, 12341, 4), (0x2F978, Status::Mapped, 12345, 3),
+    (0x2F979, Status::Mapped, 12348, 3), (0x2F97A, Status::Mapped, 7810, 3), (0x2F97B, Status::Mapped, 12351, 4),
+    (0x2F97C, Status::Mapped, 12355, 4), (0x2F97D, Status::Mapped, 12359, 3), (0x2F97E, Status::Mapped, 12362, 4),
+    (0x2F97F, Status::Mapped, 12366, 3), (0x2F980, Status::Mapped, 12369, 4), (0x2F981, Status::Mapped, 12373, 3),
+    (0x2F982, Status::Mapped, 12376, 3), (0x2F983, Status::Mapped, 12379, 3), (0x2F984, Status::Mapped, 12382, 3),
+    (0x2F985, Status::Mapped, 12385, 3), (0x2F986, Status::Mapped, 12388, 3), (0x2F987, Status::Mapped, 12391, 4),
+    (0x2F988, Status::Mapped, 12395, 4), (0x2F989, Status::Mapped, 12399, 4), (0x2F98A, Status::Mapped, 12403, 4),
+    (0x2F98B, Status::Mapped, 11714, 3), (0x2F98C, Status::Mapped, 12407, 3), (0x2F98D, Status::Mapped, 12410, 3),
+    (0x2F98E, Status::Mapped, 12413, 3), (0x2F98F, Status::Mapped, 12416, 3), (0x2F990, Status::Mapped, 12419, 3),
+    (0x2F991, Status::Mapped, 12422, 3), (0x2F992, Status::Mapped, 12425, 3), (0x2F993, Status::Mapped, 12428, 3),
+    (0x2F994, Status::Mapped, 12431, 3), (0x2F995, Status::Mapped, 12434, 3), (0x2F996, Status::Mapped, 12437, 3),
+    (0x2F997, Status::Mapped, 12440, 4), (0x2F998, Status::Mapped, 7204, 3), (0x2F999, Status::Mapped, 12444, 3),
+    (0x2F99A, Status::Mapped, 12447, 3), (0x2F99B, Status::Mapped, 12450, 3), (0x2F99C, Status::Mapped, 12453, 3),
+    (0x2F99D, Status::Mapped, 12456, 3), (0x2F99E, Status::Mapped, 12459, 3), (0x2F99F, Status::Mapped, 7819, 3),
+    (0x2F9A0, Status::Mapped, 12462, 3), (0x2F9A1, Status::Mapped, 12465, 3), (0x2F9A2, Status::Mapped, 12468, 3),
+    (0x2F9A3, Status::Mapped, 12471, 3), (0x2F9A4, Status::Mapped, 12474, 4), (0x2F9A5, Status::Mapped, 12478, 4),
+    (0x2F9A6, Status::Mapped, 12482, 4), (0x2F9A7, Status::Mapped, 12486, 3), (0x2F9A8, Status::Mapped, 12489, 3),
+    (0x2F9A9, Status::Mapped, 12492, 3), (0x2F9AA, Status::Mapped, 12495, 3), (0x2F9AB, Status::Mapped, 12498, 4),
+    (0x2F9AC, Status::Mapped, 12502, 3), (0x2F9AD, Status::Mapped, 12505, 4), (0x2F9AE, Status::Mapped, 12509, 3),
+    (0x2F9AF, Status::Mapped, 12512, 3), (0x2F9B0, Status::Mapped, 12515, 4), (0x2F9B1, Status::Mapped, 12519, 4),
+    (0x2F9B2, Status::Mapped, 12523, 3), (0x2F9B3, Status::Mapped, 12526, 3), (0x2F9B4, Status::Mapped, 7027, 3),
+    (0x2F9B5, Status::Mapped, 12529, 3), (0x2F9B6, Status::Mapped, 12532, 3), (0x2F9B7, Status::Mapped, 12535, 3),
+    (0x2F9B8, Status::Mapped, 12538, 3), (0x2F9B9, Status::Mapped, 12541, 3), (0x2F9BA, Status::Mapped, 12544, 3),
+    (0x2F9BB, Status::Mapped, 8021, 3), (0x2F9BC, Status::Mapped, 12547, 3), (0x2F9BD, Status::Mapped, 12550, 3),
+    (0x2F9BE, Status::Mapped, 12553, 3), (0x2F9BF, Status::Disallowed, 0, 0), (0x2F9C0, Status::Mapped, 12556, 3),
+    (0x2F9C1, Status::Mapped, 12559, 3), (0x2F9C2, Status::Mapped, 12562, 3), (0x2F9C3, Status::Mapped, 12565, 3),
+    (0x2F9C4, Status::Mapped, 3514, 3), (0x2F9C5, Status::Mapped, 12568, 4), (0x2F9C6, Status::Mapped, 12572, 3),
+    (0x2F9C7, Status::Mapped, 12575, 3), (0x2F9C8, Status::Mapped, 12578, 3), (0x2F9C9, Status::Mapped, 12581, 3),
+    (0x2F9CA, Status::Mapped, 12584, 3), (0x2F9CB, Status::Mapped, 12587, 4), (0x2F9CC, Status::Mapped, 12591, 4),
+    (0x2F9CD, Status::Mapped, 12595, 3), (0x2F9CE, Status::Mapped, 12598, 3), (0x2F9CF, Status::Mapped, 12601, 3),
+    (0x2F9D0, Status::Mapped, 8036, 3), (0x2F9D1, Status::Mapped, 8039, 3), (0x2F9D2, Status::Mapped, 3535, 3),
+    (0x2F9D3, Status::Mapped, 12604, 4), (0x2F9D4, Status::Mapped, 12608, 3), (0x2F9D5, Status::Mapped, 12611, 3),
+    (0x2F9D6, Status::Mapped, 12614, 3), (0x2F9D7, Status::Mapped, 12617, 3), (0x2F9D8, Status::Mapped, 12620, 4),
+    (0x2F9D9, Status::Mapped, 12624, 4), (0x2F9DA, Status::Mapped, 12628, 3), (0x2F9DB, Status::Mapped, 12631, 3),
+    (0x2F9DC, Status::Mapped, 12634, 3), (0x2F9DD, Status::Mapped, 12637, 4), (0x2F9DE, Status::Mapped, 12641, 3),
+    (0x2F9DF, Status::Mapped, 8042, 3), (0x2F9E0, Status::Mapped, 12644, 4), (0x2F9E1, Status::Mapped, 12648, 4),
+    (0x2F9E2, Status::Mapped, 12652, 3), (0x2F9E3, Status::Mapped, 12655, 3), (0x2F9E4, Status::Mapped, 12658, 3),
+    (0x2F9E5, Status::Mapped, 12661, 4), (0x2F9E6, Status::Mapped, 12665, 3), (0x2F9E7, Status::Mapped, 12668, 3),
+    (0x2F9E8, Status::Mapped, 12671, 3), (0x2F9E9, Status::Mapped, 12674, 3), (0x2F9EA, Status::Mapped, 12677, 3),
+    (0x2F9EB, Status::Mapped, 12680, 3), (0x2F9EC, Status::Mapped, 12683, 3), (0x2F9ED, Status::Mapped, 12686, 4),
+    (0x2F9EE, Status::Mapped, 12690, 3), (0x2F9EF, Status::Mapped, 12693, 3), (0x2F9F0, Status::Mapped, 12696, 3),
+    (0x2F9F1, Status::Mapped, 12699, 4), (0x2F9F2, Status::Mapped, 12703, 3), (0x2F9F3, Status::Mapped, 12706, 3),
+    (0x2F9F4, Status::Mapped, 12709, 3), (0x2F9F5, Status::Mapped, 12712, 3), (0x2F9F6, Status::Mapped, 12715, 4),
+    (0x2F9F7, Status::Mapped, 12719, 4), (0x2F9F8, Status::Mapped, 12723, 3), (0x2F9F9, Status::Mapped, 12726, 3),
+    (0x2F9FA, Status::Mapped, 12729, 3), (0x2F9FB, Status::Mapped, 12732, 4), (0x2F9FC, Status::Mapped, 12736, 3),
+    (0x2F9FD, Status::Mapped, 12739, 4), (0x2F9FE, Status::Mapped, 8060, 3), (0x2FA00, Status::Mapped, 12743, 3),
+    (0x2FA01, Status::Mapped, 12746, 4), (0x2FA02, Status::Mapped, 12750, 3), (0x2FA03, Status::Mapped, 12753, 3),
+    (0x2FA04, Status::Mapped, 12756, 3), (0x2FA05, Status::Mapped, 12759, 3), (0x2FA06, Status::Mapped, 12762, 3),
+    (0x2FA07, Status::Mapped, 12765, 3), (0x2FA08, Status::Mapped, 12768, 3), (0x2FA09, Status::Mapped, 12771, 4),
+    (0x2FA0A, Status::Mapped, 8063, 3), (0x2FA0B, Status::Mapped, 12775, 3), (0x2FA0C, Status::Mapped, 12778, 3),
+    (0x2FA0D, Status::Mapped, 12781, 3), (0x2FA0E, Status::Mapped, 12784, 3), (0x2FA0F, Status::Mapped, 12787, 3),
+    (0x2FA10, Status::Mapped, 12790, 4), (0x2FA11, Status::Mapped, 12794, 3), (0x2FA12, Status::Mapped, 12797, 4),
+    (0x2FA13, Status::Mapped, 12801, 4), (0x2FA14, Status::Mapped, 12805, 4), (0x2FA15, Status::Mapped, 3679, 3),
+    (0x2FA16, Status::Mapped, 12809, 3), (0x2FA17, Status::Mapped, 3691, 3), (0x2FA18, Status::Mapped, 12812, 3),
+    (0x2FA19, Status::Mapped, 12815, 3), (0x2FA1A, Status::Mapped, 12818, 3), (0x2FA1B, Status::Mapped, 12821, 3),
+    (0x2FA1C, Status::Mapped, 3706, 3), (0x2FA1D, Status::Mapped, 12824, 4), (0x2FA1E, Status::Disallowed, 0, 0),
+    (0x30000, Status::Valid, 0, 0), (0x3134B, Status::Disallowed, 0, 0), (0xE0100, Status::Ignored, 0, 0),
+    (0xE01F0, Status::Disallowed, 0, 0),
+];
+
+pub const MAPPING_DATA: &str = "abcdefghijklmnopqrstuvwxyz  \u{308} \u{304}23 \u{301}μ \u{327}11⁄41⁄23⁄4àáâãäåæçèéêëìíîïðñòóôõöøùúûüýþssāăąćĉċčďđēĕėęěĝğġģĥħĩīĭįi\u{307}ijĵķĺļľl·łńņňʼnŋōŏőœŕŗřśŝşšţťŧũūŭůűųŵŷÿźżžɓƃƅɔƈɖɗƌǝəɛƒɠɣɩɨƙɯɲɵơƣƥʀƨʃƭʈưʊʋƴƶʒƹƽdžljnjǎǐǒǔǖǘǚǜǟǡǣǥǧǩǫǭǯdzǵƕƿǹǻǽǿȁȃȅȇȉȋȍȏȑȓȕȗșțȝȟƞȣȥȧȩȫȭȯȱȳⱥȼƚⱦɂƀʉʌɇɉɋɍɏɦɹɻʁ \u{306} \u{307} \u{30A} \u{328} \u{303} \u{30B}ʕ\u{300}\u{301}\u{313}\u{308}\u{301}ιͱͳʹͷ ι;ϳ \u{308}\u{301}ά·έήίόύώαβγδεζηθκλνξοπρστυφχψωϊϋϗϙϛϝϟϡϣϥϧϩϫϭϯϸϻͻͼͽѐёђѓєѕіїјљњћќѝўџабвгдежзийклмнопрстуфхцчшщъыьэюяѡѣѥѧѩѫѭѯѱѳѵѷѹѻѽѿҁҋҍҏґғҕҗҙқҝҟҡңҥҧҩҫҭүұҳҵҷҹһҽҿӂӄӆӈӊӌӎӑӓӕӗәӛӝӟӡӣӥӧөӫӭӯӱӳӵӷӹӻӽӿԁԃԅԇԉԋԍԏԑԓԕԗԙԛԝԟԡԣԥԧԩԫԭԯաբգդեզէըթժիլխծկհձղճմյնշոչպջռսվտրցւփքօֆեւاٴوٴۇٴيٴक\u{93C}ख\u{93C}ग\u{93C}ज\u{93C}ड\u{93C}ढ\u{93C}फ\u{93C}य\u{93C}ড\u{9BC}ঢ\u{9BC}য\u{9BC}ਲ\u{A3C}ਸ\u{A3C}ਖ\u{A3C}ਗ\u{A3C}ਜ\u{A3C}ਫ\u{A3C}ଡ\u{B3C}ଢ\u{B3C}\u{E4D}า\u{ECD}າຫນຫມ་ག\u{FB7}ཌ\u{FB7}ད\u{FB7}བ\u{FB7}ཛ\u{FB7}ཀ\u{FB5}\u{F71}\u{F72}\u{F71}\u{F74}\u{FB2}\u{F80}\u{FB2}\u{F71}\u{F80}\u{FB3}\u{F80}\u{FB3}\u{F71}\u{F80}\u{F71}\u{F80}\u{F92}\u{FB7}\u{F9C}\u{FB7}\u{FA1}\u{FB7}\u{FA6}\u{FB7}\u{FAB}\u{FB7}\u{F90}\u{FB5}ⴧⴭნᏰᏱᏲᏳᏴᏵꙋაბგდევზთიკლმოპჟრსტუფქღყშჩცძწჭხჯჰჱჲჳჴჵჶჷჸჹჺჽჾჿɐɑᴂɜᴖᴗᴝᴥɒɕɟɡɥɪᵻʝɭᶅʟɱɰɳɴɸʂƫᴜʐʑḁḃḅḇḉḋḍḏḑḓḕḗḙḛḝḟḡḣḥḧḩḫḭḯḱḳḵḷḹḻḽḿṁṃṅṇṉṋṍṏṑṓṕṗṙṛṝṟṡṣṥṧṩṫṭṯṱṳṵṷṹṻṽṿẁẃẅẇẉẋẍẏẑẓẕaʾạảấầẩẫậắằẳẵặẹẻẽếềểễệỉịọỏốồổỗộớờởỡợụủứừửữựỳỵỷỹỻỽỿἀἁἂἃἄἅἆἇἐἑἒἓἔἕἠἡἢἣἤἥἦἧἰἱἲἳἴἵἶἷὀὁὂὃὄὅὑὓὕὗὠὡὢὣὤὥὦὧἀιἁιἂιἃιἄιἅιἆιἇιἠιἡιἢιἣιἤιἥιἦιἧιὠιὡιὢιὣιὤιὥιὦιὧιὰιαιάιᾶιᾰᾱὰ \u{313} \u{342} \u{308}\u{342}ὴιηιήιῆιὲὴ \u{313}\u{300} \u{313}\u{301} \u{313}\u{342}ΐῐῑὶ \u{314}\u{300} \u{314}\u{301} \u{314}\u{342}ΰῠῡὺῥ \u{308}\u{300}`ὼιωιώιῶιὸὼ \u{314}‐ \u{333}′′′′′‵‵‵‵‵!! \u{305}???!!?′′′′0456789+−=()rsa/ca/s°cc/oc/u°fnosmteltmאבגדfax∑1⁄71⁄91⁄101⁄32⁄31⁄52⁄53⁄54⁄51⁄65⁄61⁄83⁄85⁄87⁄81⁄iiiiiivviviiviiiixxixii0⁄3∫∫∫∫∫∮∮∮∮∮〈〉1011121314151617181920(1)(2)(3)(4)(5)(6)(7)(8)(9)(10)(11)(12)(13)(14)(15)(16)(17)(18)(19)(20)(a)(b)(c)(d)(e)(f)(g)(h)(i)(j)(k)(l)(m)(n)(o)(p)(q)(r)(s)(t)(u)(v)(w)(x)(y)(z)∫∫∫∫::======⫝\u{338}ⰰⰱⰲⰳⰴⰵⰶⰷⰸⰹⰺⰻⰼⰽⰾⰿⱀⱁⱂⱃⱄⱅⱆⱇⱈⱉⱊⱋⱌⱍⱎⱏⱐⱑⱒⱓⱔⱕⱖⱗⱘⱙⱚⱛⱜⱝⱞⱡɫᵽɽⱨⱪⱬⱳⱶȿɀⲁⲃⲅⲇⲉⲋⲍⲏⲑⲓⲕⲗⲙⲛⲝⲟⲡⲣⲥⲧⲩⲫⲭⲯⲱⲳⲵⲷⲹⲻⲽⲿⳁⳃⳅⳇⳉⳋⳍⳏⳑⳓⳕⳗⳙⳛⳝⳟⳡⳣⳬⳮⳳⵡ母龟一丨丶丿乙亅二亠人儿入八冂冖冫几凵刀力勹匕匚匸十卜卩厂厶又口囗土士夂夊夕大女子宀寸小尢尸屮山巛工己巾干幺广廴廾弋弓彐彡彳心戈戶手支攴文斗斤方无日曰月木欠止歹殳毋比毛氏气水火爪父爻爿片牙牛犬玄玉瓜瓦甘生用田疋疒癶白皮皿目矛矢石示禸禾穴立竹米糸缶网羊羽老而耒耳聿肉臣自至臼舌舛舟艮色艸虍虫血行衣襾見角言谷豆豕豸貝赤走足身車辛辰辵邑酉釆里金長門阜隶隹雨靑非面革韋韭音頁風飛食首香馬骨高髟鬥鬯鬲鬼魚鳥鹵鹿麥麻黃黍黑黹黽鼎鼓鼠鼻齊齒龍龜龠.〒卄卅 \u{3099} \u{309A}よりコトᄀᄁᆪᄂᆬᆭᄃᄄᄅᆰᆱᆲᆳᆴᆵᄚᄆᄇᄈᄡᄉᄊᄋᄌᄍᄎᄏᄐᄑ하ᅢᅣᅤᅥᅦᅧᅨᅩᅪᅫᅬᅭᅮᅯᅰᅱᅲᅳᅴᅵᄔᄕᇇᇈᇌᇎᇓᇗᇙᄜᇝᇟᄝᄞᄠᄢᄣᄧᄩᄫᄬᄭᄮᄯᄲᄶᅀᅇᅌᇱᇲᅗᅘᅙᆄᆅᆈᆑᆒᆔᆞᆡ三四上中下甲丙丁天地(ᄀ)(ᄂ)(ᄃ)(ᄅ)(ᄆ)(ᄇ)(ᄉ)(ᄋ)(ᄌ)(ᄎ)(ᄏ)(ᄐ)(ᄑ)(ᄒ)(가)(나)(다)(라)(마)(바)(사)(아)(자)(차)(카)(타)(파)(하)(주)(오전)(오후)(一)(二)(三)(四)(五)(六)(七)(八)(九)(十)(月)(火)(水)(木)(金)(土)(日)(株)(有)(社)(名)(特)(財)(祝)(労)(代)(呼)(学)(監)(企)(資)(協)(祭)(休)(自)(至)問幼箏pte212223242526272829303132333435가나다라마바사아자차카타파하참고주의우五六七九株有社名特財祝労秘男適優印注項休写正左右医宗学監企資協夜3637383940414243444546474849501月2月3月4月5月6月7月8月9月10月11月12月hgergevltdアイウエオカキクケコサシスセソタチツテトナニヌネノハヒフヘホマミムメモヤユヨラリルレロワヰヱヲ令和アパートアルファアンペアアールイニングインチウォンエスクードエーカーオンスオームカイリカラットカロリーガロンガンマギガギニーキュリーギルダーキロキログラムキロメートルキロワットグラムグラムトンクルゼイロクローネケースコルナコーポサイクルサンチームシリングセンチセントダースデシドルトンナノノットハイツパーセントパーツバーレルピアストルピクルピコビルファラッドフィートブッシェルフランヘクタールペソペニヒヘルツペンスページベータポイントボルトホンポンドホールホーンマイクロマイルマッハマルクマンションミクロンミリミリバールメガメガトンメートルヤードヤールユアンリットルリラルピールーブルレムレントゲンワット0点1点2点3点4点5点6点7点8点9点10点11点12点13点14点15点16点17点18点19点20点21点22点23点24点hpadaaubarovpcdmdm2dm3iu平成昭和大正明治株式会社panaμamakakbmbgbcalkcalpfnfμfμgmgkghzkhzmhzghzthzμlmldlklfmnmμmmmcmkmmm2cm2m2km2mm3cm3m3km3m∕sm∕s2kpampagparadrad∕srad∕s2psnsμsmspvnvμvmvkvpwnwμwmwkwkωmωbqcccdc∕kgdbgyhahpinkkktlmlnloglxmilmolphppmprsrsvwbv∕ma∕m1日2日3日4日5日6日7日8日9日10日11日12日13日14日15日16日17日18日19日20日21日22日23日24日25日26日27日28日29日30日31日galꙁꙃꙅꙇꙉꙍꙏꙑꙓꙕꙗꙙꙛꙝꙟꙡꙣꙥꙧꙩꙫꙭꚁꚃꚅꚇꚉꚋꚍꚏꚑꚓꚕꚗꚙꚛꜣꜥꜧꜩꜫꜭꜯꜳꜵꜷꜹꜻꜽꜿꝁꝃꝅꝇꝉꝋꝍꝏꝑꝓꝕꝗꝙꝛꝝꝟꝡꝣꝥꝧꝩꝫꝭꝯꝺꝼᵹꝿꞁꞃꞅꞇꞌꞑꞓꞗꞙꞛꞝꞟꞡꞣꞥꞧꞩɬʞʇꭓꞵꞷꞹꞻꞽꞿꟃꞔᶎꟈꟊꟶꬷꭒʍᎠᎡᎢᎣᎤᎥᎦᎧᎨᎩᎪᎫᎬᎭᎮᎯᎰᎱᎲᎳᎴᎵᎶᎷᎸᎹᎺᎻᎼᎽᎾᎿᏀᏁᏂᏃᏄᏅᏆᏇᏈᏉᏊᏋᏌᏍᏎᏏᏐᏑᏒᏓᏔᏕᏖᏗᏘᏙᏚᏛᏜᏝᏞᏟᏠᏡᏢᏣᏤᏥᏦᏧᏨᏩᏪᏫᏬᏭᏮᏯ豈更賈滑串句契喇奈懶癩羅蘿螺裸邏樂洛烙珞落酪駱亂卵欄爛蘭鸞嵐濫藍襤拉臘蠟廊朗浪狼郎來冷勞擄櫓爐盧蘆虜路露魯鷺碌祿綠菉錄論壟弄籠聾牢磊賂雷壘屢樓淚漏累縷陋勒肋凜凌稜綾菱陵讀拏諾丹寧怒率異北磻便復不泌數索參塞省葉說殺沈拾若掠略亮兩凉梁糧良諒量勵呂廬旅濾礪閭驪麗黎曆歷轢年憐戀撚漣煉璉秊練聯輦蓮連鍊列劣咽烈裂廉念捻殮簾獵令囹嶺怜玲瑩羚聆鈴零靈領例禮醴隸惡了僚寮尿料燎療蓼遼暈阮劉杻柳流溜琉留硫紐類戮陸倫崙淪輪律慄栗隆利吏履易李梨泥理痢罹裏裡離匿溺吝燐璘藺隣鱗麟林淋臨笠粒狀炙識什茶刺切度拓糖宅洞暴輻降廓兀嗀塚晴凞猪益礼神祥福靖精蘒諸逸都飯飼館鶴郞隷侮僧免勉勤卑喝嘆器塀墨層悔慨憎懲敏既暑梅海渚漢煮爫琢碑祉祈祐祖禍禎穀突節縉繁署者臭艹著褐視謁謹賓贈辶難響頻恵𤋮舘並况全侀充冀勇勺啕喙嗢墳奄奔婢嬨廒廙彩徭惘慎愈慠戴揄搜摒敖望杖滛滋瀞瞧爵犯瑱甆画瘝瘟盛直睊着磌窱类絛缾荒華蝹襁覆調請諭變輸遲醙鉶陼韛頋鬒𢡊𢡄𣏕㮝䀘䀹𥉉𥳐𧻓齃龎fffiflffifflstմնմեմիվնմխי\u{5B4}ײ\u{5B7}עהכלםרתש\u{5C1}ש\u{5C2}ש\u{5BC}\u{5C1}ש\u{5BC}\u{5C2}א\u{5B7}א\u{5B8}א\u{5BC}ב\u{5BC}ג\u{5BC}ד\u{5BC}ה\u{5BC}ו\u{5BC}ז\u{5BC}ט\u{5BC}י\u{5BC}ך\u{5BC}כ\u{5BC}ל\u{5BC}מ\u{5BC}נ\u{5BC}ס\u{5BC}ף\u{5BC}פ\u{5BC}צ\u{5BC}ק\u{5BC}ר\u{5BC}ש\u{5BC}ת\u{5BC}ו\u{5B9}ב\u{5BF}כ\u{5BF}פ\u{5BF}אלٱٻپڀٺٿٹڤڦڄڃچڇڍڌڎڈژڑکگڳڱںڻۀہھےۓڭۇۆۈۋۅۉېىئائەئوئۇئۆئۈئېئىیئجئحئمئيبجبحبخبمبىبيتجتحتختمتىتيثجثمثىثيجحجمحجحمخجخحخمسجسحسخسمصحصمضجضحضخضمطحطمظمعجعمغجغمفجفحفخفمفىفيقحقمقىقيكاكجكحكخكلكمكىكيلجلحلخلملىليمجمحمخمممىمينجنحنخنمنىنيهجهمهىهييجيحيخيميىييذ\u{670}ر\u{670}ى\u{670} \u{64C}\u{651} \u{64D}\u{651} \u{64E}\u{651} \u{64F}\u{651} \u{650}\u{651} \u{651}\u{670}ئرئزئنبربزبنترتزتنثرثزثنمانرنزننيريزينئخئهبهتهصخلهنهه\u{670}يهثهسهشمشهـ\u{64E}\u{651}ـ\u{64F}\u{651}ـ\u{650}\u{651}طىطيعىعيغىغيسىسيشىشيحىحيجىجيخىخيصىصيضىضيشجشحشخشرسرصرضرا\u{64B}تجمتحجتحمتخمتمجتمحتمخجمححميحمىسحجسجحسجىسمحسمجسممصححصممشحمشجيشمخشممضحىضخمطمحطممطميعجمعممعمىغممغميغمىفخمقمحقمملحملحيلحىلججلخملمحمحجمحممحيمجحمجممخجمخممجخهمجهممنحمنحىنجمنجىنمينمىيممبخيتجيتجىتخيتخىتميتمىجميجحىجمىسخىصحيشحيضحيلجيلمييحييجييميمميقمينحيعميكمينجحمخيلجمكممجحيحجيمجيفميبحيسخينجيصلےقلےاللهاكبرمحمدصلعمرسولعليهوسلمصلىصلى الله عليه وسلمجل جلالهریال,、:!?〖〗—–_{}〔〕【】《》「」『』[]#&*-<>\u{5C}$%@ \u{64B}ـ\u{64B} \u{64C} \u{64D} \u{64E}ـ\u{64E} \u{64F}ـ\u{64F} \u{650}ـ\u{650} \u{651}ـ\u{651} \u{652}ـ\u{652}ءآأؤإئابةتثجحخدذرزسشصضطظعغفقكلمنهويلآلألإلا\u{22}'/^|~⦅⦆・ァィゥェォャュョッーン\u{3099}\u{309A}¢£¬¦¥₩│←↑→↓■○𐐨𐐩𐐪𐐫𐐬𐐭𐐮𐐯𐐰𐐱𐐲𐐳𐐴𐐵𐐶𐐷𐐸𐐹𐐺𐐻𐐼𐐽𐐾𐐿𐑀𐑁𐑂𐑃𐑄𐑅𐑆𐑇𐑈𐑉𐑊𐑋𐑌𐑍𐑎𐑏𐓘𐓙𐓚𐓛𐓜𐓝𐓞𐓟𐓠𐓡𐓢𐓣𐓤𐓥𐓦𐓧𐓨𐓩𐓪𐓫𐓬𐓭𐓮𐓯𐓰𐓱𐓲𐓳𐓴𐓵𐓶𐓷𐓸𐓹𐓺𐓻𐳀𐳁𐳂𐳃𐳄𐳅𐳆𐳇𐳈𐳉𐳊𐳋𐳌𐳍𐳎𐳏𐳐𐳑𐳒𐳓𐳔𐳕𐳖𐳗𐳘𐳙𐳚𐳛𐳜𐳝𐳞𐳟𐳠𐳡𐳢𐳣𐳤𐳥𐳦𐳧𐳨𐳩𐳪𐳫𐳬𐳭𐳮𐳯𐳰𐳱𐳲𑣀𑣁𑣂𑣃𑣄𑣅𑣆𑣇𑣈𑣉𑣊𑣋𑣌𑣍𑣎𑣏𑣐𑣑𑣒𑣓𑣔𑣕𑣖𑣗𑣘𑣙𑣚𑣛𑣜𑣝𑣞𑣟𖹠𖹡𖹢𖹣𖹤𖹥𖹦𖹧𖹨𖹩𖹪𖹫𖹬𖹭𖹮𖹯𖹰𖹱𖹲𖹳𖹴𖹵𖹶𖹷𖹸𖹹𖹺𖹻𖹼𖹽𖹾𖹿𝅗\u{1D165}𝅘\u{1D165}𝅘\u{1D165}\u{1D16E}𝅘\u{1D165}\u{1D16F}𝅘\u{1D165}\u{1D170}𝅘\u{1D165}\u{1D171}𝅘\u{1D165}\u{1D172}𝆹\u{1D165}𝆺\u{1D165}𝆹\u{1D165}\u{1D16E}𝆺\u{1D165}\u{1D16E}𝆹\u{1D165}\u{1D16F}𝆺\u{1D165}\u{1D16F}ıȷ∇∂𞤢𞤣𞤤𞤥𞤦𞤧𞤨𞤩𞤪𞤫𞤬𞤭𞤮𞤯𞤰𞤱𞤲𞤳𞤴𞤵𞤶𞤷𞤸𞤹𞤺𞤻𞤼𞤽𞤾𞤿𞥀𞥁𞥂𞥃ٮڡٯ0,1,2,3,4,5,6,7,8,9,〔s〕wzhvsdppvwcmcmdmrdjほかココ字双デ多解交映無前後再新初終販声吹演投捕遊指打禁空合満申割営配〔本〕〔三〕〔二〕〔安〕〔点〕〔打〕〔盗〕〔勝〕〔敗〕得可丽丸乁𠄢你侻倂偺備像㒞𠘺兔兤具𠔜㒹內𠕋冗冤仌冬𩇟刃㓟刻剆剷㔕包匆卉博即卽卿𠨬灰及叟𠭣叫叱吆咞吸呈周咢哶唐啓啣善喫喳嗂圖圗噑噴壮城埴堍型堲報墬𡓤売壷夆夢奢𡚨𡛪姬娛娧姘婦㛮嬈嬾𡧈寃寘寳𡬘寿将㞁屠峀岍𡷤嵃𡷦嵮嵫嵼巡巢㠯巽帨帽幩㡢𢆃㡼庰庳庶𪎒𢌱舁弢㣇𣊸𦇚形彫㣣徚忍志忹悁㤺㤜𢛔惇慈慌慺憲憤憯懞成戛扝抱拔捐𢬌挽拼捨掃揤𢯱搢揅掩㨮摩摾撝摷㩬敬𣀊旣書晉㬙㬈㫤冒冕最暜肭䏙朡杞杓𣏃㭉柺枅桒𣑭梎栟椔楂榣槪檨𣚣櫛㰘次𣢧歔㱎歲殟殻𣪍𡴋𣫺汎𣲼沿泍汧洖派浩浸涅𣴞洴港湮㴳滇𣻑淹潮𣽞𣾎濆瀹瀛㶖灊災灷炭𠔥煅𤉣熜爨牐𤘈犀犕𤜵𤠔獺王㺬玥㺸瑇瑜璅瓊㼛甤𤰶甾𤲒𢆟瘐𤾡𤾸𥁄㿼䀈𥃳𥃲𥄙𥄳眞真瞋䁆䂖𥐝硎䃣𥘦𥚚𥛅秫䄯穊穏𥥼𥪧䈂𥮫篆築䈧𥲀糒䊠糨糣紀𥾆絣䌁緇縂繅䌴𦈨𦉇䍙𦋙罺𦌾羕翺𦓚𦔣聠𦖨聰𣍟䏕育脃䐋脾媵𦞧𦞵𣎓𣎜舄辞䑫芑芋芝劳花芳芽苦𦬼茝荣莭茣莽菧荓菊菌菜𦰶𦵫𦳕䔫蓱蓳蔖𧏊蕤𦼬䕝䕡𦾱𧃒䕫虐虧虩蚩蚈蜎蛢蜨蝫螆蟡蠁䗹衠𧙧裗裞䘵裺㒻𧢮𧥦䚾䛇誠𧲨貫賁贛起𧼯𠠄跋趼跰𠣞軔𨗒𨗭邔郱鄑𨜮鄛鈸鋗鋘鉼鏹鐕𨯺開䦕閷𨵷䧦雃嶲霣𩅅𩈚䩮䩶韠𩐊䪲𩒖頩𩖶飢䬳餩馧駂駾䯎𩬰鱀鳽䳎䳭鵧𪃎䳸𪄅𪈎𪊑䵖黾鼅鼏鼖𪘀";
+
+pub const COMBINING_CLASS: &[(u32, u32, u8)] = &[
+    (0x300, 0x314, 230), (0x315, 0x315, 232), (0x316, 0x319, 220), (0x31A, 0x31A, 232),
+    (0x31B, 0x31B, 216), (0x31C, 0x320, 220), (0x321, 0x322, 202), (0x323, 0x326, 220),
+    (0x327, 0x328, 202), (0x329, 0x333, 220), (0x334, 0x338, 1), (0x339, 0x33C, 220),
+    (0x33D, 0x344, 230), (0x345, 0x345, 240), (0x346, 0x346, 230), (0x347, 0x349, 220),
+    (0x34A, 0x34C, 230), (0x34D, 0x34E, 220), (0x350, 0x352, 230), (0x353, 0x356, 220),
+    (0x357, 0x357, 230), (0x358, 0x358, 232), (0x359, 0x35A, 220), (0x35B, 0x35B, 230),
+    (0x35C, 0x35C, 233), (0x35D, 0x35E, 234), (0x35F, 0x35F, 233), (0x360, 0x361, 234),
+    (0x362, 0x362, 233), (0x363, 0x36F, 230), (0x483, 0x487, 230), (0x591, 0x591, 220),
+    (0x592, 0x595, 230), (0x596, 0x596, 220), (0x597, 0x599, 230), (0x59A, 0x59A, 222),
+    (0x59B, 0x59B, 220), (0x59C, 0x5A1, 230), (0x5A2, 0x5A7, 220), (0x5A8, 0x5A9, 230),
+    (0x5AA, 0x5AA, 220), (0x5AB, 0x5AC, 230), (0x5AD, 0x5AD, 222), (0x5AE, 0x5AE, 228),
+    (0x5AF, 0x5AF, 230), (0x5B0, 0x5B0, 10), (0x5B1, 0x5B1, 11), (0x5B2, 0x5B2, 12),
+    (0x5B3, 0x5B3, 13), (0x5B4, 0x5B4, 14), (0x5B5, 0x5B5, 15), (0x5B6, 0x5B6, 16),
+    (0x5B7, 0x5B7, 17), (0x5B8, 0x5B8, 18), (0x5B9, 0x5BA, 19), (0x5BB, 0x5BB, 20),
+    (0x5BC, 0x5BC, 21), (0x5BD, 0x5BD, 22), (0x5BF, 0x5BF, 23), (0x5C1, 0x5C1, 24),
+    (0x5C2, 0x5C2, 25), (0x5C4, 0x5C4, 230), (0x5C5, 0x5C5, 220), (0x5C7, 0x5C7, 18),
+    (0x610, 0x617, 230), (0x618, 0x618, 30), (0x619, 0x619, 31), (0x61A, 0x61A, 32),
+    (0x64B, 0x64B, 27), (0x64C, 0x64C, 28), (0x64D, 0x64D, 29), (0x64E, 0x64E, 30),
+    (0x64F, 0x64F, 31), (0x650, 0x650, 32), (0x651, 0x651, 33), (0x652, 0x652, 34),
+    (0x653, 0x654, 230), (0x655, 0x656, 220), (0x657, 0x65B, 230), (0x65C, 0x65C, 220),
+    (0x65D, 0x65E, 230), (0x65F, 0x65F, 220), (0x670, 0x670, 35), (0x6D6, 0x6DC, 230),
+    (0x6DF, 0x6E2, 230), (0x6E3, 0x6E3, 220), (0x6E4, 0x6E4, 230), (0x6E7, 0x6E8, 230),
+    (0x6EA, 0x6EA, 220), (0x6EB, 0x6EC, 230), (0x6ED, 0x6ED, 220), (0x711, 0x711, 36),
+    (0x730, 0x730, 230), (0x731, 0x731, 220), (0x732, 0x733, 230), (0x734, 0x734, 220),
+    (0x735, 0x736, 230), (0x737, 0x739, 220), (0x73A, 0x73A, 230), (0x73B, 0x73C, 220),
+    (0x73D, 0x73D, 230), (0x73E, 0x73E, 220), (0x73F, 0x741, 230), (0x742, 0x742, 220),
+    (0x743, 0x743, 230), (0x744, 0x744, 220), (0x745, 0x745, 230), (0x746, 0x746, 220),
+    (0x747, 0x747, 230), (0x748, 0x748, 220), (0x749, 0x74A, 230), (0x7EB, 0x7F1, 230),
+    (0x7F2, 0x7F2, 220), (0x7F3, 0x7F3, 230), (0x7FD, 0x7FD, 220), (0x816, 0x819, 230),
+    (0x81B, 0x823, 230), (0x825, 0x827, 230), (0x829, 0x82D, 230), (0x859, 0x85B, 220),
+    (0x8D3, 0x8D3, 220), (0x8D4, 0x8E1, 230), (0x8E3, 0x8E3, 220), (0x8E4, 0x8E5, 230),
+    (0x8E6, 0x8E6, 220), (0x8E7, 0x8E8, 230), (0x8E9, 0x8E9, 220), (0x8EA, 0x8EC, 230),
+    (0x8ED, 0x8EF, 220), (0x8F0, 0x8F0, 27), (0x8F1, 0x8F1, 28), (0x8F2, 0x8F2, 29),
+    (0x8F3, 0x8F5, 230), (0x8F6, 0x8F6, 220), (0x8F7, 0x8F8, 230), (0x8F9, 0x8FA, 220),
+    (0x8FB, 0x8FF, 230), (0x93C, 0x93C, 7), (0x94D, 0x94D, 9), (0x951, 0x951, 230),
+    (0x952, 0x952, 220), (0x953, 0x954, 230), (0x9BC, 0x9BC, 7), (0x9CD, 0x9CD, 9),
+    (0x9FE, 0x9FE, 230), (0xA3C, 0xA3C, 7), (0xA4D, 0xA4D, 9), (0xABC, 0xABC, 7),
+    (0xACD, 0xACD, 9), (0xB3C, 0xB3C, 7), (0xB4D, 0xB4D, 9), (0xBCD, 0xBCD, 9),
+    (0xC4D, 0xC4D, 9), (0xC55, 0xC55, 84), (0xC56, 0xC56, 91), (0xCBC, 0xCBC, 7),
+    (0xCCD, 0xCCD, 9), (0xD3B, 0xD3C, 9), (0xD4D, 0xD4D, 9), (0xDCA, 0xDCA, 9),
+    (0xE38, 0xE39, 103), (0xE3A, 0xE3A, 9), (0xE48, 0xE4B, 107), (0xEB8, 0xEB9, 118),
+    (0xEBA, 0xEBA, 9), (0xEC8, 0xECB, 122), (0xF18, 0xF19, 220), (0xF35, 0xF35, 220),
+    (0xF37, 0xF37, 220), (0xF39, 0xF39, 216), (0xF71, 0xF71, 129), (0xF72, 0xF72, 130),
+    (0xF74, 0xF74, 132), (0xF7A, 0xF7D, 130), (0xF80, 0xF80, 130), (0xF82, 0xF83, 230),
+    (0xF84, 0xF84, 9), (0xF86, 0xF87, 230), (0xFC6, 0xFC6, 220), (0x1037, 0x1037, 7),
+    (0x1039, 0x103A, 9), (0x108D, 0x108D, 220), (0x135D, 0x135F, 230), (0x1714, 0x1714, 9),
+    (0x1734, 0x1734, 9), (0x17D2, 0x17D2, 9), (0x17DD, 0x17DD, 230), (0x18A9, 0x18A9, 228),
+    (0x1939, 0x1939, 222), (0x193A, 0x193A, 230), (0x193B, 0x193B, 220), (0x1A17, 0x1A17, 230),
+    (0x1A18, 0x1A18, 220), (0x1A60, 0x1A60, 9), (0x1A75, 0x1A7C, 230), (0x1A7F, 0x1A7F, 220),
+    (0x1AB0, 0x1AB4, 230), (0x1AB5, 0x1ABA, 220), (0x1ABB, 0x1ABC, 230), (0x1ABD, 0x1ABD, 220),
+    (0x1ABF, 0x1AC0, 220), (0x1B34, 0x1B34, 7), (0x1B44, 0x1B44, 9), (0x1B6B, 0x1B6B, 230),
+    (0x1B6C, 0x1B6C, 220), (0x1B6D, 0x1B73, 230), (0x1BAA, 0x1BAB, 9), (0x1BE6, 0x1BE6, 7),
+    (0x1BF2, 0x1BF3, 9), (0x1C37, 0x1C37, 7), (0x1CD0, 0x1CD2, 230), (0x1CD4, 0x1CD4, 1),
+    (0x1CD5, 0x1CD9, 220), (0x1CDA, 0x1CDB, 230), (0x1CDC, 0x1CDF, 220), (0x1CE0, 0x1CE0, 230),
+    (0x1CE2, 0x1CE8, 1), (0x1CED, 0x1CED, 220), (0x1CF4, 0x1CF4, 230), (0x1CF8, 0x1CF9, 230),
+    (0x1DC0, 0x1DC1, 230), (0x1DC2, 0x1DC2, 220), (0x1DC3, 0x1DC9, 230), (0x1DCA, 0x1DCA, 220),
+    (0x1DCB, 0x1DCC, 230), (0x1DCD, 0x1DCD, 234), (0x1DCE, 0x1DCE, 214), (0x1DCF, 0x1DCF, 220),
+    (0x1DD0, 0x1DD0, 202), (0x1DD1, 0x1DF5, 230), (0x1DF6, 0x1DF6, 232), (0x1DF7, 0x1DF8, 228),
+    (0x1DF9, 0x1DF9, 220), (0x1DFB, 0x1DFB, 230), (0x1DFC, 0x1DFC, 233), (0x1DFD, 0x1DFD, 220),
+    (0x1DFE, 0x1DFE, 230), (0x1DFF, 0x1DFF, 220), (0x20D0, 0x20D1, 230), (0x20D2, 0x20D3, 1),
+    (0x20D4, 0x20D7, 230), (0x20D8, 0x20DA, 1), (0x20DB, 0x20DC, 230), (0x20E1, 0x20E1, 230),
+    (0x20E5, 0x20E6, 1), (0x20E7, 0x20E7, 230), (0x20E8, 0x20E8, 220), (0x20E9, 0x20E9, 230),
+    (0x20EA, 0x20EB, 1), (0x20EC, 0x20EF, 220), (0x20F0, 0x20F0, 230), (0x2CEF, 0x2CF1, 230),
+    (0x2D7F, 0x2D7F, 9), (0x2DE0, 0x2DFF, 230), (0x302A, 0x302A, 218), (0x302B, 0x302B, 228),
+    (0x302C, 0x302C, 232), (0x302D, 0x302D, 222), (0x302E, 0x302F, 224), (0x3099, 0x309A, 8),
+    (0xA66F, 0xA66F, 230), (0xA674, 0xA67D, 230), (0xA69E, 0xA69F, 230), (0xA6F0, 0xA6F1, 230),
+    (0xA806, 0xA806, 9), (0xA82C, 0xA82C, 9), (0xA8C4, 0xA8C4, 9), (0xA8E0, 0xA8F1, 230),
+    (0xA92B, 0xA92D, 220), (0xA953, 0xA953, 9), (0xA9B3, 0xA9B3, 7), (0xA9C0, 0xA9C0, 9),
+    (0xAAB0, 0xAAB0, 230), (0xAAB2, 0xAAB3, 230), (0xAAB4, 0xAAB4, 220), (0xAAB7, 0xAAB8, 230),
+    (0xAABE, 0xAABF, 230), (0xAAC1, 0xAAC1, 230), (0xAAF6, 0xAAF6, 9), (0xABED, 0xABED, 9),
+    (0xFB1E, 0xFB1E, 26), (0xFE20, 0xFE26, 230), (0xFE27, 0xFE2D, 220), (0xFE2E, 0xFE2F, 230),
+    (0x101FD, 0x101FD, 220), (0x102E0, 0x102E0, 220), (0x10376, 0x1037A, 230), (0x10A0D, 0x10A0D, 220),
+    (0x10A0F, 0x10A0F, 230), (0x10A38, 0x10A38, 230), (0x10A39, 0x10A39, 1), (0x10A3A, 0x10A3A, 220),
+    (0x10A3F, 0x10A3F, 9), (0x10AE5, 0x10AE5, 230), (0x10AE6, 0x10AE6, 220), (0x10D24, 0x10D27, 230),
+    (0x10EAB, 0x10EAC, 230), (0x10F46, 0x10F47, 220), (0x10F48, 0x10F4A, 230), (0x10F4B, 0x10F4B, 220),
+    (0x10F4C, 0x10F4C, 230), (0x10F4D, 0x10F50, 220), (0x11046, 0x11046, 9), (0x1107F, 0x1107F, 9),
+    (0x110B9, 0x110B9, 9), (0x110BA, 0x110BA, 7), (0x11100, 0x11102, 230), (0x11133, 0x11134, 9),
+    (0x11173, 0x11173, 7), (0x111C0, 0x111C0, 9), (0x111CA, 0x111CA, 7), (0x11235, 0x11235, 9),
+    (0x11236, 0x11236, 7), (0x112E9, 0x112E9, 7), (0x112EA, 0x112EA, 9), (0x1133B, 0x1133C, 7),
+    (0x1134D, 0x1134D, 9), (0x11366, 0x1136C, 230), (0x11370, 0x11374, 230), (0x11442, 0x11442, 9),
+    (0x11446, 0x11446, 7), (0x1145E, 0x1145E, 230), (0x114C2, 0x114C2, 9), (0x114C3, 0x114C3, 7),
+    (0x115BF, 0x115BF, 9), (0x115C0, 0x115C0, 7), (0x1163F, 0x1163F, 9), (0x116B6, 0x116B6, 9),
+    (0x116B7, 0x116B7, 7), (0x1172B, 0x1172B, 9), (0x11839, 0x11839, 9), (0x1183A, 0x1183A, 7),
+    (0x1193D, 0x1193E, 9), (0x11943, 0x11943, 7), (0x119E0, 0x119E0, 9), (0x11A34, 0x11A34, 9),
+    (0x11A47, 0x11A47, 9), (0x11A99, 0x11A99, 9), (0x11C3F, 0x11C3F, 9), (0x11D42, 0x11D42, 7),
+    (0x11D44, 0x11D45, 9), (0x11D97, 0x11D97, 9), (0x16AF0, 0x16AF4, 1), (0x16B30, 0x16B36, 230),
+    (0x16FF0, 0x16FF1, 6), (0x1BC9E, 0x1BC9E, 1), (0x1D165, 0x1D166, 216), (0x1D167, 0x1D169, 1),
+    (0x1D16D, 0x1D16D, 226), (0x1D16E, 0x1D172, 216), (0x1D17B, 0x1D182, 220), (0x1D185, 0x1D189, 230),
+    (0x1D18A, 0x1D18B, 220), (0x1D1AA, 0x1D1AD, 230), (0x1D242, 0x1D244, 230), (0x1E000, 0x1E006, 230),
+    (0x1E008, 0x1E018, 230), (0x1E01B, 0x1E021, 230), (0x1E023, 0x1E024, 230), (0x1E026, 0x1E02A, 230),
+    (0x1E130, 0x1E136, 230), (0x1E2EC, 0x1E2EF, 230), (0x1E8D0, 0x1E8D6, 220), (0x1E944, 0x1E949, 230),
+    (0x1E94A, 0x1E94A, 7),
+];
+
+pub const DECOMPOSITION: &[(u32, u16, u8)] = &[
+    (0xC0, 0, 3), (0xC1, 3, 3), (0xC2, 6, 3), (0xC3, 9, 3),
+    (0xC4, 12, 3), (0xC5, 15, 3), (0xC7, 18, 3), (0xC8, 21, 3),
+    (0xC9, 24, 3), (0xCA, 27, 3), (0xCB, 30, 3), (0xCC, 33, 3),
+    (0xCD, 36, 3), (0xCE, 39, 3), (0xCF, 42, 3), (0xD1, 45, 3),
+    (0xD2, 48, 3), (0xD3, 51, 3), (0xD4, 54, 3), (0xD5, 57, 3),
+    (0xD6, 60, 3), (0xD9, 63, 3), (0xDA, 66, 3), (0xDB, 69, 3),
+    (0xDC, 72, 3), (0xDD, 75, 3), (0xE0, 78, 3), (0xE1, 81, 3),
+    (0xE2, 84, 3), (0xE3, 87, 3), (0xE4, 90, 3), (0xE5, 93, 3),
+    (0xE7, 96, 3), (0xE8, 99, 3), (0xE9, 102, 3), (0xEA, 105, 3),
+    (0xEB, 108, 3), (0xEC, 111, 3), (0xED, 114, 3), (0xEE, 117, 3),
+    (0xEF, 120, 3), (0xF1, 123, 3), (0xF2, 126, 3), (0xF3, 129, 3),
+    (0xF4, 132, 3), (0xF5, 135, 3), (0xF6, 138, 3), (0xF9, 141, 3),
+    (0xFA, 144, 3), (0xFB, 147, 3), (0xFC, 150, 3), (0xFD, 153, 3),
+    (0xFF, 156, 3), (0x100, 159, 3), (0x101, 162, 3), (0x102, 165, 3),
+    (0x103, 168, 3), (0x104, 171, 3), (0x105, 174, 3), (0x106, 177, 3),
+    (0x107, 180, 3), (0x108, 183, 3), (0x109, 186, 3), (0x10A, 189, 3),
+    (0x10B, 192, 3), (0x10C, 195, 3), (0x10D, 198, 3), (0x10E, 201, 3),
+    (0x10F, 204, 3), (0x112, 207, 3), (0x113, 210, 3), (0x114, 213, 3),
+    (0x115, 216, 3), (0x116, 219, 3), (0x117, 222, 3), (0x118, 225, 3),
+    (0x119, 228, 3), (0x11A, 231, 3), (0x11B, 234, 3), (0x11C, 237, 3),
+    (0x11D, 240, 3), (0x11E, 243, 3), (0x11F, 246, 3), (0x120, 249, 3),
+    (0x121, 252, 3), (0x122, 255, 3), (0x123, 258, 3), (0x124, 261, 3),
+    (0x125, 264, 3), (0x128, 267, 3), (0x129, 270, 3), (0x12A, 273, 3),
+    (0x12B, 276, 3), (0x12C, 279, 3), (0x12D, 282, 3), (0x12E, 285, 3),
+    (0x12F, 288, 3), (0x130, 291, 3), (0x134, 294, 3), (0x135, 297, 3),
+    (0x136, 300, 3), (0x137, 303, 3), (0x139, 306, 3), (0x13A, 309, 3),
+    (0x13B, 312, 3), (0x13C, 315, 3), (0x13D, 318, 3), (0x13E, 321, 3),
+    (0x143, 324, 3), (0x144, 327, 3), (0x145, 330, 3), (0x146, 333, 3),
+    (0x147, 336, 3), (0x148, 339, 3), (0x14C, 342, 3), (0x14D, 345, 3),
+    (0x14E, 348, 3), (0x14F, 351, 3), (0x150, 354, 3), (0x151, 357, 3),
+    (0x154, 360, 3), (0x155, 363, 3), (0x156, 366, 3), (0x157, 369, 3),
+    (0x158, 372, 3), (0x159, 375, 3), (0x15A, 378, 3), (0x15B, 381, 3),
+    (0x15C, 384, 3), (0x15D, 387, 3), (0x15E, 390, 3), (0x15F, 393, 3),
+    (0x160, 396, 3), (0x161, 399, 3), (0x162, 402, 3), (0x163, 405, 3),
+    (0x164, 408, 3), (0x165, 411, 3), (0x168, 414, 3), (0x169, 417, 3),
+    (0x16A, 420, 3), (0x16B, 423, 3), (0x16C, 426, 3), (0x16D, 429, 3),
+    (0x16E, 432, 3), (0x16F, 435, 3), (0x170, 438, 3), (0x171, 441, 3),
+    (0x172, 444, 3), (0x173, 447, 3), (0x174, 450, 3), (0x175, 453, 3),
+    (0x176, 456, 3), (0x177, 459, 3), (0x178, 462, 3), (0x179, 465, 3),
+    (0x17A, 468, 3), (0x17B, 471, 3), (0x17C, 474, 3), (0x17D, 477, 3),
+    (0x17E, 480, 3), (0x1A0, 483, 3), (0x1A1, 486, 3), (0x1AF, 489, 3),
+    (0x1B0, 492, 3), (0x1CD, 495, 3), (0x1CE, 498, 3), (0x1CF, 501, 3),
+    (0x1D0, 504, 3), (0x1D1, 507, 3), (0x1D2, 510, 3), (0x1D3, 513, 3),
+    (0x1D4, 516, 3), (0x1D5, 519, 5), (0x1D6, 524, 5), (0x1D7, 529, 5),
+    (0x1D8, 534, 5), (0x1D9, 539, 5), (0x1DA, 544, 5), (0x1DB, 549, 5),
+    (0x1DC, 554, 5), (0x1DE, 559, 5), (0x1DF, 564, 5), (0x1E0, 569, 5),
+    (0x1E1, 574, 5), (0x1E2, 579, 4), (0x1E3, 583, 4), (0x1E6, 587, 3),
+    (0x1E7, 590, 3), (0x1E8, 593, 3), (0x1E9, 596, 3), (0x1EA, 599, 3),
+    (0x1EB, 602, 3), (0x1EC, 605, 5), (0x1ED, 610, 5), (0x1EE, 615, 4),
+    (0x1EF, 619, 4), (0x1F0, 623, 3), (0x1F4, 626, 3), (0x1F5, 629, 3),
+    (0x1F8, 632, 3), (0x1F9, 635, 3), (0x1FA, 638, 5), (0x1FB, 643, 5),
+    (0x1FC, 648, 4), (0x1FD, 652, 4), (0x1FE, 656, 4), (0x1FF, 660, 4),
+    (0x200, 664, 3), (0x201, 667, 3), (0x202, 670, 3), (0x203, 673, 3),
+    (0x204, 676, 3), (0x205, 679, 3), (0x206, 682, 3), (0x207, 685, 3),
+    (0x208, 688, 3), (0x209, 691, 3), (0x20A, 694, 3), (0x20B, 697, 3),
+    (0x20C, 700, 3), (0x20D, 703, 3), (0x20E, 706, 3), (0x20F, 709, 3),
+    (0x210, 712, 3), (0x211, 715, 3), (0x212, 718, 3), (0x213, 721, 3),
+    (0x214, 724, 3), (0x215, 727, 3), (0x216, 730, 3), (0x217, 733, 3),
+    (0x218, 736, 3), (0x219, 739, 3), (0x21A, 742, 3), (0x21B, 745, 3),
+    (0x21E, 748, 3), (0x21F, 751, 3), (0x226, 754, 3), (0x227, 757, 3),
+    (0x228, 760, 3), (0x229, 763, 3), (0x22A, 766, 5), (0x22B, 771, 5),
+    (0x22C, 776, 5), (0x22D, 781, 5), (0x22E, 786, 3), (0x22F, 789, 3),
+    (0x230, 792, 5), (0x231, 797, 5), (0x232, 802, 3), (0x233, 805, 3),
+    (0x340, 808, 2), (0x341, 810, 2), (0x343, 812, 2), (0x344, 814, 4),
+    (0x374, 818, 2), (0x37E, 820, 1), (0x385, 821, 4), (0x386, 825, 4),
+    (0x387, 829, 2), (0x388, 831, 4), (0x389, 835, 4), (0x38A, 839, 4),
+    (0x38C, 843, 4), (0x38E, 847, 4), (0x38F, 851, 4), (0x390, 855, 6),
+    (0x3AA, 861, 4), (0x3AB, 865, 4), (0x3AC, 869, 4), (0x3AD, 873, 4),
+    (0x3AE, 877, 4), (0x3AF, 881, 4), (0x3B0, 885, 6), (0x3CA, 891, 4),
+    (0x3CB, 895, 4), (0x3CC, 899, 4), (0x3CD, 903, 4), (0x3CE, 907, 4),
+    (0x3D3, 911, 4), (0x3D4, 915, 4), (0x400, 919, 4), (0x401, 923, 4),
+    (0x403, 927, 4), (0x407, 931, 4), (0x40C, 935, 4), (0x40D, 939, 4),
+    (0x40E, 943, 4), (0x419, 947, 4), (0x439, 951, 4), (0x450, 955, 4),
+    (0x451, 959, 4), (0x453, 963, 4), (0x457, 967, 4), (0x45C, 971, 4),
+    (0x45D, 975, 4), (0x45E, 979, 4), (0x476, 983, 4), (0x477, 987, 4),
+    (0x4C1, 991, 4), (0x4C2, 995, 4), (0x4D0, 999, 4), (0x4D1, 1003, 4),
+    (0x4D2, 1007, 4), (0x4D3, 1011, 4), (0x4D6, 1015, 4), (0x4D7, 1019, 4),
+    (0x4DA, 1023, 4), (0x4DB, 1027, 4), (0x4DC, 1031, 4), (0x4DD, 1035, 4),
+    (0x4DE, 1039, 4), (0x4DF, 1043, 4), (0x4E2, 1047, 4), (0x4E3, 1051, 4),
+    (0x4E4, 1055, 4), (0x4E5, 1059, 4), (0x4E6, 1063, 4), (0x4E7, 1067, 4),
+    (0x4EA, 1071, 4), (0x4EB, 1075, 4), (0x4EC, 1079, 4), (0x4ED, 1083, 4),
+    (0x4EE, 1087, 4), (0x4EF, 1091, 4), (0x4F0, 1095, 4), (0x4F1, 1099, 4),
+    (0x4F2, 1103, 4), (0x4F3, 1107, 4), (0x4F4, 1111, 4), (0x4F5, 1115, 4),
+    (0x4F8, 1119, 4), (0x4F9, 1123, 4), (0x622, 1127, 4), (0x623, 1131, 4),
+    (0x624, 1135, 4), (0x625, 1139, 4), (0x626, 1143, 4), (0x6C0, 1147, 4),
+    (0x6C2, 1151, 4), (0x6D3, 1155, 4), (0x929, 1159, 6), (0x931, 1165, 6),
+    (0x934, 1171, 6), (0x958, 1177, 6), (0x959, 1183, 6), (0x95A, 1189, 6),
+    (0x95B, 1195, 6), (0x95C, 1201, 6), (0x95D, 1207, 6), (0x95E, 1213, 6),
+    (0x95F, 1219, 6), (0x9CB, 1225, 6), (0x9CC, 1231, 6), (0x9DC, 1237, 6),
+    (0x9DD, 1243, 6), (0x9DF, 1249, 6), (0xA33, 1255, 6), (0xA36, 1261, 6),
+    (0xA59, 1267, 6), (0xA5A, 1273, 6), (0xA5B, 1279, 6), (0xA5E, 1285, 6),
+    (0xB48, 1291, 6), (0xB4B, 1297, 6), (0xB4C, 1303, 6), (0xB5C, 1309, 6),
+    (0xB5D, 1315, 6), (0xB94, 1321, 6), (0xBCA, 1327, 6), (0xBCB, 1333, 6),
+    (0xBCC, 1339, 6), (0xC48, 1345, 6), (0xCC0, 1351, 6), (0xCC7, 1357, 6),
+    (0xCC8, 1363, 6), (0xCCA, 1369, 6), (0xCCB, 1375, 9), (0xD4A, 1384, 6),
+    (0xD4B, 1390, 6), (0xD4C, 1396, 6), (0xDDA, 1402, 6), (0xDDC, 1408, 6),
+    (0xDDD, 1414, 9), (0xDDE, 1423, 6), (0xF43, 1429, 6), (0xF4D, 1435, 6),
+    (0xF52, 1441, 6), (0xF57, 1447, 6), (0xF5C, 1453, 6), (0xF69, 1459, 6),
+    (0xF73, 1465, 6), (0xF75, 1471, 6), (0xF76, 1477, 6), (0xF78, 1483, 6),
+    (0xF81, 1489, 6), (0xF93, 1495, 6), (0xF9D, 1501, 6), (0xFA2, 1507, 6),
+    (0xFA7, 1513, 6), (0xFAC, 1519, 6), (0xFB9, 1525, 6), (0x1026, 1531, 6),
+    (0x1B06, 1537, 6), (0x1B08, 1543, 6), (0x1B0A, 1549, 6), (0x1B0C, 1555, 6),
+    (0x1B0E, 1561, 6), (0x1B12, 1567, 6), (0x1B3B, 1573, 6), (0x1B3D, 1579, 6),
+    (0x1B40, 1585, 6), (0x1B41, 1591, 6), (0x1B43, 1597, 6), (0x1E00, 1603, 3),
+    (0x1E01, 1606, 3), (0x1E02, 1609, 3), (0x1E03, 1612, 3), (0x1E04, 1615, 3),
+    (0x1E05, 1618, 3), (0x1E06, 1621, 3), (0x1E07, 1624, 3), (0x1E08, 1627, 5),
+    (0x1E09, 1632, 5), (0x1E0A, 1637, 3), (0x1E0B, 1640, 3), (0x1E0C, 1643, 3),
+    (0x1E0D, 1646, 3), (0x1E0E, 1649, 3), (0x1E0F, 1652, 3), (0x1E10, 1655, 3),
+    (0x1E11, 1658, 3), (0x1E12, 1661, 3), (0x1E13, 1664, 3), (0x1E14, 1667, 5),
+    (0x1E15, 1672, 5), (0x1E16, 1677, 5), (0x1E17, 1682, 5), (0x1E18, 1687, 3),
+    (0x1E19, 1690, 3), (0x1E1A, 1693, 3), (0x1E1B, 1696, 3), (0x1E1C, 1699, 5),
+    (0x1E1D, 1704, 5), (0x1E1E, 1709, 3), (0x1E1F, 1712, 3), (0x1E20, 1715, 3),
+    (0x1E21, 1718, 3), (0x1E22, 1721, 3), (0x1E23, 1724, 3), (0x1E24, 1727, 3),
+    (0x1E25, 1730, 3), (0x1E26, 1733, 3), (0x1E27, 1736, 3), (0x1E28, 1739, 3),
+    (0x1E29, 1742, 3), (0x1E2A, 1745, 3), (0x1E2B, 1748, 3), (0x1E2C, 1751, 3),
+    (0x1E2D, 1754, 3), (0x1E2E, 1757, 5), (0x1E2F, 1762, 5), (0x1E30, 1767, 3),
+    (0x1E31, 1770, 3), (0x1E32, 1773, 3), (0x1E33, 1776, 3), (0x1E34, 1779, 3),
+    (0x1E35, 1782, 3), (0x1E36, 1785, 3), (0x1E37, 1788, 3), (0x1E38, 1791, 5),
+    (0x1E39, 1796, 5), (0x1E3A, 1801, 3), (0x1E3B, 1804, 3), (0x1E3C, 1807, 3),
+    (0x1E3D, 1810, 3), (0x1E3E, 1813, 3), (0x1E3F, 1816, 3), (0x1E40, 1819, 3),
+    (0x1E41, 1822, 3), (0x1E42, 1825, 3), (0x1E43, 1828, 3), (0x1E44, 1831, 3),
+    (0x1E45, 1834, 3), (0x1E46, 1837, 3), (0x1E47, 1840, 3), (0x1E48, 1843, 3),
+    (0x1E49, 1846, 3), (0x1E4A, 1849, 3), (0x1E4B, 1852, 3), (0x1E4C, 1855, 5),
+    (0x1E4D, 1860, 5), (0x1E4E, 1865, 5), (0x1E4F, 1870, 5), (0x1E50, 1875, 5),
+    (0x1E51, 1880, 5), (0x1E52, 1885, 5), (0x1E53, 1890, 5), (0x1E54, 1895, 3),
+    (0x1E55, 1898, 3), (0x1E56, 1901, 3), (0x1E57, 1904, 3), (0x1E58, 1907, 3),
+    (0x1E59, 1910, 3), (0x1E5A, 1913, 3), (0x1E5B, 1916, 3), (0x1E5C, 1919, 5),
+    (0x1E5D, 1924, 5), (0x1E5E, 1929, 3), (0x1E5F, 1932, 3), (0x1E60, 1935, 3),
+    (0x1E61, 1938, 3), (0x1E62, 1941, 3), (0x1E63, 1944, 3), (0x1E64, 1947, 5),
+    (0x1E65, 1952, 5), (0x1E66, 1957, 5), (0x1E67, 1962, 5), (0x1E68, 1967, 5),
+    (0x1E69, 1972, 5), (0x1E6A, 1977, 3), (0x1E6B, 1980, 3), (0x1E6C, 1983, 3),
+    (0x1E6D, 1986, 3), (0x1E6E, 1989, 3), (0x1E6F, 1992, 3), (0x1E70, 1995, 3),
+    (0x1E71, 1998, 3), (0x1E72, 2001, 3), (0x1E73, 2004, 3), (0x1E74, 2007, 3),
+    (0x1E75, 2010, 3), (0x1E76, 2013, 3), (0x1E77, 2016, 3), (0x1E78, 2019, 5),
+    (0x1E79, 2024, 5), (0x1E7A, 2029, 5), (0x1E7B, 2034, 5), (0x1E7C, 2039, 3),
+    (0x1E7D, 2042, 3), (0x1E7E, 2045, 3), (0x1E7F, 2048, 3), (0x1E80, 2051, 3),
+    (0x1E81, 2054, 3), (0x1E82, 2057, 3), (0x1E83, 2060, 3), (0x1E84, 2063, 3),
+    (0x1E85, 2066, 3), (0x1E86, 2069, 3), (0x1E87, 2072, 3), (0x1E88, 2075, 3),
+    (0x1E89, 2078, 3), (0x1E8A, 2081, 3), (0x1E8B, 2084, 3), (0x1E8C, 2087, 3),
+    (0x1E8D, 2090, 3), (0x1E8E, 2093, 3), (0x1E8F, 2096, 3), (0x1E90, 2099, 3),
+    (0x1E91, 2102, 3), (0x1E92, 2105, 3), (0x1E93, 2108, 3), (0x1E94, 2111, 3),
+    (0x1E95, 2114, 3), (0x1E96, 2117, 3), (0x1E97, 2120, 3), (0x1E98, 2123, 3),
+    (0x1E99, 2126, 3), (0x1E9B, 2129, 4), (0x1EA0, 2133, 3), (0x1EA1, 2136, 3),
+    (0x1EA2, 2139, 3), (0x1EA3, 2142, 3), (0x1EA4, 2145, 5), (0x1EA5, 2150, 5),
+    (0x1EA6, 2155, 5), (0x1EA7, 2160, 5), (0x1EA8, 2165, 5), (0x1EA9, 2170, 5),
+    (0x1EAA, 2175, 5), (0x1EAB, 2180, 5), (0x1EAC, 2185, 5), (0x1EAD, 2190, 5),
+    (0x1EAE, 2195, 5), (0x1EAF, 2200, 5), (0x1EB0, 2205, 5), (0x1EB1, 2210, 5),
+    (0x1EB2, 2215, 5), (0x1EB3, 2220, 5), (0x1EB4, 2225, 5), (0x1EB5, 2230, 5),
+    (0x1EB6, 2235, 5), (0x1EB7, 2240, 5), (0x1EB8, 2245, 3), (0x1EB9, 2248, 3),
+    (0x1EBA, 2251, 3), (0x1EBB, 2254, 3), (0x1EBC, 2257, 3), (0x1EBD, 2260, 3),
+    (0x1EBE, 2263, 5), (0x1EBF, 2268, 5), (0x1EC0, 2273, 5), (0x1EC1, 2278, 5),
+    (0x1EC2, 2283, 5), (0x1EC3, 2288, 5), (0x1EC4, 2293, 5), (0x1EC5, 2298, 5),
+    (0x1EC6, 2303, 5), (0x1EC7, 2308, 5), (0x1EC8, 2313, 3), (0x1EC9, 2316, 3),
+    (0x1ECA, 2319, 3), (0x1ECB, 2322, 3), (0x1ECC, 2325, 3), (0x1ECD, 2328, 3),
+    (0x1ECE, 2331, 3), (0x1ECF, 2334, 3), (0x1ED0, 2337, 5), (0x1ED1, 2342, 5),
+    (0x1ED2, 2347, 5), (0x1ED3, 2352, 5), (0x1ED4, 2357, 5), (0x1ED5, 2362, 5),
+    (0x1ED6, 2367, 5), (0x1ED7, 2372, 5), (0x1ED8, 2377, 5), (0x1ED9, 2382, 5),
+    (0x1EDA, 2387, 5), (0x1EDB, 2392, 5), (0x1EDC, 2397, 5), (0x1EDD, 2402, 5),
+    (0x1EDE, 2407, 5), (0x1EDF, 2412, 5), (0x1EE0, 2417, 5), (0x1EE1, 2422, 5),
+    (0x1EE2, 2427, 5), (0x1EE3, 2432, 5), (0x1EE4, 2437, 3), (0x1EE5, 2440, 3),
+    (0x1EE6, 2443, 3), (0x1EE7, 2446, 3), (0x1EE8, 2449, 5), (0x1EE9, 2454, 5),
+    (0x1EEA, 2459, 5), (0x1EEB, 2464, 5), (0x1EEC, 2469, 5), (0x1EED, 2474, 5),
+    (0x1EEE, 2479, 5), (0x1EEF, 2484, 5), (0x1EF0, 2489, 5), (0x1EF1, 2494, 5),
+    (0x1EF2, 2499, 3), (0x1EF3, 2502, 3), (0x1EF4, 2505, 3), (0x1EF5, 2508, 3),
+    (0x1EF6, 2511, 3), (0x1EF7, 2514, 3), (0x1EF8, 2517, 3), (0x1EF9, 2520, 3),
+    (0x1F00, 2523, 4), (0x1F01, 2527, 4), (0x1F02, 2531, 6), (0x1F03, 2537, 6),
+    (0x1F04, 2543, 6), (0x1F05, 2549, 6), (0x1F06, 2555, 6), (0x1F07, 2561, 6),
+    (0x1F08, 2567, 4), (0x1F09, 2571, 4), (0x1F0A, 2575, 6), (0x1F0B, 2581, 6),
+    (0x1F0C, 2587, 6), (0x1F0D, 2593, 6), (0x1F0E, 2599, 6), (0x1F0F, 2605, 6),
+    (0x1F10, 2611, 4), (0x1F11, 2615, 4), (0x1F12, 2619, 6), (0x1F13, 2625, 6),
+    (0x1F14, 2631, 6), (0x1F15, 2637, 6), (0x1F18, 2643, 4), (0x1F19, 2647, 4),
+    (0x1F1A, 2651, 6), (0x1F1B, 2657, 6), (0x1F1C, 2663, 6), (0x1F1D, 2669, 6),
+    (0x1F20, 2675, 4), (0x1F21, 2679, 4), (0x1F22, 2683, 6), (0x1F23, 2689, 6),
+    (0x1F24, 2695, 6), (0x1F25, 2701, 6), (0x1F26, 2707, 6), (0x1F27, 2713, 6),
+    (0x1F28, 2719, 4), (0x1F29, 2723, 4), (0x1F2A, 2727, 6), (0x1F2B, 2733, 6),
+    (0x1F2C, 2739, 6), (0x1F2D, 2745, 6), (0x1F2E, 2751, 6), (0x1F2F, 2757, 6),
+    (0x1F30, 2763, 4), (0x1F31, 2767, 4), (0x1F32, 2771, 6), (0x1F33, 2777, 6),
+    (0x1F34, 2783, 6), (0x1F35, 2789, 6), (0x1F36, 2795, 6), (0x1F37, 2801, 6),
+    (0x1F38, 2807, 4), (0x1F39, 2811, 4), (0x1F3A, 2815, 6), (0x1F3B, 2821, 6),
+    (0x1F3C, 2827, 6), (0x1F3D, 2833, 6), (0x1F3E, 2839, 6), (0x1F3F, 2845, 6),
+    (0x1F40, 2851, 4), (0x1F41, 2855, 4), (0x1F42, 2859, 6), (0x1F43, 2865, 6),
+    (0x1F44, 2871, 6), (0x1F45, 2877, 6), (0x1F48, 2883, 4), (0x1F49, 2887, 4),
+    (0x1F4A, 2891, 6), (0x1F4B, 2897, 6), (0x1F4C, 2903, 6), (0x1F4D, 2909, 6),
+    (0x1F50, 2915, 4), (0x1F51, 2919, 4), (0x1F52, 2923, 6), (0x1F53, 2929, 6),
+    (0x1F54, 2935, 6), (0x1F55, 2941, 6), (0x1F56, 2947, 6), (0x1F57, 2953, 6),
+    (0x1F59, 2959, 4), (0x1F5B, 2963, 6), (0x1F5D, 2969, 6), (0x1F5F, 2975, 6),
+    (0x1F60, 2981, 4), (0x1F61, 2985, 4), (0x1F62, 2989, 6), (0x1F63, 2995, 6),
+    (0x1F64, 3001, 6), (0x1F65, 3007, 6), (0x1F66, 3013, 6), (0x1F67, 3019, 6),
+    (0x1F68, 3025, 4), (0x1F69, 3029, 4), (0x1F6A, 3033, 6), (0x1F6B, 3039, 6),
+    (0x1F6C, 3045, 6), (0x1F6D, 3051, 6), (0x1F6E, 3057, 6), (0x1F6F, 3063, 6),
+    (0x1F70, 3069, 4), (0x1F71, 3073, 4), (0x1F72, 3077, 4), (0x1F73, 3081, 4),
+    (0x1F74, 3085, 4), (0x1F75, 3089, 4), (0x1F76, 3093, 4), (0x1F77, 3097, 4),
+    (0x1F78, 3101, 4), (0x1F79, 3105, 4), (0x1F7A, 3109, 4), (0x1F7B, 3113, 4),
+    (0x1F7C, 3117, 4), (0x1F7D, 3121, 4), (0x1F80, 3125, 6), (0x1F81, 3131, 6),
+    (0x1F82, 3137, 8), (0x1F83, 3145, 8), (0x1F84, 3153, 8), (0x1F85, 3161, 8),
+    (0x1F86, 3169, 8), (0x1F87, 3177, 8), (0x1F88, 3185, 6), (0x1F89, 3191, 6),
+    (0x1F8A, 3197, 8), (0x1F8B, 3205, 8), (0x1F8C, 3213, 8), (0x1F8D, 3221, 8),
+    (0x1F8E, 3229, 8), (0x1F8F, 3237, 8), (0x1F90, 3245, 6), (0x1F91, 3251, 6),
+    (0x1F92, 3257, 8), (0x1F93, 3265, 8), (0x1F94, 3273, 8), (0x1F95, 3281, 8),
+    (0x1F96, 3289, 8), (0x1F97, 3297, 8), (0x1F98, 3305, 6), (0x1F99, 3311, 6),
+    (0x1F9A, 3317, 8), (0x1F9B, 3325, 8), (0x1F9C, 3333, 8), (0x1F9D, 3341, 8),
+    (0x1F9E, 3349, 8), (0x1F9F, 3357, 8), (0x1FA0, 3365, 6), (0x1FA1, 3371, 6),
+    (0x1FA2, 3377, 8), (0x1FA3, 3385, 8), (0x1FA4, 3393, 8), (0x1FA5, 3401, 8),
+    (0x1FA6, 3409, 8), (0x1FA7, 3417, 8), (0x1FA8, 3425, 6), (0x1FA9, 3431, 6),
+    (0x1FAA, 3437, 8), (0x1FAB, 3445, 8), (0x1FAC, 3453, 8), (0x1FAD, 3461, 8),
+    (0x1FAE, 3469, 8), (0x1FAF, 3477, 8), (0x1FB0, 3485, 4), (0x1FB1, 3489, 4),
+    (0x1FB2, 3493, 6), (0x1FB3, 3499, 4), (0x1FB4, 3503, 6), (0x1FB6, 3509, 4),
+    (0x1FB7, 3513, 6), (0x1FB8, 3519, 4), (0x1FB9, 3523, 4), (0x1FBA, 3527, 4),
+    (0x1FBB, 3531, 4), (0x1FBC, 3535, 4), (0x1FBE, 3539, 2), (0x1FC1, 3541, 4),
+    (0x1FC2, 3545, 6), (0x1FC3, 3551, 4), (0x1FC4, 3555, 6), (0x1FC6, 3561, 4),
+    (0x1FC7, 3565, 6), (0x1FC8, 3571, 4), (0x1FC9, 3575, 4), (0x1FCA, 3579, 4),
+    (0x1FCB, 3583, 4), (0x1FCC, 3587, 4), (0x1FCD, 3591, 5), (0x1FCE, 3596, 5),
+    (0x1FCF, 3601, 5), (0x1FD0, 3606, 4), (0x1FD1, 3610, 4), (0x1FD2, 3614, 6),
+    (0x1FD3, 3620, 6), (0x1FD6, 3626, 4), (0x1FD7, 3630, 6), (0x1FD8, 3636, 4),
+    (0x1FD9, 3640, 4), (0x1FDA, 3644, 4), (0x1FDB, 3648, 4), (0x1FDD, 3652, 5),
+    (0x1FDE, 3657, 5), (0x1FDF, 3662, 5), (0x1FE0, 3667, 4), (0x1FE1, 3671, 4),
+    (0x1FE2, 3675, 6), (0x1FE3, 3681, 6), (0x1FE4, 3687, 4), (0x1FE5, 3691, 4),
+    (0x1FE6, 3695, 4), (0x1FE7, 3699, 6), (0x1FE8, 3705, 4), (0x1FE9, 3709, 4),
+    (0x1FEA, 3713, 4), (0x1FEB, 3717, 4), (0x1FEC, 3721, 4), (0x1FED, 3725, 4),
+    (0x1FEE, 3729, 4), (0x1FEF, 3733, 1), (0x1FF2, 3734, 6), (0x1FF3, 3740, 4),
+    (0x1FF4, 3744, 6), (0x1FF6, 3750, 4), (0x1FF7, 3754, 6), (0x1FF8, 3760, 4),
+    (0x1FF9, 3764, 4), (0x1FFA, 3768, 4), (0x1FFB, 3772, 4), (0x1FFC, 3776, 4),
+    (0x1FFD, 3780, 2), (0x2000, 3782, 3), (0x2001, 3785, 3), (0x2126, 3788, 2),
+    (0x212A, 3790, 1), (0x212B, 3791, 3), (0x219A, 3794, 5), (0x219B, 3799, 5),
+    (0x21AE, 3804, 5), (0x21CD, 3809, 5), (0x21CE, 3814, 5), (0x21CF, 3819, 5),
+    (0x2204, 3824, 5), (0x2209, 3829, 5), (0x220C, 3834, 5), (0x2224, 3839, 5),
+    (0x2226, 3844, 5), (0x2241, 3849, 5), (0x2244, 3854, 5), (0x2247, 3859, 5),
+    (0x2249, 3864, 5), (0x2260, 3869, 3), (0x2262, 3872, 5), (0x226D, 3877, 5),
+    (0x226E, 3882, 3), (0x226F, 3885, 3), (0x2270, 3888, 5), (0x2271, 3893, 5),
+    (0x2274, 3898, 5), (0x2275, 3903, 5), (0x2278, 3908, 5), (0x2279, 3913, 5),
+    (0x2280, 3918, 5), (0x2281, 3923, 5), (0x2284, 3928, 5), (0x2285, 3933, 5),
+    (0x2288, 3938, 5), (0x2289, 3943, 5), (0x22AC, 3948, 5), (0x22AD, 3953, 5),
+    (0x22AE, 3958, 5), (0x22AF, 3963, 5), (0x22E0, 3968, 5), (0x22E1, 3973, 5),
+    (0x22E2, 3978, 5), (0x22E3, 3983, 5), (0x22EA, 3988, 5), (0x22EB, 3993, 5),
+    (0x22EC, 3998, 5), (0x22ED, 4003, 5), (0x2329, 4008, 3), (0x232A, 4011, 3),
+    (0x2ADC, 4014, 5), (0x304C, 4019, 6), (0x304E, 4025, 6), (0x3050, 4031, 6),
+    (0x3052, 4037, 6), (0x3054, 4043, 6), (0x3056, 4049, 6), (0x3058, 4055, 6),
+    (0x305A, 4061, 6), (0x305C, 4067, 6), (0x305E, 4073, 6), (0x3060, 4079, 6),
+    (0x3062, 4085, 6), (0x3065, 4091, 6), (0x3067, 4097, 6), (0x3069, 4103, 6),
+    (0x3070, 4109, 6), (0x3071, 4115, 6), (0x3073, 4121, 6), (0x3074, 4127, 6),
+    (0x3076, 4133, 6), (0x3077, 4139, 6), (0x3079, 4145, 6), (0x307A, 4151, 6),
+    (0x307C, 4157, 6), (0x307D, 4163, 6), (0x3094, 4169, 6), (0x309E, 4175, 6),
+    (0x30AC, 4181, 6), (0x30AE, 4187, 6), (0x30B0, 4193, 6), (0x30B2, 4199, 6),
+    (0x30B4, 4205, 6), (0x30B6, 4211, 6), (0x30B8, 4217, 6), (0x30BA, 4223, 6),
+    (0x30BC, 4229, 6), (0x30BE, 4235, 6), (0x30C0, 4241, 6), (0x30C2, 4247, 6),
+    (0x30C5, 4253, 6), (0x30C7, 4259, 6), (0x30C9, 4265, 6), (0x30D0, 4271, 6),
+    (0x30D1, 4277, 6), (0x30D3, 4283, 6), (0x30D4, 4289, 6), (0x30D6, 4295, 6),
+    (0x30D7, 4301, 6), (0x30D9, 4307, 6), (0x30DA, 4313, 6), (0x30DC, 4319, 6),
+    (0x30DD, 4325, 6), (0x30F4, 4331, 6), (0x30F7, 4337, 6), (0x30F8, 4343, 6),
+    (0x30F9, 4349, 6), (0x30FA, 4355, 6), (0x30FE, 4361, 6), (0xF900, 4367, 3),
+    (0xF901, 4370, 3), (0xF902, 4373, 3), (0xF903, 4376, 3), (0xF904, 4379, 3),
+    (0xF905, 4382, 3), (0xF906, 4385, 3), (0xF907, 4388, 3), (0xF908, 4391, 3),
+    (0xF909, 4394, 3), (0xF90A, 4397, 3), (0xF90B, 4400, 3), (0xF90C, 4403, 3),
+    (0xF90D, 4406, 3), (0xF90E, 4409, 3), (0xF90F, 4412, 3), (0xF910, 4415, 3),
+    (0xF911, 4418, 3), (0xF912, 4421, 3), (0xF913, 4424, 3), (0xF914, 4427, 3),
+    (0xF915, 4430, 3), (0xF916, 4433, 3), (0xF917, 4436, 3), (0xF918, 4439, 3),
+    (0xF919, 4442, 3), (0xF91A, 4445, 3), (0xF91B, 4448, 3), (0xF91C, 4451, 3),
+    (0xF91D, 4454, 3), (0xF91E, 4457, 3), (0xF91F, 4460, 3), (0xF920, 4463, 3),
+    (0xF921, 4466, 3), (0xF922, 4469, 3), (0xF923, 4472, 3), (0xF924, 4475, 3),
+    (0xF925, 4478, 3), (0xF926, 4481, 3), (0xF927, 4484, 3), (0xF928, 4487, 3),
+    (0xF929, 4490, 3), (0xF92A, 4493, 3), (0xF92B, 4496, 3), (0xF92C, 4499, 3),
+    (0xF92D, 4502, 3), (0xF92E, 4505, 3), (0xF92F, 4508, 3), (0xF930, 4511, 3),
+    (0xF931, 4514, 3), (0xF932, 4517, 3), (0xF933, 4520, 3), (0xF934, 4523, 3),
+    (0xF935, 4526, 3), (0xF936, 4529, 3), (0xF937, 4532, 3), (0xF938, 4535, 3),
+    (0xF939, 4538, 3), (0xF93A, 4541, 3), (0xF93B, 4544, 3), (0xF93C, 4547, 3),
+    (0xF93D, 4550, 3), (0xF93E, 4553, 3), (0xF93F, 4556, 3), (0xF940, 4559, 3),
+    (0xF941, 4562, 3), (0xF942, 4565, 3), (0xF943, 4568, 3), (0xF944, 4571, 3),
+    (0xF945, 4574, 3), (0xF946, 4577, 3), (0xF947, 4580, 3), (0xF948, 4583, 3),
+    (0xF949, 4586, 3), (0xF94A, 4589, 3), (0xF94B, 4592, 3), (0xF94C, 4595, 3),
+    (0xF94D, 4598, 3), (0xF94E, 4601, 3), (0xF94F, 4604, 3), (0xF950, 4607, 3),
+    (0xF951, 4610, 3), (0xF952, 4613, 3), (0xF953, 4616, 3), (0xF954, 4619, 3),
+    (0xF955, 4622, 3), (0xF956, 4625, 3), (0xF957, 4628, 3), (0xF958, 4631, 3),
+    (0xF959, 4634, 3), (0xF95A, 4637, 3), (0xF95B, 4640, 3), (0xF95C, 4643, 3),
+    (0xF95D, 4646, 3), (0xF95E, 4649, 3), (0xF95F, 4652, 3), (0xF960, 4655, 3),
+    (0xF961, 4658, 3), (0xF962, 4661, 3), (0xF963, 4664, 3), (0xF964, 4667, 3),
+    (0xF965, 4670, 3), (0xF966, 4673, 3), (0xF967, 4676, 3), (0xF968, 4679, 3),
+    (0xF969, 4682, 3), (0xF96A, 4685, 3), (0xF96B, 4688, 3), (0xF96C, 4691, 3),
+    (0xF96D, 4694, 3), (0xF96E, 4697, 3), (0xF96F, 4700, 3), (0xF970, 4703, 3),
+    (0xF971, 4706, 3), (0xF972, 4709, 3), (0xF973, 4712, 3), (0xF974, 4715, 3),
+    (0xF975, 4718, 3), (0xF976, 4721, 3), (0xF977, 4724, 3), (0xF978, 4727, 3),
+    (0xF979, 4730, 3), (0xF97A, 4733, 3), (0xF97B, 4736, 3), (0xF97C, 4739, 3),
+    (0xF97D, 4742, 3), (0xF97E, 4745, 3), (0xF97F, 4748, 3), (0xF980, 4751, 3),
+    (0xF981, 4754, 3), (0xF982, 4757, 3), (0xF983, 4760, 3), (0xF984, 4763, 3),
+    (0xF985, 4766, 3), (0xF986, 4769, 3), (0xF987, 4772, 3), (0xF988, 4775, 3),
+    (0xF989, 4778, 3), (0xF98A, 4781, 3), (0xF98B, 4784, 3), (0xF98C, 4787, 3),
+    (0xF98D, 4790, 3), (0xF98E, 4793, 3), (0xF98F, 4796, 3), (0xF990, 4799, 3),
+    (0xF991, 4802, 3), (0xF992, 4805, 3), (0xF993, 4808, 3), (0xF994, 4811, 3),
+    (0xF995, 4814, 3), (0xF996, 4817, 3), (0xF997, 4820, 3), (0xF998, 4823, 3),
+    (0xF999, 4826, 3), (0xF99A, 4829, 3), (0xF99B, 4832, 3), (0xF99C, 4835, 3),
+    (0xF99D, 4838, 3), (0xF99E, 4841, 3), (0xF99F, 4844, 3), (0xF9A0, 4847, 3),
+    (0xF9A1, 4850, 3), (0xF9A2, 4853, 3), (0xF9A3, 4856, 3), (0xF9A4, 4859, 3),
+    (0xF9A5, 4862, 3), (0xF9A6, 4865, 3), (0xF9A7, 4868, 3), (0xF9A8, 4871, 3),
+    (0xF9A9, 4874, 3), (0xF9AA, 4877, 3), (0xF9AB, 4880, 3), (0xF9AC, 4883, 3),
+    (0xF9AD, 4886, 3), (0xF9AE, 4889, 3), (0xF9AF, 4892, 3), (0xF9B0, 4895, 3),
+    (0xF9B1, 4898, 3), (0xF9B2, 4901, 3), (0xF9B3, 4904, 3), (0xF9B4, 4907, 3),
+    (0xF9B5, 4910, 3), (0xF9B6, 4913, 3), (0xF9B7, 4916, 3), (0xF9B8, 4919, 3),
+    (0xF9B9, 4922, 3), (0xF9BA, 4925, 3), (0xF9BB, 4928, 3), (0xF9BC, 4931, 3),
+    (0xF9BD, 4934, 3), (0xF9BE, 4937, 3), (0xF9BF, 4940, 3), (0xF9C0, 4943, 3),
+    (0xF9C1, 4946, 3), (0xF9C2, 4949, 3), (0xF9C3, 4952, 3), (0xF9C4, 4955, 3),
+    (0xF9C5, 4958, 3), (0xF9C6, 4961, 3), (0xF9C7, 4964, 3), (0xF9C8, 4967, 3),
+    (0xF9C9, 4970, 3), (0xF9CA, 4973, 3), (0xF9CB, 4976, 3), (0xF9CC, 4979, 3),
+    (0xF9CD, 4982, 3), (0xF9CE, 4985, 3), (0xF9CF, 4988, 3), (0xF9D0, 4991, 3),
+    (0xF9D1, 4994, 3), (0xF9D2, 4997, 3), (0xF9D3, 5000, 3), (0xF9D4, 5003, 3),
+    (0xF9D5, 5006, 3), (0xF9D6, 5009, 3), (0xF9D7, 5012, 3), (0xF9D8, 5015, 3),
+    (0xF9D9, 5018, 3), (0xF9DA, 5021, 3), (0xF9DB, 5024, 3), (0xF9DC, 5027, 3),
+    (0xF9DD, 5030, 3), (0xF9DE, 5033, 3), (0xF9DF, 5036, 3), (0xF9E0, 5039, 3),
+    (0xF9E1, 5042, 3), (0xF9E2, 5045, 3), (0xF9E3, 5048, 3), (0xF9E4, 5051, 3),
+    (0xF9E5, 5054, 3), (0xF9E6, 5057, 3), (0xF9E7, 5060, 3), (0xF9E8, 5063, 3),
+    (0xF9E9, 5066, 3), (0xF9EA, 5069, 3), (0xF9EB, 5072, 3), (0xF9EC, 5075, 3),
+    (0xF9ED, 5078, 3), (0xF9EE, 5081, 3), (0xF9EF, 5084, 3), (0xF9F0, 5087, 3),
+    (0xF9F1, 5090, 3), (0xF9F2, 5093, 3), (0xF9F3, 5096, 3), (0xF9F4, 5099, 3),
+    (0xF9F5, 5102, 3), (0xF9F6, 5105, 3), (0xF9F7, 5108, 3), (0xF9F8, 5111, 3),
+    (0xF9F9, 5114, 3), (0xF9FA, 5117, 3), (0xF9FB, 5120, 3), (0xF9FC, 5123, 3),
+    (0xF9FD, 5126, 3), (0xF9FE, 5129, 3), (0xF9FF, 5132, 3), (0xFA00, 5135, 3),
+    (0xFA01, 5138, 3), (0xFA02, 5141, 3), (0xFA03, 5144, 3), (0xFA04, 5147, 3),
+    (0xFA05, 5150, 3), (0xFA06, 5153, 3), (0xFA07, 5156, 3), (0xFA08, 5159, 3),
+    (0xFA09, 5162, 3), (0xFA0A, 5165, 3), (0xFA0B, 5168, 3), (0xFA0C, 5171, 3),
+    (0xFA0D, 5174, 3), (0xFA10, 5177, 3), (0xFA12, 5180, 3), (0xFA15, 5183, 3),
+    (0xFA16, 5186, 3), (0xFA17, 5189, 3), (0xFA18, 5192, 3), (0xFA19, 5195, 3),
+    (0xFA1A, 5198, 3), (0xFA1B, 5201, 3), (0xFA1C, 5204, 3), (0xFA1D, 5207, 3),
+    (0xFA1E, 5210, 3), (0xFA20, 5213, 3), (0xFA22, 5216, 3), (0xFA25, 5219, 3),
+    (0xFA26, 5222, 3), (0xFA2A, 5225, 3), (0xFA2B, 5228, 3), (0xFA2C, 5231, 3),
+    (0xFA2D, 5234, 3), (0xFA2E, 5237, 3), (0xFA2F, 5240, 3), (0xFA30, 5243, 3),
+    (0xFA31, 5246, 3), (0xFA32, 5249, 3), (0xFA33, 5252, 3), (0xFA34, 5255, 3),
+    (0xFA35, 5258, 3), (0xFA36, 5261, 3), (0xFA37, 5264, 3), (0xFA38, 5267, 3),
+    (0xFA39, 5270, 3), (0xFA3A, 5273, 3), (0xFA3B, 5276, 3), (0xFA3C, 5279, 3),
+    (0xFA3D, 5282, 3), (0xFA3E, 5285, 3), (0xFA3F, 5288, 3), (0xFA40, 5291, 3),
+    (0xFA41, 5294, 3), (0xFA42, 5297, 3), (0xFA43, 5300, 3), (0xFA44, 5303, 3),
+    (0xFA45, 5306, 3), (0xFA46, 5309, 3), (0xFA47, 5312, 3), (0xFA48, 5315, 3),
+    (0xFA49, 5318, 3), (0xFA4A, 5321, 3), (0xFA4B, 5324, 3), (0xFA4C, 5327, 3),
+    (0xFA4D, 5330, 3), (0xFA4E, 5333, 3), (0xFA4F, 5336, 3), (0xFA50, 5339, 3),
+    (0xFA51, 5342, 3), (0xFA52, 5345, 3), (0xFA53, 5348, 3), (0xFA54, 5351, 3),
+    (0xFA55, 5354, 3), (0xFA56, 5357, 3), (0xFA57, 5360, 3), (0xFA58, 5363, 3),
+    (0xFA59, 5366, 3), (0xFA5A, 5369, 3), (0xFA5B, 5372, 3), (0xFA5C, 5375, 3),
+    (0xFA5D, 5378, 3), (0xFA5E, 5381, 3), (0xFA5F, 5384, 3), (0xFA60, 5387, 3),
+    (0xFA61, 5390, 3), (0xFA62, 5393, 3), (0xFA63, 5396, 3), (0xFA64, 5399, 3),
+    (0xFA65, 5402, 3), (0xFA66, 5405, 3), (0xFA67, 5408, 3), (0xFA68, 5411, 3),
+    (0xFA69, 5414, 3), (0xFA6A, 5417, 3), (0xFA6B, 5420, 3), (0xFA6C, 5423, 4),
+    (0xFA6D, 5427, 3), (0xFA70, 5430, 3), (0xFA71, 5433, 3), (0xFA72, 5436, 3),
+    (0xFA73, 5439, 3), (0xFA74, 5442, 3), (0xFA75, 5445, 3), (0xFA76, 5448, 3),
+    (0xFA77, 5451, 3), (0xFA78, 5454, 3), (0xFA79, 5457, 3), (0xFA7A, 5460, 3),
+    (0xFA7B, 5463, 3), (0xFA7C, 5466, 3), (0xFA7D, 5469, 3), (0xFA7E, 5472, 3),
+    (0xFA7F, 5475, 3), (0xFA80, 5478, 3), (0xFA81, 5481, 3), (0xFA82, 5484, 3),
+    (0xFA83, 5487, 3), (0xFA84, 5490, 3), (0xFA85, 5493, 3), (0xFA86, 5496, 3),
+    (0xFA87, 5499, 3), (0xFA88, 5502, 3), (0xFA89, 5505, 3), (0xFA8A, 5508, 3),
+    (0xFA8B, 5511, 3), (0xFA8C, 5514, 3), (0xFA8D, 5517, 3), (0xFA8E, 5520, 3),
+    (0xFA8F, 5523, 3), (0xFA90, 5526, 3), (0xFA91, 5529, 3), (0xFA92, 5532, 3),
+    (0xFA93, 5535, 3), (0xFA94, 5538, 3), (0xFA95, 5541, 3), (0xFA96, 5544, 3),
+    (0xFA97, 5547, 3), (0xFA98, 5550, 3), (0xFA99, 5553, 3), (0xFA9A, 5556, 3),
+    (0xFA9B, 5559, 3), (0xFA9C, 5562, 3), (0xFA9D, 5565, 3), (0xFA9E, 5568, 3),
+    (0xFA9F, 5571, 3), (0xFAA0, 5574, 3), (0xFAA1, 5577, 3), (0xFAA2, 5580, 3),
+    (0xFAA3, 5583, 3), (0xFAA4, 5586, 3), (0xFAA5, 5589, 3), (0xFAA6, 5592, 3),
+    (0xFAA7, 5595, 3), (0xFAA8, 5598, 3), (0xFAA9, 5601, 3), (0xFAAA, 5604, 3),
+    (0xFAAB, 5607, 3), (0xFAAC, 5610, 3), (0xFAAD, 5613, 3), (0xFAAE, 5616, 3),
+    (0xFAAF, 5619, 3), (0xFAB0, 5622, 3), (0xFAB1, 5625, 3), (0xFAB2, 5628, 3),
+    (0xFAB3, 5631, 3), (0xFAB4, 5634, 3), (0xFAB5, 5637, 3), (0xFAB6, 5640, 3),
+    (0xFAB7, 5643, 3), (0xFAB8, 5646, 3), (0xFAB9, 5649, 3), (0xFABA, 5652, 3),
+    (0xFABB, 5655, 3), (0xFABC, 5658, 3), (0xFABD, 5661, 3), (0xFABE, 5664, 3),
+    (0xFABF, 5667, 3), (0xFAC0, 5670, 3), (0xFAC1, 5673, 3), (0xFAC2, 5676, 3),
+    (0xFAC3, 5679, 3), (0xFAC4, 5682, 3), (0xFAC5, 5685, 3), (0xFAC6, 5688, 3),
+    (0xFAC7, 5691, 3), (0xFAC8, 5694, 3), (0xFAC9, 5697, 3), (0xFACA, 5700, 3),
+    (0xFACB, 5703, 3), (0xFACC, 5706, 3), (0xFACD, 5709, 3), (0xFACE, 5712, 3),
+    (0xFACF, 5715, 4), (0xFAD0, 5719, 4), (0xFAD1, 5723, 4), (0xFAD2, 5727, 3),
+    (0xFAD3, 5730, 3), (0xFAD4, 5733, 3), (0xFAD5, 5736, 4), (0xFAD6, 5740, 4),
+    (0xFAD7, 5744, 4), (0xFAD8, 5748, 3), (0xFAD9, 5751, 3), (0xFB1D, 5754, 4),
+    (0xFB1F, 5758, 4), (0xFB2A, 5762, 4), (0xFB2B, 5766, 4), (0xFB2C, 5770, 6),
+    (0xFB2D, 5776, 6), (0xFB2E, 5782, 4), (0xFB2F, 5786, 4), (0xFB30, 5790, 4),
+    (0xFB31, 5794, 4), (0xFB32, 5798, 4), (0xFB33, 5802, 4), (0xFB34, 5806, 4),
+    (0xFB35, 5810, 4), (0xFB36, 5814, 4), (0xFB38, 5818, 4), (0xFB39, 5822, 4),
+    (0xFB3A, 5826, 4), (0xFB3B, 5830, 4), (0xFB3C, 5834, 4), (0xFB3E, 5838, 4),
+    (0xFB40, 5842, 4), (0xFB41, 5846, 4), (0xFB43, 5850, 4), (0xFB44, 5854, 4),
+    (0xFB46, 5858, 4), (0xFB47, 5862, 4), (0xFB48, 5866, 4), (0xFB49, 5870, 4),
+    (0xFB4A, 5874, 4), (0xFB4B, 5878, 4), (0xFB4C, 5882, 4), (0xFB4D, 5886, 4),
+    (0xFB4E, 5890, 4), (0x1109A, 5894, 8), (0x1109C, 5902, 8), (0x110AB, 5910, 8),
+    (0x1112E, 5918, 8), (0x1112F, 5926, 8), (0x1134B, 5934, 8), (0x1134C, 5942, 8),
+    (0x114BB, 5950, 8), (0x114BC, 5958, 8), (0x114BE, 5966, 8), (0x115BA, 5974, 8),
+    (0x115BB, 5982, 8), (0x11938, 5990, 8), (0x1D15E, 5998, 8), (0x1D15F, 6006, 8),
+    (0x1D160, 6014, 12), (0x1D161, 6026, 12), (0x1D162, 6038, 12), (0x1D163, 6050, 12),
+    (0x1D164, 6062, 12), (0x1D1BB, 6074, 8), (0x1D1BC, 6082, 8), (0x1D1BD, 6090, 12),
+    (0x1D1BE, 6102, 12), (0x1D1BF, 6114, 12), (0x1D1C0, 6126, 12), (0x2F800, 6138, 3),
+    (0x2F801, 6141, 3), (0x2F802, 6144, 3), (0x2F803, 6147, 4), (0x2F804, 6151, 3),
+    (0x2F805, 6154, 3), (0x2F806, 6157, 3), (0x2F807, 6160, 3), (0x2F808, 6163, 3),
+    (0x2F809, 6166, 3), (0x2F80A, 6169, 3), (0x2F80B, 6172, 3), (0x2F80C, 6175, 3),
+    (0x2F80D, 6178, 4), (0x2F80E, 6182, 3), (0x2F80F, 6185, 3), (0x2F810, 6188, 3),
+    (0x2F811, 6191, 3), (0x2F812, 6194, 4), (0x2F813, 6198, 3), (0x2F814, 6201, 3),
+    (0x2F815, 6204, 3), (0x2F816, 6207, 4), (0x2F817, 6211, 3), (0x2F818, 6214, 3),
+    (0x2F819, 6217, 3), (0x2F81A, 6220, 3), (0x2F81B, 6223, 3), (0x2F81C, 6226, 4),
+    (0x2F81D, 6230, 3), (0x2F81E, 6233, 3), (0x2F81F, 6236, 3), (0x2F820, 6239, 3),
+    (0x2F821, 6242, 3), (0x2F822, 6245, 3), (0x2F823, 6248, 3), (0x2F824, 6251, 3),
+    (0x2F825, 6254, 3), (0x2F826, 6257, 3), (0x2F827, 6260, 3), (0x2F828, 6263, 3),
+    (0x2F829, 6266, 3), (0x2F82A, 6269, 3), (0x2F82B, 6272, 3), (0x2F82C, 6275, 3),
+    (0x2F82D, 6278, 3), (0x2F82E, 6281, 3), (0x2F82F, 6284, 3), (0x2F830, 6287, 3),
+    (0x2F831, 6290, 3), (0x2F832, 6293, 3), (0x2F833, 6296, 3), (0x2F834, 6299, 4),
+    (0x2F835, 6303, 3), (0x2F836, 6306, 3), (0x2F837, 6309, 3), (0x2F838, 6312, 4),
+    (0x2F839, 6316, 3), (0x2F83A, 6319, 3), (0x2F83B, 6322, 3), (0x2F83C, 6325, 3),
+    (0x2F83D, 6328, 3), (0x2F83E, 6331, 3), (0x2F83F, 6334, 3), (0x2F840, 6337, 3),
+    (0x2F841, 6340, 3), (0x2F842, 6343, 3), (0x2F843, 6346, 3), (0x2F844, 6349, 3),
+    (0x2F845, 6352, 3), (0x2F846, 6355, 3), (0x2F847, 6358, 3), (0x2F848, 6361, 3),
+    (0x2F849, 6364, 3), (0x2F84A, 6367, 3), (0x2F84B, 6370, 3), (0x2F84C, 6373, 3),
+    (0x2F84D, 6376, 3), (0x2F84E, 6379, 3), (0x2F84F, 6382, 3), (0x2F850, 6385, 3),
+    (0x2F851, 6388, 3), (0x2F852, 6391, 3), (0x2F853, 6394, 3), (0x2F854, 6397, 3),
+    (0x2F855, 6400, 3), (0x2F856, 6403, 3), (0x2F857, 6406, 3), (0x2F858, 6409, 3),
+    (0x2F859, 6412, 4), (0x2F85A, 6416, 3), (0x2F85B, 6419, 3), (0x2F85C, 6422, 3),
+    (0x2F85D, 6425, 3), (0x2F85E, 6428, 3), (0x2F85F, 6431, 3), (0x2F860, 6434, 4),
+    (0x2F861, 6438, 4), (0x2F862, 6442, 3), (0x2F863, 6445, 3), (0x2F864, 6448, 3),
+    (0x2F865, 6451, 3), (0x2F866, 6454, 3), (0x2F867, 6457, 3), (0x2F868, 6460, 3),
+    (0x2F869, 6463, 3), (0x2F86A, 6466, 3), (0x2F86B, 6469, 3), (0x2F86C, 6472, 4),
+    (0x2F86D, 6476, 3), (0x2F86E, 6479, 3), (0x2F86F, 6482, 3), (0x2F870, 6485, 3),
+    (0x2F871, 6488, 4), (0x2F872, 6492, 3), (0x2F873, 6495, 3), (0x2F874, 6498, 3),
+    (0x2F875, 6501, 3), (0x2F876, 6504, 3), (0x2F877, 6507, 3), (0x2F878, 6510, 3),
+    (0x2F879, 6513, 3), (0x2F87A, 6516, 3), (0x2F87B, 6519, 4), (0x2F87C, 6523, 3),
+    (0x2F87D, 6526, 4), (0x2F87E, 6530, 3), (0x2F87F, 6533, 3), (0x2F880, 6536, 3),
+    (0x2F881, 6539, 3), (0x2F882, 6542, 3), (0x2F883, 6545, 3), (0x2F884, 6548, 3),
+    (0x2F885, 6551, 3), (0x2F886, 6554, 3), (0x2F887, 6557, 3), (0x2F888, 6560, 3),
+    (0x2F889, 6563, 4), (0x2F88A, 6567, 3), (0x2F88B, 6570, 3), (0x2F88C, 6573, 3),
+    (0x2F88D, 6576, 3), (0x2F88E, 6579, 3), (0x2F88F, 6582, 4), (0x2F890, 6586, 3),
+    (0x2F891, 6589, 4), (0x2F892, 6593, 4), (0x2F893, 6597, 3), (0x2F894, 6600, 3),
+    (0x2F895, 6603, 3), (0x2F896, 6606, 3), (0x2F897, 6609, 4), (0x2F898, 6613, 4),
+    (0x2F899, 6617, 3), (0x2F89A, 6620, 3), (0x2F89B, 6623, 3), (0x2F89C, 6626, 3),
+    (0x2F89D, 6629, 3), (0x2F89E, 6632, 3), (0x2F89F, 6635, 3), (0x2F8A0, 6638, 3),
+    (0x2F8A1, 6641, 3), (0x2F8A2, 6644, 3), (0x2F8A3, 6647, 3), (0x2F8A4, 6650, 4),
+    (0x2F8A5, 6654, 3), (0x2F8A6, 6657, 3), (0x2F8A7, 6660, 3), (0x2F8A8, 6663, 3),
+    (0x2F8A9, 6666, 3), (0x2F8AA, 6669, 3), (0x2F8AB, 6672, 3), (0x2F8AC, 6675, 3),
+    (0x2F8AD, 6678, 3), (0x2F8AE, 6681, 3), (0x2F8AF, 6684, 3), (0x2F8B0, 6687, 3),
+    (0x2F8B1, 6690, 3), (0x2F8B2, 6693, 3), (0x2F8B3, 6696, 3), (0x2F8B4, 6699, 3),
+    (0x2F8B5, 6702, 3), (0x2F8B6, 6705, 3), (0x2F8B7, 6708, 3), (0x2F8B8, 6711, 4),
+    (0x2F8B9, 6715, 3), (0x2F8BA, 6718, 3), (0x2F8BB, 6721, 3), (0x2F8BC, 6724, 3),
+    (0x2F8BD, 6727, 3), (0x2F8BE, 6730, 4), (0x2F8BF, 6734, 3), (0x2F8C0, 6737, 3),
+    (0x2F8C1, 6740, 3), (0x2F8C2, 6743, 3), (0x2F8C3, 6746, 3), (0x2F8C4, 6749, 3),
+    (0x2F8C5, 6752, 3), (0x2F8C6, 6755, 3), (0x2F8C7, 6758, 3), (0x2F8C8, 6761, 3),
+    (0x2F8C9, 6764, 3), (0x2F8CA, 6767, 4), (0x2F8CB, 6771, 3), (0x2F8CC, 6774, 3),
+    (0x2F8CD, 6777, 3), (0x2F8CE, 6780, 3), (0x2F8CF, 6783, 3), (0x2F8D0, 6786, 3),
+    (0x2F8D1, 6789, 3), (0x2F8D2, 6792, 3), (0x2F8D3, 6795, 3), (0x2F8D4, 6798, 3),
+    (0x2F8D5, 6801, 3), (0x2F8D6, 6804, 3), (0x2F8D7, 6807, 3), (0x2F8D8, 6810, 3),
+    (0x2F8D9, 6813, 3), (0x2F8DA, 6816, 3), (0x2F8DB, 6819, 3), (0x2F8DC, 6822, 3),
+    (0x2F8DD, 6825, 4), (0x2F8DE, 6829, 3), (0x2F8DF, 6832, 3), (0x2F8E0, 6835, 3),
+    (0x2F8E1, 6838, 3), (0x2F8E2, 6841, 3), (0x2F8E3, 6844, 4), (0x2F8E4, 6848, 3),
+    (0x2F8E5, 6851, 3), (0x2F8E6, 6854, 3), (0x2F8E7, 6857, 3), (0x2F8E8, 6860, 3),
+    (0x2F8E9, 6863, 3), (0x2F8EA, 6866, 3), (0x2F8EB, 6869, 3), (0x2F8EC, 6872, 4),
+    (0x2F8ED, 6876, 3), (0x2F8EE, 6879, 3), (0x2F8EF, 6882, 3), (0x2F8F0, 6885, 4),
+    (0x2F8F1, 6889, 3), (0x2F8F2, 6892, 3), (0x2F8F3, 6895, 3), (0x2F8F4, 6898, 3),
+    (0x2F8F5, 6901, 3), (0x2F8F6, 6904, 3), (0x2F8F7, 6907, 4), (0x2F8F8, 6911, 4),
+    (0x2F8F9, 6915, 4), (0x2F8FA, 6919, 3), (0x2F8FB, 6922, 4), (0x2F8FC, 6926, 3),
+    (0x2F8FD, 6929, 3), (0x2F8FE, 6932, 3), (0x2F8FF, 6935, 3), (0x2F900, 6938, 3),
+    (0x2F901, 6941, 3), (0x2F902, 6944, 3), (0x2F903, 6947, 3), (0x2F904, 6950, 3),
+    (0x2F905, 6953, 3), (0x2F906, 6956, 4), (0x2F907, 6960, 3), (0x2F908, 6963, 3),
+    (0x2F909, 6966, 3), (0x2F90A, 6969, 3), (0x2F90B, 6972, 3), (0x2F90C, 6975, 3),
+    (0x2F90D, 6978, 4), (0x2F90E, 6982, 3), (0x2F90F, 6985, 3), (0x2F910, 6988, 4),
+    (0x2F911, 6992, 4), (0x2F912, 6996, 3), (0x2F913, 6999, 3), (0x2F914, 7002, 3),
+    (0x2F915, 7005, 3), (0x2F916, 7008, 3), (0x2F917, 7011, 3), (0x2F918, 7014, 3),
+    (0x2F919, 7017, 3), (0x2F91A, 7020, 3), (0x2F91B, 7023, 4), (0x2F91C, 7027, 3),
+    (0x2F91D, 7030, 4), (0x2F91E, 7034, 3), (0x2F91F, 7037, 4), (0x2F920, 7041, 3),
+    (0x2F921, 7044, 3), (0x2F922, 7047, 3), (0x2F923, 7050, 4), (0x2F924, 7054, 3),
+    (0x2F925, 7057, 3), (0x2F926, 7060, 4), (0x2F927, 7064, 4), (0x2F928, 7068, 3),
+    (0x2F929, 7071, 3), (0x2F92A, 7074, 3), (0x2F92B, 7077, 3), (0x2F92C, 7080, 3),
+    (0x2F92D, 7083, 3), (0x2F92E, 7086, 3), (0x2F92F, 7089, 3), (0x2F930, 7092, 3),
+    (0x2F931, 7095, 3), (0x2F932, 7098, 3), (0x2F933, 7101, 3), (0x2F934, 7104, 3),
+    (0x2F935, 7107, 4), (0x2F936, 7111, 3), (0x2F937, 7114, 4), (0x2F938, 7118, 3),
+    (0x2F939, 7121, 4), (0x2F93A, 7125, 3), (0x2F93B, 7128, 4), (0x2F93C, 7132, 4),
+    (0x2F93D, 7136, 4), (0x2F93E, 7140, 3), (0x2F93F, 7143, 3), (0x2F940, 7146, 3),
+    (0x2F941, 7149, 4), (0x2F942, 7153, 4), (0x2F943, 7157, 4), (0x2F944, 7161, 4),
+    (0x2F945, 7165, 3), (0x2F946, 7168, 3), (0x2F947, 7171, 3), (0x2F948, 7174, 3),
+    (0x2F949, 7177, 3), (0x2F94A, 7180, 3), (0x2F94B, 7183, 3), (0x2F94C, 7186, 3),
+    (0x2F94D, 7189, 4), (0x2F94E, 7193, 3), (0x2F94F, 7196, 3), (0x2F950, 7199, 3),
+    (0x2F951, 7202, 3), (0x2F952, 7205, 4), (0x2F953, 7209, 3), (0x2F954, 7212, 4),
+    (0x2F955, 7216, 4), (0x2F956, 7220, 3), (0x2F957, 7223, 3), (0x2F958, 7226, 3),
+    (0x2F959, 7229, 3), (0x2F95A, 7232, 3), (0x2F95B, 7235, 3), (0x2F95C, 7238, 4),
+    (0x2F95D, 7242, 4), (0x2F95E, 7246, 4), (0x2F95F, 7250, 3), (0x2F960, 7253, 3),
+    (0x2F961, 7256, 4), (0x2F962, 7260, 3), (0x2F963, 7263, 3), (0x2F964, 7266, 3),
+    (0x2F965, 7269, 4), (0x2F966, 7273, 3), (0x2F967, 7276, 3), (0x2F968, 7279, 3),
+    (0x2F969, 7282, 3), (0x2F96A, 7285, 3), (0x2F96B, 7288, 4), (0x2F96C, 7292, 3),
+    (0x2F96D, 7295, 3), (0x2F96E, 7298, 3), (0x2F96F, 7301, 3), (0x2F970, 7304, 3),
+    (0x2F971, 7307, 3), (0x2F972, 7310, 4), (0x2F973, 7314, 4), (0x2F974, 7318, 3),
+    (0x2F975, 7321, 4), (0x2F976, 7325, 3), (0x2F977, 7328, 4), (0x2F978, 7332, 3),
+    (0x2F979, 7335, 3), (0x2F97A, 7338, 3), (0x2F97B, 7341, 4), (0x2F97C, 7345, 4),
+    (0x2F97D, 7349, 3), (0x2F97E, 7352, 4), (0x2F97F, 7356, 3), (0x2F980, 7359, 4),
+    (0x2F981, 7363, 3), (0x2F982, 7366, 3), (0x2F983, 7369, 3), (0x2F984, 7372, 3),
+    (0x2F985, 7375, 3), (0x2F986, 7378, 3), (0x2F987, 7381, 4), (0x2F988, 7385, 4),
+    (0x2F989, 7389, 4), (0x2F98A, 7393, 4), (0x2F98B, 7397, 3), (0x2F98C, 7400, 3),
+    (0x2F98D, 7403, 3), (0x2F98E, 7406, 3), (0x2F98F, 7409, 3), (0x2F990, 7412, 3),
+    (0x2F991, 7415, 3), (0x2F992, 7418, 3), (0x2F993, 7421, 3), (0x2F994, 7424, 3),
+    (0x2F995, 7427, 3), (0x2F996, 7430, 3), (0x2F997, 7433, 4), (0x2F998, 7437, 3),
+    (0x2F999, 7440, 3), (0x2F99A, 7443, 3), (0x2F99B, 7446, 3), (0x2F99C, 7449, 3),
+    (0x2F99D, 7452, 3), (0x2F99E, 7455, 3), (0x2F99F, 7458, 3), (0x2F9A0, 7461, 3),
+    (0x2F9A1, 7464, 3), (0x2F9A2, 7467, 3), (0x2F9A3, 7470, 3), (0x2F9A4, 7473, 4),
+    (0x2F9A5, 7477, 4), (0x2F9A6, 7481, 4), (0x2F9A7, 7485, 3), (0x2F9A8, 7488, 3),
+    (0x2F9A9, 7491, 3), (0x2F9AA, 7494, 3), (0x2F9AB, 7497, 4), (0x2F9AC, 7501, 3),
+    (0x2F9AD, 7504, 4), (0x2F9AE, 7508, 3), (0x2F9AF, 7511, 3), (0x2F9B0, 7514, 4),
+    (0x2F9B1, 7518, 4), (0x2F9B2, 7522, 3), (0x2F9B3, 7525, 3), (0x2F9B4, 7528, 3),
+    (0x2F9B5, 7531, 3), (0x2F9B6, 7534, 3), (0x2F9B7, 7537, 3), (0x2F9B8, 7540, 3),
+    (0x2F9B9, 7543, 3), (0x2F9BA, 7546, 3), (0x2F9BB, 7549, 3), (0x2F9BC, 7552, 3),
+    (0x2F9BD, 7555, 3), (0x2F9BE, 7558, 3), (0x2F9BF, 7561, 3), (0x2F9C0, 7564, 3),
+    (0x2F9C1, 7567, 3), (0x2F9C2, 7570, 3), (0x2F9C3, 7573, 3), (0x2F9C4, 7576, 3),
+    (0x2F9C5, 7579, 4), (0x2F9C6, 7583, 3), (0x2F9C7, 7586, 3), (0x2F9C8, 7589, 3),
+    (0x2F9C9, 7592, 3), (0x2F9CA, 7595, 3), (0x2F9CB, 7598, 4), (0x2F9CC, 7602, 4),
+    (0x2F9CD, 7606, 3), (0x2F9CE, 7609, 3), (0x2F9CF, 7612, 3), (0x2F9D0, 7615, 3),
+    (0x2F9D1, 7618, 3), (0x2F9D2, 7621, 3), (0x2F9D3, 7624, 4), (0x2F9D4, 7628, 3),
+    (0x2F9D5, 7631, 3), (0x2F9D6, 7634, 3), (0x2F9D7, 7637, 3), (0x2F9D8, 7640, 4),
+    (0x2F9D9, 7644, 4), (0x2F9DA, 7648, 3), (0x2F9DB, 7651, 3), (0x2F9DC, 7654, 3),
+    (0x2F9DD, 7657, 4), (0x2F9DE, 7661, 3), (0x2F9DF, 7664, 3), (0x2F9E0, 7667, 4),
+    (0x2F9E1, 7671, 4), (0x2F9E2, 7675, 3), (0x2F9E3, 7678, 3), (0x2F9E4, 7681, 3),
+    (0x2F9E5, 7684, 4), (0x2F9E6, 7688, 3), (0x2F9E7, 7691, 3), (0x2F9E8, 7694, 3),
+    (0x2F9E9, 7697, 3), (0x2F9EA, 7700, 3), (0x2F9EB, 7703, 3), (0x2F9EC, 7706, 3),
+    (0x2F9ED, 7709, 4), (0x2F9EE, 7713, 3), (0x2F9EF, 7716, 3), (0x2F9F0, 7719, 3),
+    (0x2F9F1, 7722, 4), (0x2F9F2, 7726, 3), (0x2F9F3, 7729, 3), (0x2F9F4, 7732, 3),
+    (0x2F9F5, 7735, 3), (0x2F9F6, 7738, 4), (0x2F9F7, 7742, 4), (0x2F9F8, 7746, 3),
+    (0x2F9F9, 7749, 3), (0x2F9FA, 7752, 3), (0x2F9FB, 7755, 4), (0x2F9FC, 7759, 3),
+    (0x2F9FD, 7762, 4), (0x2F9FE, 7766, 3), (0x2F9FF, 7769, 3), (0x2FA00, 7772, 3),
+    (0x2FA01, 7775, 4), (0x2FA02, 7779, 3), (0x2FA03, 7782, 3), (0x2FA04, 7785, 3),
+    (0x2FA05, 7788, 3), (0x2FA06, 7791, 3), (0x2FA07, 7794, 3), (0x2FA08, 7797, 3),
+    (0x2FA09, 7800, 4), (0x2FA0A, 7804, 3), (0x2FA0B, 7807, 3), (0x2FA0C, 7810, 3),
+    (0x2FA0D, 7813, 3), (0x2FA0E, 7816, 3), (0x2FA0F, 7819, 3), (0x2FA10, 7822, 4),
+    (0x2FA11, 7826, 3), (0x2FA12, 7829, 4), (0x2FA13, 7833, 4), (0x2FA14, 7837, 4),
+    (0x2FA15, 7841, 3), (0x2FA16, 7844, 3), (0x2FA17, 7847, 3), (0x2FA18, 7850, 3),
+    (0x2FA19, 7853, 3), (0x2FA1A, 7856, 3), (0x2FA1B, 7859, 3), (0x2FA1C, 7862, 3),
+    (0x2FA1D, 7865, 4),
+];
+
+pub const DECOMPOSITION_DATA: &str = "A\u{300}A\u{301}A\u{302}A\u{303}A\u{308}A\u{30A}C\u{327}E\u{300}E\u{301}E\u{302}E\u{308}I\u{300}I\u{301}I\u{302}I\u{308}N\u{303}O\u{300}O\u{301}O\u{302}O\u{303}O\u{308}U\u{300}U\u{301}U\u{302}U\u{308}Y\u{301}a\u{300}a\u{301}a\u{302}a\u{303}a\u{308}a\u{30A}c\u{327}e\u{300}e\u{301}e\u{302}e\u{308}i\u{300}i\u{301}i\u{302}i\u{308}n\u{303}o\u{300}o\u{301}o\u{302}o\u{303}o\u{308}u\u{300}u\u{301}u\u{302}u\u{308}y\u{301}y\u{308}A\u{304}a\u{304}A\u{306}a\u{306}A\u{328}a\u{328}C\u{301}c\u{301}C\u{302}c\u{302}C\u{307}c\u{307}C\u{30C}c\u{30C}D\u{30C}d\u{30C}E\u{304}e\u{304}E\u{306}e\u{306}E\u{307}e\u{307}E\u{328}e\u{328}E\u{30C}e\u{30C}G\u{302}g\u{302}G\u{306}g\u{306}G\u{307}g\u{307}G\u{327}g\u{327}H\u{302}h\u{302}I\u{303}i\u{303}I\u{304}i\u{304}I\u{306}i\u{306}I\u{328}i\u{328}I\u{307}J\u{302}j\u{302}K\u{327}k\u{327}L\u{301}l\u{301}L\u{327}l\u{327}L\u{30C}l\u{30C}N\u{301}n\u{301}N\u{327}n\u{327}N\u{30C}n\u{30C}O\u{304}o\u{304}O\u{306}o\u{306}O\u{30B}o\u{30B}R\u{301}r\u{301}R\u{327}r\u{327}R\u{30C}r\u{30C}S\u{301}s\u{301}S\u{302}s\u{302}S\u{327}s\u{327}S\u{30C}s\u{30C}T\u{327}t\u{327}T\u{30C}t\u{30C}U\u{303}u\u{303}U\u{304}u\u{304}U\u{306}u\u{306}U\u{30A}u\u{30A}U\u{30B}u\u{30B}U\u{328}u\u{328}W\u{302}w\u{302}Y\u{302}y\u{302}Y\u{308}Z\u{301}z\u{301}Z\u{307}z\u{307}Z\u{30C}z\u{30C}O\u{31B}o\u{31B}U\u{31B}u\u{31B}A\u{30C}a\u{30C}I\u{30C}i\u{30C}O\u{30C}o\u{30C}U\u{30C}u\u{30C}U\u{308}\u{304}u\u{308}\u{304}U\u{308}\u{301}u\u{308}\u{301}U\u{308}\u{30C}u\u{308}\u{30C}U\u{308}\u{300}u\u{308}\u{300}A\u{308}\u{304}a\u{308}\u{304}A\u{307}\u{304}a\u{307}\u{304}Æ\u{304}æ\u{304}G\u{30C}g\u{30C}K\u{30C}k\u{30C}O\u{328}o\u{328}O\u{328}\u{304}o\u{328}\u{304}Ʒ\u{30C}ʒ\u{30C}j\u{30C}G\u{301}g\u{301}N\u{300}n\u{300}A\u{30A}\u{301}a\u{30A}\u{301}Æ\u{301}æ\u{301}Ø\u{301}ø\u{301}A\u{30F}a\u{30F}A\u{311}a\u{311}E\u{30F}e\u{30F}E\u{311}e\u{311}I\u{30F}i\u{30F}I\u{311}i\u{311}O\u{30F}o\u{30F}O\u{311}o\u{311}R\u{30F}r\u{30F}R\u{311}r\u{311}U\u{30F}u\u{30F}U\u{311}u\u{311}S\u{326}s\u{326}T\u{326}t\u{326}H\u{30C}h\u{30C}A\u{307}a\u{307}E\u{327}e\u{327}O\u{308}\u{304}o\u{308}\u{304}O\u{303}\u{304}o\u{303}\u{304}O\u{307}o\u{307}O\u{307}\u{304}o\u{307}\u{304}Y\u{304}y\u{304}\u{300}\u{301}\u{313}\u{308}\u{301}ʹ;¨\u{301}Α\u{301}·Ε\u{301}Η\u{301}Ι\u{301}Ο\u{301}Υ\u{301}Ω\u{301}ι\u{308}\u{301}Ι\u{308}Υ\u{308}α\u{301}ε\u{301}η\u{301}ι\u{301}υ\u{308}\u{301}ι\u{308}υ\u{308}ο\u{301}υ\u{301}ω\u{301}ϒ\u{301}ϒ\u{308}Е\u{300}Е\u{308}Г\u{301}І\u{308}К\u{301}И\u{300}У\u{306}И\u{306}и\u{306}е\u{300}е\u{308}г\u{301}і\u{308}к\u{301}и\u{300}у\u{306}Ѵ\u{30F}ѵ\u{30F}Ж\u{306}ж\u{306}А\u{306}а\u{306}А\u{308}а\u{308}Е\u{306}е\u{306}Ә\u{308}ә\u{308}Ж\u{308}ж\u{308}З\u{308}з\u{308}И\u{304}и\u{304}И\u{308}и\u{308}О\u{308}о\u{308}Ө\u{308}ө\u{308}Э\u{308}э\u{308}У\u{304}у\u{304}У\u{308}у\u{308}У\u{30B}у\u{30B}Ч\u{308}ч\u{308}Ы\u{308}ы\u{308}ا\u{653}ا\u{654}و\u{654}ا\u{655}ي\u{654}ە\u{654}ہ\u{654}ے\u{654}न\u{93C}र\u{93C}ळ\u{93C}क\u{93C}ख\u{93C}ग\u{93C}ज\u{93C}ड\u{93C}ढ\u{93C}फ\u{93C}य\u{93C}\u{9C7}\u{9BE}\u{9C7}\u{9D7}ড\u{9BC}ঢ\u{9BC}য\u{9BC}ਲ\u{A3C}ਸ\u{A3C}ਖ\u{A3C}ਗ\u{A3C}ਜ\u{A3C}ਫ\u{A3C}\u{B47}\u{B56}\u{B47}\u{B3E}\u{B47}\u{B57}ଡ\u{B3C}ଢ\u{B3C}ஒ\u{BD7}\u{BC6}\u{BBE}\u{BC7}\u{BBE}\u{BC6}\u{BD7}\u{C46}\u{C56}\u{CBF}\u{CD5}\u{CC6}\u{CD5}\u{CC6}\u{CD6}\u{CC6}\u{CC2}\u{CC6}\u{CC2}\u{CD5}\u{D46}\u{D3E}\u{D47}\u{D3E}\u{D46}\u{D57}\u{DD9}\u{DCA}\u{DD9}\u{DCF}\u{DD9}\u{DCF}\u{DCA}\u{DD9}\u{DDF}ག\u{FB7}ཌ\u{FB7}ད\u{FB7}བ\u{FB7}ཛ\u{FB7}ཀ\u{FB5}\u{F71}\u{F72}\u{F71}\u{F74}\u{FB2}\u{F80}\u{FB3}\u{F80}\u{F71}\u{F80}\u{F92}\u{FB7}\u{F9C}\u{FB7}\u{FA1}\u{FB7}\u{FA6}\u{FB7}\u{FAB}\u{FB7}\u{F90}\u{FB5}ဥ\u{102E}ᬅ\u{1B35}ᬇ\u{1B35}ᬉ\u{1B35}ᬋ\u{1B35}ᬍ\u{1B35}ᬑ\u{1B35}\u{1B3A}\u{1B35}\u{1B3C}\u{1B35}\u{1B3E}\u{1B35}\u{1B3F}\u{1B35}\u{1B42}\u{1B35}A\u{325}a\u{325}B\u{307}b\u{307}B\u{323}b\u{323}B\u{331}b\u{331}C\u{327}\u{301}c\u{327}\u{301}D\u{307}d\u{307}D\u{323}d\u{323}D\u{331}d\u{331}D\u{327}d\u{327}D\u{32D}d\u{32D}E\u{304}\u{300}e\u{304}\u{300}E\u{304}\u{301}e\u{304}\u{301}E\u{32D}e\u{32D}E\u{330}e\u{330}E\u{327}\u{306}e\u{327}\u{306}F\u{307}f\u{307}G\u{304}g\u{304}H\u{307}h\u{307}H\u{323}h\u{323}H\u{308}h\u{308}H\u{327}h\u{327}H\u{32E}h\u{32E}I\u{330}i\u{330}I\u{308}\u{301}i\u{308}\u{301}K\u{301}k\u{301}K\u{323}k\u{323}K\u{331}k\u{331}L\u{323}l\u{323}L\u{323}\u{304}l\u{323}\u{304}L\u{331}l\u{331}L\u{32D}l\u{32D}M\u{301}m\u{301}M\u{307}m\u{307}M\u{323}m\u{323}N\u{307}n\u{307}N\u{323}n\u{323}N\u{331}n\u{331}N\u{32D}n\u{32D}O\u{303}\u{301}o\u{303}\u{301}O\u{303}\u{308}o\u{303}\u{308}O\u{304}\u{300}o\u{304}\u{300}O\u{304}\u{301}o\u{304}\u{301}P\u{301}p\u{301}P\u{307}p\u{307}R\u{307}r\u{307}R\u{323}r\u{323}R\u{323}\u{304}r\u{323}\u{304}R\u{331}r\u{331}S\u{307}s\u{307}S\u{323}s\u{323}S\u{301}\u{307}s\u{301}\u{307}S\u{30C}\u{307}s\u{30C}\u{307}S\u{323}\u{307}s\u{323}\u{307}T\u{307}t\u{307}T\u{323}t\u{323}T\u{331}t\u{331}T\u{32D}t\u{32D}U\u{324}u\u{324}U\u{330}u\u{330}U\u{32D}u\u{32D}U\u{303}\u{301}u\u{303}\u{301}U\u{304}\u{308}u\u{304}\u{308}V\u{303}v\u{303}V\u{323}v\u{323}W\u{300}w\u{300}W\u{301}w\u{301}W\u{308}w\u{308}W\u{307}w\u{307}W\u{323}w\u{323}X\u{307}x\u{307}X\u{308}x\u{308}Y\u{307}y\u{307}Z\u{302}z\u{302}Z\u{323}z\u{323}Z\u{331}z\u{331}h\u{331}t\u{308}w\u{30A}y\u{30A}ſ\u{307}A\u{323}a\u{323}A\u{309}a\u{309}A\u{302}\u{301}a\u{302}\u{301}A\u{302}\u{300}a\u{302}\u{300}A\u{302}\u{309}a\u{302}\u{309}A\u{302}\u{303}a\u{302}\u{303}A\u{323}\u{302}a\u{323}\u{302}A\u{306}\u{301}a\u{306}\u{301}A\u{306}\u{300}a\u{306}\u{300}A\u{306}\u{309}a\u{306}\u{309}A\u{306}\u{303}a\u{306}\u{303}A\u{323}\u{306}a\u{323}\u{306}E\u{323}e\u{323}E\u{309}e\u{309}E\u{303}e\u{303}E\u{302}\u{301}e\u{302}\u{301}E\u{302}\u{300}e\u{302}\u{300}E\u{302}\u{309}e\u{302}\u{309}E\u{302}\u{303}e\u{302}\u{303}E\u{323}\u{302}e\u{323}\u{302}I\u{309}i\u{309}I\u{323}i\u{323}O\u{323}o\u{323}O\u{309}o\u{309}O\u{302}\u{301}o\u{302}\u{301}O\u{302}\u{300}o\u{302}\u{300}O\u{302}\u{309}o\u{302}\u{309}O\u{302}\u{303}o\u{302}\u{303}O\u{323}\u{302}o\u{323}\u{302}O\u{31B}\u{301}o\u{31B}\u{301}O\u{31B}\u{300}o\u{31B}\u{300}O\u{31B}\u{309}o\u{31B}\u{309}O\u{31B}\u{303}o\u{31B}\u{303}O\u{31B}\u{323}o\u{31B}\u{323}U\u{323}u\u{323}U\u{309}u\u{309}U\u{31B}\u{301}u\u{31B}\u{301}U\u{31B}\u{300}u\u{31B}\u{300}U\u{31B}\u{309}u\u{31B}\u{309}U\u{31B}\u{303}u\u{31B}\u{303}U\u{31B}\u{323}u\u{31B}\u{323}Y\u{300}y\u{300}Y\u{323}y\u{323}Y\u{309}y\u{309}Y\u{303}y\u{303}α\u{313}α\u{314}α\u{313}\u{300}α\u{314}\u{300}α\u{313}\u{301}α\u{314}\u{301}α\u{313}\u{342}α\u{314}\u{342}Α\u{313}Α\u{314}Α\u{313}\u{300}Α\u{314}\u{300}Α\u{313}\u{301}Α\u{314}\u{301}Α\u{313}\u{342}Α\u{314}\u{342}ε\u{313}ε\u{314}ε\u{313}\u{300}ε\u{314}\u{300}ε\u{313}\u{301}ε\u{314}\u{301}Ε\u{313}Ε\u{314}Ε\u{313}\u{300}Ε\u{314}\u{300}Ε\u{313}\u{301}Ε\u{314}\u{301}η\u{313}η\u{314}η\u{313}\u{300}η\u{314}\u{300}η\u{313}\u{301}η\u{314}\u{301}η\u{313}\u{342}η\u{314}\u{342}Η\u{313}Η\u{314}Η\u{313}\u{300}Η\u{314}\u{300}Η\u{313}\u{301}Η\u{314}\u{301}Η\u{313}\u{342}Η\u{314}\u{342}ι\u{313}ι\u{314}ι\u{313}\u{300}ι\u{314}\u{300}ι\u{313}\u{301}ι\u{314}\u{301}ι\u{313}\u{342}ι\u{314}\u{342}Ι\u{313}Ι\u{314}Ι\u{313}\u{300}Ι\u{314}\u{300}Ι\u{313}\u{301}Ι\u{314}\u{301}Ι\u{313}\u{342}Ι\u{314}\u{342}ο\u{313}ο\u{314}ο\u{313}\u{300}ο\u{314}\u{300}ο\u{313}\u{301}ο\u{314}\u{301}Ο\u{313}Ο\u{314}Ο\u{313}\u{300}Ο\u{314}\u{300}Ο\u{313}\u{301}Ο\u{314}\u{301}υ\u{313}υ\u{314}υ\u{313}\u{300}υ\u{314}\u{300}υ\u{313}\u{301}υ\u{314}\u{301}υ\u{313}\u{342}υ\u{314}\u{342}Υ\u{314}Υ\u{314}\u{300}Υ\u{314}\u{301}Υ\u{314}\u{342}ω\u{313}ω\u{314}ω\u{313}\u{300}ω\u{314}\u{300}ω\u{313}\u{301}ω\u{314}\u{301}ω\u{313}\u{342}ω\u{314}\u{342}Ω\u{313}Ω\u{314}Ω\u{313}\u{300}Ω\u{314}\u{300}Ω\u{313}\u{301}Ω\u{314}\u{301}Ω\u{313}\u{342}Ω\u{314}\u{342}α\u{300}α\u{301}ε\u{300}ε\u{301}η\u{300}η\u{301}ι\u{300}ι\u{301}ο\u{300}ο\u{301}υ\u{300}υ\u{301}ω\u{300}ω\u{301}α\u{313}\u{345}α\u{314}\u{345}α\u{313}\u{300}\u{345}α\u{314}\u{300}\u{345}α\u{313}\u{301}\u{345}α\u{314}\u{301}\u{345}α\u{313}\u{342}\u{345}α\u{314}\u{342}\u{345}Α\u{313}\u{345}Α\u{314}\u{345}Α\u{313}\u{300}\u{345}Α\u{314}\u{300}\u{345}Α\u{313}\u{301}\u{345}Α\u{314}\u{301}\u{345}Α\u{313}\u{342}\u{345}Α\u{314}\u{342}\u{345}η\u{313}\u{345}η\u{314}\u{345}η\u{313}\u{300}\u{345}η\u{314}\u{300}\u{345}η\u{313}\u{301}\u{345}η\u{314}\u{301}\u{345}η\u{313}\u{342}\u{345}η\u{314}\u{342}\u{345}Η\u{313}\u{345}Η\u{314}\u{345}Η\u{313}\u{300}\u{345}Η\u{314}\u{300}\u{345}Η\u{313}\u{301}\u{345}Η\u{314}\u{301}\u{345}Η\u{313}\u{342}\u{345}Η\u{314}\u{342}\u{345}ω\u{313}\u{345}ω\u{314}\u{345}ω\u{313}\u{300}\u{345}ω\u{314}\u{300}\u{345}ω\u{313}\u{301}\u{345}ω\u{314}\u{301}\u{345}ω\u{313}\u{342}\u{345}ω\u{314}\u{342}\u{345}Ω\u{313}\u{345}Ω\u{314}\u{345}Ω\u{313}\u{300}\u{345}Ω\u{314}\u{300}\u{345}Ω\u{313}\u{301}\u{345}Ω\u{314}\u{301}\u{345}Ω\u{313}\u{342}\u{345}Ω\u{314}\u{342}\u{345}α\u{306}α\u{304}α\u{300}\u{345}α\u{345}α\u{301}\u{345}α\u{342}α\u{342}\u{345}Α\u{306}Α\u{304}Α\u{300}Α\u{301}Α\u{345}ι¨\u{342}η\u{300}\u{345}η\u{345}η\u{301}\u{345}η\u{342}η\u{342}\u{345}Ε\u{300}Ε\u{301}Η\u{300}Η\u{301}Η\u{345}᾿\u{300}᾿\u{301}᾿\u{342}ι\u{306}ι\u{304}ι\u{308}\u{300}ι\u{308}\u{301}ι\u{342}ι\u{308}\u{342}Ι\u{306}Ι\u{304}Ι\u{300}Ι\u{301}῾\u{300}῾\u{301}῾\u{342}υ\u{306}υ\u{304}υ\u{308}\u{300}υ\u{308}\u{301}ρ\u{313}ρ\u{314}υ\u{342}υ\u{308}\u{342}Υ\u{306}Υ\u{304}Υ\u{300}Υ\u{301}Ρ\u{314}¨\u{300}¨\u{301}`ω\u{300}\u{345}ω\u{345}ω\u{301}\u{345}ω\u{342}ω\u{342}\u{345}Ο\u{300}Ο\u{301}Ω\u{300}Ω\u{301}Ω\u{345}´\u{2002}\u{2003}ΩKA\u{30A}←\u{338}→\u{338}↔\u{338}⇐\u{338}⇔\u{338}⇒\u{338}∃\u{338}∈\u{338}∋\u{338}∣\u{338}∥\u{338}∼\u{338}≃\u{338}≅\u{338}≈\u{338}=\u{338}≡\u{338}≍\u{338}<\u{338}>\u{338}≤\u{338}≥\u{338}≲\u{338}≳\u{338}≶\u{338}≷\u{338}≺\u{338}≻\u{338}⊂\u{338}⊃\u{338}⊆\u{338}⊇\u{338}⊢\u{338}⊨\u{338}⊩\u{338}⊫\u{338}≼\u{338}≽\u{338}⊑\u{338}⊒\u{338}⊲\u{338}⊳\u{338}⊴\u{338}⊵\u{338}〈〉⫝\u{338}か\u{3099}き\u{3099}く\u{3099}け\u{3099}こ\u{3099}さ\u{3099}し\u{3099}す\u{3099}せ\u{3099}そ\u{3099}た\u{3099}ち\u{3099}つ\u{3099}て\u{3099}と\u{3099}は\u{3099}は\u{309A}ひ\u{3099}ひ\u{309A}ふ\u{3099}ふ\u{309A}へ\u{3099}へ\u{309A}ほ\u{3099}ほ\u{309A}う\u{3099}ゝ\u{3099}カ\u{3099}キ\u{3099}ク\u{3099}ケ\u{3099}コ\u{3099}サ\u{3099}シ\u{3099}ス\u{3099}セ\u{3099}ソ\u{3099}タ\u{3099}チ\u{3099}ツ\u{3099}テ\u{3099}ト\u{3099}ハ\u{3099}ハ\u{309A}ヒ\u{3099}ヒ\u{309A}フ\u{3099}フ\u{309A}ヘ\u{3099}ヘ\u{309A}ホ\u{3099}ホ\u{309A}ウ\u{3099}ワ\u{3099}ヰ\u{3099}ヱ\u{3099}ヲ\u{3099}ヽ\u{3099}豈更車賈滑串句龜龜契金喇奈懶癩羅蘿螺裸邏樂洛烙珞落酪駱亂卵欄爛蘭鸞嵐濫藍襤拉臘蠟廊朗浪狼郎來冷勞擄櫓爐盧老蘆虜路露魯鷺碌祿綠菉錄鹿論壟弄籠聾牢磊賂雷壘屢樓淚漏累縷陋勒肋凜凌稜綾菱陵讀拏樂諾丹寧怒率異北磻便復不泌數索參塞省葉說殺辰沈拾若掠略亮兩凉梁糧良諒量勵呂女廬旅濾礪閭驪麗黎力曆歷轢年憐戀撚漣煉璉秊練聯輦蓮連鍊列劣咽烈裂說廉念捻殮簾獵令囹寧嶺怜玲瑩羚聆鈴零靈領例禮醴隸惡了僚寮尿料樂燎療蓼遼龍暈阮劉杻柳流溜琉留硫紐類六戮陸倫崙淪輪律慄栗率隆利吏履易李梨泥理痢罹裏裡里離匿溺吝燐璘藺隣鱗麟林淋臨立笠粒狀炙識什茶刺切度拓糖宅洞暴輻行降見廓兀嗀塚晴凞猪益礼神祥福靖精羽蘒諸逸都飯飼館鶴郞隷侮僧免勉勤卑喝嘆器塀墨層屮悔慨憎懲敏既暑梅海渚漢煮爫琢碑社祉祈祐祖祝禍禎穀突節練縉繁署者臭艹艹著褐視謁謹賓贈辶逸難響頻恵𤋮舘並况全侀充冀勇勺喝啕喙嗢塚墳奄奔婢嬨廒廙彩徭惘慎愈憎慠懲戴揄搜摒敖晴朗望杖歹殺流滛滋漢瀞煮瞧爵犯猪瑱甆画瘝瘟益盛直睊着磌窱節类絛練缾者荒華蝹襁覆視調諸請謁諾諭謹變贈輸遲醙鉶陼難靖韛響頋頻鬒龜𢡊𢡄𣏕㮝䀘䀹𥉉𥳐𧻓齃龎י\u{5B4}ײ\u{5B7}ש\u{5C1}ש\u{5C2}ש\u{5BC}\u{5C1}ש\u{5BC}\u{5C2}א\u{5B7}א\u{5B8}א\u{5BC}ב\u{5BC}ג\u{5BC}ד\u{5BC}ה\u{5BC}ו\u{5BC}ז\u{5BC}ט\u{5BC}י\u{5BC}ך\u{5BC}כ\u{5BC}ל\u{5BC}מ\u{5BC}נ\u{5BC}ס\u{5BC}ף\u{5BC}פ\u{5BC}צ\u{5BC}ק\u{5BC}ר\u{5BC}ש\u{5BC}ת\u{5BC}ו\u{5B9}ב\u{5BF}כ\u{5BF}פ\u{5BF}𑂙\u{110BA}𑂛\u{110BA}𑂥\u{110BA}\u{11131}\u{11127}\u{11132}\u{11127}\u{11347}\u{1133E}\u{11347}\u{11357}\u{114B9}\u{114BA}\u{114B9}\u{114B0}\u{114B9}\u{114BD}\u{115B8}\u{115AF}\u{115B9}\u{115AF}\u{11935}\u{11930}𝅗\u{1D165}𝅘\u{1D165}𝅘\u{1D165}\u{1D16E}𝅘\u{1D165}\u{1D16F}𝅘\u{1D165}\u{1D170}𝅘\u{1D165}\u{1D171}𝅘\u{1D165}\u{1D172}𝆹\u{1D165}𝆺\u{1D165}𝆹\u{1D165}\u{1D16E}𝆺\u{1D165}\u{1D16E}𝆹\u{1D165}\u{1D16F}𝆺\u{1D165}\u{1D16F}丽丸乁𠄢你侮侻倂偺備僧像㒞𠘺免兔兤具𠔜㒹內再𠕋冗冤仌冬况𩇟凵刃㓟刻剆割剷㔕勇勉勤勺包匆北卉卑博即卽卿卿卿𠨬灰及叟𠭣叫叱吆咞吸呈周咢哶唐啓啣善善喙喫喳嗂圖嘆圗噑噴切壮城埴堍型堲報墬𡓤売壷夆多夢奢𡚨𡛪姬娛娧姘婦㛮㛼嬈嬾嬾𡧈寃寘寧寳𡬘寿将当尢㞁屠屮峀岍𡷤嵃𡷦嵮嵫嵼巡巢㠯巽帨帽幩㡢𢆃㡼庰庳庶廊𪎒廾𢌱𢌱舁弢弢㣇𣊸𦇚形彫㣣徚忍志忹悁㤺㤜悔𢛔惇慈慌慎慌慺憎憲憤憯懞懲懶成戛扝抱拔捐𢬌挽拼捨掃揤𢯱搢揅掩㨮摩摾撝摷㩬敏敬𣀊旣書晉㬙暑㬈㫤冒冕最暜肭䏙朗望朡杞杓𣏃㭉柺枅桒梅𣑭梎栟椔㮝楂榣槪檨𣚣櫛㰘次𣢧歔㱎歲殟殺殻𣪍𡴋𣫺汎𣲼沿泍汧洖派海流浩浸涅𣴞洴港湮㴳滋滇𣻑淹潮𣽞𣾎濆瀹瀞瀛㶖灊災灷炭𠔥煅𤉣熜𤎫爨爵牐𤘈犀犕𤜵𤠔獺王㺬玥㺸㺸瑇瑜瑱璅瓊㼛甤𤰶甾𤲒異𢆟瘐𤾡𤾸𥁄㿼䀈直𥃳𥃲𥄙𥄳眞真真睊䀹瞋䁆䂖𥐝硎碌磌䃣𥘦祖𥚚𥛅福秫䄯穀穊穏𥥼𥪧𥪧竮䈂𥮫篆築䈧𥲀糒䊠糨糣紀𥾆絣䌁緇縂繅䌴𦈨𦉇䍙𦋙罺𦌾羕翺者𦓚𦔣聠𦖨聰𣍟䏕育脃䐋脾媵𦞧𦞵𣎓𣎜舁舄辞䑫芑芋芝劳花芳芽苦𦬼若茝荣莭茣莽菧著荓菊菌菜𦰶𦵫𦳕䔫蓱蓳蔖𧏊蕤𦼬䕝䕡𦾱𧃒䕫虐虜虧虩蚩蚈蜎蛢蝹蜨蝫螆䗗蟡蠁䗹衠衣𧙧裗裞䘵裺㒻𧢮𧥦䚾䛇誠諭變豕𧲨貫賁贛起𧼯𠠄跋趼跰𠣞軔輸𨗒𨗭邔郱鄑𨜮鄛鈸鋗鋘鉼鏹鐕𨯺開䦕閷𨵷䧦雃嶲霣𩅅𩈚䩮䩶韠𩐊䪲𩒖頋頋頩𩖶飢䬳餩馧駂駾䯎𩬰鬒鱀鳽䳎䳭鵧𪃎䳸𪄅𪈎𪊑麻䵖黹黾鼅鼏鼖鼻𪘀";
+
+pub const COMPOSITION: &[(u32, u32, u32)] = &[
+    (0x3C, 0x338, 0x226E), (0x3D, 0x338, 0x2260), (0x3E, 0x338, 0x226F), (0x41, 0x300, 0xC0),
+    (0x41, 0x301, 0xC1), (0x41, 0x302, 0xC2), (0x41, 0x303, 0xC3), (0x41, 0x304, 0x100),
+    (0x41, 0x306, 0x102), (0x41, 0x307, 0x226), (0x41, 0x308, 0xC4), (0x41, 0x309, 0x1EA2),
+    (0x41, 0x30A, 0xC5), (0x41, 0x30C, 0x1CD), (0x41, 0x30F, 0x200), (0x41, 0x311, 0x202),
+    (0x41, 0x323, 0x1EA0), (0x41, 0x325, 0x1E00), (0x41, 0x328, 0x104), (0x42, 0x307, 0x1E02),
+    (0x42, 0x323, 0x1E04), (0x42, 0x331, 0x1E06), (0x43, 0x301, 0x106), (0x43, 0x302, 0x108),
+    (0x43, 0x307, 0x10A), (0x43, 0x30C, 0x10C), (0x43, 0x327, 0xC7), (0x44, 0x307, 0x1E0A),
+    (0x44, 0x30C, 0x10E), (0x44, 0x323, 0x1E0C), (0x44, 0x327, 0x1E10), (0x44, 0x32D, 0x1E12),
+    (0x44, 0x331, 0x1E0E), (0x45, 0x300, 0xC8), (0x45, 0x301, 0xC9), (0x45, 0x302, 0xCA),
+    (0x45, 0x303, 0x1EBC), (0x45, 0x304, 0x112), (0x45, 0x306, 0x114), (0x45, 0x307, 0x116),
+    (0x45, 0x308, 0xCB), (0x45, 0x309, 0x1EBA), (0x45, 0x30C, 0x11A), (0x45, 0x30F, 0x204),
+    (0x45, 0x311, 0x206), (0x45, 0x323, 0x1EB8), (0x45, 0x327, 0x228), (0x45, 0x328, 0x118),
+    (0x45, 0x32D, 0x1E18), (0x45, 0x330, 0x1E1A), (0x46, 0x307, 0x1E1E), (0x47, 0x301, 0x1F4),
+    (0x47, 0x302, 0x11C), (0x47, 0x304, 0x1E20), (0x47, 0x306, 0x11E), (0x47, 0x307, 0x120),
+    (0x47, 0x30C, 0x1E6), (0x47, 0x327, 0x122), (0x48, 0x302, 0x124), (0x48, 0x307, 0x1E22),
+    (0x48, 0x308, 0x1E26), (0x48, 0x30C, 0x21E), (0x48, 0x323, 0x1E24), (0x48, 0x327, 0x1E28),
+    (0x48, 0x32E, 0x1E2A), (0x49, 0x300, 0xCC), (0x49, 0x301, 0xCD), (0x49, 0x302, 0xCE),
+    (0x49, 0x303, 0x128), (0x49, 0x304, 0x12A), (0x49, 0x306, 0x12C), (0x49, 0x307, 0x130),
+    (0x49, 0x308, 0xCF), (0x49, 0x309, 0x1EC8), (0x49, 0x30C, 0x1CF), (0x49, 0x30F, 0x208),
+    (0x49, 0x311, 0x20A), (0x49, 0x323, 0x1ECA), (0x49, 0x328, 0x12E), (0x49, 0x330, 0x1E2C),
+    (0x4A, 0x302, 0x134), (0x4B, 0x301, 0x1E30), (0x4B, 0x30C, 0x1E8), (0x4B, 0x323, 0x1E32),
+    (0x4B, 0x327, 0x136), (0x4B, 0x331, 0x1E34), (0x4C, 0x301, 0x139), (0x4C, 0x30C, 0x13D),
+    (0x4C, 0x323, 0x1E36), (0x4C, 0x327, 0x13B), (0x4C, 0x32D, 0x1E3C), (0x4C, 0x331, 0x1E3A),
+    (0x4D, 0x301, 0x1E3E), (0x4D, 0x307, 0x1E40), (0x4D, 0x323, 0x1E42), (0x4E, 0x300, 0x1F8),
+    (0x4E, 0x301, 0x143), (0x4E, 0x303, 0xD1), (0x4E, 0x307, 0x1E44), (0x4E, 0x30C, 0x147),
+    (0x4E, 0x323, 0x1E46), (0x4E, 0x327, 0x145), (0x4E, 0x32D, 0x1E4A), (0x4E, 0x331, 0x1E48),
+    (0x4F, 0x300, 0xD2), (0x4F, 0x301, 0xD3), (0x4F, 0x302, 0xD4), (0x4F, 0x303, 0xD5),
+    (0x4F, 0x304, 0x14C), (0x4F, 0x306, 0x14E), (0x4F, 0x307, 0x22E), (0x4F, 0x308, 0xD6),
+    (0x4F, 0x309, 0x1ECE), (0x4F, 0x30B, 0x150), (0x4F, 0x30C, 0x1D1), (0x4F, 0x30F, 0x20C),
+    (0x4F, 0x311, 0x20E), (0x4F, 0x31B, 0x1A0), (0x4F, 0x323, 0x1ECC), (0x4F, 0x328, 0x1EA),
+    (0x50, 0x301, 0x1E54), (0x50, 0x307, 0x1E56), (0x52, 0x301, 0x154), (0x52, 0x307, 0x1E58),
+    (0x52, 0x30C, 0x158), (0x52, 0x30F, 0x210), (0x52, 0x311, 0x212), (0x52, 0x323, 0x1E5A),
+    (0x52, 0x327, 0x156), (0x52, 0x331, 0x1E5E), (0x53, 0x301, 0x15A), (0x53, 0x302, 0x15C),
+    (0x53, 0x307, 0x1E60), (0x53, 0x30C, 0x160), (0x53, 0x323, 0x1E62), (0x53, 0x326, 0x218),
+    (0x53, 0x327, 0x15E), (0x54, 0x307, 0x1E6A), (0x54, 0x30C, 0x164), (0x54, 0x323, 0x1E6C),
+    (0x54, 0x326, 0x21A), (0x54, 0x327, 0x162), (0x54, 0x32D, 0x1E70), (0x54, 0x331, 0x1E6E),
+    (0x55, 0x300, 0xD9), (0x55, 0x301, 0xDA), (0x55, 0x302, 0xDB), (0x55, 0x303, 0x168),
+    (0x55, 0x304, 0x16A), (0x55, 0x306, 0x16C), (0x55, 0x308, 0xDC), (0x55, 0x309, 0x1EE6),
+    (0x55, 0x30A, 0x16E), (0x55, 0x30B, 0x170), (0x55, 0x30C, 0x1D3), (0x55, 0x30F, 0x214),
+    (0x55, 0x311, 0x216), (0x55, 0x31B, 0x1AF), (0x55, 0x323, 0x1EE4), (0x55, 0x324, 0x1E72),
+    (0x55, 0x328, 0x172), (0x55, 0x32D, 0x1E76), (0x55, 0x330, 0x1E74), (0x56, 0x303, 0x1E7C),
+    (0x56, 0x323, 0x1E7E), (0x57, 0x300, 0x1E80), (0x57, 0x301, 0x1E82), (0x57, 0x302, 0x174),
+    (0x57, 0x307, 0x1E86), (0x57, 0x308, 0x1E84), (0x57, 0x323, 0x1E88), (0x58, 0x307, 0x1E8A),
+    (0x58, 0x308, 0x1E8C), (0x59, 0x300, 0x1EF2), (0x59, 0x301, 0xDD), (0x59, 0x302, 0x176),
+    (0x59, 0x303, 0x1EF8), (0x59, 0x304, 0x232), (0x59, 0x307, 0x1E8E), (0x59, 0x308, 0x178),
+    (0x59, 0x309, 0x1EF6), (0x59, 0x323, 0x1EF4), (0x5A, 0x301, 0x179), (0x5A, 0x302, 0x1E90),
+    (0x5A, 0x307, 0x17B), (0x5A, 0x30C, 0x17D), (0x5A, 0x323, 0x1E92), (0x5A, 0x331, 0x1E94),
+    (0x61, 0x300, 0xE0), (0x61, 0x301, 0xE1), (0x61, 0x302, 0xE2), (0x61, 0x303, 0xE3),
+    (0x61, 0x304, 0x101), (0x61, 0x306, 0x103), (0x61, 0x307, 0x227), (0x61, 0x308, 0xE4),
+    (0x61, 0x309, 0x1EA3), (0x61, 0x30A, 0xE5), (0x61, 0x30C, 0x1CE), (0x61, 0x30F, 0x201),
+    (0x61, 0x311, 0x203), (0x61, 0x323, 0x1EA1), (0x61, 0x325, 0x1E01), (0x61, 0x328, 0x105),
+    (0x62, 0x307, 0x1E03), (0x62, 0x323, 0x1E05), (0x62, 0x331, 0x1E07), (0x63, 0x301, 0x107),
+    (0x63, 0x302, 0x109), (0x63, 0x307, 0x10B), (0x63, 0x30C, 0x10D), (0x63, 0x327, 0xE7),
+    (0x64, 0x307, 0x1E0B), (0x64, 0x30C, 0x10F), (0x64, 0x323, 0x1E0D), (0x64, 0x327, 0x1E11),
+    (0x64, 0x32D, 0x1E13), (0x64, 0x331, 0x1E0F), (0x65, 0x300, 0xE8), (0x65, 0x301, 0xE9),
+    (0x65, 0x302, 0xEA), (0x65, 0x303, 0x1EBD), (0x65, 0x304, 0x113), (0x65, 0x306, 0x115),
+    (0x65, 0x307, 0x117), (0x65, 0x308, 0xEB), (0x65, 0x309, 0x1EBB), (0x65, 0x30C, 0x11B),
+    (0x65, 0x30F, 0x205), (0x65, 0x311, 0x207), (0x65, 0x323, 0x1EB9), (0x65, 0x327, 0x229),
+    (0x65, 0x328, 0x119), (0x65, 0x32D, 0x1E19), (0x65, 0x330, 0x1E1B), (0x66, 0x307, 0x1E1F),
+    (0x67, 0x301, 0x1F5), (0x67, 0x302, 0x11D), (0x67, 0x304, 0x1E21), (0x67, 0x306, 0x11F),
+    (0x67, 0x307, 0x121), (0x67, 0x30C, 0x1E7), (0x67, 0x327, 0x123), (0x68, 0x302, 0x125),
+    (0x68, 0x307, 0x1E23), (0x68, 0x308, 0x1E27), (0x68, 0x30C, 0x21F), (0x68, 0x323, 0x1E25),
+    (0x68, 0x327, 0x1E29), (0x68, 0x32E, 0x1E2B), (0x68, 0x331, 0x1E96), (0x69, 0x300, 0xEC),
+    (0x69, 0x301, 0xED), (0x69, 0x302, 0xEE), (0x69, 0x303, 0x129), (0x69, 0x304, 0x12B),
+    (0x69, 0x306, 0x12D), (0x69, 0x308, 0xEF), (0x69, 0x309, 0x1EC9), (0x69, 0x30C, 0x1D0),
+    (0x69, 0x30F, 0x209), (0x69, 0x311, 0x20B), (0x69, 0x323, 0x1ECB), (0x69, 0x328, 0x12F),
+    (0x69, 0x330, 0x1E2D), (0x6A, 0x302, 0x135), (0x6A, 0x30C, 0x1F0), (0x6B, 0x301, 0x1E31),
+    (0x6B, 0x30C, 0x1E9), (0x6B, 0x323, 0x1E33), (0x6B, 0x327, 0x137), (0x6B, 0x331, 0x1E35),
+    (0x6C, 0x301, 0x13A), (0x6C, 0x30C, 0x13E), (0x6C, 0x323, 0x1E37), (0x6C, 0x327, 0x13C),
+    (0x6C, 0x32D, 0x1E3D), (0x6C, 0x331, 0x1E3B), (0x6D, 0x301, 0x1E3F), (0x6D, 0x307, 0x1E41),
+    (0x6D, 0x323, 0x1E43), (0x6E, 0x300, 0x1F9), (0x6E, 0x301, 0x144), (0x6E, 0x303, 0xF1),
+    (0x6E, 0x307, 0x1E45), (0x6E, 0x30C, 0x148), (0x6E, 0x323, 0x1E47), (0x6E, 0x327, 0x146),
+    (0x6E, 0x32D, 0x1E4B), (0x6E, 0x331, 0x1E49), (0x6F, 0x300, 0xF2), (0x6F, 0x301, 0xF3),
+    (0x6F, 0x302, 0xF4), (0x6F, 0x303, 0xF5), (0x6F, 0x304, 0x14D), (0x6F, 0x306, 0x14F),
+    (0x6F, 0x307, 0x22F), (0x6F, 0x308, 0xF6), (0x6F, 0x309, 0x1ECF), (0x6F, 0x30B, 0x151),
+    (0x6F, 0x30C, 0x1D2), (0x6F, 0x30F, 0x20D), (0x6F, 0x311, 0x20F), (0x6F, 0x31B, 0x1A1),
+    (0x6F, 0x323, 0x1ECD), (0x6F, 0x328, 0x1EB), (0x70, 0x301, 0x1E55), (0x70, 0x307, 0x1E57),
+    (0x72, 0x301, 0x155), (0x72, 0x307, 0x1E59), (0x72, 0x30C, 0x159), (0x72, 0x30F, 0x211),
+    (0x72, 0x311, 0x213), (0x72, 0x323, 0x1E5B), (0x72, 0x327, 0x157), (0x72, 0x331, 0x1E5F),
+    (0x73, 0x301, 0x15B), (0x73, 0x302, 0x15D), (0x73, 0x307, 0x1E61), (0x73, 0x30C, 0x161),
+    (0x73, 0x323, 0x1E63), (0x73, 0x326, 0x219), (0x73, 0x327, 0x15F), (0x74, 0x307, 0x1E6B),
+    (0x74, 0x308, 0x1E97), (0x74, 0x30C, 0x165), (0x74, 0x323, 0x1E6D), (0x74, 0x326, 0x21B),
+    (0x74, 0x327, 0x163), (0x74, 0x32D, 0x1E71), (0x74, 0x331, 0x1E6F), (0x75, 0x300, 0xF9),
+    (0x75, 0x301, 0xFA), (0x75, 0x302, 0xFB), (0x75, 0x303, 0x169), (0x75, 0x304, 0x16B),
+    (0x75, 0x306, 0x16D), (0x75, 0x308, 0xFC), (0x75, 0x309, 0x1EE7), (0x75, 0x30A, 0x16F),
+    (0x75, 0x30B, 0x171), (0x75, 0x30C, 0x1D4), (0x75, 0x30F, 0x215), (0x75, 0x311, 0x217),
+    (0x75, 0x31B, 0x1B0), (0x75, 0x323, 0x1EE5), (0x75, 0x324, 0x1E73), (0x75, 0x328, 0x173),
+    (0x75, 0x32D, 0x1E77), (0x75, 0x330, 0x1E75), (0x76, 0x303, 0x1E7D), (0x76, 0x323, 0x1E7F),
+    (0x77, 0x300, 0x1E81), (0x77, 0x301, 0x1E83), (0x77, 0x302, 0x175), (0x77, 0x307, 0x1E87),
+    (0x77, 0x308, 0x1E85), (0x77, 0x30A, 0x1E98), (0x77, 0x323, 0x1E89), (0x78, 0x307, 0x1E8B),
+    (0x78, 0x308, 0x1E8D), (0x79, 0x300, 0x1EF3), (0x79, 0x301, 0xFD), (0x79, 0x302, 0x177),
+    (0x79, 0x303, 0x1EF9), (0x79, 0x304, 0x233), (0x79, 0x307, 0x1E8F), (0x79, 0x308, 0xFF),
+    (0x79, 0x309, 0x1EF7), (0x79, 0x30A, 0x1E99), (0x79, 0x323, 0x1EF5), (0x7A, 0x301, 0x17A),
+    (0x7A, 0x302, 0x1E91), (0x7A, 0x307, 0x17C), (0x7A, 0x30C, 0x17E), (0x7A, 0x323, 0x1E93),
+    (0x7A, 0x331, 0x1E95), (0xA8, 0x300, 0x1FED), (0xA8, 0x301, 0x385), (0xA8, 0x342, 0x1FC1),
+    (0xC2, 0x300, 0x1EA6), (0xC2, 0x301, 0x1EA4), (0xC2, 0x303, 0x1EAA), (0xC2, 0x309, 0x1EA8),
+    (0xC4, 0x304, 0x1DE), (0xC5, 0x301, 0x1FA), (0xC6, 0x301, 0x1FC), (0xC6, 0x304, 0x1E2),
+    (0xC7, 0x301, 0x1E08), (0xCA, 0x300, 0x1EC0), (0xCA, 0x301, 0x1EBE), (0xCA, 0x303, 0x1EC4),
+    (0xCA, 0x309, 0x1EC2), (0xCF, 0x301, 0x1E2E), (0xD4, 0x300, 0x1ED2), (0xD4, 0x301, 0x1ED0),
+    (0xD4, 0x303, 0x1ED6), (0xD4, 0x309, 0x1ED4), (0xD5, 0x301, 0x1E4C), (0xD5, 0x304, 0x22C),
+    (0xD5, 0x308, 0x1E4E), (0xD6, 0x304, 0x22A), (0xD8, 0x301, 0x1FE), (0xDC, 0x300, 0x1DB),
+    (0xDC, 0x301, 0x1D7), (0xDC, 0x304, 0x1D5), (0xDC, 0x30C, 0x1D9), (0xE2, 0x300, 0x1EA7),
+    (0xE2, 0x301, 0x1EA5), (0xE2, 0x303, 0x1EAB), (0xE2, 0x309, 0x1EA9), (0xE4, 0x304, 0x1DF),
+    (0xE5, 0x301, 0x1FB), (0xE6, 0x301, 0x1FD), (0xE6, 0x304, 0x1E3), (0xE7, 0x301, 0x1E09),
+    (0xEA, 0x300, 0x1EC1), (0xEA, 0x301, 0x1EBF), (0xEA, 0x303, 0x1EC5), (0xEA, 0x309, 0x1EC3),
+    (0xEF, 0x301, 0x1E2F), (0xF4, 0x300, 0x1ED3), (0xF4, 0x301, 0x1ED1), (0xF4, 0x303, 0x1ED7),
+    (0xF4, 0x309, 0x1ED5), (0xF5, 0x301, 0x1E4D), (0xF5, 0x304, 0x22D), (0xF5, 0x308, 0x1E4F),
+    (0xF6, 0x304, 0x22B), (0xF8, 0x301, 0x1FF), (0xFC, 0x300, 0x1DC), (0xFC, 0x301, 0x1D8),
+    (0xFC, 0x304, 0x1D6), (0xFC, 0x30C, 0x1DA), (0x102, 0x300, 0x1EB0), (0x102, 0x301, 0x1EAE),
+    (0x102, 0x303, 0x1EB4), (0x102, 0x309, 0x1EB2), (0x103, 0x300, 0x1EB1), (0x103, 0x301, 0x1EAF),
+    (0x103, 0x303, 0x1EB5), (0x103, 0x309, 0x1EB3), (0x112, 0x300, 0x1E14), (0x112, 0x301, 0x1E16),
+    (0x113, 0x300, 0x1E15), (0x113, 0x301, 0x1E17), (0x14C, 0x300, 0x1E50), (0x14C, 0x301, 0x1E52),
+    (0x14D, 0x300, 0x1E51), (0x14D, 0x301, 0x1E53), (0x15A, 0x307, 0x1E64), (0x15B, 0x307, 0x1E65),
+    (0x160, 0x307, 0x1E66), (0x161, 0x307, 0x1E67), (0x168, 0x301, 0x1E78), (0x169, 0x301, 0x1E79),
+    (0x16A, 0x308, 0x1E7A), (0x16B, 0x308, 0x1E7B), (0x17F, 0x307, 0x1E9B), (0x1A0, 0x300, 0x1EDC),
+    (0x1A0, 0x301, 0x1EDA), (0x1A0, 0x303, 0x1EE0), (0x1A0, 0x309, 0x1EDE), (0x1A0, 0x323, 0x1EE2),
+    (0x1A1, 0x300, 0x1EDD), (0x1A1, 0x301, 0x1EDB), (0x1A1, 0x303, 0x1EE1), (0x1A1, 0x309, 0x1EDF),
+    (0x1A1, 0x323, 0x1EE3), (0x1AF, 0x300, 0x1EEA), (0x1AF, 0x301, 0x1EE8), (0x1AF, 0x303, 0x1EEE),
+    (0x1AF, 0x309, 0x1EEC), (0x1AF, 0x323, 0x1EF0), (0x1B0, 0x300, 0x1EEB), (0x1B0, 0x301, 0x1EE9),
+    (0x1B0, 0x303, 0x1EEF), (0x1B0, 0x309, 0x1EED), (0x1B0, 0x323, 0x1EF1), (0x1B7, 0x30C, 0x1EE),
+    (0x1EA, 0x304, 0x1EC), (0x1EB, 0x304, 0x1ED), (0x226, 0x304, 0x1E0), (0x227, 0x304, 0x1E1),
+    (0x228, 0x306, 0x1E1C), (0x229, 0x306, 0x1E1D), (0x22E, 0x304, 0x230), (0x22F, 0x304, 0x231),
+    (0x292, 0x30C, 0x1EF), (0x391, 0x300, 0x1FBA), (0x391, 0x301, 0x386), (0x391, 0x304, 0x1FB9),
+    (0x391, 0x306, 0x1FB8), (0x391, 0x313, 0x1F08), (0x391, 0x314, 0x1F09), (0x391, 0x345, 0x1FBC),
+    (0x395, 0x300, 0x1FC8), (0x395, 0x301, 0x388), (0x395, 0x313, 0x1F18), (0x395, 0x314, 0x1F19),
+    (0x397, 0x300, 0x1FCA), (0x397, 0x301, 0x389), (0x397, 0x313, 0x1F28), (0x397, 0x314, 0x1F29),
+    (0x397, 0x345, 0x1FCC), (0x399, 0x300, 0x1FDA), (0x399, 0x301, 0x38A), (0x399, 0x304, 0x1FD9),
+    (0x399, 0x306, 0x1FD8), (0x399, 0x308, 0x3AA), (0x399, 0x313, 0x1F38), (0x399, 0x314, 0x1F39),
+    (0x39F, 0x300, 0x1FF8), (0x39F, 0x301, 0x38C), (0x39F, 0x313, 0x1F48), (0x39F, 0x314, 0x1F49),
+    (0x3A1, 0x314, 0x1FEC), (0x3A5, 0x300, 0x1FEA), (0x3A5, 0x301, 0x38E), (0x3A5, 0x304, 0x1FE9),
+    (0x3A5, 0x306, 0x1FE8), (0x3A5, 0x308, 0x3AB), (0x3A5, 0x314, 0x1F59), (0x3A9, 0x300, 0x1FFA),
+    (0x3A9, 0x301, 0x38F), (0x3A9, 0x313, 0x1F68), (0x3A9, 0x314, 0x1F69), (0x3A9, 0x345, 0x1FFC),
+    (0x3AC, 0x345, 0x1FB4), (0x3AE, 0x345, 0x1FC4), (0x3B1, 0x300, 0x1F70), (0x3B1, 0x301, 0x3AC),
+    (0x3B1, 0x304, 0x1FB1), (0x3B1, 0x306, 0x1FB0), (0x3B1, 0x313, 0x1F00), (0x3B1, 0x314, 0x1F01),
+    (0x3B1, 0x342, 0x1FB6), (0x3B1, 0x345, 0x1FB3), (0x3B5, 0x300, 0x1F72), (0x3B5, 0x301, 0x3AD),
+    (0x3B5, 0x313, 0x1F10), (0x3B5, 0x314, 0x1F11), (0x3B7, 0x300, 0x1F74), (0x3B7, 0x301, 0x3AE),
+    (0x3B7, 0x313, 0x1F20), (0x3B7, 0x314, 0x1F21), (0x3B7, 0x342, 0x1FC6), (0x3B7, 0x345, 0x1FC3),
+    (0x3B9, 0x300, 0x1F76), (0x3B9, 0x301, 0x3AF), (0x3B9, 0x304, 0x1FD1), (0x3B9, 0x306, 0x1FD0),
+    (0x3B9, 0x308, 0x3CA), (0x3B9, 0x313, 0x1F30), (0x3B9, 0x314, 0x1F31), (0x3B9, 0x342, 0x1FD6),
+    (0x3BF, 0x300, 0x1F78), (0x3BF, 0x301, 0x3CC), (0x3BF, 0x313, 0x1F40), (0x3BF, 0x314, 0x1F41),
+    (0x3C1, 0x313, 0x1FE4), (0x3C1, 0x314, 0x1FE5), (0x3C5, 0x300, 0x1F7A), (0x3C5, 0x301, 0x3CD),
+    (0x3C5, 0x304, 0x1FE1), (0x3C5, 0x306, 0x1FE0), (0x3C5, 0x308, 0x3CB), (0x3C5, 0x313, 0x1F50),
+    (0x3C5, 0x314, 0x1F51), (0x3C5, 0x342, 0x1FE6), (0x3C9, 0x300, 0x1F7C), (0x3C9, 0x301, 0x3CE),
+    (0x3C9, 0x313, 0x1F60), (0x3C9, 0x314, 0x1F61), (0x3C9, 0x342, 0x1FF6), (0x3C9, 0x345, 0x1FF3),
+    (0x3CA, 0x300, 0x1FD2), (0x3CA, 0x301, 0x390), (0x3CA, 0x342, 0x1FD7), (0x3CB, 0x300, 0x1FE2),
+    (0x3CB, 0x301, 0x3B0), (0x3CB, 0x342, 0x1FE7), (0x3CE, 0x345, 0x1FF4), (0x3D2, 0x301, 0x3D3),
+    (0x3D2, 0x308, 0x3D4), (0x406, 0x308, 0x407), (0x410, 0x306, 0x4D0), (0x410, 0x308, 0x4D2),
+    (0x413, 0x301, 0x403), (0x415, 0x300, 0x400), (0x415, 0x306, 0x4D6), (0x415, 0x308, 0x401),
+    (0x416, 0x306, 0x4C1), (0x416, 0x308, 0x4DC), (0x417, 0x308, 0x4DE), (0x418, 0x300, 0x40D),
+    (0x418, 0x304, 0x4E2), (0x418, 0x306, 0x419), (0x418, 0x308, 0x4E4), (0x41A, 0x301, 0x40C),
+    (0x41E, 0x308, 0x4E6), (0x423, 0x304, 0x4EE), (0x423, 0x306, 0x40E), (0x423, 0x308, 0x4F0),
+    (0x423, 0x30B, 0x4F2), (0x427, 0x308, 0x4F4), (0x42B, 0x308, 0x4F8), (0x42D, 0x308, 0x4EC),
+    (0x430, 0x306, 0x4D1), (0x430, 0x308, 0x4D3), (0x433, 0x301, 0x453), (0x435, 0x300, 0x450),
+    (0x435, 0x306, 0x4D7), (0x435, 0x308, 0x451), (0x436, 0x306, 0x4C2), (0x436, 0x308, 0x4DD),
+    (0x437, 0x308, 0x4DF), (0x438, 0x300, 0x45D), (0x438, 0x304, 0x4E3), (0x438, 0x306, 0x439),
+    (0x438, 0x308, 0x4E5), (0x43A, 0x301, 0x45C), (0x43E, 0x308, 0x4E7), (0x443, 0x304, 0x4EF),
+    (0x443, 0x306, 0x45E), (0x443, 0x308, 0x4F1), (0x443, 0x30B, 0x4F3), (0x447, 0x308, 0x4F5),
+    (0x44B, 0x308, 0x4F9), (0x44D, 0x308, 0x4ED), (0x456, 0x308, 0x457), (0x474, 0x30F, 0x476),
+    (0x475, 0x30F, 0x477), (0x4D8, 0x308, 0x4DA), (0x4D9, 0x308, 0x4DB), (0x4E8, 0x308, 0x4EA),
+    (0x4E9, 0x308, 0x4EB), (0x627, 0x653, 0x622), (0x627, 0x654, 0x623), (0x627, 0x655, 0x625),
+    (0x648, 0x654, 0x624), (0x64A, 0x654, 0x626), (0x6C1, 0x654, 0x6C2), (0x6D2, 0x654, 0x6D3),
+    (0x6D5, 0x654, 0x6C0), (0x928, 0x93C, 0x929), (0x930, 0x93C, 0x931), (0x933, 0x93C, 0x934),
+    (0x9C7, 0x9BE, 0x9CB), (0x9C7, 0x9D7, 0x9CC), (0xB47, 0xB3E, 0xB4B), (0xB47, 0xB56, 0xB48),
+    (0xB47, 0xB57, 0xB4C), (0xB92, 0xBD7, 0xB94), (0xBC6, 0xBBE, 0xBCA), (0xBC6, 0xBD7, 0xBCC),
+    (0xBC7, 0xBBE, 0xBCB), (0xC46, 0xC56, 0xC48), (0xCBF, 0xCD5, 0xCC0), (0xCC6, 0xCC2, 0xCCA),
+    (0xCC6, 0xCD5, 0xCC7), (0xCC6, 0xCD6, 0xCC8), (0xCCA, 0xCD5, 0xCCB), (0xD46, 0xD3E, 0xD4A),
+    (0xD46, 0xD57, 0xD4C), (0xD47, 0xD3E, 0xD4B), (0xDD9, 0xDCA, 0xDDA), (0xDD9, 0xDCF, 0xDDC),
+    (0xDD9, 0xDDF, 0xDDE), (0xDDC, 0xDCA, 0xDDD), (0x1025, 0x102E, 0x1026), (0x1B05, 0x1B35, 0x1B06),
+    (0x1B07, 0x1B35, 0x1B08), (0x1B09, 0x1B35, 0x1B0A), (0x1B0B, 0x1B35, 0x1B0C), (0x1B0D, 0x1B35, 0x1B0E),
+    (0x1B11, 0x1B35, 0x1B12), (0x1B3A, 0x1B35, 0x1B3B), (0x1B3C, 0x1B35, 0x1B3D), (0x1B3E, 0x1B35, 0x1B40),
+    (0x1B3F, 0x1B35, 0x1B41), (0x1B42, 0x1B35, 0x1B43), (0x1E36, 0x304, 0x1E38), (0x1E37, 0x304, 0x1E39),
+    (0x1E5A, 0x304, 0x1E5C), (0x1E5B, 0x304, 0x1E5D), (0x1E62, 0x307, 0x1E68), (0x1E63, 0x307, 0x1E69),
+    (0x1EA0, 0x302, 0x1EAC), (0x1EA0, 0x306, 0x1EB6), (0x1EA1, 0x302, 0x1EAD), (0x1EA1, 0x306, 0x1EB7),
+    (0x1EB8, 0x302, 0x1EC6), (0x1EB9, 0x302, 0x1EC7), (0x1ECC, 0x302, 0x1ED8), (0x1ECD, 0x302, 0x1ED9),
+    (0x1F00, 0x300, 0x1F02), (0x1F00, 0x301, 0x1F04), (0x1F00, 0x342, 0x1F06), (0x1F00, 0x345, 0x1F80),
+    (0x1F01, 0x300, 0x1F03), (0x1F01, 0x301, 0x1F05), (0x1F01, 0x342, 0x1F07), (0x1F01, 0x345, 0x1F81),
+    (0x1F02, 0x345, 0x1F82), (0x1F03, 0x345, 0x1F83), (0x1F04, 0x345, 0x1F84), (0x1F05, 0x345, 0x1F85),
+    (0x1F06, 0x345, 0x1F86), (0x1F07, 0x345, 0x1F87), (0x1F08, 0x300, 0x1F0A), (0x1F08, 0x301, 0x1F0C),
+    (0x1F08, 0x342, 0x1F0E), (0x1F08, 0x345, 0x1F88), (0x1F09, 0x300, 0x1F0B), (0x1F09, 0x301, 0x1F0D),
+    (0x1F09, 0x342, 0x1F0F), (0x1F09, 0x345, 0x1F89), (0x1F0A, 0x345, 0x1F8A), (0x1F0B, 0x345, 0x1F8B),
+    (0x1F0C, 0x345, 0x1F8C), (0x1F0D, 0x345, 0x1F8D), (0x1F0E, 0x345, 0x1F8E), (0x1F0F, 0x345, 0x1F8F),
+    (0x1F10, 0x300, 0x1F12), (0x1F10, 0x301, 0x1F14), (0x1F11, 0x300, 0x1F13), (0x1F11, 0x301, 0x1F15),
+    (0x1F18, 0x300, 0x1F1A), (0x1F18, 0x301, 0x1F1C), (0x1F19, 0x300, 0x1F1B), (0x1F19, 0x301, 0x1F1D),
+    (0x1F20, 0x300, 0x1F22), (0x1F20, 0x301, 0x1F24), (0x1F20, 0x342, 0x1F26), (0x1F20, 0x345, 0x1F90),
+    (0x1F21, 0x300, 0x1F23), (0x1F21, 0x301, 0x1F25), (0x1F21, 0x342, 0x1F27), (0x1F21, 0x345, 0x1F91),
+    (0x1F22, 0x345, 0x1F92), (0x1F23, 0x345, 0x1F93), (0x1F24, 0x345, 0x1F94), (0x1F25, 0x345, 0x1F95),
+    (0x1F26, 0x345, 0x1F96), (0x1F27, 0x345, 0x1F97), (0x1F28, 0x300, 0x1F2A), (0x1F28, 0x301, 0x1F2C),
+    (0x1F28, 0x342, 0x1F2E), (0x1F28, 0x345, 0x1F98), (0x1F29, 0x300, 0x1F2B), (0x1F29, 0x301, 0x1F2D),
+    (0x1F29, 0x342, 0x1F2F), (0x1F29, 0x345, 0x1F99), (0x1F2A, 0x345, 0x1F9A), (0x1F2B, 0x345, 0x1F9B),
+    (0x1F2C, 0x345, 0x1F9C), (0x1F2D, 0x345, 0x1F9D), (0x1F2E, 0x345, 0x1F9E), (0x1F2F, 0x345, 0x1F9F),
+    (0x1F30, 0x300, 0x1F32), (0x1F30, 0x301, 0x1F34), (0x1F30, 0x342, 0x1F36), (0x1F31, 0x300, 0x1F33),
+    (0x1F31, 0x301, 0x1F35), (0x1F31, 0x342, 0x1F37), (0x1F38, 0x300, 0x1F3A), (0x1F38, 0x301, 0x1F3C),
+    (0x1F38, 0x342, 0x1F3E), (0x1F39, 0x300, 0x1F3B), (0x1F39, 0x301, 0x1F3D), (0x1F39, 0x342, 0x1F3F),
+    (0x1F40, 0x300, 0x1F42), (0x1F40, 0x301, 0x1F44), (0x1F41, 0x300, 0x1F43), (0x1F41, 0x301, 0x1F45),
+    (0x1F48, 0x300, 0x1F4A), (0x1F48, 0x301, 0x1F4C), (0x1F49, 0x300, 0x1F4B), (0x1F49, 0x301, 0x1F4D),
+    (0x1F50, 0x300, 0x1F52), (0x1F50, 0x301, 0x1F54), (0x1F50, 0x342, 0x1F56), (0x1F51, 0x300, 0x1F53),
+    (0x1F51, 0x301, 0x1F55), (0x1F51, 0x342, 0x1F57), (0x1F59, 0x300, 0x1F5B), (0x1F59, 0x301, 0x1F5D),
+    (0x1F59, 0x342, 0x1F5F), (0x1F60, 0x300, 0x1F62), (0x1F60, 0x301, 0x1F64), (0x1F60, 0x342, 0x1F66),
+    (0x1F60, 0x345, 0x1FA0), (0x1F61, 0x300, 0x1F63), (0x1F61, 0x301, 0x1F65), (0x1F61, 0x342, 0x1F67),
+    (0x1F61, 0x345, 0x1FA1), (0x1F62, 0x345, 0x1FA2), (0x1F63, 0x345, 0x1FA3), (0x1F64, 0x345, 0x1FA4),
+    (0x1F65, 0x345, 0x1FA5), (0x1F66, 0x345, 0x1FA6), (0x1F67, 0x345, 0x1FA7), (0x1F68, 0x300, 0x1F6A),
+    (0x1F68, 0x301, 0x1F6C), (0x1F68, 0x342, 0x1F6E), (0x1F68, 0x345, 0x1FA8), (0x1F69, 0x300, 0x1F6B),
+    (0x1F69, 0x301, 0x1F6D), (0x1F69, 0x342, 0x1F6F), (0x1F69, 0x345, 0x1FA9), (0x1F6A, 0x345, 0x1FAA),
+    (0x1F6B, 0x345, 0x1FAB), (0x1F6C, 0x345, 0x1FAC), (0x1F6D, 0x345, 0x1FAD), (0x1F6E, 0x345, 0x1FAE),
+    (0x1F6F, 0x345, 0x1FAF), (0x1F70, 0x345, 0x1FB2), (0x1F74, 0x345, 0x1FC2), (0x1F7C, 0x345, 0x1FF2),
+    (0x1FB6, 0x345, 0x1FB7), (0x1FBF, 0x300, 0x1FCD), (0x1FBF, 0x301, 0x1FCE), (0x1FBF, 0x342, 0x1FCF),
+    (0x1FC6, 0x345, 0x1FC7), (0x1FF6, 0x345, 0x1FF7), (0x1FFE, 0x300, 0x1FDD), (0x1FFE, 0x301, 0x1FDE),
+    (0x1FFE, 0x342, 0x1FDF), (0x2190, 0x338, 0x219A), (0x2192, 0x338, 0x219B), (0x2194, 0x338, 0x21AE),
+    (0x21D0, 0x338, 0x21CD), (0x21D2, 0x338, 0x21CF), (0x21D4, 0x338, 0x21CE), (0x2203, 0x338, 0x2204),
+    (0x2208, 0x338, 0x2209), (0x220B, 0x338, 0x220C), (0x2223, 0x338, 0x2224), (0x2225, 0x338, 0x2226),
+    (0x223C, 0x338, 0x2241), (0x2243, 0x338, 0x2244), (0x2245, 0x338, 0x2247), (0x2248, 0x338, 0x2249),
+    (0x224D, 0x338, 0x226D), (0x2261, 0x338, 0x2262), (0x2264, 0x338, 0x2270), (0x2265, 0x338, 0x2271),
+    (0x2272, 0x338, 0x2274), (0x2273, 0x338, 0x2275), (0x2276, 0x338, 0x2278), (0x2277, 0x338, 0x2279),
+    (0x227A, 0x338, 0x2280), (0x227B, 0x338, 0x2281), (0x227C, 0x338, 0x22E0), (0x227D, 0x338, 0x22E1),
+    (0x2282, 0x338, 0x2284), (0x2283, 0x338, 0x2285), (0x2286, 0x338, 0x2288), (0x2287, 0x338, 0x2289),
+    (0x2291, 0x338, 0x22E2), (0x2292, 0x338, 0x22E3), (0x22A2, 0x338, 0x22AC), (0x22A8, 0x338, 0x22AD),
+    (0x22A9, 0x338, 0x22AE), (0x22AB, 0x338, 0x22AF), (0x22B2, 0x338, 0x22EA), (0x22B3, 0x338, 0x22EB),
+    (0x22B4, 0x338, 0x22EC), (0x22B5, 0x338, 0x22ED), (0x3046, 0x3099, 0x3094), (0x304B, 0x3099, 0x304C),
+    (0x304D, 0x3099, 0x304E), (0x304F, 0x3099, 0x3050), (0x3051, 0x3099, 0x3052), (0x3053, 0x3099, 0x3054),
+    (0x3055, 0x3099, 0x3056), (0x3057, 0x3099, 0x3058), (0x3059, 0x3099, 0x305A), (0x305B, 0x3099, 0x305C),
+    (0x305D, 0x3099, 0x305E), (0x305F, 0x3099, 0x3060), (0x3061, 0x3099, 0x3062), (0x3064, 0x3099, 0x3065),
+    (0x3066, 0x3099, 0x3067), (0x3068, 0x3099, 0x3069), (0x306F, 0x3099, 0x3070), (0x306F, 0x309A, 0x3071),
+    (0x3072, 0x3099, 0x3073), (0x3072, 0x309A, 0x3074), (0x3075, 0x3099, 0x3076), (0x3075, 0x309A, 0x3077),
+    (0x3078, 0x3099, 0x3079), (0x3078, 0x309A, 0x307A), (0x307B, 0x3099, 0x307C), (0x307B, 0x309A, 0x307D),
+    (0x309D, 0x3099, 0x309E), (0x30A6, 0x3099, 0x30F4), (0x30AB, 0x3099, 0x30AC), (0x30AD, 0x3099, 0x30AE),
+    (0x30AF, 0x3099, 0x30B0), (0x30B1, 0x3099, 0x30B2), (0x30B3, 0x3099, 0x30B4), (0x30B5, 0x3099, 0x30B6),
+    (0x30B7, 0x3099, 0x30B8), (0x30B9, 0x3099, 0x30BA), (0x30BB, 0x3099, 0x30BC), (0x30BD, 0x3099, 0x30BE),
+    (0x30BF, 0x3099, 0x30C0), (0x30C1, 0x3099, 0x30C2), (0x30C4, 0x3099, 0x30C5), (0x30C6, 0x3099, 0x30C7),
+    (0x30C8, 0x3099, 0x30C9), (0x30CF, 0x3099, 0x30D0), (0x30CF, 0x309A, 0x30D1), (0x30D2, 0x3099, 0x30D3),
+    (0x30D2, 0x309A, 0x30D4), (0x30D5, 0x3099, 0x30D6), (0x30D5, 0x309A, 0x30D7), (0x30D8, 0x3099, 0x30D9),
+    (0x30D8, 0x309A, 0x30DA), (0x30DB, 0x3099, 0x30DC), (0x30DB, 0x309A, 0x30DD), (0x30EF, 0x3099, 0x30F7),
+    (0x30F0, 0x3099, 0x30F8), (0x30F1, 0x3099, 0x30F9), (0x30F2, 0x3099, 0x30FA), (0x30FD, 0x3099, 0x30FE),
+    (0x11099, 0x110BA, 0x1109A), (0x1109B, 0x110BA, 0x1109C), (0x110A5, 0x110BA, 0x110AB), (0x11131, 0x11127, 0x1112E),
+    (0x11132, 0x11127, 0x1112F), (0x11347, 0x1133E, 0x1134B), (0x11347, 0x11357, 0x1134C), (0x114B9, 0x114B0, 0x114BC),
+    (0x114B9, 0x114BA, 0x114BB), (0x114B9, 0x114BD, 0x114BE), (0x115B8, 0x115AF, 0x115BA), (0x115B9, 0x115AF, 0x115BB),
+    (0x11935, 0x11930, 0x11938),
+];
+
+pub const BIDI_CLASS: &[(u32, u32, BidiClass)] = &[
+    (0x0, 0x8, BidiClass::Bn), (0x9, 0xD, BidiClass::Other), (0xE, 0x1B, BidiClass::Bn),
+    (0x1C, 0x20, BidiClass::Other), (0x21, 0x22, BidiClass::On), (0x23, 0x25, BidiClass::Et),
+    (0x26, 0x2A, BidiClass::On), (0x2B, 0x2B, BidiClass::Es), (0x2C, 0x2C, BidiClass::Cs),
+    (0x2D, 0x2D, BidiClass::Es), (0x2E, 0x2F, BidiClass::Cs), (0x30, 0x39, BidiClass::En),
+    (0x3A, 0x3A, BidiClass::Cs), (0x3B, 0x40, BidiClass::On), (0x5B, 0x60, BidiClass::On),
+    (0x7B, 0x7E, BidiClass::On), (0x7F, 0x84, BidiClass::Bn), (0x85, 0x85, BidiClass::Other),
+    (0x86, 0x9F, BidiClass::Bn), (0xA0, 0xA0, BidiClass::Cs), (0xA1, 0xA1, BidiClass::On),
+    (0xA2, 0xA5, BidiClass::Et), (0xA6, 0xA9, BidiClass::On), (0xAB, 0xAC, BidiClass::On),
+    (0xAD, 0xAD, BidiClass::Bn), (0xAE, 0xAF, BidiClass::On), (0xB0, 0xB1, BidiClass::Et),
+    (0xB2, 0xB3, BidiClass::En), (0xB4, 0xB4, BidiClass::On), (0xB6, 0xB8, BidiClass::On),
+    (0xB9, 0xB9, BidiClass::En), (0xBB, 0xBF, BidiClass::On), (0xD7, 0xD7, BidiClass::On),
+    (0xF7, 0xF7, BidiClass::On), (0x2B9, 0x2BA, BidiClass::On), (0x2C2, 0x2CF, BidiClass::On),
+    (0x2D2, 0x2DF, BidiClass::On), (0x2E5, 0x2ED, BidiClass::On), (0x2EF, 0x2FF, BidiClass::On),
+    (0x300, 0x36F, BidiClass::Nsm), (0x374, 0x375, BidiClass::On), (0x37E, 0x37E, BidiClass::On),
+    (0x384, 0x385, BidiClass::On), (0x387, 0x387, BidiClass::On), (0x3F6, 0x3F6, BidiClass::On),
+    (0x483, 0x489, BidiClass::Nsm), (0x58A, 0x58A, BidiClass::On), (0x58D, 0x58E, BidiClass::On),
+    (0x58F, 0x58F, BidiClass::Et), (0x591, 0x5BD, BidiClass::Nsm), (0x5BE, 0x5BE, BidiClass::R),
+    (0x5BF, 0x5BF, BidiClass::Nsm), (0x5C0, 0x5C0, BidiClass::R), (0x5C1, 0x5C2, BidiClass::Nsm),
+    (0x5C3, 0x5C3, BidiClass::R), (0x5C4, 0x5C5, BidiClass::Nsm), (0x5C6, 0x5C6, BidiClass::R),
+    (0x5C7, 0x5C7, BidiClass::Nsm), (0x5D0, 0x5EA, BidiClass::R), (0x5EF, 0x5F4, BidiClass::R),
+    (0x600, 0x605, BidiClass::An), (0x606, 0x607, BidiClass::On), (0x608, 0x608, BidiClass::Al),
+    (0x609, 0x60A, BidiClass::Et), (0x60B, 0x60B, BidiClass::Al), (0x60C, 0x60C, BidiClass::Cs),
+    (0x60D, 0x60D, BidiClass::Al), (0x60E, 0x60F, BidiClass::On), (0x610, 0x61A, BidiClass::Nsm),
+    (0x61B, 0x61C, BidiClass::Al), (0x61E, 0x64A, BidiClass::Al), (0x64B, 0x65F, BidiClass::Nsm),
+    (0x660, 0x669, BidiClass::An), (0x66A, 0x66A, BidiClass::Et), (0x66B, 0x66C, BidiClass::An),
+    (0x66D, 0x66F, BidiClass::Al), (0x670, 0x670, BidiClass::Nsm), (0x671, 0x6D5, BidiClass::Al),
+    (0x6D6, 0x6DC, BidiClass::Nsm), (0x6DD, 0x6DD, BidiClass::An), (0x6DE, 0x6DE, BidiClass::On),
+    (0x6DF, 0x6E4, BidiClass::Nsm), (0x6E5, 0x6E6, BidiClass::Al), (0x6E7, 0x6E8, BidiClass::Nsm),
+    (0x6E9, 0x6E9, BidiClass::On), (0x6EA, 0x6ED, BidiClass::Nsm), (0x6EE, 0x6EF, BidiClass::Al),
+    (0x6F0, 0x6F9, BidiClass::En), (0x6FA, 0x70D, BidiClass::Al), (0x70F, 0x710, BidiClass::Al),
+    (0x711, 0x711, BidiClass::Nsm), (0x712, 0x72F, BidiClass::Al), (0x730, 0x74A, BidiClass::Nsm),
+    (0x74D, 0x7A5, BidiClass::Al), (0x7A6, 0x7B0, BidiClass::Nsm), (0x7B1, 0x7B1, BidiClass::Al),
+    (0x7C0, 0x7EA, BidiClass::R), (0x7EB, 0x7F3, BidiClass::Nsm), (0x7F4, 0x7F5, BidiClass::R),
+    (0x7F6, 0x7F9, BidiClass::On), (0x7FA, 0x7FA, BidiClass::R), (0x7FD, 0x7FD, BidiClass::Nsm),
+    (0x7FE, 0x815, BidiClass::R), (0x816, 0x819, BidiClass::Nsm), (0x81A, 0x81A, BidiClass::R),
+    (0x81B, 0x823, BidiClass::Nsm), (0x824, 0x824, BidiClass::R), (0x825, 0x827, BidiClass::Nsm),
+    (0x828, 0x828, BidiClass::R), (0x829, 0x82D, BidiClass::Nsm), (0x830, 0x83E, BidiClass::R),
+    (0x840, 0x858, BidiClass::R), (0x859, 0x85B, BidiClass::Nsm), (0x85E, 0x85E, BidiClass::R),
+    (0x860, 0x86A, BidiClass::Al), (0x8A0, 0x8B4, BidiClass::Al), (0x8B6, 0x8C7, BidiClass::Al),
+    (0x8D3, 0x8E1, BidiClass::Nsm), (0x8E2, 0x8E2, BidiClass::An), (0x8E3, 0x902, BidiClass::Nsm),
+    (0x93A, 0x93A, BidiClass::Nsm), (0x93C, 0x93C, BidiClass::Nsm), (0x941, 0x948, BidiClass::Nsm),
+    (0x94D, 0x94D, BidiClass::Nsm), (0x951, 0x957, BidiClass::Nsm), (0x962, 0x963, BidiClass::Nsm),
+    (0x981, 0x981, BidiClass::Nsm), (0x9BC, 0x9BC, BidiClass::Nsm), (0x9C1, 0x9C4, BidiClass::Nsm),
+    (0x9CD, 0x9CD, BidiClass::Nsm), (0x9E2, 0x9E3, BidiClass::Nsm), (0x9F2, 0x9F3, BidiClass::Et),
+    (0x9FB, 0x9FB, BidiClass::Et), (0x9FE, 0x9FE, BidiClass::Nsm), (0xA01, 0xA02, BidiClass::Nsm),
+    (0xA3C, 0xA3C, BidiClass::Nsm), (0xA41, 0xA42, BidiClass::Nsm), (0xA47, 0xA48, BidiClass::Nsm),
+    (0xA4B, 0xA4D, BidiClass::Nsm), (0xA51, 0xA51, BidiClass::Nsm), (0xA70, 0xA71, BidiClass::Nsm),
+    (0xA75, 0xA75, BidiClass::Nsm), (0xA81, 0xA82, BidiClass::Nsm), (0xABC, 0xABC, BidiClass::Nsm),
+    (0xAC1, 0xAC5, BidiClass::Nsm), (0xAC7, 0xAC8, BidiClass::Nsm), (0xACD, 0xACD, BidiClass::Nsm),
+    (0xAE2, 0xAE3, BidiClass::Nsm), (0xAF1, 0xAF1, BidiClass::Et), (0xAFA, 0xAFF, BidiClass::Nsm),
+    (0xB01, 0xB01, BidiClass::Nsm), (0xB3C, 0xB3C, BidiClass::Nsm), (0xB3F, 0xB3F, BidiClass::Nsm),
+    (0xB41, 0xB44, BidiClass::Nsm), (0xB4D, 0xB4D, BidiClass::Nsm), (0xB55, 0xB56, BidiClass::Nsm),
+    (0xB62, 0xB63, BidiClass::Nsm), (0xB82, 0xB82, BidiClass::Nsm), (0xBC0, 0xBC0, BidiClass::Nsm),
+    (0xBCD, 0xBCD, BidiClass::Nsm), (0xBF3, 0xBF8, BidiClass::On), (0xBF9, 0xBF9, BidiClass::Et),
+    (0xBFA, 0xBFA, BidiClass::On), (0xC00, 0xC00, BidiClass::Nsm), (0xC04, 0xC04, BidiClass::Nsm),
+    (0xC3E, 0xC40, BidiClass::Nsm), (0xC46, 0xC48, BidiClass::Nsm), (0xC4A, 0xC4D, BidiClass::Nsm),
+    (0xC55, 0xC56, BidiClass::Nsm), (0xC62, 0xC63, BidiClass::Nsm), (0xC78, 0xC7E, BidiClass::On),
+    (0xC81, 0xC81, BidiClass::Nsm), (0xCBC, 0xCBC, BidiClass::Nsm), (0xCCC, 0xCCD, BidiClass::Nsm),
+    (0xCE2, 0xCE3, BidiClass::Nsm), (0xD00, 0xD01, BidiClass::Nsm), (0xD3B, 0xD3C, BidiClass::Nsm),
+    (0xD41, 0xD44, BidiClass::Nsm), (0xD4D, 0xD4D, BidiClass::Nsm), (0xD62, 0xD63, BidiClass::Nsm),
+    (0xD81, 0xD81, BidiClass::Nsm), (0xDCA, 0xDCA, BidiClass::Nsm), (0xDD2, 0xDD4, BidiClass::Nsm),
+    (0xDD6, 0xDD6, BidiClass::Nsm), (0xE31, 0xE31, BidiClass::Nsm), (0xE34, 0xE3A, BidiClass::Nsm),
+    (0xE3F, 0xE3F, BidiClass::Et), (0xE47, 0xE4E, BidiClass::Nsm), (0xEB1, 0xEB1, BidiClass::Nsm),
+    (0xEB4, 0xEBC, BidiClass::Nsm), (0xEC8, 0xECD, BidiClass::Nsm), (0xF18, 0xF19, BidiClass::Nsm),
+    (0xF35, 0xF35, BidiClass::Nsm), (0xF37, 0xF37, BidiClass::Nsm), (0xF39, 0xF39, BidiClass::Nsm),
+    (0xF3A, 0xF3D, BidiClass::On), (0xF71, 0xF7E, BidiClass::Nsm), (0xF80, 0xF84, BidiClass::Nsm),
+    (0xF86, 0xF87, BidiClass::Nsm), (0xF8D, 0xF97, BidiClass::Nsm), (0xF99, 0xFBC, BidiClass::Nsm),
+    (0xFC6, 0xFC6, BidiClass::Nsm), (0x102D, 0x1030, BidiClass::Nsm), (0x1032, 0x1037, BidiClass::Nsm),
+    (0x1039, 0x103A, BidiClass::Nsm), (0x103D, 0x103E, BidiClass::Nsm), (0x1058, 0x1059, BidiClass::Nsm),
+    (0x105E, 0x1060, BidiClass::Nsm), (0x1071, 0x1074, BidiClass::Nsm), (0x1082, 0x1082, BidiClass::Nsm),
+    (0x1085, 0x1086, BidiClass::Nsm), (0x108D, 0x108D, BidiClass::Nsm), (0x109D, 0x109D, BidiClass::Nsm),
+    (0x135D, 0x135F, BidiClass::Nsm), (0x1390, 0x1399, BidiClass::On), (0x1400, 0x1400, BidiClass::On),
+    (0x1680, 0x1680, BidiClass::Other), (0x169B, 0x169C, BidiClass::On), (0x1712, 0x1714, BidiClass::Nsm),
+    (0x1732, 0x1734, BidiClass::Nsm), (0x1752, 0x1753, BidiClass::Nsm), (0x1772, 0x1773, BidiClass::Nsm),
+    (0x17B4, 0x17B5, BidiClass::Nsm), (0x17B7, 0x17BD, BidiClass::Nsm), (0x17C6, 0x17C6, BidiClass::Nsm),
+    (0x17C9, 0x17D3, BidiClass::Nsm), (0x17DB, 0x17DB, BidiClass::Et), (0x17DD, 0x17DD, BidiClass::Nsm),
+    (0x17F0, 0x17F9, BidiClass::On), (0x1800, 0x180A, BidiClass::On), (0x180B, 0x180D, BidiClass::Nsm),
+    (0x180E, 0x180E, BidiClass::Bn), (0x1885, 0x1886, BidiClass::Nsm), (0x18A9, 0x18A9, BidiClass::Nsm),
+    (0x1920, 0x1922, BidiClass::Nsm), (0x1927, 0x1928, BidiClass::Nsm), (0x1932, 0x1932, BidiClass::Nsm),
+    (0x1939, 0x193B, BidiClass::Nsm), (0x1940, 0x1940, BidiClass::On), (0x1944, 0x1945, BidiClass::On),
+    (0x19DE, 0x19FF, BidiClass::On), (0x1A17, 0x1A18, BidiClass::Nsm), (0x1A1B, 0x1A1B, BidiClass::Nsm),
+    (0x1A56, 0x1A56, BidiClass::Nsm), (0x1A58, 0x1A5E, BidiClass::Nsm), (0x1A60, 0x1A60, BidiClass::Nsm),
+    (0x1A62, 0x1A62, BidiClass::Nsm), (0x1A65, 0x1A6C, BidiClass::Nsm), (0x1A73, 0x1A7C, BidiClass::Nsm),
+    (0x1A7F, 0x1A7F, BidiClass::Nsm), (0x1AB0, 0x1AC0, BidiClass::Nsm), (0x1B00, 0x1B03, BidiClass::Nsm),
+    (0x1B34, 0x1B34, BidiClass::Nsm), (0x1B36, 0x1B3A, BidiClass::Nsm), (0x1B3C, 0x1B3C, BidiClass::Nsm),
+    (0x1B42, 0x1B42, BidiClass::Nsm), (0x1B6B, 0x1B73, BidiClass::Nsm), (0x1B80, 0x1B81, BidiClass::Nsm),
+    (0x1BA2, 0x1BA5, BidiClass::Nsm), (0x1BA8, 0x1BA9, BidiClass::Nsm), (0x1BAB, 0x1BAD, BidiClass::Nsm),
+    (0x1BE6, 0x1BE6, BidiClass::Nsm), (0x1BE8, 0x1BE9, BidiClass::Nsm), (0x1BED, 0x1BED, BidiClass::Nsm),
+    (0x1BEF, 0x1BF1, BidiClass::Nsm), (0x1C2C, 0x1C33, BidiClass::Nsm), (0x1C36, 0x1C37, BidiClass::Nsm),
+    (0x1CD0, 0x1CD2, BidiClass::Nsm), (0x1CD4, 0x1CE0, BidiClass::Nsm), (0x1CE2, 0x1CE8, BidiClass::Nsm),
+    (0x1CED, 0x1CED, BidiClass::Nsm), (0x1CF4, 0x1CF4, BidiClass::Nsm), (0x1CF8, 0x1CF9, BidiClass::Nsm),
+    (0x1DC0, 0x1DF9, BidiClass::Nsm), (0x1DFB, 0x1DFF, BidiClass::Nsm), (0x1FBD, 0x1FBD, BidiClass::On),
+    (0x1FBF, 0x1FC1, BidiClass::On), (0x1FCD, 0x1FCF, BidiClass::On), (0x1FDD, 0x1FDF, BidiClass::On),
+    (0x1FED, 0x1FEF, BidiClass::On), (0x1FFD, 0x1FFE, BidiClass::On), (0x2000, 0x200A, BidiClass::Other),
+    (0x200B, 0x200D, BidiClass::Bn), (0x200F, 0x200F, BidiClass::R), (0x2010, 0x2027, BidiClass::On),
+    (0x2028, 0x202E, BidiClass::Other), (0x202F, 0x202F, BidiClass::Cs), (0x2030, 0x2034, BidiClass::Et),
+    (0x2035, 0x2043, BidiClass::On), (0x2044, 0x2044, BidiClass::Cs), (0x2045, 0x205E, BidiClass::On),
+    (0x205F, 0x205F, BidiClass::Other), (0x2060, 0x2064, BidiClass::Bn), (0x2066, 0x2069, BidiClass::Other),
+    (0x206A, 0x206F, BidiClass::Bn), (0x2070, 0x2070, BidiClass::En), (0x2074, 0x2079, BidiClass::En),
+    (0x207A, 0x207B, BidiClass::Es), (0x207C, 0x207E, BidiClass::On), (0x2080, 0x2089, BidiClass::En),
+    (0x208A, 0x208B, BidiClass::Es), (0x208C, 0x208E, BidiClass::On), (0x20A0, 0x20BF, BidiClass::Et),
+    (0x20D0, 0x20F0, BidiClass::Nsm), (0x2100, 0x2101, BidiClass::On), (0x2103, 0x2106, BidiClass::On),
+    (0x2108, 0x2109, BidiClass::On), (0x2114, 0x2114, BidiClass::On), (0x2116, 0x2118, BidiClass::On),
+    (0x211E, 0x2123, BidiClass::On), (0x2125, 0x2125, BidiClass::On), (0x2127, 0x2127, BidiClass::On),
+    (0x2129, 0x2129, BidiClass::On), (0x212E, 0x212E, BidiClass::Et), (0x213A, 0x213B, BidiClass::On),
+    (0x2140, 0x2144, BidiClass::On), (0x214A, 0x214D, BidiClass::On), (0x2150, 0x215F, BidiClass::On),
+    (0x2189, 0x218B, BidiClass::On), (0x2190, 0x2211, BidiClass::On), (0x2212, 0x2212, BidiClass::Es),
+    (0x2213, 0x2213, BidiClass::Et), (0x2214, 0x2335, BidiClass::On), (0x237B, 0x2394, BidiClass::On),
+    (0x2396, 0x2426, BidiClass::On), (0x2440, 0x244A, BidiClass::On), (0x2460, 0x2487, BidiClass::On),
+    (0x2488, 0x249B, BidiClass::En), (0x24EA, 0x26AB, BidiClass::On), (0x26AD, 0x27FF, BidiClass::On),
+    (0x2900, 0x2B73, BidiClass::On), (0x2B76, 0x2B95, BidiClass::On), (0x2B97, 0x2BFF, BidiClass::On),
+    (0x2CE5, 0x2CEA, BidiClass::On), (0x2CEF, 0x2CF1, BidiClass::Nsm), (0x2CF9, 0x2CFF, BidiClass::On),
+    (0x2D7F, 0x2D7F, BidiClass::Nsm), (0x2DE0, 0x2DFF, BidiClass::Nsm), (0x2E00, 0x2E52, BidiClass::On),
+    (0x2E80, 0x2E99, BidiClass::On), (0x2E9B, 0x2EF3, BidiClass::On), (0x2F00, 0x2FD5, BidiClass::On),
+    (0x2FF0, 0x2FFB, BidiClass::On), (0x3000, 0x3000, BidiClass::Other), (0x3001, 0x3004, BidiClass::On),
+    (0x3008, 0x3020, BidiClass::On), (0x302A, 0x302D, BidiClass::Nsm), (0x3030, 0x3030, BidiClass::On),
+    (0x3036, 0x3037, BidiClass::On), (0x303D, 0x303F, BidiClass::On), (0x3099, 0x309A, BidiClass::Nsm),
+    (0x309B, 0x309C, BidiClass::On), (0x30A0, 0x30A0, BidiClass::On), (0x30FB, 0x30FB, BidiClass::On),
+    (0x31C0, 0x31E3, BidiClass::On), (0x321D, 0x321E, BidiClass::On), (0x3250, 0x325F, BidiClass::On),
+    (0x327C, 0x327E, BidiClass::On), (0x32B1, 0x32BF, BidiClass::On), (0x32CC, 0x32CF, BidiClass::On),
+    (0x3377, 0x337A, BidiClass::On), (0x33DE, 0x33DF, BidiClass::On), (0x33FF, 0x33FF, BidiClass::On),
+    (0x4DC0, 0x4DFF, BidiClass::On), (0xA490, 0xA4C6, BidiClass::On), (0xA60D, 0xA60F, BidiClass::On),
+    (0xA66F, 0xA672, BidiClass::Nsm), (0xA673, 0xA673, BidiClass::On), (0xA674, 0xA67D, BidiClass::Nsm),
+    (0xA67E, 0xA67F, BidiClass::On), (0xA69E, 0xA69F, BidiClass::Nsm), (0xA6F0, 0xA6F1, BidiClass::Nsm),
+    (0xA700, 0xA721, BidiClass::On), (0xA788, 0xA788, BidiClass::On), (0xA802, 0xA802, BidiClass::Nsm),
+    (0xA806, 0xA806, BidiClass::Nsm), (0xA80B, 0xA80B, BidiClass::Nsm), (0xA825, 0xA826, BidiClass::Nsm),
+    (0xA828, 0xA82B, BidiClass::On), (0xA82C, 0xA82C, BidiClass::Nsm), (0xA838, 0xA839, BidiClass::Et),
+    (0xA874, 0xA877, BidiClass::On), (0xA8C4, 0xA8C5, BidiClass::Nsm), (0xA8E0, 0xA8F1, BidiClass::Nsm),
+    (0xA8FF, 0xA8FF, BidiClass::Nsm), (0xA926, 0xA92D, BidiClass::Nsm), (0xA947, 0xA951, BidiClass::Nsm),
+    (0xA980, 0xA982, BidiClass::Nsm), (0xA9B3, 0xA9B3, BidiClass::Nsm), (0xA9B6, 0xA9B9, BidiClass::Nsm),
+    (0xA9BC, 0xA9BD, BidiClass::Nsm), (0xA9E5, 0xA9E5, BidiClass::Nsm), (0xAA29, 0xAA2E, BidiClass::Nsm),
+    (0xAA31, 0xAA32, BidiClass::Nsm), (0xAA35, 0xAA36, BidiClass::Nsm), (0xAA43, 0xAA43, BidiClass::Nsm),
+    (0xAA4C, 0xAA4C, BidiClass::Nsm), (0xAA7C, 0xAA7C, BidiClass::Nsm), (0xAAB0, 0xAAB0, BidiClass::Nsm),
+    (0xAAB2, 0xAAB4, BidiClass::Nsm), (0xAAB7, 0xAAB8, BidiClass::Nsm), (0xAABE, 0xAABF, BidiClass::Nsm),
+    (0xAAC1, 0xAAC1, BidiClass::Nsm), (0xAAEC, 0xAAED, BidiClass::Nsm), (0xAAF6, 0xAAF6, BidiClass::Nsm),
+    (0xAB6A, 0xAB6B, BidiClass::On), (0xABE5, 0xABE5, BidiClass::Nsm), (0xABE8, 0xABE8, BidiClass::Nsm),
+    (0xABED, 0xABED, BidiClass::Nsm), (0xFB1D, 0xFB1D, BidiClass::R), (0xFB1E, 0xFB1E, BidiClass::Nsm),
+    (0xFB1F, 0xFB28, BidiClass::R), (0xFB29, 0xFB29, BidiClass::Es), (0xFB2A, 0xFB36, BidiClass::R),
+    (0xFB38, 0xFB3C, BidiClass::R), (0xFB3E, 0xFB3E, BidiClass::R), (0xFB40, 0xFB41, BidiClass::R),
+    (0xFB43, 0xFB44, BidiClass::R), (0xFB46, 0xFB4F, BidiClass::R), (0xFB50, 0xFBC1, BidiClass::Al),
+    (0xFBD3, 0xFD3D, BidiClass::Al), (0xFD3E, 0xFD3F, BidiClass::On), (0xFD50, 0xFD8F, BidiClass::Al),
+    (0xFD92, 0xFDC7, BidiClass::Al), (0xFDF0, 0xFDFC, BidiClass::Al), (0xFDFD, 0xFDFD, BidiClass::On),
+    (0xFE00, 0xFE0F, BidiClass::Nsm), (0xFE10, 0xFE19, BidiClass::On), (0xFE20, 0xFE2F, BidiClass::Nsm),
+    (0xFE30, 0xFE4F, BidiClass::On), (0xFE50, 0xFE50, BidiClass::Cs), (0xFE51, 0xFE51, BidiClass::On),
+    (0xFE52, 0xFE52, BidiClass::Cs), (0xFE54, 0xFE54, BidiClass::On), (0xFE55, 0xFE55, BidiClass::Cs),
+    (0xFE56, 0xFE5E, BidiClass::On), (0xFE5F, 0xFE5F, BidiClass::Et), (0xFE60, 0xFE61, BidiClass::On),
+    (0xFE62, 0xFE63, BidiClass::Es), (0xFE64, 0xFE66, BidiClass::On), (0xFE68, 0xFE68, BidiClass::On),
+    (0xFE69, 0xFE6A, BidiClass::Et), (0xFE6B, 0xFE6B, BidiClass::On), (0xFE70, 0xFE74, BidiClass::Al),
+    (0xFE76, 0xFEFC, BidiClass::Al), (0xFEFF, 0xFEFF, BidiClass::Bn), (0xFF01, 0xFF02, BidiClass::On),
+    (0xFF03, 0xFF05, BidiClass::Et), (0xFF06, 0xFF0A, BidiClass::On), (0xFF0B, 0xFF0B, BidiClass::Es),
+    (0xFF0C, 0xFF0C, BidiClass::Cs), (0xFF0D, 0xFF0D, BidiClass::Es), (0xFF0E, 0xFF0F, BidiClass::Cs),
+    (0xFF10, 0xFF19, BidiClass::En), (0xFF1A, 0xFF1A, BidiClass::Cs), (0xFF1B, 0xFF20, BidiClass::On),
+    (0xFF3B, 0xFF40, BidiClass::On), (0xFF5B, 0xFF65, BidiClass::On), (0xFFE0, 0xFFE1, BidiClass::Et),
+    (0xFFE2, 0xFFE4, BidiClass::On), (0xFFE5, 0xFFE6, BidiClass::Et), (0xFFE8, 0xFFEE, BidiClass::On),
+    (0xFFF9, 0xFFFD, BidiClass::On), (0x10101, 0x10101, BidiClass::On), (0x10140, 0x1018C, BidiClass::On),
+    (0x10190, 0x1019C, BidiClass::On), (0x101A0, 0x101A0, BidiClass::On), (0x101FD, 0x101FD, BidiClass::Nsm),
+    (0x102E0, 0x102E0, BidiClass::Nsm), (0x102E1, 0x102FB, BidiClass::En), (0x10376, 0x1037A, BidiClass::Nsm),
+    (0x10800, 0x10805, BidiClass::R), (0x10808, 0x10808, BidiClass::R), (0x1080A, 0x10835, BidiClass::R),
+    (0x10837, 0x10838, BidiClass::R), (0x1083C, 0x1083C, BidiClass::R), (0x1083F, 0x10855, BidiClass::R),
+    (0x10857, 0x1089E, BidiClass::R), (0x108A7, 0x108AF, BidiClass::R), (0x108E0, 0x108F2, BidiClass::R),
+    (0x108F4, 0x108F5, BidiClass::R), (0x108FB, 0x1091B, BidiClass::R), (0x1091F, 0x1091F, BidiClass::On),
+    (0x10920, 0x10939, BidiClass::R), (0x1093F, 0x1093F, BidiClass::R), (0x10980, 0x109B7, BidiClass::R),
+    (0x109BC, 0x109CF, BidiClass::R), (0x109D2, 0x10A00, BidiClass::R), (0x10A01, 0x10A03, BidiClass::Nsm),
+    (0x10A05, 0x10A06, BidiClass::Nsm), (0x10A0C, 0x10A0F, BidiClass::Nsm), (0x10A10, 0x10A13, BidiClass::R),
+    (0x10A15, 0x10A17, BidiClass::R), (0x10A19, 0x10A35, BidiClass::R), (0x10A38, 0x10A3A, BidiClass::Nsm),
+    (0x10A3F, 0x10A3F, BidiClass::Nsm), (0x10A40, 0x10A48, BidiClass::R), (0x10A50, 0x10A58, BidiClass::R),
+    (0x10A60, 0x10A9F, BidiClass::R), (0x10AC0, 0x10AE4, BidiClass::R), (0x10AE5, 0x10AE6, BidiClass::Nsm),
+    (0x10AEB, 0x10AF6, BidiClass::R), (0x10B00, 0x10B35, BidiClass::R), (0x10B39, 0x10B3F, BidiClass::On),
+    (0x10B40, 0x10B55, BidiClass::R), (0x10B58, 0x10B72, BidiClass::R), (0x10B78, 0x10B91, BidiClass::R),
+    (0x10B99, 0x10B9C, BidiClass::R), (0x10BA9, 0x10BAF, BidiClass::R), (0x10C00, 0x10C48, BidiClass::R),
+    (0x10C80, 0x10CB2, BidiClass::R), (0x10CC0, 0x10CF2, BidiClass::R), (0x10CFA, 0x10CFF, BidiClass::R),
+    (0x10D00, 0x10D23, BidiClass::Al), (0x10D24, 0x10D27, BidiClass::Nsm), (0x10D30, 0x10D39, BidiClass::An),
+    (0x10E60, 0x10E7E, BidiClass::An), (0x10E80, 0x10EA9, BidiClass::R), (0x10EAB, 0x10EAC, BidiClass::Nsm),
+    (0x10EAD, 0x10EAD, BidiClass::R), (0x10EB0, 0x10EB1, BidiClass::R), (0x10F00, 0x10F27, BidiClass::R),
+    (0x10F30, 0x10F45, BidiClass::Al), (0x10F46, 0x10F50, BidiClass::Nsm), (0x10F51, 0x10F59, BidiClass::Al),
+    (0x10FB0, 0x10FCB, BidiClass::R), (0x10FE0, 0x10FF6, BidiClass::R), (0x11001, 0x11001, BidiClass::Nsm),
+    (0x11038, 0x11046, BidiClass::Nsm), (0x11052, 0x11065, BidiClass::On), (0x1107F, 0x11081, BidiClass::Nsm),
+    (0x110B3, 0x110B6, BidiClass::Nsm), (0x110B9, 0x110BA, BidiClass::Nsm), (0x11100, 0x11102, BidiClass::Nsm),
+    (0x11127, 0x1112B, BidiClass::Nsm), (0x1112D, 0x11134, BidiClass::Nsm), (0x11173, 0x11173, BidiClass::Nsm),
+    (0x11180, 0x11181, BidiClass::Nsm), (0x111B6, 0x111BE, BidiClass::Nsm), (0x111C9, 0x111CC, BidiClass::Nsm),
+    (0x111CF, 0x111CF, BidiClass::Nsm), (0x1122F, 0x11231, BidiClass::Nsm), (0x11234, 0x11234, BidiClass::Nsm),
+    (0x11236, 0x11237, BidiClass::Nsm), (0x1123E, 0x1123E, BidiClass::Nsm), (0x112DF, 0x112DF, BidiClass::Nsm),
+    (0x112E3, 0x112EA, BidiClass::Nsm), (0x11300, 0x11301, BidiClass::Nsm), (0x1133B, 0x1133C, BidiClass::Nsm),
+    (0x11340, 0x11340, BidiClass::Nsm), (0x11366, 0x1136C, BidiClass::Nsm), (0x11370, 0x11374, BidiClass::Nsm),
+    (0x11438, 0x1143F, BidiClass::Nsm), (0x11442, 0x11444, BidiClass::Nsm), (0x11446, 0x11446, BidiClass::Nsm),
+    (0x1145E, 0x1145E, BidiClass::Nsm), (0x114B3, 0x114B8, BidiClass::Nsm), (0x114BA, 0x114BA, BidiClass::Nsm),
+    (0x114BF, 0x114C0, BidiClass::Nsm), (0x114C2, 0x114C3, BidiClass::Nsm), (0x115B2, 0x115B5, BidiClass::Nsm),
+    (0x115BC, 0x115BD, BidiClass::Nsm), (0x115BF, 0x115C0, BidiClass::Nsm), (0x115DC, 0x115DD, BidiClass::Nsm),
+    (0x11633, 0x1163A, BidiClass::Nsm), (0x1163D, 0x1163D, BidiClass::Nsm), (0x1163F, 0x11640, BidiClass::Nsm),
+    (0x11660, 0x1166C, BidiClass::On), (0x116AB, 0x116AB, BidiClass::Nsm), (0x116AD, 0x116AD, BidiClass::Nsm),
+    (0x116B0, 0x116B5, BidiClass::Nsm), (0x116B7, 0x116B7, BidiClass::Nsm), (0x1171D, 0x1171F, BidiClass::Nsm),
+    (0x11722, 0x11725, BidiClass::Nsm), (0x11727, 0x1172B, BidiClass::Nsm), (0x1182F, 0x11837, BidiClass::Nsm),
+    (0x11839, 0x1183A, BidiClass::Nsm), (0x1193B, 0x1193C, BidiClass::Nsm), (0x1193E, 0x1193E, BidiClass::Nsm),
+    (0x11943, 0x11943, BidiClass::Nsm), (0x119D4, 0x119D7, BidiClass::Nsm), (0x119DA, 0x119DB, BidiClass::Nsm),
+    (0x119E0, 0x119E0, BidiClass::Nsm), (0x11A01, 0x11A06, BidiClass::Nsm), (0x11A09, 0x11A0A, BidiClass::Nsm),
+    (0x11A33, 0x11A38, BidiClass::Nsm), (0x11A3B, 0x11A3E, BidiClass::Nsm), (0x11A47, 0x11A47, BidiClass::Nsm),
+    (0x11A51, 0x11A56, BidiClass::Nsm), (0x11A59, 0x11A5B, BidiClass::Nsm), (0x11A8A, 0x11A96, BidiClass::Nsm),
+    (0x11A98, 0x11A99, BidiClass::Nsm), (0x11C30, 0x11C36, BidiClass::Nsm), (0x11C38, 0x11C3D, BidiClass::Nsm),
+    (0x11C92, 0x11CA7, BidiClass::Nsm), (0x11CAA, 0x11CB0, BidiClass::Nsm), (0x11CB2, 0x11CB3, BidiClass::Nsm),
+    (0x11CB5, 0x11CB6, BidiClass::Nsm), (0x11D31, 0x11D36, BidiClass::Nsm), (0x11D3A, 0x11D3A, BidiClass::Nsm),
+    (0x11D3C, 0x11D3D, BidiClass::Nsm), (0x11D3F, 0x11D45, BidiClass::Nsm), (0x11D47, 0x11D47, BidiClass::Nsm),
+    (0x11D90, 0x11D91, BidiClass::Nsm), (0x11D95, 0x11D95, BidiClass::Nsm), (0x11D97, 0x11D97, BidiClass::Nsm),
+    (0x11EF3, 0x11EF4, BidiClass::Nsm), (0x11FD5, 0x11FDC, BidiClass::On), (0x11FDD, 0x11FE0, BidiClass::Et),
+    (0x11FE1, 0x11FF1, BidiClass::On), (0x16AF0, 0x16AF4, BidiClass::Nsm), (0x16B30, 0x16B36, BidiClass::Nsm),
+    (0x16F4F, 0x16F4F, BidiClass::Nsm), (0x16F8F, 0x16F92, BidiClass::Nsm), (0x16FE2, 0x16FE2, BidiClass::On),
+    (0x16FE4, 0x16FE4, BidiClass::Nsm), (0x1BC9D, 0x1BC9E, BidiClass::Nsm), (0x1BCA0, 0x1BCA3, BidiClass::Bn),
+    (0x1D167, 0x1D169, BidiClass::Nsm), (0x1D173, 0x1D17A, BidiClass::Bn), (0x1D17B, 0x1D182, BidiClass::Nsm),
+    (0x1D185, 0x1D18B, BidiClass::Nsm), (0x1D1AA, 0x1D1AD, BidiClass::Nsm), (0x1D200, 0x1D241, BidiClass::On),
+    (0x1D242, 0x1D244, BidiClass::Nsm), (0x1D245, 0x1D245, BidiClass::On), (0x1D300, 0x1D356, BidiClass::On),
+    (0x1D6DB, 0x1D6DB, BidiClass::On), (0x1D715, 0x1D715, BidiClass::On), (0x1D74F, 0x1D74F, BidiClass::On),
+    (0x1D789, 0x1D789, BidiClass::On), (0x1D7C3, 0x1D7C3, BidiClass::On), (0x1D7CE, 0x1D7FF, BidiClass::En),
+    (0x1DA00, 0x1DA36, BidiClass::Nsm), (0x1DA3B, 0x1DA6C, BidiClass::Nsm), (0x1DA75, 0x1DA75, BidiClass::Nsm),
+    (0x1DA84, 0x1DA84, BidiClass::Nsm), (0x1DA9B, 0x1DA9F, BidiClass::Nsm), (0x1DAA1, 0x1DAAF, BidiClass::Nsm),
+    (0x1E000, 0x1E006, BidiClass::Nsm), (0x1E008, 0x1E018, BidiClass::Nsm), (0x1E01B, 0x1E021, BidiClass::Nsm),
+    (0x1E023, 0x1E024, BidiClass::Nsm), (0x1E026, 0x1E02A, BidiClass::Nsm), (0x1E130, 0x1E136, BidiClass::Nsm),
+    (0x1E2EC, 0x1E2EF, BidiClass::Nsm), (0x1E2FF, 0x1E2FF, BidiClass::Et), (0x1E800, 0x1E8C4, BidiClass::R),
+    (0x1E8C7, 0x1E8CF, BidiClass::R), (0x1E8D0, 0x1E8D6, BidiClass::Nsm), (0x1E900, 0x1E943, BidiClass::R),
+    (0x1E944, 0x1E94A, BidiClass::Nsm), (0x1E94B, 0x1E94B, BidiClass::R), (0x1E950, 0x1E959, BidiClass::R),
+    (0x1E95E, 0x1E95F, BidiClass::R), (0x1EC71, 0x1ECB4, BidiClass::Al), (0x1ED01, 0x1ED3D, BidiClass::Al),
+    (0x1EE00, 0x1EE03, BidiClass::Al), (0x1EE05, 0x1EE1F, BidiClass::Al), (0x1EE21, 0x1EE22, BidiClass::Al),
+    (0x1EE24, 0x1EE24, BidiClass::Al), (0x1EE27, 0x1EE27, BidiClass::Al), (0x1EE29, 0x1EE32, BidiClass::Al),
+    (0x1EE34, 0x1EE37, BidiClass::Al), (0x1EE39, 0x1EE39, BidiClass::Al), (0x1EE3B, 0x1EE3B, BidiClass::Al),
+    (0x1EE42, 0x1EE42, BidiClass::Al), (0x1EE47, 0x1EE47, BidiClass::Al), (0x1EE49, 0x1EE49, BidiClass::Al),
+    (0x1EE4B, 0x1EE4B, BidiClass::Al), (0x1EE4D, 0x1EE4F, BidiClass::Al), (0x1EE51, 0x1EE52, BidiClass::Al),
+    (0x1EE54, 0x1EE54, BidiClass::Al), (0x1EE57, 0x1EE57, BidiClass::Al), (0x1EE59, 0x1EE59, BidiClass::Al),
+    (0x1EE5B, 0x1EE5B, BidiClass::Al), (0x1EE5D, 0x1EE5D, BidiClass::Al), (0x1EE5F, 0x1EE5F, BidiClass::Al),
+    (0x1EE61, 0x1EE62, BidiClass::Al), (0x1EE64, 0x1EE64, BidiClass::Al), (0x1EE67, 0x1EE6A, BidiClass::Al),
+    (0x1EE6C, 0x1EE72, BidiClass::Al), (0x1EE74, 0x1EE77, BidiClass::Al), (0x1EE79, 0x1EE7C, BidiClass::Al),
+    (0x1EE7E, 0x1EE7E, BidiClass::Al), (0x1EE80, 0x1EE89, BidiClass::Al), (0x1EE8B, 0x1EE9B, BidiClass::Al),
+    (0x1EEA1, 0x1EEA3, BidiClass::Al), (0x1EEA5, 0x1EEA9, BidiClass::Al), (0x1EEAB, 0x1EEBB, BidiClass::Al),
+    (0x1EEF0, 0x1EEF1, BidiClass::On), (0x1F000, 0x1F02B, BidiClass::On), (0x1F030, 0x1F093, BidiClass::On),
+    (0x1F0A0, 0x1F0AE, BidiClass::On), (0x1F0B1, 0x1F0BF, BidiClass::On), (0x1F0C1, 0x1F0CF, BidiClass::On),
+    (0x1F0D1, 0x1F0F5, BidiClass::On), (0x1F100, 0x1F10A, BidiClass::En), (0x1F10B, 0x1F10F, BidiClass::On),
+    (0x1F12F, 0x1F12F, BidiClass::On), (0x1F16A, 0x1F16F, BidiClass::On), (0x1F1AD, 0x1F1AD, BidiClass::On),
+    (0x1F260, 0x1F265, BidiClass::On), (0x1F300, 0x1F6D7, BidiClass::On), (0x1F6E0, 0x1F6EC, BidiClass::On),
+    (0x1F6F0, 0x1F6FC, BidiClass::On), (0x1F700, 0x1F773, BidiClass::On), (0x1F780, 0x1F7D8, BidiClass::On),
+    (0x1F7E0, 0x1F7EB, BidiClass::On), (0x1F800, 0x1F80B, BidiClass::On), (0x1F810, 0x1F847, BidiClass::On),
+    (0x1F850, 0x1F859, BidiClass::On), (0x1F860, 0x1F887, BidiClass::On), (0x1F890, 0x1F8AD, BidiClass::On),
+    (0x1F8B0, 0x1F8B1, BidiClass::On), (0x1F900, 0x1F978, BidiClass::On), (0x1F97A, 0x1F9CB, BidiClass::On),
+    (0x1F9CD, 0x1FA53, BidiClass::On), (0x1FA60, 0x1FA6D, BidiClass::On), (0x1FA70, 0x1FA74, BidiClass::On),
+    (0x1FA78, 0x1FA7A, BidiClass::On), (0x1FA80, 0x1FA86, BidiClass::On), (0x1FA90, 0x1FAA8, BidiClass::On),
+    (0x1FAB0, 0x1FAB6, BidiClass::On), (0x1FAC0, 0x1FAC2, BidiClass::On), (0x1FAD0, 0x1FAD6, BidiClass::On),
+    (0x1FB00, 0x1FB92, BidiClass::On), (0x1FB94, 0x1FBCA, BidiClass::On), (0x1FBF0, 0x1FBF9, BidiClass::En),
+    (0xE0001, 0xE0001, BidiClass::Bn), (0xE0020, 0xE007F, BidiClass::Bn), (0xE0100, 0xE01EF, BidiClass::Nsm),
+];
+
+pub const COMBINING_MARK: &[(u32, u32)] = &[
+    (0x300, 0x36F), (0x483, 0x489), (0x591, 0x5BD), (0x5BF, 0x5BF), (0x5C1, 0x5C2),
+    (0x5C4, 0x5C5), (0x5C7, 0x5C7), (0x610, 0x61A), (0x64B, 0x65F), (0x670, 0x670),
+    (0x6D6, 0x6DC), (0x6DF, 0x6E4), (0x6E7, 0x6E8), (0x6EA, 0x6ED), (0x711, 0x711),
+    (0x730, 0x74A), (0x7A6, 0x7B0), (0x7EB, 0x7F3), (0x7FD, 0x7FD), (0x816, 0x819),
+    (0x81B, 0x823), (0x825, 0x827), (0x829, 0x82D), (0x859, 0x85B), (0x8D3, 0x8E1),
+    (0x8E3, 0x903), (0x93A, 0x93C), (0x93E, 0x94F), (0x951, 0x957), (0x962, 0x963),
+    (0x981, 0x983), (0x9BC, 0x9BC), (0x9BE, 0x9C4), (0x9C7, 0x9C8), (0x9CB, 0x9CD),
+    (0x9D7, 0x9D7), (0x9E2, 0x9E3), (0x9FE, 0x9FE), (0xA01, 0xA03), (0xA3C, 0xA3C),
+    (0xA3E, 0xA42), (0xA47, 0xA48), (0xA4B, 0xA4D), (0xA51, 0xA51), (0xA70, 0xA71),
+    (0xA75, 0xA75), (0xA81, 0xA83), (0xABC, 0xABC), (0xABE, 0xAC5), (0xAC7, 0xAC9),
+    (0xACB, 0xACD), (0xAE2, 0xAE3), (0xAFA, 0xAFF), (0xB01, 0xB03), (0xB3C, 0xB3C),
+    (0xB3E, 0xB44), (0xB47, 0xB48), (0xB4B, 0xB4D), (0xB55, 0xB57), (0xB62, 0xB63),
+    (0xB82, 0xB82), (0xBBE, 0xBC2), (0xBC6, 0xBC8), (0xBCA, 0xBCD), (0xBD7, 0xBD7),
+    (0xC00, 0xC04), (0xC3E, 0xC44), (0xC46, 0xC48), (0xC4A, 0xC4D), (0xC55, 0xC56),
+    (0xC62, 0xC63), (0xC81, 0xC83), (0xCBC, 0xCBC), (0xCBE, 0xCC4), (0xCC6, 0xCC8),
+    (0xCCA, 0xCCD), (0xCD5, 0xCD6), (0xCE2, 0xCE3), (0xD00, 0xD03), (0xD3B, 0xD3C),
+    (0xD3E, 0xD44), (0xD46, 0xD48), (0xD4A, 0xD4D), (0xD57, 0xD57), (0xD62, 0xD63),
+    (0xD81, 0xD83), (0xDCA, 0xDCA), (0xDCF, 0xDD4), (0xDD6, 0xDD6), (0xDD8, 0xDDF),
+    (0xDF2, 0xDF3), (0xE31, 0xE31), (0xE34, 0xE3A), (0xE47, 0xE4E), (0xEB1, 0xEB1),
+    (0xEB4, 0xEBC), (0xEC8, 0xECD), (0xF18, 0xF19), (0xF35, 0xF35), (0xF37, 0xF37),
+    (0xF39, 0xF39), (0xF3E, 0xF3F), (0xF71, 0xF84), (0xF86, 0xF87), (0xF8D, 0xF97),
+    (0xF99, 0xFBC), (0xFC6, 0xFC6), (0x102B, 0x103E), (0x1056, 0x1059), (0x105E, 0x1060),
+    (0x1062, 0x1064), (0x1067, 0x106D), (0x1071, 0x1074), (0x1082, 0x108D), (0x108F, 0x108F),
+    (0x109A, 0x109D), (0x135D, 0x135F), (0x1712, 0x1714), (0x1732, 0x1734), (0x1752, 0x1753),
+    (0x1772, 0x1773), (0x17B4, 0x17D3), (0x17DD, 0x17DD), (0x180B, 0x180D), (0x1885, 0x1886),
+    (0x18A9, 0x18A9), (0x1920, 0x192B), (0x1930, 0x193B), (0x1A17, 0x1A1B), (0x1A55, 0x1A5E),
+    (0x1A60, 0x1A7C), (0x1A7F, 0x1A7F), (0x1AB0, 0x1AC0), (0x1B00, 0x1B04), (0x1B34, 0x1B44),
+    (0x1B6B, 0x1B73), (0x1B80, 0x1B82), (0x1BA1, 0x1BAD), (0x1BE6, 0x1BF3), (0x1C24, 0x1C37),
+    (0x1CD0, 0x1CD2), (0x1CD4, 0x1CE8), (0x1CED, 0x1CED), (0x1CF4, 0x1CF4), (0x1CF7, 0x1CF9),
+    (0x1DC0, 0x1DF9), (0x1DFB, 0x1DFF), (0x20D0, 0x20F0), (0x2CEF, 0x2CF1), (0x2D7F, 0x2D7F),
+    (0x2DE0, 0x2DFF), (0x302A, 0x302F), (0x3099, 0x309A), (0xA66F, 0xA672), (0xA674, 0xA67D),
+    (0xA69E, 0xA69F), (0xA6F0, 0xA6F1), (0xA802, 0xA802), (0xA806, 0xA806), (0xA80B, 0xA80B),
+    (0xA823, 0xA827), (0xA82C, 0xA82C), (0xA880, 0xA881), (0xA8B4, 0xA8C5), (0xA8E0, 0xA8F1),
+    (0xA8FF, 0xA8FF), (0xA926, 0xA92D), (0xA947, 0xA953), (0xA980, 0xA983), (0xA9B3, 0xA9C0),
+    (0xA9E5, 0xA9E5), (0xAA29, 0xAA36), (0xAA43, 0xAA43), (0xAA4C, 0xAA4D), (0xAA7B, 0xAA7D),
+    (0xAAB0, 0xAAB0), (0xAAB2, 0xAAB4), (0xAAB7, 0xAAB8), (0xAABE, 0xAABF), (0xAAC1, 0xAAC1),
+    (0xAAEB, 0xAAEF), (0xAAF5, 0xAAF6), (0xABE3, 0xABEA), (0xABEC, 0xABED), (0xFB1E, 0xFB1E),
+    (0xFE00, 0xFE0F), (0xFE20, 0xFE2F), (0x101FD, 0x101FD), (0x102E0, 0x102E0), (0x10376, 0x1037A),
+    (0x10A01, 0x10A03), (0x10A05, 0x10A06), (0x10A0C, 0x10A0F), (0x10A38, 0x10A3A), (0x10A3F, 0x10A3F),
+    (0x10AE5, 0x10AE6), (0x10D24, 0x10D27), (0x10EAB, 0x10EAC), (0x10F46, 0x10F50), (0x11000, 0x11002),
+    (0x11038, 0x11046), (0x1107F, 0x11082), (0x110B0, 0x110BA), (0x11100, 0x11102), (0x11127, 0x11134),
+    (0x11145, 0x11146), (0x11173, 0x11173), (0x11180, 0x11182), (0x111B3, 0x111C0), (0x111C9, 0x111CC),
+    (0x111CE, 0x111CF), (0x1122C, 0x11237), (0x1123E, 0x1123E), (0x112DF, 0x112EA), (0x11300, 0x11303),
+    (0x1133B, 0x1133C), (0x1133E, 0x11344), (0x11347, 0x11348), (0x1134B, 0x1134D), (0x11357, 0x11357),
+    (0x11362, 0x11363), (0x11366, 0x1136C), (0x11370, 0x11374), (0x11435, 0x11446), (0x1145E, 0x1145E),
+    (0x114B0, 0x114C3), (0x115AF, 0x115B5), (0x115B8, 0x115C0), (0x115DC, 0x115DD), (0x11630, 0x11640),
+    (0x116AB, 0x116B7), (0x1171D, 0x1172B), (0x1182C, 0x1183A), (0x11930, 0x11935), (0x11937, 0x11938),
+    (0x1193B, 0x1193E), (0x11940, 0x11940), (0x11942, 0x11943), (0x119D1, 0x119D7), (0x119DA, 0x119E0),
+    (0x119E4, 0x119E4), (0x11A01, 0x11A0A), (0x11A33, 0x11A39), (0x11A3B, 0x11A3E), (0x11A47, 0x11A47),
+    (0x11A51, 0x11A5B), (0x11A8A, 0x11A99), (0x11C2F, 0x11C36), (0x11C38, 0x11C3F), (0x11C92, 0x11CA7),
+    (0x11CA9, 0x11CB6), (0x11D31, 0x11D36), (0x11D3A, 0x11D3A), (0x11D3C, 0x11D3D), (0x11D3F, 0x11D45),
+    (0x11D47, 0x11D47), (0x11D8A, 0x11D8E), (0x11D90, 0x11D91), (0x11D93, 0x11D97), (0x11EF3, 0x11EF6),
+    (0x16AF0, 0x16AF4), (0x16B30, 0x16B36), (0x16F4F, 0x16F4F), (0x16F51, 0x16F87), (0x16F8F, 0x16F92),
+    (0x16FE4, 0x16FE4), (0x16FF0, 0x16FF1), (0x1BC9D, 0x1BC9E), (0x1D165, 0x1D169), (0x1D16D, 0x1D172),
+    (0x1D17B, 0x1D182), (0x1D185, 0x1D18B), (0x1D1AA, 0x1D1AD), (0x1D242, 0x1D244), (0x1DA00, 0x1DA36),
+    (0x1DA3B, 0x1DA6C), (0x1DA75, 0x1DA75), (0x1DA84, 0x1DA84), (0x1DA9B, 0x1DA9F), (0x1DAA1, 0x1DAAF),
+    (0x1E000, 0x1E006), (0x1E008, 0x1E018), (0x1E01B, 0x1E021), (0x1E023, 0x1E024), (0x1E026, 0x1E02A),
+    (0x1E130, 0x1E136), (0x1E2EC, 0x1E2EF), (0x1E8D0, 0x1E8D6), (0x1E944, 0x1E94A), (0xE0100, 0xE01EF),
+];
+
+pub const JOINING_TYPE: &[(u32, u32, JoiningType)] = &[
+    (0xAD, 0xAD, JoiningType::T), (0x300, 0x36F, JoiningType::T), (0x483, 0x489, JoiningType::T),
+    (0x591, 0x5BD, JoiningType::T), (0x5BF, 0x5BF, JoiningType::T), (0x5C1, 0x5C2, JoiningType::T),
+    (0x5C4, 0x5C5, JoiningType::T), (0x5C7, 0x5C7, JoiningType::T), (0x610, 0x61A, JoiningType::T),
+    (0x61C, 0x61C, JoiningType::T), (0x620, 0x620, JoiningType::D), (0x622, 0x625, JoiningType::R),
+    (0x626, 0x626, JoiningType::D), (0x627, 0x627, JoiningType::R), (0x628, 0x628, JoiningType::D),
+    (0x629, 0x629, JoiningType::R), (0x62A, 0x62E, JoiningType::D), (0x62F, 0x632, JoiningType::R),
+    (0x633, 0x63F, JoiningType::D), (0x640, 0x640, JoiningType::C), (0x641, 0x647, JoiningType::D),
+    (0x648, 0x648, JoiningType::R), (0x649, 0x64A, JoiningType::D), (0x64B, 0x65F, JoiningType::T),
+    (0x66E, 0x66F, JoiningType::D), (0x670, 0x670, JoiningType::T), (0x671, 0x673, JoiningType::R),
+    (0x675, 0x677, JoiningType::R), (0x678, 0x687, JoiningType::D), (0x688, 0x699, JoiningType::R),
+    (0x69A, 0x6BF, JoiningType::D), (0x6C0, 0x6C0, JoiningType::R), (0x6C1, 0x6C2, JoiningType::D),
+    (0x6C3, 0x6CB, JoiningType::R), (0x6CC, 0x6CC, JoiningType::D), (0x6CD, 0x6CD, JoiningType::R),
+    (0x6CE, 0x6CE, JoiningType::D), (0x6CF, 0x6CF, JoiningType::R), (0x6D0, 0x6D1, JoiningType::D),
+    (0x6D2, 0x6D3, JoiningType::R), (0x6D5, 0x6D5, JoiningType::R), (0x6D6, 0x6DC, JoiningType::T),
+    (0x6DF, 0x6E4, JoiningType::T), (0x6E7, 0x6E8, JoiningType::T), (0x6EA, 0x6ED, JoiningType::T),
+    (0x6EE, 0x6EF, JoiningType::R), (0x6FA, 0x6FC, JoiningType::D), (0x6FF, 0x6FF, JoiningType::D),
+    (0x70F, 0x70F, JoiningType::T), (0x710, 0x710, JoiningType::R), (0x711, 0x711, JoiningType::T),
+    (0x712, 0x714, JoiningType::D), (0x715, 0x719, JoiningType::R), (0x71A, 0x71D, JoiningType::D),
+    (0x71E, 0x71E, JoiningType::R), (0x71F, 0x727, JoiningType::D), (0x728, 0x728, JoiningType::R),
+    (0x729, 0x729, JoiningType::D), (0x72A, 0x72A, JoiningType::R), (0x72B, 0x72B, JoiningType::D),
+    (0x72C, 0x72C, JoiningType::R), (0x72D, 0x72E, JoiningType::D), (0x72F, 0x72F, JoiningType::R),
+    (0x730, 0x74A, JoiningType::T), (0x74D, 0x74D, JoiningType::R), (0x74E, 0x758, JoiningType::D),
+    (0x759, 0x75B, JoiningType::R), (0x75C, 0x76A, JoiningType::D), (0x76B, 0x76C, JoiningType::R),
+    (0x76D, 0x770, JoiningType::D), (0x771, 0x771, JoiningType::R), (0x772, 0x772, JoiningType::D),
+    (0x773, 0x774, JoiningType::R), (0x775, 0x777, JoiningType::D), (0x778, 0x779, JoiningType::R),
+    (0x77A, 0x77F, JoiningType::D), (0x7A6, 0x7B0, JoiningType::T), (0x7CA, 0x7EA, JoiningType::D),
+    (0x7EB, 0x7F3, JoiningType::T), (0x7FA, 0x7FA, JoiningType::C), (0x7FD, 0x7FD, JoiningType::T),
+    (0x816, 0x819, JoiningType::T), (0x81B, 0x823, JoiningType::T), (0x825, 0x827, JoiningType::T),
+    (0x829, 0x82D, JoiningType::T), (0x840, 0x840, JoiningType::R), (0x841, 0x845, JoiningType::D),
+    (0x846, 0x847, JoiningType::R), (0x848, 0x848, JoiningType::D), (0x849, 0x849, JoiningType::R),
+    (0x84A, 0x853, JoiningType::D), (0x854, 0x854, JoiningType::R), (0x855, 0x855, JoiningType::D),
+    (0x856, 0x858, JoiningType::R), (0x859, 0x85B, JoiningType::T), (0x860, 0x860, JoiningType::D),
+    (0x862, 0x865, JoiningType::D), (0x867, 0x867, JoiningType::R), (0x868, 0x868, JoiningType::D),
+    (0x869, 0x86A, JoiningType::R), (0x8A0, 0x8A9, JoiningType::D), (0x8AA, 0x8AC, JoiningType::R),
+    (0x8AE, 0x8AE, JoiningType::R), (0x8AF, 0x8B0, JoiningType::D), (0x8B1, 0x8B2, JoiningType::R),
+    (0x8B3, 0x8B4, JoiningType::D), (0x8B6, 0x8B8, JoiningType::D), (0x8B9, 0x8B9, JoiningType::R),
+    (0x8BA, 0x8C7, JoiningType::D), (0x8D3, 0x8E1, JoiningType::T), (0x8E3, 0x902, JoiningType::T),
+    (0x93A, 0x93A, JoiningType::T), (0x93C, 0x93C, JoiningType::T), (0x941, 0x948, JoiningType::T),
+    (0x94D, 0x94D, JoiningType::T), (0x951, 0x957, JoiningType::T), (0x962, 0x963, JoiningType::T),
+    (0x981, 0x981, JoiningType::T), (0x9BC, 0x9BC, JoiningType::T), (0x9C1, 0x9C4, JoiningType::T),
+    (0x9CD, 0x9CD, JoiningType::T), (0x9E2, 0x9E3, JoiningType::T), (0x9FE, 0x9FE, JoiningType::T),
+    (0xA01, 0xA02, JoiningType::T), (0xA3C, 0xA3C, JoiningType::T), (0xA41, 0xA42, JoiningType::T),
+    (0xA47, 0xA48, JoiningType::T), (0xA4B, 0xA4D, JoiningType::T), (0xA51, 0xA51, JoiningType::T),
+    (0xA70, 0xA71, JoiningType::T), (0xA75, 0xA75, JoiningType::T), (0xA81, 0xA82, JoiningType::T),
+    (0xABC, 0xABC, JoiningType::T), (0xAC1, 0xAC5, JoiningType::T), (0xAC7, 0xAC8, JoiningType::T),
+    (0xACD, 0xACD, JoiningType::T), (0xAE2, 0xAE3, JoiningType::T), (0xAFA, 0xAFF, JoiningType::T),
+    (0xB01, 0xB01, JoiningType::T), (0xB3C, 0xB3C, JoiningType::T), (0xB3F, 0xB3F, JoiningType::T),
+    (0xB41, 0xB44, JoiningType::T), (0xB4D, 0xB4D, JoiningType::T), (0xB55, 0xB56, JoiningType::T),
+    (0xB62, 0xB63, JoiningType::T), (0xB82, 0xB82, JoiningType::T), (0xBC0, 0xBC0, JoiningType::T),
+    (0xBCD, 0xBCD, JoiningType::T), (0xC00, 0xC00, JoiningType::T), (0xC04, 0xC04, JoiningType::T),
+    (0xC3E, 0xC40, JoiningType::T), (0xC46, 0xC48, JoiningType::T), (0xC4A, 0xC4D, JoiningType::T),
+    (0xC55, 0xC56, JoiningType::T), (0xC62, 0xC63, JoiningType::T), (0xC81, 0xC81, JoiningType::T),
+    (0xCBC, 0xCBC, JoiningType::T), (0xCBF, 0xCBF, JoiningType::T), (0xCC6, 0xCC6, JoiningType::T),
+    (0xCCC, 0xCCD, JoiningType::T), (0xCE2, 0xCE3, JoiningType::T), (0xD00, 0xD01, JoiningType::T),
+    (0xD3B, 0xD3C, JoiningType::T), (0xD41, 0xD44, JoiningType::T), (0xD4D, 0xD4D, JoiningType::T),
+    (0xD62, 0xD63, JoiningType::T), (0xD81, 0xD81, JoiningType::T), (0xDCA, 0xDCA, JoiningType::T),
+    (0xDD2, 0xDD4, JoiningType::T), (0xDD6, 0xDD6, JoiningType::T), (0xE31, 0xE31, JoiningType::T),
+    (0xE34, 0xE3A, JoiningType::T), (0xE47, 0xE4E, JoiningType::T), (0xEB1, 0xEB1, JoiningType::T),
+    (0xEB4, 0xEBC, JoiningType::T), (0xEC8, 0xECD, JoiningType::T), (0xF18, 0xF19, JoiningType::T),
+    (0xF35, 0xF35, JoiningType::T), (0xF37, 0xF37, JoiningType::T), (0xF39, 0xF39, JoiningType::T),
+    (0xF71, 0xF7E, JoiningType::T), (0xF80, 0xF84, JoiningType::T), (0xF86, 0xF87, JoiningType::T),
+    (0xF8D, 0xF97, JoiningType::T), (0xF99, 0xFBC, JoiningType::T), (0xFC6, 0xFC6, JoiningType::T),
+    (0x102D, 0x1030, JoiningType::T), (0x1032, 0x1037, JoiningType::T), (0x1039, 0x103A, JoiningType::T),
+    (0x103D, 0x103E, JoiningType::T), (0x1058, 0x1059, JoiningType::T), (0x105E, 0x1060, JoiningType::T),
+    (0x1071, 0x1074, JoiningType::T), (0x1082, 0x1082, JoiningType::T), (0x1085, 0x1086, JoiningType::T),
+    (0x108D, 0x108D, JoiningType::T), (0x109D, 0x109D, JoiningType::T), (0x135D, 0x135F, JoiningType::T),
+    (0x1712, 0x1714, JoiningType::T), (0x1732, 0x1733, JoiningType::T), (0x1752, 0x1753, JoiningType::T),
+    (0x1772, 0x1773, JoiningType::T), (0x17B4, 0x17B5, JoiningType::T), (0x17B7, 0x17BD, JoiningType::T),
+    (0x17C6, 0x17C6, JoiningType::T), (0x17C9, 0x17D3, JoiningType::T), (0x17DD, 0x17DD, JoiningType::T),
+    (0x1807, 0x1807, JoiningType::D), (0x180A, 0x180A, JoiningType::C), (0x180B, 0x180D, JoiningType::T),
+    (0x1820, 0x1878, JoiningType::D), (0x1885, 0x1886, JoiningType::T), (0x1887, 0x18A8, JoiningType::D),
+    (0x18A9, 0x18A9, JoiningType::T), (0x18AA, 0x18AA, JoiningType::D), (0x1920, 0x1922, JoiningType::T),
+    (0x1927, 0x1928, JoiningType::T), (0x1932, 0x1932, JoiningType::T), (0x1939, 0x193B, JoiningType::T),
+    (0x1A17, 0x1A18, JoiningType::T), (0x1A1B, 0x1A1B, JoiningType::T), (0x1A56, 0x1A56, JoiningType::T),
+    (0x1A58, 0x1A5E, JoiningType::T), (0x1A60, 0x1A60, JoiningType::T), (0x1A62, 0x1A62, JoiningType::T),
+    (0x1A65, 0x1A6C, JoiningType::T), (0x1A73, 0x1A7C, JoiningType::T), (0x1A7F, 0x1A7F, JoiningType::T),
+    (0x1AB0, 0x1AC0, JoiningType::T), (0x1B00, 0x1B03, JoiningType::T), (0x1B34, 0x1B34, JoiningType::T),
+    (0x1B36, 0x1B3A, JoiningType::T), (0x1B3C, 0x1B3C, JoiningType::T), (0x1B42, 0x1B42, JoiningType::T),
+    (0x1B6B, 0x1B73, JoiningType::T), (0x1B80, 0x1B81, JoiningType::T), (0x1BA2, 0x1BA5, JoiningType::T),
+    (0x1BA8, 0x1BA9, JoiningType::T), (0x1BAB, 0x1BAD, JoiningType::T), (0x1BE6, 0x1BE6, JoiningType::T),
+    (0x1BE8, 0x1BE9, JoiningType::T), (0x1BED, 0x1BED, JoiningType::T), (0x1BEF, 0x1BF1, JoiningType::T),
+    (0x1C2C, 0x1C33, JoiningType::T), (0x1C36, 0x1C37, JoiningType::T), (0x1CD0, 0x1CD2, JoiningType::T),
+    (0x1CD4, 0x1CE0, JoiningType::T), (0x1CE2, 0x1CE8, JoiningType::T), (0x1CED, 0x1CED, JoiningType::T),
+    (0x1CF4, 0x1CF4, JoiningType::T), (0x1CF8, 0x1CF9, JoiningType::T), (0x1DC0, 0x1DF9, JoiningType::T),
+    (0x1DFB, 0x1DFF, JoiningType::T), (0x200B, 0x200B, JoiningType::T), (0x200D, 0x200D, JoiningType::C),
+    (0x200E, 0x200F, JoiningType::T), (0x202A, 0x202E, JoiningType::T), (0x2060, 0x2064, JoiningType::T),
+    (0x206A, 0x206F, JoiningType::T), (0x20D0, 0x20F0, JoiningType::T), (0x2CEF, 0x2CF1, JoiningType::T),
+    (0x2D7F, 0x2D7F, JoiningType::T), (0x2DE0, 0x2DFF, JoiningType::T), (0x302A, 0x302D, JoiningType::T),
+    (0x3099, 0x309A, JoiningType::T), (0xA66F, 0xA672, JoiningType::T), (0xA674, 0xA67D, JoiningType::T),
+    (0xA69E, 0xA69F, JoiningType::T), (0xA6F0, 0xA6F1, JoiningType::T), (0xA802, 0xA802, JoiningType::T),
+    (0xA806, 0xA806, JoiningType::T), (0xA80B, 0xA80B, JoiningType::T), (0xA825, 0xA826, JoiningType::T),
+    (0xA82C, 0xA82C, JoiningType::T), (0xA840, 0xA871, JoiningType::D), (0xA872, 0xA872, JoiningType::L),
+    (0xA8C4, 0xA8C5, JoiningType::T), (0xA8E0, 0xA8F1, JoiningType::T), (0xA8FF, 0xA8FF, JoiningType::T),
+    (0xA926, 0xA92D, JoiningType::T), (0xA947, 0xA951, JoiningType::T), (0xA980, 0xA982, JoiningType::T),
+    (0xA9B3, 0xA9B3, JoiningType::T), (0xA9B6, 0xA9B9, JoiningType::T), (0xA9BC, 0xA9BD, JoiningType::T),
+    (0xA9E5, 0xA9E5, JoiningType::T), (0xAA29, 0xAA2E, JoiningType::T), (0xAA31, 0xAA32, JoiningType::T),
+    (0xAA35, 0xAA36, JoiningType::T), (0xAA43, 0xAA43, JoiningType::T), (0xAA4C, 0xAA4C, JoiningType::T),
+    (0xAA7C, 0xAA7C, JoiningType::T), (0xAAB0, 0xAAB0, JoiningType::T), (0xAAB2, 0xAAB4, JoiningType::T),
+    (0xAAB7, 0xAAB8, JoiningType::T), (0xAABE, 0xAABF, JoiningType::T), (0xAAC1, 0xAAC1, JoiningType::T),
+    (0xAAEC, 0xAAED, JoiningType::T), (0xAAF6, 0xAAF6, JoiningType::T), (0xABE5, 0xABE5, JoiningType::T),
+    (0xABE8, 0xABE8, JoiningType::T), (0xABED, 0xABED, JoiningType::T), (0xFB1E, 0xFB1E, JoiningType::T),
+    (0xFE00, 0xFE0F, JoiningType::T), (0xFE20, 0xFE2F, JoiningType::T), (0xFEFF, 0xFEFF, JoiningType::T),
+    (0xFFF9, 0xFFFB, JoiningType::T), (0x101FD, 0x101FD, JoiningType::T), (0x102E0, 0x102E0, JoiningType::T),
+    (0x10376, 0x1037A, JoiningType::T), (0x10A01, 0x10A03, JoiningType::T), (0x10A05, 0x10A06, JoiningType::T),
+    (0x10A0C, 0x10A0F, JoiningType::T), (0x10A38, 0x10A3A, JoiningType::T), (0x10A3F, 0x10A3F, JoiningType::T),
+    (0x10AC0, 0x10AC4, JoiningType::D), (0x10AC5, 0x10AC5, JoiningType::R), (0x10AC7, 0x10AC7, JoiningType::R),
+    (0x10AC9, 0x10ACA, JoiningType::R), (0x10ACD, 0x10ACD, JoiningType::L), (0x10ACE, 0x10AD2, JoiningType::R),
+    (0x10AD3, 0x10AD6, JoiningType::D), (0x10AD7, 0x10AD7, JoiningType::L), (0x10AD8, 0x10ADC, JoiningType::D),
+    (0x10ADD, 0x10ADD, JoiningType::R), (0x10ADE, 0x10AE0, JoiningType::D), (0x10AE1, 0x10AE1, JoiningType::R),
+    (0x10AE4, 0x10AE4, JoiningType::R), (0x10AE5, 0x10AE6, JoiningType::T), (0x10AEB, 0x10AEE, JoiningType::D),
+    (0x10AEF, 0x10AEF, JoiningType::R), (0x10B80, 0x10B80, JoiningType::D), (0x10B81, 0x10B81, JoiningType::R),
+    (0x10B82, 0x10B82, JoiningType::D), (0x10B83, 0x10B85, JoiningType::R), (0x10B86, 0x10B88, JoiningType::D),
+    (0x10B89, 0x10B89, JoiningType::R), (0x10B8A, 0x10B8B, JoiningType::D), (0x10B8C, 0x10B8C, JoiningType::R),
+    (0x10B8D, 0x10B8D, JoiningType::D), (0x10B8E, 0x10B8F, JoiningType::R), (0x10B90, 0x10B90, JoiningType::D),
+    (0x10B91, 0x10B91, JoiningType::R), (0x10BA9, 0x10BAC, JoiningType::R), (0x10BAD, 0x10BAE, JoiningType::D),
+    (0x10D00, 0x10D00, JoiningType::L), (0x10D01, 0x10D21, JoiningType::D), (0x10D22, 0x10D22, JoiningType::R),
+    (0x10D23, 0x10D23, JoiningType::D), (0x10D24, 0x10D27, JoiningType::T), (0x10EAB, 0x10EAC, JoiningType::T),
+    (0x10F30, 0x10F32, JoiningType::D), (0x10F33, 0x10F33, JoiningType::R), (0x10F34, 0x10F44, JoiningType::D),
+    (0x10F46, 0x10F50, JoiningType::T), (0x10F51, 0x10F53, JoiningType::D), (0x10F54, 0x10F54, JoiningType::R),
+    (0x10FB0, 0x10FB0, JoiningType::D), (0x10FB2, 0x10FB3, JoiningType::D), (0x10FB4, 0x10FB6, JoiningType::R),
+    (0x10FB8, 0x10FB8, JoiningType::D), (0x10FB9, 0x10FBA, JoiningType::R), (0x10FBB, 0x10FBC, JoiningType::D),
+    (0x10FBD, 0x10FBD, JoiningType::R), (0x10FBE, 0x10FBF, JoiningType::D), (0x10FC1, 0x10FC1, JoiningType::D),
+    (0x10FC2, 0x10FC3, JoiningType::R), (0x10FC4, 0x10FC4, JoiningType::D), (0x10FC9, 0x10FC9, JoiningType::R),
+    (0x10FCA, 0x10FCA, JoiningType::D), (0x10FCB, 0x10FCB, JoiningType::L), (0x11001, 0x11001, JoiningType::T),
+    (0x11038, 0x11046, JoiningType::T), (0x1107F, 0x11081, JoiningType::T), (0x110B3, 0x110B6, JoiningType::T),
+    (0x110B9, 0x110BA, JoiningType::T), (0x11100, 0x11102, JoiningType::T), (0x11127, 0x1112B, JoiningType::T),
+    (0x1112D, 0x11134, JoiningType::T), (0x11173, 0x11173, JoiningType::T), (0x11180, 0x11181, JoiningType::T),
+    (0x111B6, 0x111BE, JoiningType::T), (0x111C9, 0x111CC, JoiningType::T), (0x111CF, 0x111CF, JoiningType::T),
+    (0x1122F, 0x11231, JoiningType::T), (0x11234, 0x11234, JoiningType::T), (0x11236, 0x11237, JoiningType::T),
+    (0x1123E, 0x1123E, JoiningType::T), (0x112DF, 0x112DF, JoiningType::T), (0x112E3, 0x112EA, JoiningType::T),
+    (0x11300, 0x11301, JoiningType::T), (0x1133B, 0x1133C, JoiningType::T), (0x11340, 0x11340, JoiningType::T),
+    (0x11366, 0x1136C, JoiningType::T), (0x11370, 0x11374, JoiningType::T), (0x11438, 0x1143F, JoiningType::T),
+    (0x11442, 0x11444, JoiningType::T), (0x11446, 0x11446, JoiningType::T), (0x1145E, 0x1145E, JoiningType::T),
+    (0x114B3, 0x114B8, JoiningType::T), (0x114BA, 0x114BA, JoiningType::T), (0x114BF, 0x114C0, JoiningType::T),
+    (0x114C2, 0x114C3, JoiningType::T), (0x115B2, 0x115B5, JoiningType::T), (0x115BC, 0x115BD, JoiningType::T),
+    (0x115BF, 0x115C0, JoiningType::T), (0x115DC, 0x115DD, JoiningType::T), (0x11633, 0x1163A, JoiningType::T),
+    (0x1163D, 0x1163D, JoiningType::T), (0x1163F, 0x11640, JoiningType::T), (0x116AB, 0x116AB, JoiningType::T),
+    (0x116AD, 0x116AD, JoiningType::T), (0x116B0, 0x116B5, JoiningType::T), (0x116B7, 0x116B7, JoiningType::T),
+    (0x1171D, 0x1171F, JoiningType::T), (0x11722, 0x11725, JoiningType::T), (0x11727, 0x1172B, JoiningType::T),
+    (0x1182F, 0x11837, JoiningType::T), (0x11839, 0x1183A, JoiningType::T), (0x1193B, 0x1193C, JoiningType::T),
+    (0x1193E, 0x1193E, JoiningType::T), (0x11943, 0x11943, JoiningType::T), (0x119D4, 0x119D7, JoiningType::T),
+    (0x119DA, 0x119DB, JoiningType::T), (0x119E0, 0x119E0, JoiningType::T), (0x11A01, 0x11A0A, JoiningType::T),
+    (0x11A33, 0x11A38, JoiningType::T), (0x11A3B, 0x11A3E, JoiningType::T), (0x11A47, 0x11A47, JoiningType::T),
+    (0x11A51, 0x11A56, JoiningType::T), (0x11A59, 0x11A5B, JoiningType::T), (0x11A8A, 0x11A96, JoiningType::T),
+    (0x11A98, 0x11A99, JoiningType::T), (0x11C30, 0x11C36, JoiningType::T), (0x11C38, 0x11C3D, JoiningType::T),
+    (0x11C3F, 0x11C3F, JoiningType::T), (0x11C92, 0x11CA7, JoiningType::T), (0x11CAA, 0x11CB0, JoiningType::T),
+    (0x11CB2, 0x11CB3, JoiningType::T), (0x11CB5, 0x11CB6, JoiningType::T), (0x11D31, 0x11D36, JoiningType::T),
+    (0x11D3A, 0x11D3A, JoiningType::T), (0x11D3C, 0x11D3D, JoiningType::T), (0x11D3F, 0x11D45, JoiningType::T),
+    (0x11D47, 0x11D47, JoiningType::T), (0x11D90, 0x11D91, JoiningType::T), (0x11D95, 0x11D95, JoiningType::T),
+    (0x11D97, 0x11D97, JoiningType::T), (0x11EF3, 0x11EF4, JoiningType::T), (0x13430, 0x13438, JoiningType::T),
+    (0x16AF0, 0x16AF4, JoiningType::T), (0x16B30, 0x16B36, JoiningType::T), (0x16F4F, 0x16F4F, JoiningType::T),
+    (0x16F8F, 0x16F92, JoiningType::T), (0x16FE4, 0x16FE4, JoiningType::T), (0x1BC9D, 0x1BC9E, JoiningType::T),
+    (0x1BCA0, 0x1BCA3, JoiningType::T), (0x1D167, 0x1D169, JoiningType::T), (0x1D173, 0x1D182, JoiningType::T),
+    (0x1D185, 0x1D18B, JoiningType::T), (0x1D1AA, 0x1D1AD, JoiningType::T), (0x1D242, 0x1D244, JoiningType::T),
+    (0x1DA00, 0x1DA36, JoiningType::T), (0x1DA3B, 0x1DA6C, JoiningType::T), (0x1DA75, 0x1DA75, JoiningType::T),
+    (0x1DA84, 0x1DA84, JoiningType::T), (0x1DA9B, 0x1DA9F, JoiningType::T), (0x1DAA1, 0x1DAAF, JoiningType::T),
+    (0x1E000, 0x1E006, JoiningType::T), (0x1E008, 0x1E018, JoiningType::T), (0x1E01B, 0x1E021, JoiningType::T),
+    (0x1E023, 0x1E024, JoiningType::T), (0x1E026, 0x1E02A, JoiningType::T), (0x1E130, 0x1E136, JoiningType::T),
+    (0x1E2EC, 0x1E2EF, JoiningType::T), (0x1E8D0, 0x1E8D6, JoiningType::T), (0x1E900, 0x1E943, JoiningType::D),
+    (0x1E944, 0x1E94B, JoiningType::T), (0xE0001, 0xE0001, JoiningType::T), (0xE0020, 0xE007F, JoiningType::T),
+    (0xE0100, 0xE01EF, JoiningType::T),
+];
+
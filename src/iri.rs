@@ -0,0 +1,269 @@
+use super::char_buf::new_iri_char_buf;
+use super::parse_end;
+use super::parse_uri;
+use super::parse_uri_reference;
+use super::Authority;
+use super::Char;
+use super::Fragment;
+use super::Host;
+use super::Path;
+use super::Query;
+use super::RegName;
+use super::RelativeRef;
+use super::Scheme;
+use super::Segment;
+use super::Uri;
+use super::UriRef;
+use super::Userinfo;
+use common_failures::prelude::*;
+
+//    IRI            = scheme ":" ihier-part [ "?" iquery ] [ "#" ifragment ]
+//
+// The IRI grammar of RFC 3987 is the URI grammar with `iunreserved = unreserved / ucschar` in
+// place of `unreserved` and `iprivate` added to the query. The URI parsers accept both as soon as
+// the input is read with `new_iri_char_buf`, which yields `Char::Unicode` for non-ASCII input.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Iri(Uri);
+
+impl Iri {
+    pub fn parse(s: &str) -> Result<Iri> {
+        let mut tb = new_iri_char_buf(s.as_bytes());
+        let uri = match parse_uri(&mut tb)? {
+            Some(uri) => uri,
+            None => return Err(format_err!("Missing scheme.")),
+        };
+        parse_end(&mut tb)?;
+        Ok(Iri(uri))
+    }
+
+    pub fn scheme(&self) -> &Scheme {
+        self.0.scheme()
+    }
+
+    pub fn authority(&self) -> Option<&Authority> {
+        self.0.authority()
+    }
+
+    pub fn path(&self) -> &Path {
+        self.0.path()
+    }
+
+    pub fn query(&self) -> Option<&Query> {
+        self.0.query()
+    }
+
+    pub fn fragment(&self) -> Option<&Fragment> {
+        self.0.fragment()
+    }
+
+    /// Maps the IRI to a URI by percent-encoding the UTF-8 octets of all non-ASCII characters,
+    /// see RFC 3987 section 3.1.
+    pub fn to_uri(&self) -> Uri {
+        map_uri(&self.0, &encode_chars)
+    }
+}
+
+impl std::str::FromStr for Iri {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Iri> {
+        Iri::parse(s)
+    }
+}
+
+impl std::fmt::Display for Iri {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(fmt, "{}", &self.0)
+    }
+}
+
+//    IRI-reference  = IRI / irelative-ref
+#[derive(Clone, Debug, PartialEq)]
+pub struct IriRef(UriRef);
+
+impl IriRef {
+    pub fn parse(s: &str) -> Result<IriRef> {
+        let mut tb = new_iri_char_buf(s.as_bytes());
+        let uri_ref = parse_uri_reference(&mut tb)?;
+        parse_end(&mut tb)?;
+        Ok(IriRef(uri_ref))
+    }
+
+    pub fn is_relative(&self) -> bool {
+        self.0.is_relative()
+    }
+
+    pub fn authority(&self) -> Option<&Authority> {
+        self.0.authority()
+    }
+
+    pub fn path(&self) -> &Path {
+        self.0.path()
+    }
+
+    pub fn query(&self) -> Option<&Query> {
+        self.0.query()
+    }
+
+    pub fn fragment(&self) -> Option<&Fragment> {
+        self.0.fragment()
+    }
+
+    pub fn to_uri_ref(&self) -> UriRef {
+        match &self.0 {
+            UriRef::Uri(uri) => UriRef::Uri(map_uri(uri, &encode_chars)),
+            UriRef::RelativeRef(relative_ref) => {
+                UriRef::RelativeRef(map_relative_ref(relative_ref, &encode_chars))
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for IriRef {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<IriRef> {
+        IriRef::parse(s)
+    }
+}
+
+impl std::fmt::Display for IriRef {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(fmt, "{}", &self.0)
+    }
+}
+
+impl Uri {
+    /// Maps the URI to an IRI by decoding percent-encoded UTF-8 sequences, as far as RFC 3987
+    /// section 3.2 allows: only into `ucschar` (and `iprivate` within the query), never into
+    /// ASCII and never into the bidirectional formatting characters.
+    pub fn to_iri(&self) -> Iri {
+        Iri(map_uri(self, &decode_chars))
+    }
+}
+
+fn map_uri<F>(uri: &Uri, f: &F) -> Uri
+where
+    F: Fn(&[Char], bool) -> Vec<Char>,
+{
+    Uri {
+        scheme: uri.scheme.clone(),
+        authority: uri.authority.as_ref().map(|authority| map_authority(authority, f)),
+        path: map_path(&uri.path, f),
+        query: uri.query.as_ref().map(|query| Query(f(&query.0, true))),
+        fragment: uri.fragment.as_ref().map(|fragment| Fragment(f(&fragment.0, false))),
+    }
+}
+
+fn map_relative_ref<F>(relative_ref: &RelativeRef, f: &F) -> RelativeRef
+where
+    F: Fn(&[Char], bool) -> Vec<Char>,
+{
+    RelativeRef {
+        authority: relative_ref
+            .authority
+            .as_ref()
+            .map(|authority| map_authority(authority, f)),
+        path: map_path(&relative_ref.path, f),
+        query: relative_ref.query.as_ref().map(|query| Query(f(&query.0, true))),
+        fragment: relative_ref
+            .fragment
+            .as_ref()
+            .map(|fragment| Fragment(f(&fragment.0, false))),
+    }
+}
+
+fn map_authority<F>(authority: &Authority, f: &F) -> Authority
+where
+    F: Fn(&[Char], bool) -> Vec<Char>,
+{
+    Authority {
+        userinfo: authority
+            .userinfo
+            .as_ref()
+            .map(|userinfo| Userinfo(f(&userinfo.0, false))),
+        host: match &authority.host {
+            Host::RegName(reg_name) => Host::RegName(RegName(f(&reg_name.0, false))),
+            host => host.clone(),
+        },
+        port: authority.port.clone(),
+    }
+}
+
+fn map_path<F>(path: &Path, f: &F) -> Path
+where
+    F: Fn(&[Char], bool) -> Vec<Char>,
+{
+    Path {
+        segments: path
+            .segments
+            .iter()
+            .map(|segment| Segment(f(&segment.0, false)))
+            .collect(),
+        absolute: path.absolute,
+    }
+}
+
+fn encode_chars(chars: &[Char], _query: bool) -> Vec<Char> {
+    let mut encoded: Vec<Char> = Vec::new();
+    for c in chars {
+        match c {
+            Char::Unicode(c) => {
+                let mut buf = [0; 4];
+                for b in c.encode_utf8(&mut buf).bytes() {
+                    encoded.push(Char::pct_encoded(b));
+                }
+            }
+            c => encoded.push(*c),
+        }
+    }
+    encoded
+}
+
+fn decode_chars(chars: &[Char], query: bool) -> Vec<Char> {
+    let mut decoded: Vec<Char> = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        if let Some((c, len)) = decode_utf8(&chars[i..]) {
+            let c = Char::Unicode(c);
+            if c.is_ucschar() && !is_bidi_format(c) || query && c.is_iprivate() {
+                decoded.push(c);
+                i += len;
+                continue;
+            }
+        }
+        decoded.push(chars[i]);
+        i += 1;
+    }
+    decoded
+}
+
+// The non-ASCII character encoded by the percent-encoded UTF-8 sequence at the start of `chars`,
+// together with the number of `Char`s it spans.
+fn decode_utf8(chars: &[Char]) -> Option<(char, usize)> {
+    let len = match chars.first().and_then(|c| c.decode()) {
+        Some(0xC0..=0xDF) => 2,
+        Some(0xE0..=0xEF) => 3,
+        Some(0xF0..=0xF7) => 4,
+        _ => return None,
+    };
+    if chars.len() < len {
+        return None;
+    }
+
+    let mut bytes: Vec<u8> = Vec::new();
+    for c in &chars[..len] {
+        bytes.push(c.decode()?);
+    }
+    match std::str::from_utf8(&bytes) {
+        Ok(s) => s.chars().next().map(|c| (c, len)),
+        Err(_) => None,
+    }
+}
+
+fn is_bidi_format(c: Char) -> bool {
+    matches!(
+        c,
+        Char::Unicode('\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}')
+    )
+}
@@ -2,51 +2,1247 @@ extern crate common_failures;
 #[macro_use]
 extern crate failure;
 extern crate srcpit_token_buf as token_buf;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(all(test, feature = "serde"))]
+#[macro_use]
+extern crate serde_derive;
+
+mod builder;
+mod char_buf;
+mod equivalence;
+mod idna;
+mod iri;
+mod nested;
+mod normalize;
+mod path;
+pub mod percent;
+mod query;
+#[cfg(feature = "serde")]
+mod query_de;
+mod resolve;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod whatwg;
+
+#[cfg(test)]
+mod tests;
+
+use common_failures::prelude::*;
+
+pub use self::builder::UriBuilder;
+pub use self::char_buf::Char;
+pub use self::equivalence::Level;
+pub use self::equivalence::NormalizedUri;
+pub use self::idna::IdnaOptions;
+pub use self::iri::Iri;
+pub use self::iri::IriRef;
+pub use self::nested::NestedQueryOptions;
+pub use self::nested::QueryValue;
+pub use self::normalize::NormalizeOptions;
+pub use self::normalize::SchemeDefault;
+pub use self::normalize::SchemeDefaults;
+pub use self::path::PathMut;
+pub use self::query::Pairs;
+pub use self::query::QueryBuilder;
+pub use self::query::QueryMut;
+pub use self::query::SpaceEncoding;
+#[cfg(feature = "serde")]
+pub use self::query_de::from_query;
+
+use self::char_buf::new_char_buf;
+use self::char_buf::CharStream;
+use std::borrow::Cow;
+use std::fmt::Write;
+use std::net::Ipv4Addr;
+use std::net::Ipv6Addr;
+use std::net::SocketAddrV6;
+use token_buf::ByteStream;
+use token_buf::TokenBuffer;
+use token_buf::TokenStream;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum UriError {
+    PortOutOfRange(String),
+    InvalidDnsName(String, String),
+    InvalidComponent(String, String),
+}
+
+impl std::fmt::Display for UriError {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            UriError::PortOutOfRange(port) => write!(fmt, "Port out of range: {}", port),
+            UriError::InvalidDnsName(name, reason) => {
+                write!(fmt, "Invalid DNS name '{}': {}", name, reason)
+            }
+            UriError::InvalidComponent(component, reason) => {
+                write!(fmt, "Invalid {}: {}", component, reason)
+            }
+        }
+    }
+}
+
+impl failure::Fail for UriError {}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ParseMode {
+    /// The generic URI syntax of RFC 3986.
+    Rfc3986,
+    /// The URL Standard of the WHATWG, as implemented by web browsers. Its special schemes (http,
+    /// https, ws, wss, ftp and file) treat '\\' like '/', drop default ports and require a host,
+    /// tabs and newlines are removed, IPv4 hosts may be written in hex or octal and characters
+    /// outside the URL code points are percent-encoded instead of rejected. What the standard
+    /// leaves as is but RFC 3986 does not allow, like the space of "mailto:a b", is
+    /// percent-encoded as well, so the result is a valid URI.
+    Whatwg,
+}
+
+impl Default for ParseMode {
+    fn default() -> ParseMode {
+        ParseMode::Rfc3986
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ParseOptions {
+    /// Requires reg-name hosts to be valid DNS host names, see `RegName::validate_dns`.
+    pub strict_dns: bool,
+    pub mode: ParseMode,
+}
+
+impl ParseOptions {
+    fn validate(&self, authority: Option<&Authority>) -> Result<()> {
+        if let Some(authority) = authority {
+            if let Host::RegName(reg_name) = authority.host() {
+                if self.strict_dns {
+                    reg_name.validate_dns()?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+//    URI           = scheme ":" hier-part [ "?" query ] [ "#" fragment ]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Uri {
+    scheme: Scheme,
+    authority: Option<Authority>,
+    path: Path,
+    query: Option<Query>,
+    fragment: Option<Fragment>,
+}
+
+impl Uri {
+    pub fn parse(s: &str) -> Result<Uri> {
+        Uri::parse_with(s, &ParseOptions::default())
+    }
+
+    pub fn parse_with(s: &str, options: &ParseOptions) -> Result<Uri> {
+        let uri = match options.mode {
+            ParseMode::Rfc3986 => {
+                let mut tb = new_char_buf(s.as_bytes());
+                let uri = match parse_uri(&mut tb)? {
+                    Some(uri) => uri,
+                    None => return Err(format_err!("Missing scheme.")),
+                };
+                parse_end(&mut tb)?;
+                uri
+            }
+            ParseMode::Whatwg => whatwg::parse(s, None)?,
+        };
+        options.validate(uri.authority())?;
+        Ok(uri)
+    }
+
+    /// Parses `s` as a reference and resolves it against `base`. In the WHATWG mode this is the
+    /// URL parser run with a base URL.
+    pub fn parse_with_base(s: &str, base: &Uri, options: &ParseOptions) -> Result<Uri> {
+        let uri = match options.mode {
+            ParseMode::Rfc3986 => base.resolve(&UriRef::parse_with(s, options)?),
+            ParseMode::Whatwg => whatwg::parse(s, Some(base))?,
+        };
+        options.validate(uri.authority())?;
+        Ok(uri)
+    }
+
+    pub fn scheme(&self) -> &Scheme {
+        &self.scheme
+    }
+
+    pub fn authority(&self) -> Option<&Authority> {
+        self.authority.as_ref()
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn query(&self) -> Option<&Query> {
+        self.query.as_ref()
+    }
+
+    pub fn fragment(&self) -> Option<&Fragment> {
+        self.fragment.as_ref()
+    }
+
+    pub fn set_scheme(&mut self, scheme: &str) -> Result<()> {
+        self.scheme = Scheme::parse(scheme)?;
+        Ok(())
+    }
+
+    /// Sets the host, adding an authority if there is none. `None` removes the authority
+    /// including its userinfo and port.
+    pub fn set_host(&mut self, host: Option<&str>) -> Result<()> {
+        let authority = match host {
+            Some(host) => {
+                let host = parse_component(host, parse_host)?;
+                match self.authority.take() {
+                    Some(authority) => Authority { host, ..authority },
+                    None => Authority {
+                        userinfo: None,
+                        host,
+                        port: None,
+                    },
+                }
+            }
+            None => {
+                check_path(None, &self.path)?;
+                self.authority = None;
+                return Ok(());
+            }
+        };
+        check_path(Some(&authority), &self.path)?;
+        self.authority = Some(authority);
+        Ok(())
+    }
+
+    pub fn set_port(&mut self, port: Option<u16>) -> Result<()> {
+        match &mut self.authority {
+            Some(authority) => {
+                authority.port = port.map(Port::new);
+                Ok(())
+            }
+            None if port.is_none() => Ok(()),
+            None => Err(format_err!("Cannot set a port without a host.")),
+        }
+    }
+
+    pub fn set_path(&mut self, path: &str) -> Result<()> {
+        let path = parse_component(path, |tb| {
+            if let Some(path_abempty) = parse_path_abempty(tb)? {
+                return Ok(path_abempty);
+            }
+            match parse_path_rootless(tb)? {
+                Some(path_rootless) => Ok(path_rootless),
+                None => Ok(Path::empty()),
+            }
+        })?;
+        check_path(self.authority.as_ref(), &path)?;
+        self.path = path;
+        Ok(())
+    }
+
+    pub fn set_query(&mut self, query: Option<&str>) -> Result<()> {
+        self.query = match query {
+            Some(query) => Some(parse_component(query, parse_query)?),
+            None => None,
+        };
+        Ok(())
+    }
+
+    pub fn set_fragment(&mut self, fragment: Option<&str>) -> Result<()> {
+        self.fragment = match fragment {
+            Some(fragment) => Some(parse_component(fragment, parse_fragment)?),
+            None => None,
+        };
+        Ok(())
+    }
+}
+
+// Parses all of `s` with `parse`, for the setters.
+fn parse_component<'a, F, X>(s: &'a str, parse: F) -> Result<X>
+where
+    F: FnOnce(&mut TokenBuffer<Char, CharStream<ByteStream<&'a [u8]>>>) -> Result<X>,
+{
+    let mut tb = new_char_buf(s.as_bytes());
+    let component = parse(&mut tb)?;
+    parse_end(&mut tb)?;
+    Ok(component)
+}
+
+// The constraints of RFC 3986 section 3.3 on the path: with an authority it is empty or starts
+// with "/", without one it cannot start with "//".
+fn check_path(authority: Option<&Authority>, path: &Path) -> Result<()> {
+    match authority {
+        Some(_) if !path.absolute && !path.is_empty() => Err(format_err!(
+            "Path '{}' must be empty or start with '/' when there is an authority.",
+            path
+        )),
+        None if path.absolute && path.segments.len() > 1 && path.segments[0].0.is_empty() => {
+            Err(format_err!(
+                "Path '{}' cannot start with '//' without an authority.",
+                path
+            ))
+        }
+        _ => Ok(()),
+    }
+}
+
+// Puts a "." segment in front of an absolute path starting with "//" when there is no authority,
+// which the path would otherwise turn into. "/.//x" is still "//x" once dot-segments are removed.
+fn guard_double_slash(authority: Option<&Authority>, mut path: Path) -> Path {
+    let double_slash = path.absolute && path.segments.len() > 1 && path.segments[0].0.is_empty();
+    if authority.is_none() && double_slash {
+        path.segments.insert(0, Segment(vec![Char::Ascii(b'.')]));
+    }
+    path
+}
+
+impl std::str::FromStr for Uri {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Uri> {
+        Uri::parse(s)
+    }
+}
+
+impl std::fmt::Display for Uri {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(fmt, "{}:", &self.scheme)?;
+        if let Some(authority) = &self.authority {
+            write!(fmt, "//{}", authority)?;
+        }
+        write!(fmt, "{}", &self.path)?;
+        if let Some(query) = &self.query {
+            write!(fmt, "?{}", query)?;
+        }
+        if let Some(fragment) = &self.fragment {
+            write!(fmt, "#{}", fragment)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_uri<T>(tb: &mut TokenBuffer<Char, T>) -> Result<Option<Uri>>
+where
+    T: TokenStream<Char>,
+{
+    let scheme = match parse_scheme(tb)? {
+        Some(scheme) => scheme,
+        None => return Ok(None),
+    };
+    let (authority, path) = parse_hier_part(tb)?;
+    let query = match parse_token(tb, b'?')? {
+        Some(_) => Some(parse_query(tb)?),
+        None => None,
+    };
+    let fragment = match parse_token(tb, b'#')? {
+        Some(_) => Some(parse_fragment(tb)?),
+        None => None,
+    };
+    Ok(Some(Uri {
+        scheme,
+        authority,
+        path,
+        query,
+        fragment,
+    }))
+}
+
+fn parse_token<T>(tb: &mut TokenBuffer<Char, T>, byte: u8) -> Result<Option<Char>>
+where
+    T: TokenStream<Char>,
+{
+    if let Some(token) = tb.pop()? {
+        if token.is(byte) {
+            return Ok(Some(token));
+        }
+        tb.push(token);
+    }
+    Ok(None)
+}
+
+fn parse_end<T>(tb: &mut TokenBuffer<Char, T>) -> Result<()>
+where
+    T: TokenStream<Char>,
+{
+    match tb.pop()? {
+        Some(token) => Err(format_err!("Unexpected character '{}'.", token)),
+        None => Ok(()),
+    }
+}
+
+//    hier-part     = "//" authority path-abempty
+//                  / path-absolute
+//                  / path-rootless
+//                  / path-empty
+fn parse_hier_part<T>(tb: &mut TokenBuffer<Char, T>) -> Result<(Option<Authority>, Path)>
+where
+    T: TokenStream<Char>,
+{
+    if let Some(slash1) = parse_token(tb, b'/')? {
+        if parse_token(tb, b'/')?.is_some() {
+            let authority = parse_authority(tb)?;
+            let path = match parse_path_abempty(tb)? {
+                Some(path) => path,
+                None => Path::empty(),
+            };
+            return Ok((Some(authority), path));
+        }
+        tb.push(slash1);
+    }
+    Ok((None, parse_path(tb)?))
+}
+
+//    URI-reference = URI / relative-ref
+#[derive(Clone, Debug, PartialEq)]
+pub enum UriRef {
+    Uri(Uri),
+    RelativeRef(RelativeRef),
+}
+
+impl UriRef {
+    pub fn parse(s: &str) -> Result<UriRef> {
+        UriRef::parse_with(s, &ParseOptions::default())
+    }
+
+    /// In the WHATWG mode there are no relative references without a base, see
+    /// `Uri::parse_with_base`.
+    pub fn parse_with(s: &str, options: &ParseOptions) -> Result<UriRef> {
+        let uri_ref = match options.mode {
+            ParseMode::Rfc3986 => {
+                let mut tb = new_char_buf(s.as_bytes());
+                let uri_ref = parse_uri_reference(&mut tb)?;
+                parse_end(&mut tb)?;
+                uri_ref
+            }
+            ParseMode::Whatwg => UriRef::Uri(whatwg::parse(s, None)?),
+        };
+        options.validate(uri_ref.authority())?;
+        Ok(uri_ref)
+    }
+
+    pub fn is_relative(&self) -> bool {
+        match self {
+            UriRef::Uri(_) => false,
+            UriRef::RelativeRef(_) => true,
+        }
+    }
+
+    pub fn authority(&self) -> Option<&Authority> {
+        match self {
+            UriRef::Uri(uri) => uri.authority(),
+            UriRef::RelativeRef(relative_ref) => relative_ref.authority(),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        match self {
+            UriRef::Uri(uri) => uri.path(),
+            UriRef::RelativeRef(relative_ref) => relative_ref.path(),
+        }
+    }
+
+    pub fn query(&self) -> Option<&Query> {
+        match self {
+            UriRef::Uri(uri) => uri.query(),
+            UriRef::RelativeRef(relative_ref) => relative_ref.query(),
+        }
+    }
+
+    pub fn fragment(&self) -> Option<&Fragment> {
+        match self {
+            UriRef::Uri(uri) => uri.fragment(),
+            UriRef::RelativeRef(relative_ref) => relative_ref.fragment(),
+        }
+    }
+}
+
+impl std::str::FromStr for UriRef {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<UriRef> {
+        UriRef::parse(s)
+    }
+}
+
+impl std::fmt::Display for UriRef {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            UriRef::Uri(uri) => write!(fmt, "{}", uri),
+            UriRef::RelativeRef(relative_ref) => write!(fmt, "{}", relative_ref),
+        }
+    }
+}
+
+impl From<Uri> for UriRef {
+    fn from(uri: Uri) -> UriRef {
+        UriRef::Uri(uri)
+    }
+}
+
+impl From<RelativeRef> for UriRef {
+    fn from(relative_ref: RelativeRef) -> UriRef {
+        UriRef::RelativeRef(relative_ref)
+    }
+}
+
+fn parse_uri_reference<T>(tb: &mut TokenBuffer<Char, T>) -> Result<UriRef>
+where
+    T: TokenStream<Char>,
+{
+    if let Some(uri) = parse_uri(tb)? {
+        return Ok(UriRef::Uri(uri));
+    }
+    Ok(UriRef::RelativeRef(parse_relative_ref(tb)?))
+}
+
+//    absolute-URI  = scheme ":" hier-part [ "?" query ]
+
+//    relative-ref  = relative-part [ "?" query ] [ "#" fragment ]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RelativeRef {
+    authority: Option<Authority>,
+    path: Path,
+    query: Option<Query>,
+    fragment: Option<Fragment>,
+}
+
+impl RelativeRef {
+    pub fn parse(s: &str) -> Result<RelativeRef> {
+        let mut tb = new_char_buf(s.as_bytes());
+        let relative_ref = parse_relative_ref(&mut tb)?;
+        parse_end(&mut tb)?;
+        Ok(relative_ref)
+    }
+
+    pub fn authority(&self) -> Option<&Authority> {
+        self.authority.as_ref()
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn query(&self) -> Option<&Query> {
+        self.query.as_ref()
+    }
+
+    pub fn fragment(&self) -> Option<&Fragment> {
+        self.fragment.as_ref()
+    }
+}
+
+impl std::str::FromStr for RelativeRef {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<RelativeRef> {
+        RelativeRef::parse(s)
+    }
+}
+
+impl std::fmt::Display for RelativeRef {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if let Some(authority) = &self.authority {
+            write!(fmt, "//{}", authority)?;
+        }
+        write!(fmt, "{}", &self.path)?;
+        if let Some(query) = &self.query {
+            write!(fmt, "?{}", query)?;
+        }
+        if let Some(fragment) = &self.fragment {
+            write!(fmt, "#{}", fragment)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_relative_ref<T>(tb: &mut TokenBuffer<Char, T>) -> Result<RelativeRef>
+where
+    T: TokenStream<Char>,
+{
+    let (authority, path) = parse_relative_part(tb)?;
+    let query = match parse_token(tb, b'?')? {
+        Some(_) => Some(parse_query(tb)?),
+        None => None,
+    };
+    let fragment = match parse_token(tb, b'#')? {
+        Some(_) => Some(parse_fragment(tb)?),
+        None => None,
+    };
+    Ok(RelativeRef {
+        authority,
+        path,
+        query,
+        fragment,
+    })
+}
+
+//    relative-part = "//" authority path-abempty
+//                  / path-absolute
+//                  / path-noscheme
+//                  / path-empty
+fn parse_relative_part<T>(tb: &mut TokenBuffer<Char, T>) -> Result<(Option<Authority>, Path)>
+where
+    T: TokenStream<Char>,
+{
+    if let Some(slash1) = parse_token(tb, b'/')? {
+        if parse_token(tb, b'/')?.is_some() {
+            let authority = parse_authority(tb)?;
+            let path = match parse_path_abempty(tb)? {
+                Some(path) => path,
+                None => Path::empty(),
+            };
+            return Ok((Some(authority), path));
+        }
+        tb.push(slash1);
+    }
+
+    let path: Path;
+    if let Some(path_absolute) = parse_path_absolute(tb)? {
+        path = path_absolute;
+    } else if let Some(path_noscheme) = parse_path_noscheme(tb)? {
+        path = path_noscheme;
+    } else {
+        path = Path::empty();
+    }
+    Ok((None, path))
+}
+
+//    scheme        = ALPHA *( ALPHA / DIGIT / "+" / "-" / "." )
+#[derive(Clone, Debug)]
+pub struct Scheme(Cow<'static, str>);
+
+impl Scheme {
+    pub const HTTP: Scheme = Scheme(Cow::Borrowed("http"));
+    pub const HTTPS: Scheme = Scheme(Cow::Borrowed("https"));
+    pub const FILE: Scheme = Scheme(Cow::Borrowed("file"));
+    pub const MAILTO: Scheme = Scheme(Cow::Borrowed("mailto"));
+    pub const URN: Scheme = Scheme(Cow::Borrowed("urn"));
+
+    pub fn parse(s: &str) -> Result<Scheme> {
+        let mut bytes = s.bytes();
+        let valid = match bytes.next() {
+            Some(b) => Char::Ascii(b).is_alpha() && bytes.all(|b| is_scheme_char(Char::Ascii(b))),
+            None => false,
+        };
+        match valid {
+            true => Ok(Scheme(Cow::Owned(s.to_string()))),
+            false => Err(format_err!("Invalid scheme '{}'.", s)),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn normalize(&self) -> Scheme {
+        Scheme(Cow::Owned(self.0.to_ascii_lowercase()))
+    }
+}
+
+impl PartialEq for Scheme {
+    fn eq(&self, other: &Scheme) -> bool {
+        self.0.eq_ignore_ascii_case(&other.0)
+    }
+}
+
+impl Eq for Scheme {}
+
+impl std::hash::Hash for Scheme {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        for b in self.0.bytes() {
+            state.write_u8(b.to_ascii_lowercase());
+        }
+    }
+}
+
+impl std::str::FromStr for Scheme {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Scheme> {
+        Scheme::parse(s)
+    }
+}
+
+impl std::fmt::Display for Scheme {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        fmt.write_str(&self.0)
+    }
+}
+
+fn is_scheme_char(c: Char) -> bool {
+    c.is_alpha() || c.is_digit() || c.is(b'+') || c.is(b'-') || c.is(b'.')
+}
+
+fn parse_scheme<T>(tb: &mut TokenBuffer<Char, T>) -> Result<Option<Scheme>>
+where
+    T: TokenStream<Char>,
+{
+    let mut tokens: Vec<Char> = Vec::new();
+    loop {
+        if let Some(token) = tb.pop()? {
+            if token.is_alpha() || (!tokens.is_empty() && is_scheme_char(token)) {
+                tokens.push(token);
+                continue;
+            }
+            tb.push(token);
+        }
+        break;
+    }
+
+    if !tokens.is_empty() && parse_token(tb, b':')?.is_some() {
+        let scheme: String = tokens.iter().map(|c| c.to_string()).collect();
+        return Ok(Some(Scheme(Cow::Owned(scheme))));
+    }
+    tb.push_tokens(tokens);
+    Ok(None)
+}
+
+//    authority     = [ userinfo "@" ] host [ ":" port ]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Authority {
+    userinfo: Option<Userinfo>,
+    host: Host,
+    port: Option<Port>,
+}
+
+impl Authority {
+    pub fn userinfo(&self) -> Option<&Userinfo> {
+        self.userinfo.as_ref()
+    }
+
+    pub fn host(&self) -> &Host {
+        &self.host
+    }
+
+    pub fn port(&self) -> Option<u16> {
+        match &self.port {
+            Some(port) => port.value(),
+            None => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Authority {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if let Some(userinfo) = &self.userinfo {
+            write!(fmt, "{}@", userinfo)?;
+        }
+        write!(fmt, "{}", &self.host)?;
+        if let Some(port) = &self.port {
+            write!(fmt, ":{}", port)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_authority<T>(tb: &mut TokenBuffer<Char, T>) -> Result<Authority>
+where
+    T: TokenStream<Char>,
+{
+    let userinfo = parse_userinfo(tb)?;
+    let host = parse_host(tb)?;
+    let port = match parse_token(tb, b':')? {
+        Some(_) => Some(parse_port(tb)?),
+        None => None,
+    };
+    Ok(Authority {
+        userinfo,
+        host,
+        port,
+    })
+}
+
+//    userinfo      = *( unreserved / pct-encoded / sub-delims / ":" )
+#[derive(Clone, Debug, PartialEq)]
+pub struct Userinfo(Vec<Char>);
+
+impl std::fmt::Display for Userinfo {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for c in &self.0 {
+            fmt.write_str(c.to_string().as_str())?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_userinfo<T>(tb: &mut TokenBuffer<Char, T>) -> Result<Option<Userinfo>>
+where
+    T: TokenStream<Char>,
+{
+    let mut tokens: Vec<Char> = Vec::new();
+    loop {
+        if let Some(token) = tb.pop()? {
+            if token.is_unreserved()
+                || token.is_pct_encoded()
+                || token.is_sub_delim()
+                || token.is(b':')
+            {
+                tokens.push(token);
+                continue;
+            }
+            tb.push(token);
+        }
+        break;
+    }
+
+    if parse_token(tb, b'@')?.is_some() {
+        return Ok(Some(Userinfo(tokens)));
+    }
+    tb.push_tokens(tokens);
+    Ok(None)
+}
+
+//    host          = IP-literal / IPv4address / reg-name
+#[derive(Clone, Debug, PartialEq)]
+pub enum Host {
+    Ipv6address(Ipv6address, Option<ZoneId>),
+    IpFuture(IpFuture),
+    Ipv4address(Ipv4address),
+    RegName(RegName),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HostKind {
+    Ipv4,
+    Ipv6,
+    IpFuture,
+    RegName,
+}
+
+impl Host {
+    pub fn kind(&self) -> HostKind {
+        match self {
+            Host::Ipv6address(_, _) => HostKind::Ipv6,
+            Host::IpFuture(_) => HostKind::IpFuture,
+            Host::Ipv4address(_) => HostKind::Ipv4,
+            Host::RegName(_) => HostKind::RegName,
+        }
+    }
+
+    pub fn zone_id(&self) -> Option<&ZoneId> {
+        match self {
+            Host::Ipv6address(_, zone_id) => zone_id.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn to_socket_addr_v6(&self, port: u16) -> Option<SocketAddrV6> {
+        match self {
+            Host::Ipv6address(ipv6_address, zone_id) => {
+                let scope_id = match zone_id {
+                    Some(zone_id) => zone_id.to_string().parse::<u32>().unwrap_or(0),
+                    None => 0,
+                };
+                Some(SocketAddrV6::new(
+                    ipv6_address.to_ipv6_addr(),
+                    port,
+                    0,
+                    scope_id,
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// Converts a reg-name to its ASCII form by the ToASCII operation of UTS #46, for example
+    /// "bücher.example" to "xn--bcher-kva.example". Other hosts are returned unchanged.
+    pub fn to_ascii(&self) -> Result<Host> {
+        self.to_ascii_with(&IdnaOptions::default())
+    }
+
+    pub fn to_ascii_with(&self, options: &IdnaOptions) -> Result<Host> {
+        match self {
+            Host::RegName(reg_name) if !reg_name.0.is_empty() => {
+                let ascii = idna::to_ascii(&reg_name.decode()?, options)?;
+                Ok(Host::RegName(RegName::encode(&ascii)))
+            }
+            host => Ok(host.clone()),
+        }
+    }
+
+    /// Converts a reg-name to its Unicode form by the ToUnicode operation of UTS #46, for example
+    /// "xn--bcher-kva.example" to "bücher.example". Other hosts are returned unchanged.
+    pub fn to_unicode(&self) -> Result<Host> {
+        self.to_unicode_with(&IdnaOptions::default())
+    }
+
+    pub fn to_unicode_with(&self, options: &IdnaOptions) -> Result<Host> {
+        match self {
+            Host::RegName(reg_name) if !reg_name.0.is_empty() => {
+                let unicode = idna::to_unicode(&reg_name.decode()?, options)?;
+                Ok(Host::RegName(RegName::encode(&unicode)))
+            }
+            host => Ok(host.clone()),
+        }
+    }
+}
+
+impl std::fmt::Display for Host {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Host::Ipv6address(ipv6_address, None) => write!(fmt, "[{}]", ipv6_address),
+            Host::Ipv6address(ipv6_address, Some(zone_id)) => {
+                write!(fmt, "[{}%25{}]", ipv6_address, zone_id)
+            }
+            Host::IpFuture(ip_future) => write!(fmt, "[{}]", ip_future),
+            Host::Ipv4address(ipv4_address) => write!(fmt, "{}", ipv4_address),
+            Host::RegName(reg_name) => write!(fmt, "{}", reg_name),
+        }
+    }
+}
+
+fn parse_host<T>(tb: &mut TokenBuffer<Char, T>) -> Result<Host>
+where
+    T: TokenStream<Char>,
+{
+    if let Some(ip_literal) = parse_ip_literal(tb)? {
+        return Ok(ip_literal);
+    }
+    if let Some(ipv4_address) = parse_ipv4_address(tb)? {
+        // "1.2.3.4.example" is a reg-name that merely starts like an IPv4address
+        match tb.pop()? {
+            Some(token) => {
+                let is_reg_name = is_reg_name_char(token);
+                tb.push(token);
+                if !is_reg_name {
+                    return Ok(Host::Ipv4address(ipv4_address));
+                }
+                tb.push_tokens(ipv4_address.into_tokens());
+            }
+            None => return Ok(Host::Ipv4address(ipv4_address)),
+        }
+    }
+    Ok(Host::RegName(parse_reg_name(tb)?))
+}
+
+//    port          = *DIGIT
+#[derive(Clone, Debug, PartialEq)]
+pub struct Port(Vec<Char>);
+
+impl Port {
+    fn new(port: u16) -> Port {
+        Port(port.to_string().bytes().map(Char::Ascii).collect())
+    }
+
+    pub fn value(&self) -> Option<u16> {
+        match self.0.is_empty() {
+            true => None,
+            false => self.to_string().parse::<u16>().ok(),
+        }
+    }
+}
+
+impl std::fmt::Display for Port {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for c in &self.0 {
+            fmt.write_str(c.to_string().as_str())?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_port<T>(tb: &mut TokenBuffer<Char, T>) -> Result<Port>
+where
+    T: TokenStream<Char>,
+{
+    let mut tokens: Vec<Char> = Vec::new();
+    loop {
+        if let Some(token) = tb.pop()? {
+            if token.is_digit() {
+                tokens.push(token);
+                continue;
+            }
+            tb.push(token);
+        }
+        break;
+    }
+
+    let port = Port(tokens);
+    if !port.0.is_empty() && port.value().is_none() {
+        return Err(UriError::PortOutOfRange(port.to_string()).into());
+    }
+    Ok(port)
+}
+
+//    reg-name      = *( unreserved / pct-encoded / sub-delims )
+#[derive(Clone, Debug, PartialEq)]
+pub struct RegName(Vec<Char>);
+
+impl RegName {
+    pub fn labels(&self) -> Vec<String> {
+        let name = self.to_string();
+        let name = match self.is_fqdn() {
+            true => &name[..name.len() - 1],
+            false => &name[..],
+        };
+        match name.is_empty() {
+            true => Vec::new(),
+            false => name.split('.').map(|label| label.to_string()).collect(),
+        }
+    }
+
+    pub fn is_fqdn(&self) -> bool {
+        self.0.len() > 1 && self.0[self.0.len() - 1].is(b'.')
+    }
+
+    pub fn tld(&self) -> Option<String> {
+        self.labels().pop()
+    }
+
+    /// Checks the name against the host name syntax of RFC 1123: labels of letters, digits and
+    /// hyphens, neither starting nor ending with a hyphen, at most 63 octets per label and 253
+    /// octets in total. An empty reg-name is accepted.
+    pub fn validate_dns(&self) -> Result<()> {
+        let invalid = |reason: &str| -> Result<()> {
+            Err(UriError::InvalidDnsName(self.to_string(), reason.to_string()).into())
+        };
+
+        if self.0.is_empty() {
+            return Ok(());
+        }
+        let len = match self.is_fqdn() {
+            true => self.0.len() - 1,
+            false => self.0.len(),
+        };
+        if len > 253 {
+            return invalid("name exceeds 253 octets");
+        }
+        for label in self.labels() {
+            if label.is_empty() {
+                return invalid("empty label");
+            }
+            if label.len() > 63 {
+                return invalid("label exceeds 63 octets");
+            }
+            if !label.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-') {
+                return invalid("label contains characters other than letters, digits and '-'");
+            }
+            if label.starts_with('-') || label.ends_with('-') {
+                return invalid("label starts or ends with '-'");
+            }
+        }
+        Ok(())
+    }
+
+    // The name with percent-encoded UTF-8 sequences decoded.
+    fn decode(&self) -> Result<String> {
+        match String::from_utf8(percent::decode(&self.0)) {
+            Ok(name) => Ok(name),
+            Err(_) => Err(format_err!("Invalid UTF-8 in reg-name '{}'.", self)),
+        }
+    }
+
+    // A reg-name for `name`, percent-encoding what may not appear in a reg-name as is.
+    fn encode(name: &str) -> RegName {
+        let mut chars: Vec<Char> = Vec::new();
+        for c in name.chars() {
+            let token = match c.is_ascii() {
+                true => Char::Ascii(c as u8),
+                false => Char::Unicode(c),
+            };
+            if is_reg_name_char(token) {
+                chars.push(token);
+                continue;
+            }
+            let mut buf = [0; 4];
+            for b in c.encode_utf8(&mut buf).bytes() {
+                chars.push(Char::pct_encoded(b));
+            }
+        }
+        RegName(chars)
+    }
+}
+
+impl std::fmt::Display for RegName {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for c in &self.0 {
+            fmt.write_str(c.to_string().as_str())?;
+        }
+        Ok(())
+    }
+}
 
-mod char_buf;
+fn is_reg_name_char(c: Char) -> bool {
+    c.is_unreserved() || c.is_pct_encoded() || c.is_sub_delim()
+}
 
-#[cfg(test)]
-mod tests;
+fn parse_reg_name<T>(tb: &mut TokenBuffer<Char, T>) -> Result<RegName>
+where
+    T: TokenStream<Char>,
+{
+    let mut tokens: Vec<Char> = Vec::new();
+    loop {
+        if let Some(token) = tb.pop()? {
+            if is_reg_name_char(token) {
+                tokens.push(token);
+                continue;
+            }
+            tb.push(token);
+        }
+        break;
+    }
+    Ok(RegName(tokens))
+}
 
-use common_failures::prelude::*;
+//    IP-literal    = "[" ( IPv6address / IPv6addrz / IPvFuture  ) "]"
+//    IPv6addrz     = IPv6address "%25" ZoneID
+fn parse_ip_literal<T>(tb: &mut TokenBuffer<Char, T>) -> Result<Option<Host>>
+where
+    T: TokenStream<Char>,
+{
+    if parse_token(tb, b'[')?.is_none() {
+        return Ok(None);
+    }
 
-use self::char_buf::Char;
-use self::char_buf::CharStream;
-use std::fmt::Write;
-use std::net::Ipv4Addr;
-use token_buf::TokenBuffer;
-use token_buf::TokenStream;
+    let host = if let Some(ipv6_address) = parse_ipv6_address(tb)? {
+        Host::Ipv6address(ipv6_address, parse_zone_id(tb)?)
+    } else if let Some(ip_future) = parse_ip_future(tb)? {
+        Host::IpFuture(ip_future)
+    } else {
+        return Err(format_err!("Invalid IP-literal."));
+    };
 
-//    URI           = scheme ":" hier-part [ "?" query ] [ "#" fragment ]
+    match parse_token(tb, b']')? {
+        Some(_) => Ok(Some(host)),
+        None => Err(format_err!("Invalid IP-literal.")),
+    }
+}
 
-//    hier-part     = "//" authority path-abempty
-//                  / path-absolute
-//                  / path-rootless
-//                  / path-empty
+//    ZoneID        = 1*( unreserved / pct-encoded )
+#[derive(Clone, Debug, PartialEq)]
+pub struct ZoneId(Vec<Char>);
 
-//    URI-reference = URI / relative-ref
+impl std::fmt::Display for ZoneId {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for c in &self.0 {
+            fmt.write_str(c.to_string().as_str())?;
+        }
+        Ok(())
+    }
+}
 
-//    absolute-URI  = scheme ":" hier-part [ "?" query ]
+fn parse_zone_id<T>(tb: &mut TokenBuffer<Char, T>) -> Result<Option<ZoneId>>
+where
+    T: TokenStream<Char>,
+{
+    match tb.pop()? {
+        Some(Char::PctEncoded(b'2', b'5')) => {}
+        Some(token) => {
+            tb.push(token);
+            return Ok(None);
+        }
+        None => return Ok(None),
+    }
 
-//    relative-ref  = relative-part [ "?" query ] [ "#" fragment ]
+    let mut tokens: Vec<Char> = Vec::new();
+    loop {
+        if let Some(token) = tb.pop()? {
+            if token.is_unreserved() || token.is_pct_encoded() {
+                tokens.push(token);
+                continue;
+            }
+            tb.push(token);
+        }
+        break;
+    }
 
-//    relative-part = "//" authority path-abempty
-//                  / path-absolute
-//                  / path-noscheme
-//                  / path-empty
+    match tokens.is_empty() {
+        true => Err(format_err!("Empty zone identifier.")),
+        false => Ok(Some(ZoneId(tokens))),
+    }
+}
 
-//    scheme        = ALPHA *( ALPHA / DIGIT / "+" / "-" / "." )
+//    IPvFuture     = "v" 1*HEXDIG "." 1*( unreserved / sub-delims / ":" )
+#[derive(Clone, Debug, PartialEq)]
+pub struct IpFuture {
+    v: Char,
+    version: Vec<Char>,
+    address: Vec<Char>,
+}
 
-//    authority     = [ userinfo "@" ] host [ ":" port ]
-//    userinfo      = *( unreserved / pct-encoded / sub-delims / ":" )
-//    host          = IP-literal / IPv4address / reg-name
-//    port          = *DIGIT
+impl IpFuture {
+    pub fn version(&self) -> String {
+        self.version.iter().map(|c| c.to_string()).collect()
+    }
 
-//    reg-name      = *( unreserved / pct-encoded / sub-delims )
+    pub fn address(&self) -> String {
+        self.address.iter().map(|c| c.to_string()).collect()
+    }
+}
 
-//    IP-literal    = "[" ( IPv6address / IPvFuture  ) "]"
+impl std::fmt::Display for IpFuture {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(fmt, "{}{}.{}", &self.v, self.version(), self.address())
+    }
+}
 
-//    IPvFuture     = "v" 1*HEXDIG "." 1*( unreserved / sub-delims / ":" )
+fn parse_ip_future<T>(tb: &mut TokenBuffer<Char, T>) -> Result<Option<IpFuture>>
+where
+    T: TokenStream<Char>,
+{
+    let v = match tb.pop()? {
+        Some(token) => {
+            if !token.is(b'v') && !token.is(b'V') {
+                tb.push(token);
+                return Ok(None);
+            }
+            token
+        }
+        None => return Ok(None),
+    };
+
+    let mut version: Vec<Char> = Vec::new();
+    loop {
+        if let Some(token) = tb.pop()? {
+            if token.is_hex() {
+                version.push(token);
+                continue;
+            }
+            tb.push(token);
+        }
+        break;
+    }
+
+    let dot = match version.is_empty() {
+        true => None,
+        false => parse_token(tb, b'.')?,
+    };
+    let dot = match dot {
+        Some(dot) => dot,
+        None => {
+            tb.push_tokens(version);
+            tb.push(v);
+            return Ok(None);
+        }
+    };
+
+    let mut address: Vec<Char> = Vec::new();
+    loop {
+        if let Some(token) = tb.pop()? {
+            if token.is_unreserved() || token.is_sub_delim() || token.is(b':') {
+                address.push(token);
+                continue;
+            }
+            tb.push(token);
+        }
+        break;
+    }
+
+    if address.is_empty() {
+        tb.push(dot);
+        tb.push_tokens(version);
+        tb.push(v);
+        return Ok(None);
+    }
+    Ok(Some(IpFuture {
+        v,
+        version,
+        address,
+    }))
+}
 
 //    IPv6address   =                            6( h16 ":" ) ls32
 //                  /                       "::" 5( h16 ":" ) ls32
@@ -57,21 +1253,218 @@ use token_buf::TokenStream;
 //                  / [ *4( h16 ":" ) h16 ] "::"              ls32
 //                  / [ *5( h16 ":" ) h16 ] "::"              h16
 //                  / [ *6( h16 ":" ) h16 ] "::"
+//
+// The pieces in front of "::" are kept in `head`, the ones behind it in `tail`. `ls32` holds the
+// trailing 32 bits of the first alternative and any embedded IPv4address; with "::" the trailing
+// h16 pieces stay in `tail`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ipv6address {
+    head: Vec<H16>,
+    tail: Option<Vec<H16>>,
+    ls32: Option<Ls32>,
+}
+
+impl Ipv6address {
+    pub fn segments(&self) -> [u16; 8] {
+        let mut pieces: Vec<u16> = self.head.iter().map(|h16| h16.value()).collect();
+        let mut ls32: Vec<u16> = match &self.ls32 {
+            Some(ls32) => ls32.values().to_vec(),
+            None => Vec::new(),
+        };
+        if let Some(tail) = &self.tail {
+            let mut tail: Vec<u16> = tail.iter().map(|h16| h16.value()).collect();
+            tail.append(&mut ls32);
+            while pieces.len() + tail.len() < 8 {
+                pieces.push(0);
+            }
+            pieces.append(&mut tail);
+        } else {
+            pieces.append(&mut ls32);
+        }
+
+        let mut segments = [0; 8];
+        segments.copy_from_slice(&pieces[..8]);
+        segments
+    }
+
+    pub fn to_ipv6_addr(&self) -> Ipv6Addr {
+        let s = self.segments();
+        Ipv6Addr::new(s[0], s[1], s[2], s[3], s[4], s[5], s[6], s[7])
+    }
+}
+
+impl std::fmt::Display for Ipv6address {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for (i, h16) in self.head.iter().enumerate() {
+            if i > 0 {
+                fmt.write_char(':')?;
+            }
+            write!(fmt, "{}", h16)?;
+        }
+        if let Some(tail) = &self.tail {
+            fmt.write_str("::")?;
+            for (i, h16) in tail.iter().enumerate() {
+                if i > 0 {
+                    fmt.write_char(':')?;
+                }
+                write!(fmt, "{}", h16)?;
+            }
+        }
+        if let Some(ls32) = &self.ls32 {
+            let separate = match &self.tail {
+                Some(tail) => !tail.is_empty(),
+                None => !self.head.is_empty(),
+            };
+            if separate {
+                fmt.write_char(':')?;
+            }
+            write!(fmt, "{}", ls32)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_ipv6_address<T>(tb: &mut TokenBuffer<Char, T>) -> Result<Option<Ipv6address>>
+where
+    T: TokenStream<Char>,
+{
+    let mut consumed: Vec<Char> = Vec::new();
+    let ipv6_address = parse_ipv6_pieces(tb, &mut consumed)?;
+    if ipv6_address.is_none() {
+        tb.push_tokens(consumed);
+    }
+    Ok(ipv6_address)
+}
+
+fn parse_ipv6_pieces<T>(
+    tb: &mut TokenBuffer<Char, T>,
+    consumed: &mut Vec<Char>,
+) -> Result<Option<Ipv6address>>
+where
+    T: TokenStream<Char>,
+{
+    let mut head: Vec<H16> = Vec::new();
+    let mut tail: Option<Vec<H16>> = None;
+    let mut ipv4_address: Option<Ipv4address> = None;
+
+    if let Some(colon) = parse_token(tb, b':')? {
+        consumed.push(colon);
+        match parse_token(tb, b':')? {
+            Some(colon) => consumed.push(colon),
+            None => return Ok(None),
+        }
+        tail = Some(Vec::new());
+    }
+
+    let mut expect_piece = false;
+    loop {
+        if let Some(ipv4) = parse_ipv4_address(tb)? {
+            consumed.extend(ipv4.clone().into_tokens());
+            ipv4_address = Some(ipv4);
+            expect_piece = false;
+            break;
+        }
+
+        let h16 = match parse_h16(tb)? {
+            Some(h16) => h16,
+            None => break,
+        };
+        consumed.extend(h16.0.iter().cloned());
+        match &mut tail {
+            Some(tail) => tail.push(h16),
+            None => head.push(h16),
+        }
+        expect_piece = false;
+
+        let colon = match parse_token(tb, b':')? {
+            Some(colon) => colon,
+            None => break,
+        };
+        consumed.push(colon);
+        match parse_token(tb, b':')? {
+            Some(colon) => {
+                consumed.push(colon);
+                if tail.is_some() {
+                    return Ok(None);
+                }
+                tail = Some(Vec::new());
+            }
+            None => expect_piece = true,
+        }
+    }
+
+    if expect_piece {
+        return Ok(None);
+    }
+
+    let pieces = head.len()
+        + tail.as_ref().map_or(0, |tail| tail.len())
+        + ipv4_address.as_ref().map_or(0, |_| 2);
+    let ls32 = match (&tail, ipv4_address) {
+        (&None, _) if pieces != 8 => return Ok(None),
+        (&Some(_), _) if pieces > 7 => return Ok(None),
+        (_, Some(ipv4_address)) => Some(Ls32::Ipv4address(ipv4_address)),
+        (&None, None) => {
+            let h2 = head.pop().unwrap();
+            let h1 = head.pop().unwrap();
+            Some(Ls32::Ipv6Part(h1, h2))
+        }
+        (&Some(_), None) => None,
+    };
+
+    Ok(Some(Ipv6address { head, tail, ls32 }))
+}
 
 //    ls32          = ( h16 ":" h16 ) / IPv4address
-enum Ls32 {
+#[derive(Clone, Debug, PartialEq)]
+pub enum Ls32 {
     Ipv6Part(H16, H16),
     Ipv4address(Ipv4address),
 }
 
+impl Ls32 {
+    fn values(&self) -> [u16; 2] {
+        match self {
+            Ls32::Ipv6Part(h1, h2) => [h1.value(), h2.value()],
+            Ls32::Ipv4address(ipv4_address) => {
+                let octets = ipv4_address.to_ipv4_addr().octets();
+                [
+                    (u16::from(octets[0]) << 8) | u16::from(octets[1]),
+                    (u16::from(octets[2]) << 8) | u16::from(octets[3]),
+                ]
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Ls32 {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Ls32::Ipv6Part(h1, h2) => write!(fmt, "{}:{}", h1, h2),
+            Ls32::Ipv4address(ipv4_address) => write!(fmt, "{}", ipv4_address),
+        }
+    }
+}
+
+//    h16           = 1*4HEXDIG
 #[derive(Clone, Debug, PartialEq)]
-struct H16(Char, Char, Char, Char);
+pub struct H16(Vec<Char>);
+
+impl H16 {
+    pub fn value(&self) -> u16 {
+        u16::from_str_radix(&self.to_string(), 16).unwrap_or(0)
+    }
+}
 
 impl std::fmt::Display for H16 {
     fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(fmt, "{}{}{}{}", &self.0, &self.1, &self.2, &self.3)
+        for c in &self.0 {
+            fmt.write_str(c.to_string().as_str())?;
+        }
+        Ok(())
     }
 }
+
 fn parse_h16<T>(tb: &mut TokenBuffer<Char, T>) -> Result<Option<H16>>
 where
     T: TokenStream<Char>,
@@ -88,22 +1481,35 @@ where
         break;
     }
 
-    match tokens.len() {
-        4 => Ok(Some(H16(
-            tokens.remove(0),
-            tokens.remove(0),
-            tokens.remove(0),
-            tokens.remove(0),
-        ))),
-        _ => {
-            tb.push_tokens(tokens);
-            Ok(None)
-        }
+    match tokens.is_empty() {
+        true => Ok(None),
+        false => Ok(Some(H16(tokens))),
     }
 }
 
 #[derive(Clone, Debug, PartialEq)]
-struct Ipv4address(DecOctet, DecOctet, DecOctet, DecOctet);
+pub struct Ipv4address(DecOctet, DecOctet, DecOctet, DecOctet);
+
+impl Ipv4address {
+    pub fn to_ipv4_addr(&self) -> Ipv4Addr {
+        Ipv4Addr::new(
+            self.0.value(),
+            self.1.value(),
+            self.2.value(),
+            self.3.value(),
+        )
+    }
+
+    fn into_tokens(self) -> Vec<Char> {
+        let Ipv4address(d1, d2, d3, d4) = self;
+        let mut tokens = d1.0;
+        for dec_octet in [d2, d3, d4].iter() {
+            tokens.push(Char::Ascii(b'.'));
+            tokens.extend(dec_octet.0.iter().cloned());
+        }
+        tokens
+    }
+}
 
 impl std::fmt::Display for Ipv4address {
     fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -225,6 +1631,12 @@ where
 #[derive(Clone, Debug, PartialEq)]
 struct DecOctet(Vec<Char>);
 
+impl DecOctet {
+    fn value(&self) -> u8 {
+        self.to_string().parse::<u8>().unwrap_or(0)
+    }
+}
+
 impl std::fmt::Display for DecOctet {
     fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
         for c in &self.0 {
@@ -257,8 +1669,8 @@ where
         .join("");
 
     match dec_octet_str.parse::<u8>() {
-        Ok(dec_octet) => Ok(Some(DecOctet(tokens))),
-        Err(e) => {
+        Ok(_) => Ok(Some(DecOctet(tokens))),
+        Err(_) => {
             tb.push_tokens(tokens);
             Ok(None)
         }
@@ -266,11 +1678,24 @@ where
 }
 
 #[derive(Clone, Debug, PartialEq)]
-struct Path {
+pub struct Path {
     segments: Vec<Segment>,
     absolute: bool,
 }
 
+impl Path {
+    fn empty() -> Path {
+        Path {
+            segments: vec![Segment(Vec::new())],
+            absolute: false,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        !self.absolute && self.segments.iter().all(|segment| segment.0.is_empty())
+    }
+}
+
 impl std::fmt::Display for Path {
     fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
         if self.absolute {
@@ -318,14 +1743,14 @@ where
                 continue;
             }
             tb.push(token);
-            break;
         }
+        break;
     }
     match segments.is_empty() {
         true => Ok(None),
         false => Ok(Some(Path {
-            segments: segments,
-            absolute: false,
+            segments,
+            absolute: true,
         })),
     }
 }
@@ -373,14 +1798,14 @@ where
                     continue;
                 }
                 tb.push(token);
-                break;
             }
+            break;
         }
     }
     match segments.is_empty() {
         true => Ok(None),
         false => Ok(Some(Path {
-            segments: segments,
+            segments,
             absolute: false,
         })),
     }
@@ -408,39 +1833,24 @@ where
     match segments.is_empty() {
         true => Ok(None),
         false => Ok(Some(Path {
-            segments: segments,
+            segments,
             absolute: false,
         })),
     }
 }
 
-fn parse_path_empty<T>(tb: &mut TokenBuffer<Char, T>) -> Result<Option<Path>>
-where
-    T: TokenStream<Char>,
-{
-    let path: Option<Path>;
+#[derive(Clone, Debug, PartialEq)]
+pub struct Segment(Vec<Char>);
 
-    if let Some(token) = tb.pop()? {
-        if token.is_pchar() {
-            path = None;
-        } else {
-            path = Some(Path {
-                segments: Vec::new(),
-                absolute: false,
-            });
-        }
-        tb.push(token);
-    } else {
-        path = Some(Path {
-            segments: vec![Segment(Vec::new())],
-            absolute: false,
-        });
+impl Segment {
+    fn is_dot(&self) -> bool {
+        self.0.len() == 1 && self.0[0].is(b'.')
     }
-    Ok(path)
-}
 
-#[derive(Clone, Debug, PartialEq)]
-struct Segment(Vec<Char>);
+    fn is_dot_dot(&self) -> bool {
+        self.0.len() == 2 && self.0[0].is(b'.') && self.0[1].is(b'.')
+    }
+}
 
 impl std::fmt::Display for Segment {
     fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -516,7 +1926,7 @@ where
 }
 
 #[derive(Clone, Debug, PartialEq)]
-struct Query(Vec<Char>);
+pub struct Query(Vec<Char>);
 
 impl std::fmt::Display for Query {
     fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -531,11 +1941,22 @@ fn parse_query<T>(tb: &mut TokenBuffer<Char, T>) -> Result<Query>
 where
     T: TokenStream<Char>,
 {
-    Ok(Query(parse_fragment(tb)?.0))
+    let mut tokens: Vec<Char> = Vec::new();
+    loop {
+        if let Some(token) = tb.pop()? {
+            if token.is_pchar() || token.is(b'/') || token.is(b'?') || token.is_iprivate() {
+                tokens.push(token);
+                continue;
+            }
+            tb.push(token);
+        }
+        break;
+    }
+    Ok(Query(tokens))
 }
 
 #[derive(Clone, Debug, PartialEq)]
-struct Fragment(Vec<Char>);
+pub struct Fragment(Vec<Char>);
 
 impl std::fmt::Display for Fragment {
     fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -0,0 +1,217 @@
+use super::Query;
+use super::QueryBuilder;
+use common_failures::prelude::*;
+use std::collections::BTreeMap;
+
+/// Limits for `Query::parse_nested_with`, which fails on queries exceeding them.
+#[derive(Clone, Debug)]
+pub struct NestedQueryOptions {
+    /// The most brackets after a name, 2 for "a[b][c]".
+    pub max_depth: usize,
+    /// The highest array index, given as in "a[20]" or reached by repeating "a[]".
+    pub max_array_index: usize,
+}
+
+impl Default for NestedQueryOptions {
+    fn default() -> NestedQueryOptions {
+        NestedQueryOptions {
+            max_depth: 5,
+            max_array_index: 20,
+        }
+    }
+}
+
+/// A query decoded into a tree by the bracket convention of Rails and PHP, where
+/// "filter[tags][]=a&filter[tags][]=b&page[size]=10" is a map holding the map "filter" with the
+/// array "tags" and the map "page" with "size" set to "10".
+///
+/// '[' and ']' are not allowed in a query by RFC 3986, so `Uri::parse` only accepts such a query
+/// with the brackets percent-encoded, as in "filter%5Btags%5D%5B%5D=a". Parse browser-style input
+/// with `ParseMode::Whatwg`, which percent-encodes them.
+#[derive(Clone, Debug, PartialEq)]
+pub enum QueryValue {
+    String(String),
+    Array(Vec<QueryValue>),
+    Map(BTreeMap<String, QueryValue>),
+}
+
+impl QueryValue {
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            QueryValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// The element at `key` of a map, or at the index `key` of an array.
+    pub fn get(&self, key: &str) -> Option<&QueryValue> {
+        match self {
+            QueryValue::Map(map) => map.get(key),
+            QueryValue::Array(array) => key.parse::<usize>().ok().and_then(|i| array.get(i)),
+            QueryValue::String(_) => None,
+        }
+    }
+
+    /// Encodes a map as a query, arrays of strings with "[]" and all other arrays with indices so
+    /// that their elements decode as they were.
+    pub fn to_query(&self) -> Result<Query> {
+        let map = match self {
+            QueryValue::Map(map) => map,
+            _ => return Err(format_err!("Only a map can be encoded as a query.")),
+        };
+        let mut builder = QueryBuilder::new();
+        for (name, value) in map {
+            append(&mut builder, name.clone(), value);
+        }
+        Ok(builder.build())
+    }
+}
+
+fn append(builder: &mut QueryBuilder, name: String, value: &QueryValue) {
+    match value {
+        QueryValue::String(s) => {
+            builder.append(&name, s);
+        }
+        QueryValue::Array(array) => {
+            let is_flat = array.iter().all(|element| element.as_str().is_some());
+            for (i, element) in array.iter().enumerate() {
+                match is_flat {
+                    true => append(builder, format!("{}[]", name), element),
+                    false => append(builder, format!("{}[{}]", name, i), element),
+                }
+            }
+        }
+        QueryValue::Map(map) => {
+            for (key, element) in map {
+                append(builder, format!("{}[{}]", name, key), element);
+            }
+        }
+    }
+}
+
+impl Query {
+    pub fn parse_nested(&self) -> Result<QueryValue> {
+        self.parse_nested_with(&NestedQueryOptions::default())
+    }
+
+    /// Decodes the pairs into a map, with the brackets after a name selecting map entries, array
+    /// elements by index or, when empty, a new array element. A name that is not of this form,
+    /// like "a[b" or "a[b]c", is taken literally and a repeated name keeps the last value. Empty
+    /// brackets after a map, as in "a[b]=1&a[]=2", are a conflict like a key after an array.
+    pub fn parse_nested_with(&self, options: &NestedQueryOptions) -> Result<QueryValue> {
+        let mut root = Node::Map(BTreeMap::new());
+        for (name, value) in self.pairs() {
+            let keys = split_name(&name);
+            if keys.len() - 1 > options.max_depth {
+                return Err(format_err!(
+                    "Query parameter '{}' is nested deeper than {} levels.",
+                    name,
+                    options.max_depth
+                ));
+            }
+            let mut node = &mut root;
+            for (i, key) in keys.iter().enumerate() {
+                node = node.child(key, i > 0, &name, options)?;
+            }
+            match node {
+                Node::String(_) | Node::Empty => *node = Node::String(value.into_owned()),
+                _ => return Err(conflict(&name)),
+            }
+        }
+        Ok(root.into_value())
+    }
+}
+
+fn conflict(name: &str) -> Error {
+    format_err!("Query parameter '{}' conflicts with an earlier one.", name)
+}
+
+// "a[b][]" as ["a", "b", ""], or the name itself if it does not end in well-formed brackets
+fn split_name(name: &str) -> Vec<&str> {
+    let open = match name.find('[') {
+        Some(open) if open > 0 => open,
+        _ => return vec![name],
+    };
+    let mut keys = vec![&name[..open]];
+    let mut rest = &name[open..];
+    while let Some(bracketed) = rest.strip_prefix('[') {
+        match bracketed.find(']') {
+            Some(close) => {
+                keys.push(&bracketed[..close]);
+                rest = &bracketed[close + 1..];
+            }
+            None => return vec![name],
+        }
+    }
+    match rest.is_empty() {
+        true => keys,
+        false => vec![name],
+    }
+}
+
+// Arrays are kept by index while decoding, as "a[1]=x&a[0]=y" fills them out of order
+enum Node {
+    Empty,
+    String(String),
+    Array(BTreeMap<usize, Node>),
+    Map(BTreeMap<String, Node>),
+}
+
+impl Node {
+    // The child at `key` of the parameter `name`, failing if this node is of another kind than
+    // `key` requires. `Empty` nodes, just created, become whatever `key` asks for.
+    fn child(
+        &mut self,
+        key: &str,
+        bracketed: bool,
+        name: &str,
+        options: &NestedQueryOptions,
+    ) -> Result<&mut Node> {
+        let index = match bracketed {
+            true if key.is_empty() => Some(None),
+            true => key.parse::<usize>().ok().map(Some),
+            false => None,
+        };
+        if let Node::Empty = self {
+            *self = match index {
+                Some(_) => Node::Array(BTreeMap::new()),
+                None => Node::Map(BTreeMap::new()),
+            };
+        }
+        match (self, index) {
+            (Node::Array(array), Some(index)) => {
+                let index = match index {
+                    Some(index) => index,
+                    None => array.keys().next_back().map_or(0, |last| last + 1),
+                };
+                if index > options.max_array_index {
+                    return Err(format_err!(
+                        "Array index {} of query parameter '{}' exceeds {}.",
+                        index,
+                        name,
+                        options.max_array_index
+                    ));
+                }
+                Ok(array.entry(index).or_insert(Node::Empty))
+            }
+            (Node::Map(_), Some(None)) => Err(conflict(name)),
+            (Node::Map(map), _) => Ok(map.entry(key.to_string()).or_insert(Node::Empty)),
+            _ => Err(conflict(name)),
+        }
+    }
+
+    fn into_value(self) -> QueryValue {
+        match self {
+            Node::Empty => QueryValue::String(String::new()),
+            Node::String(s) => QueryValue::String(s),
+            Node::Array(array) => {
+                QueryValue::Array(array.into_values().map(Node::into_value).collect())
+            }
+            Node::Map(map) => QueryValue::Map(
+                map.into_iter()
+                    .map(|(key, node)| (key, node.into_value()))
+                    .collect(),
+            ),
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use super::guard_double_slash;
+use super::Authority;
+use super::Char;
+use super::Fragment;
+use super::Host;
+use super::IpFuture;
+use super::Ipv6address;
+use super::Ls32;
+use super::Path;
+use super::Query;
+use super::RegName;
+use super::Scheme;
+use super::Segment;
+use super::Uri;
+use super::Userinfo;
+use super::ZoneId;
+use super::H16;
+use std::collections::HashMap;
+
+/// Selects the steps of the syntax-based normalization of RFC 3986 section 6.2.2.
+#[derive(Clone, Debug)]
+pub struct NormalizeOptions {
+    pub lowercase_scheme: bool,
+    pub lowercase_host: bool,
+    pub uppercase_percent_encoding: bool,
+    pub decode_unreserved: bool,
+    pub remove_dot_segments: bool,
+}
+
+impl Default for NormalizeOptions {
+    fn default() -> NormalizeOptions {
+        NormalizeOptions {
+            lowercase_scheme: true,
+            lowercase_host: true,
+            uppercase_percent_encoding: true,
+            decode_unreserved: true,
+            remove_dot_segments: true,
+        }
+    }
+}
+
+/// What a scheme implies for URIs that omit a component, see RFC 3986 section 6.2.3.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SchemeDefault {
+    pub port: Option<u16>,
+    /// Whether an empty path is equivalent to "/" when an authority is present.
+    pub empty_path_is_root: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct SchemeDefaults(HashMap<Scheme, SchemeDefault>);
+
+impl SchemeDefaults {
+    pub fn new() -> SchemeDefaults {
+        SchemeDefaults(HashMap::new())
+    }
+
+    pub fn insert(&mut self, scheme: Scheme, default: SchemeDefault) -> Option<SchemeDefault> {
+        self.0.insert(scheme, default)
+    }
+
+    pub fn get(&self, scheme: &Scheme) -> Option<&SchemeDefault> {
+        self.0.get(scheme)
+    }
+}
+
+impl Default for SchemeDefaults {
+    fn default() -> SchemeDefaults {
+        let mut defaults = SchemeDefaults::new();
+        let mut insert = |scheme: &'static str, port: u16, empty_path_is_root: bool| {
+            defaults.insert(
+                Scheme(scheme.into()),
+                SchemeDefault {
+                    port: Some(port),
+                    empty_path_is_root,
+                },
+            );
+        };
+        insert("http", 80, true);
+        insert("https", 443, true);
+        insert("ws", 80, true);
+        insert("wss", 443, true);
+        insert("ftp", 21, false);
+        defaults
+    }
+}
+
+impl Uri {
+    pub fn normalize(&self) -> Uri {
+        self.normalize_with(&NormalizeOptions::default())
+    }
+
+    pub fn normalize_with(&self, options: &NormalizeOptions) -> Uri {
+        let scheme = match options.lowercase_scheme {
+            true => self.scheme.normalize(),
+            false => self.scheme.clone(),
+        };
+        let authority = self.authority.as_ref().map(|authority| Authority {
+            userinfo: authority
+                .userinfo
+                .as_ref()
+                .map(|userinfo| Userinfo(normalize_chars(&userinfo.0, options))),
+            host: normalize_host(&authority.host, options),
+            port: authority.port.clone(),
+        });
+        let path = Path {
+            segments: self
+                .path
+                .segments
+                .iter()
+                .map(|segment| Segment(normalize_chars(&segment.0, options)))
+                .collect(),
+            absolute: self.path.absolute,
+        };
+        let path = match options.remove_dot_segments {
+            true => guard_double_slash(authority.as_ref(), path.remove_dot_segments()),
+            false => path,
+        };
+        Uri {
+            scheme,
+            authority,
+            path,
+            query: self
+                .query
+                .as_ref()
+                .map(|query| Query(normalize_chars(&query.0, options))),
+            fragment: self
+                .fragment
+                .as_ref()
+                .map(|fragment| Fragment(normalize_chars(&fragment.0, options))),
+        }
+    }
+
+    /// Applies the scheme-based normalization of RFC 3986 section 6.2.3 on top of `normalize`:
+    /// an empty or default port is dropped and, where the scheme says so, an empty path becomes
+    /// "/".
+    pub fn normalize_for_scheme(&self, defaults: &SchemeDefaults) -> Uri {
+        let mut uri = self.normalize();
+        let (default_port, empty_path_is_root) = match defaults.get(&uri.scheme) {
+            Some(default) => (default.port, default.empty_path_is_root),
+            None => (None, false),
+        };
+        if let Some(authority) = &mut uri.authority {
+            let drop_port = match &authority.port {
+                Some(port) => port.0.is_empty() || port.value() == default_port,
+                None => false,
+            };
+            if drop_port {
+                authority.port = None;
+            }
+            if uri.path.is_empty() && empty_path_is_root {
+                uri.path = Path {
+                    segments: vec![Segment(Vec::new())],
+                    absolute: true,
+                };
+            }
+        }
+        uri
+    }
+}
+
+fn normalize_host(host: &Host, options: &NormalizeOptions) -> Host {
+    let lowercase = |chars: &[Char]| -> Vec<Char> {
+        match options.lowercase_host {
+            true => chars.iter().map(|c| lowercase_char(*c)).collect(),
+            false => chars.to_vec(),
+        }
+    };
+    let lowercase_h16 = |h16: &H16| H16(lowercase(&h16.0));
+
+    match host {
+        Host::Ipv6address(ipv6_address, zone_id) => Host::Ipv6address(
+            Ipv6address {
+                head: ipv6_address.head.iter().map(&lowercase_h16).collect(),
+                tail: ipv6_address
+                    .tail
+                    .as_ref()
+                    .map(|tail| tail.iter().map(&lowercase_h16).collect()),
+                ls32: ipv6_address.ls32.as_ref().map(|ls32| match ls32 {
+                    Ls32::Ipv6Part(h1, h2) => Ls32::Ipv6Part(lowercase_h16(h1), lowercase_h16(h2)),
+                    Ls32::Ipv4address(ipv4_address) => Ls32::Ipv4address(ipv4_address.clone()),
+                }),
+            },
+            zone_id
+                .as_ref()
+                .map(|zone_id| ZoneId(normalize_chars(&zone_id.0, options))),
+        ),
+        Host::IpFuture(ip_future) => Host::IpFuture(IpFuture {
+            v: lowercase_char(ip_future.v),
+            version: lowercase(&ip_future.version),
+            address: lowercase(&ip_future.address),
+        }),
+        Host::Ipv4address(ipv4_address) => Host::Ipv4address(ipv4_address.clone()),
+        Host::RegName(reg_name) => Host::RegName(RegName(lowercase(&normalize_chars(
+            &reg_name.0,
+            options,
+        )))),
+    }
+}
+
+fn normalize_chars(chars: &[Char], options: &NormalizeOptions) -> Vec<Char> {
+    chars.iter().map(|c| normalize_char(*c, options)).collect()
+}
+
+fn normalize_char(c: Char, options: &NormalizeOptions) -> Char {
+    match c {
+        Char::PctEncoded(byte1, byte2) => {
+            if options.decode_unreserved {
+                let decoded = Char::Ascii(c.decode().unwrap_or(0));
+                if decoded.is_unreserved() {
+                    return decoded;
+                }
+            }
+            match options.uppercase_percent_encoding {
+                true => Char::PctEncoded(byte1.to_ascii_uppercase(), byte2.to_ascii_uppercase()),
+                false => c,
+            }
+        }
+        _ => c,
+    }
+}
+
+fn lowercase_char(c: Char) -> Char {
+    match c {
+        Char::Ascii(byte) => Char::Ascii(byte.to_ascii_lowercase()),
+        _ => c,
+    }
+}
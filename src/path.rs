@@ -0,0 +1,252 @@
+use super::percent;
+use super::percent::EncodeSet;
+use super::Char;
+use super::Path;
+use super::Segment;
+use super::Uri;
+use std::ops::Deref;
+use std::ops::DerefMut;
+
+impl Path {
+    /// The decoded segments, with invalid UTF-8 replaced by U+FFFD. A trailing '/' gives an empty
+    /// last segment, so "/org/foo/" has the segments "org", "foo" and "".
+    pub fn segments(&self) -> Vec<String> {
+        self.raw_segments().iter().map(Segment::decode).collect()
+    }
+
+    /// Appends `segment`, percent-encoding what may not appear in a segment as is, and "." and
+    /// ".." as they would be dot-segments otherwise. An empty last segment, as in "/a/", is
+    /// replaced. An empty `segment` is followed by such a last segment, so that it is not replaced
+    /// in turn, and a relative path starting with it gets a "." segment in front, as in ".//".
+    pub fn push_segment(&mut self, segment: &str) {
+        let encoded = match segment {
+            "." => vec![Char::pct_encoded(b'.')],
+            ".." => vec![Char::pct_encoded(b'.'), Char::pct_encoded(b'.')],
+            _ => percent::encode(segment.as_bytes(), percent::PATH_SEGMENT),
+        };
+        if self.segments.last().map_or(false, |last| last.0.is_empty()) {
+            self.segments.pop();
+        }
+        if segment.is_empty() && self.segments.is_empty() && !self.absolute {
+            self.segments.push(Segment(vec![Char::Ascii(b'.')]));
+        }
+        self.segments.push(Segment(encoded));
+        if segment.is_empty() {
+            self.segments.push(Segment(Vec::new()));
+        }
+    }
+
+    /// Turns the path into its parent and returns the decoded segment that was removed, `None`
+    /// for the empty path and "/". The parent keeps a trailing '/', like "/a/" for "/a/b".
+    pub fn pop(&mut self) -> Option<String> {
+        if self.is_empty() {
+            return None;
+        }
+        if self.segments.len() > 1 && self.segments.last().unwrap().0.is_empty() {
+            self.segments.pop();
+        }
+        if self.absolute && self.segments.len() == 1 && self.segments[0].0.is_empty() {
+            return None;
+        }
+        let last = self.segments.pop().map(|segment| segment.decode());
+        self.segments.push(Segment(Vec::new()));
+        last
+    }
+
+    pub fn parent(&self) -> Option<Path> {
+        let mut parent = self.clone();
+        parent.pop().map(|_| parent)
+    }
+
+    /// The decoded last segment, unless it is empty, "." or "..".
+    pub fn file_name(&self) -> Option<String> {
+        match self.raw_segments().last() {
+            Some(last) if !last.0.is_empty() && !last.is_dot() && !last.is_dot_dot() => {
+                Some(last.decode())
+            }
+            _ => None,
+        }
+    }
+
+    /// The part of the file name after its last '.', if there is one that does not start the
+    /// file name.
+    pub fn extension(&self) -> Option<String> {
+        let file_name = self.file_name()?;
+        match file_name.rfind('.') {
+            Some(dot) if dot > 0 => Some(file_name[dot + 1..].to_string()),
+            _ => None,
+        }
+    }
+
+    /// Whether the segments of `base` start this path, ignoring a trailing '/' of `base`.
+    /// Segments are compared as they are, without decoding them.
+    pub fn starts_with(&self, base: &Path) -> bool {
+        let prefix = base.prefix_segments();
+        self.absolute == base.absolute
+            && self.raw_segments().len() >= prefix.len()
+            && self.raw_segments()[..prefix.len()] == *prefix
+    }
+
+    /// The relative path of the segments after `base`, see `starts_with`. A rest starting with an
+    /// empty segment or one with a ':' gets a "." segment in front, so "/a//b" without "/a" is
+    /// ".//b" and not "/b", and "/a/b:c" without "/a/" is "./b:c" and not the scheme "b".
+    pub fn strip_prefix(&self, base: &Path) -> Option<Path> {
+        if !self.starts_with(base) {
+            return None;
+        }
+        let rest = &self.raw_segments()[base.prefix_segments().len()..];
+        let mut segments: Vec<Segment> = Vec::new();
+        let empty_first = rest.len() > 1 && rest[0].0.is_empty();
+        let colon_first = rest
+            .first()
+            .map_or(false, |first| first.0.iter().any(|c| c.is(b':')));
+        if empty_first || colon_first {
+            segments.push(Segment(vec![Char::Ascii(b'.')]));
+        }
+        segments.extend(rest.iter().cloned());
+        match segments.is_empty() {
+            true => Some(Path::empty()),
+            false => Some(Path {
+                segments,
+                absolute: false,
+            }),
+        }
+    }
+
+    pub fn segment(&self, index: usize) -> Option<&Segment> {
+        self.raw_segments().get(index)
+    }
+
+    pub fn segment_mut(&mut self, index: usize) -> Option<&mut Segment> {
+        match self.is_empty() {
+            true => None,
+            false => self.segments.get_mut(index),
+        }
+    }
+
+    // The segments, none for the empty path
+    fn raw_segments(&self) -> &[Segment] {
+        match self.is_empty() {
+            true => &[],
+            false => &self.segments,
+        }
+    }
+
+    // The segments without an empty last one
+    fn prefix_segments(&self) -> &[Segment] {
+        match self.raw_segments().split_last() {
+            Some((last, segments)) if last.0.is_empty() => segments,
+            _ => self.raw_segments(),
+        }
+    }
+}
+
+impl Uri {
+    /// Edits the path in place, it is written back when the returned `PathMut` is dropped. A
+    /// path that would not fit the authority, see `set_path`, is adjusted on the way: with an
+    /// authority a path that is not empty becomes absolute, without one a path starting with
+    /// "//" gets a "." segment in front, as in "/.//a".
+    pub fn path_mut(&mut self) -> PathMut<'_> {
+        let path = self.path.clone();
+        PathMut { uri: self, path }
+    }
+}
+
+pub struct PathMut<'a> {
+    uri: &'a mut Uri,
+    path: Path,
+}
+
+impl<'a> Deref for PathMut<'a> {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl<'a> DerefMut for PathMut<'a> {
+    fn deref_mut(&mut self) -> &mut Path {
+        &mut self.path
+    }
+}
+
+impl<'a> Drop for PathMut<'a> {
+    fn drop(&mut self) {
+        let mut path = std::mem::replace(&mut self.path, Path::empty());
+        match self.uri.authority {
+            Some(_) if !path.is_empty() => path.absolute = true,
+            None if path.absolute && path.segments.len() > 1 && path.segments[0].0.is_empty() => {
+                path.segments.insert(0, Segment(vec![Char::Ascii(b'.')]))
+            }
+            _ => {}
+        }
+        self.uri.path = path;
+    }
+}
+
+//    segment       = name *( ";" param )
+//    param         = name [ "=" value ]
+//
+// Matrix parameters are a convention on top of RFC 3986, which reserves the sub-delims ';' and
+// '=' within segments for such uses.
+impl Segment {
+    /// The decoded segment up to its first ';', "cars" for "cars;color=red;year=2012".
+    pub fn name(&self) -> String {
+        decode(self.0.split(|c| c.is(b';')).next().unwrap())
+    }
+
+    /// The decoded matrix parameters after the name, a parameter without '=' has an empty value.
+    pub fn params(&self) -> Vec<(String, String)> {
+        self.0
+            .split(|c| c.is(b';'))
+            .skip(1)
+            .map(|param| match param.iter().position(|c| c.is(b'=')) {
+                Some(eq) => (decode(&param[..eq]), decode(&param[eq + 1..])),
+                None => (decode(param), String::new()),
+            })
+            .collect()
+    }
+
+    /// Appends ";name=value", percent-encoding ';' and '=' within `name` and `value`.
+    pub fn push_param(&mut self, name: &str, value: &str) {
+        self.0.push(Char::Ascii(b';'));
+        self.0.extend(percent::encode(name.as_bytes(), PARAM));
+        self.0.push(Char::Ascii(b'='));
+        self.0.extend(percent::encode(value.as_bytes(), PARAM));
+    }
+
+    /// Removes all matrix parameters named `name`, leaving the others as they are.
+    pub fn remove_param(&mut self, name: &str) {
+        let mut parts = self.0.split(|c| c.is(b';'));
+        let mut chars: Vec<Char> = parts.next().unwrap().to_vec();
+        for param in parts {
+            let param_name = match param.iter().position(|c| c.is(b'=')) {
+                Some(eq) => &param[..eq],
+                None => param,
+            };
+            if decode(param_name) != name {
+                chars.push(Char::Ascii(b';'));
+                chars.extend(param);
+            }
+        }
+        self.0 = chars;
+    }
+
+    fn decode(&self) -> String {
+        decode(&self.0)
+    }
+}
+
+const PARAM: EncodeSet = EncodeSet::new(is_param_char);
+
+fn is_param_char(c: Char) -> bool {
+    c.is_pchar() && !c.is(b';') && !c.is(b'=')
+}
+
+fn decode(chars: &[Char]) -> String {
+    match String::from_utf8(percent::decode(chars)) {
+        Ok(s) => s,
+        Err(e) => String::from_utf8_lossy(e.as_bytes()).into_owned(),
+    }
+}
@@ -0,0 +1,85 @@
+//! Percent-encoding as specified by RFC 3986 section 2.1.
+
+use super::Char;
+
+/// The characters `encode` leaves as they are, all other bytes are percent-encoded. '%' and bytes
+/// outside of ASCII are always encoded.
+#[derive(Clone, Copy)]
+pub struct EncodeSet {
+    is_allowed: fn(Char) -> bool,
+}
+
+impl EncodeSet {
+    pub const fn new(is_allowed: fn(Char) -> bool) -> EncodeSet {
+        EncodeSet { is_allowed }
+    }
+
+    pub fn encodes(&self, byte: u8) -> bool {
+        byte == b'%' || !byte.is_ascii() || !(self.is_allowed)(Char::Ascii(byte))
+    }
+}
+
+impl std::fmt::Debug for EncodeSet {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        fmt.write_str("EncodeSet")
+    }
+}
+
+//    userinfo      = *( unreserved / pct-encoded / sub-delims / ":" )
+pub const USERINFO: EncodeSet = EncodeSet::new(is_userinfo_char);
+
+//    reg-name      = *( unreserved / pct-encoded / sub-delims )
+pub const REG_NAME: EncodeSet = EncodeSet::new(is_reg_name_char);
+
+//    segment       = *pchar
+pub const PATH_SEGMENT: EncodeSet = EncodeSet::new(is_segment_char);
+
+//    query         = *( pchar / "/" / "?" )
+pub const QUERY: EncodeSet = EncodeSet::new(is_query_char);
+
+//    fragment      = *( pchar / "/" / "?" )
+pub const FRAGMENT: EncodeSet = EncodeSet::new(is_query_char);
+
+fn is_userinfo_char(c: Char) -> bool {
+    c.is_unreserved() || c.is_sub_delim() || c.is(b':')
+}
+
+fn is_reg_name_char(c: Char) -> bool {
+    c.is_unreserved() || c.is_sub_delim()
+}
+
+fn is_segment_char(c: Char) -> bool {
+    c.is_pchar()
+}
+
+fn is_query_char(c: Char) -> bool {
+    c.is_pchar() || c.is(b'/') || c.is(b'?')
+}
+
+/// Encodes `bytes`, percent-encoding every byte `set` does not allow.
+pub fn encode(bytes: &[u8], set: EncodeSet) -> Vec<Char> {
+    bytes
+        .iter()
+        .map(|&b| match set.encodes(b) {
+            true => Char::pct_encoded(b),
+            false => Char::Ascii(b),
+        })
+        .collect()
+}
+
+/// Decodes the percent-encoded characters of `chars`, leaving the others as they are, with
+/// `Char::Unicode` as UTF-8.
+pub fn decode(chars: &[Char]) -> Vec<u8> {
+    let mut bytes: Vec<u8> = Vec::with_capacity(chars.len());
+    for c in chars {
+        match c {
+            Char::Ascii(b) => bytes.push(*b),
+            Char::PctEncoded(_, _) => bytes.push(c.decode().unwrap()),
+            Char::Unicode(c) => {
+                let mut buf = [0; 4];
+                bytes.extend(c.encode_utf8(&mut buf).bytes());
+            }
+        }
+    }
+    bytes
+}
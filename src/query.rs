@@ -0,0 +1,290 @@
+use super::percent;
+use super::percent::EncodeSet;
+use super::Char;
+use super::Query;
+use super::Uri;
+use std::borrow::Cow;
+use std::ops::Deref;
+use std::ops::DerefMut;
+
+impl Query {
+    /// The name-value pairs of an application/x-www-form-urlencoded query, separated by '&' or
+    /// ';', with '+' decoded as space. Empty pairs are skipped, a pair without '=' has an empty
+    /// value and invalid UTF-8 is replaced by U+FFFD.
+    pub fn pairs(&self) -> Pairs<'_> {
+        Pairs { chars: &self.0 }
+    }
+
+    /// The value of the first pair named `name`.
+    pub fn get(&self, name: &str) -> Option<Cow<'_, str>> {
+        self.pairs()
+            .find(|(n, _)| n == name)
+            .map(|(_, value)| value)
+    }
+
+    pub fn get_all(&self, name: &str) -> Vec<Cow<'_, str>> {
+        self.pairs()
+            .filter(|(n, _)| n == name)
+            .map(|(_, value)| value)
+            .collect()
+    }
+
+    pub fn contains_key(&self, name: &str) -> bool {
+        self.pairs().any(|(n, _)| n == name)
+    }
+}
+
+pub struct Pairs<'a> {
+    chars: &'a [Char],
+}
+
+impl<'a> Pairs<'a> {
+    // The next pair that is not empty, as it is in the query
+    fn next_raw(&mut self) -> Option<&'a [Char]> {
+        while !self.chars.is_empty() {
+            let (pair, rest) = match self.chars.iter().position(|c| c.is(b'&') || c.is(b';')) {
+                Some(end) => (&self.chars[..end], &self.chars[end + 1..]),
+                None => (self.chars, &self.chars[self.chars.len()..]),
+            };
+            self.chars = rest;
+            if !pair.is_empty() {
+                return Some(pair);
+            }
+        }
+        None
+    }
+}
+
+impl<'a> Iterator for Pairs<'a> {
+    type Item = (Cow<'a, str>, Cow<'a, str>);
+
+    fn next(&mut self) -> Option<(Cow<'a, str>, Cow<'a, str>)> {
+        self.next_raw().map(decode_pair)
+    }
+}
+
+fn decode_pair<'a>(pair: &[Char]) -> (Cow<'a, str>, Cow<'a, str>) {
+    match pair.iter().position(|c| c.is(b'=')) {
+        Some(eq) => (decode(&pair[..eq]), decode(&pair[eq + 1..])),
+        None => (decode(pair), Cow::Borrowed("")),
+    }
+}
+
+// Decodes '+' and the percent-encoded characters in one go. The query is not held as a `str` to
+// borrow from, except for empty names and values the result is owned.
+fn decode<'a>(chars: &[Char]) -> Cow<'a, str> {
+    if chars.is_empty() {
+        return Cow::Borrowed("");
+    }
+    let mut bytes: Vec<u8> = Vec::with_capacity(chars.len());
+    for c in chars {
+        match c {
+            Char::Ascii(b'+') => bytes.push(b' '),
+            Char::Ascii(b) => bytes.push(*b),
+            Char::PctEncoded(_, _) => bytes.push(c.decode().unwrap()),
+            Char::Unicode(c) => {
+                let mut buf = [0; 4];
+                bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+            }
+        }
+    }
+    match String::from_utf8(bytes) {
+        Ok(s) => Cow::Owned(s),
+        Err(e) => Cow::Owned(String::from_utf8_lossy(e.as_bytes()).into_owned()),
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SpaceEncoding {
+    /// "%20", which means a space in any query.
+    Percent,
+    /// "+", as in HTML forms.
+    Plus,
+}
+
+impl Default for SpaceEncoding {
+    fn default() -> SpaceEncoding {
+        SpaceEncoding::Percent
+    }
+}
+
+/// Serializes name-value pairs into a `Query` as `pairs` reads it. Everything outside of
+/// `pchar / "/" / "?"` is percent-encoded, and so are the delimiters '&', ';', '=' and '+'.
+#[derive(Clone, Debug, Default)]
+pub struct QueryBuilder {
+    pairs: Vec<Pair>,
+    space_encoding: SpaceEncoding,
+}
+
+// A pair taken from a query keeps its characters until it is changed
+#[derive(Clone, Debug)]
+struct Pair {
+    name: String,
+    value: String,
+    raw: Option<Vec<Char>>,
+}
+
+impl Pair {
+    fn new(name: &str, value: &str) -> Pair {
+        Pair {
+            name: name.to_string(),
+            value: value.to_string(),
+            raw: None,
+        }
+    }
+}
+
+impl QueryBuilder {
+    pub fn new() -> QueryBuilder {
+        QueryBuilder::default()
+    }
+
+    /// A builder holding the decoded pairs of `query`. Until they are replaced, `build` writes
+    /// these pairs as they are in `query`, so "a=1+1" stays as is whatever the space encoding.
+    pub fn from_query(query: &Query) -> QueryBuilder {
+        let mut pairs: Vec<Pair> = Vec::new();
+        let mut raw_pairs = query.pairs();
+        while let Some(raw) = raw_pairs.next_raw() {
+            let (name, value) = decode_pair(raw);
+            pairs.push(Pair {
+                name: name.into_owned(),
+                value: value.into_owned(),
+                raw: Some(raw.to_vec()),
+            });
+        }
+        QueryBuilder {
+            pairs,
+            space_encoding: SpaceEncoding::default(),
+        }
+    }
+
+    pub fn space_encoding(&mut self, space_encoding: SpaceEncoding) -> &mut QueryBuilder {
+        self.space_encoding = space_encoding;
+        self
+    }
+
+    pub fn append(&mut self, name: &str, value: &str) -> &mut QueryBuilder {
+        self.pairs.push(Pair::new(name, value));
+        self
+    }
+
+    /// Sets the value of the first pair named `name` and removes all others, or appends a pair if
+    /// there is none.
+    pub fn replace(&mut self, name: &str, value: &str) -> &mut QueryBuilder {
+        match self.pairs.iter().position(|pair| pair.name == name) {
+            Some(first) => {
+                self.pairs[first] = Pair::new(name, value);
+                let rest = self.pairs.split_off(first + 1);
+                self.pairs
+                    .extend(rest.into_iter().filter(|pair| pair.name != name));
+            }
+            None => self.pairs.push(Pair::new(name, value)),
+        }
+        self
+    }
+
+    pub fn remove(&mut self, name: &str) -> &mut QueryBuilder {
+        self.pairs.retain(|pair| pair.name != name);
+        self
+    }
+
+    /// Sorts the pairs by name, keeping the order of pairs with the same name.
+    pub fn sort(&mut self) -> &mut QueryBuilder {
+        self.pairs.sort_by(|a, b| a.name.cmp(&b.name));
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+
+    pub fn build(&self) -> Query {
+        let mut chars: Vec<Char> = Vec::new();
+        for (i, pair) in self.pairs.iter().enumerate() {
+            if i > 0 {
+                chars.push(Char::Ascii(b'&'));
+            }
+            match &pair.raw {
+                Some(raw) => chars.extend(raw),
+                None => {
+                    self.encode(&pair.name, &mut chars);
+                    chars.push(Char::Ascii(b'='));
+                    self.encode(&pair.value, &mut chars);
+                }
+            }
+        }
+        Query(chars)
+    }
+
+    fn encode(&self, s: &str, chars: &mut Vec<Char>) {
+        for c in percent::encode(s.as_bytes(), PAIR) {
+            match c {
+                Char::PctEncoded(b'2', b'0') if self.space_encoding == SpaceEncoding::Plus => {
+                    chars.push(Char::Ascii(b'+'))
+                }
+                c => chars.push(c),
+            }
+        }
+    }
+}
+
+const PAIR: EncodeSet = EncodeSet::new(is_pair_char);
+
+fn is_pair_char(c: Char) -> bool {
+    (c.is_pchar() || c.is(b'/') || c.is(b'?'))
+        && !c.is(b'&')
+        && !c.is(b';')
+        && !c.is(b'=')
+        && !c.is(b'+')
+}
+
+impl Uri {
+    /// Edits the query pairs in place, the query is written back when the returned `QueryMut`
+    /// is dropped, unless it was only read. The pairs are then separated by '&', those taken
+    /// from the query keep their encoding, see `QueryBuilder::from_query`, and a query left
+    /// without pairs is removed.
+    pub fn query_mut(&mut self) -> QueryMut<'_> {
+        let builder = match &self.query {
+            Some(query) => QueryBuilder::from_query(query),
+            None => QueryBuilder::new(),
+        };
+        QueryMut {
+            uri: self,
+            builder,
+            changed: false,
+        }
+    }
+}
+
+pub struct QueryMut<'a> {
+    uri: &'a mut Uri,
+    builder: QueryBuilder,
+    changed: bool,
+}
+
+impl<'a> Deref for QueryMut<'a> {
+    type Target = QueryBuilder;
+
+    fn deref(&self) -> &QueryBuilder {
+        &self.builder
+    }
+}
+
+impl<'a> DerefMut for QueryMut<'a> {
+    fn deref_mut(&mut self) -> &mut QueryBuilder {
+        self.changed = true;
+        &mut self.builder
+    }
+}
+
+impl<'a> Drop for QueryMut<'a> {
+    fn drop(&mut self) {
+        if !self.changed {
+            return;
+        }
+        self.uri.query = match self.builder.is_empty() {
+            true => None,
+            false => Some(self.builder.build()),
+        };
+    }
+}
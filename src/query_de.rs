@@ -0,0 +1,245 @@
+use super::Query;
+use common_failures::prelude::*;
+use serde::de;
+use serde::de::IntoDeserializer;
+use std::collections::HashMap;
+use std::fmt;
+use std::vec;
+
+/// Deserializes the pairs of `query` into `T`, usually a struct with a field per name. Numbers
+/// and booleans are parsed from the decoded values, a repeated name fills a `Vec` or else keeps
+/// its last value, and an `Option` is `None` when the name is missing or its value empty.
+pub fn from_query<T>(query: &Query) -> Result<T>
+where
+    T: de::DeserializeOwned,
+{
+    // the names in order of appearance, with an index to find them again
+    let mut names: Vec<(String, Vec<String>)> = Vec::new();
+    let mut indices: HashMap<String, usize> = HashMap::new();
+    for (name, value) in query.pairs() {
+        match indices.get(name.as_ref()) {
+            Some(&i) => names[i].1.push(value.into_owned()),
+            None => {
+                indices.insert(name.to_string(), names.len());
+                names.push((name.into_owned(), vec![value.into_owned()]));
+            }
+        }
+    }
+    T::deserialize(PairsDeserializer(names)).map_err(Error::from)
+}
+
+#[derive(Debug)]
+struct DeError(String);
+
+impl fmt::Display for DeError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for DeError {}
+
+impl de::Error for DeError {
+    fn custom<T: fmt::Display>(msg: T) -> DeError {
+        DeError(msg.to_string())
+    }
+}
+
+type DeResult<T> = std::result::Result<T, DeError>;
+
+// The whole query, as a map from the names to their values
+struct PairsDeserializer(Vec<(String, Vec<String>)>);
+
+impl<'de> de::Deserializer<'de> for PairsDeserializer {
+    type Error = DeError;
+
+    fn deserialize_any<V: de::Visitor<'de>>(self, visitor: V) -> DeResult<V::Value> {
+        visitor.visit_map(PairsAccess {
+            names: self.0.into_iter(),
+            values: None,
+        })
+    }
+
+    ::serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        option unit unit_struct newtype_struct seq tuple tuple_struct map struct enum identifier
+        ignored_any
+    }
+}
+
+struct PairsAccess {
+    names: vec::IntoIter<(String, Vec<String>)>,
+    values: Option<Vec<String>>,
+}
+
+impl<'de> de::MapAccess<'de> for PairsAccess {
+    type Error = DeError;
+
+    fn next_key_seed<K: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> DeResult<Option<K::Value>> {
+        match self.names.next() {
+            Some((name, values)) => {
+                self.values = Some(values);
+                seed.deserialize(name.into_deserializer()).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: de::DeserializeSeed<'de>>(&mut self, seed: V) -> DeResult<V::Value> {
+        let values = self.values.take().unwrap_or_default();
+        seed.deserialize(ValuesDeserializer(values))
+    }
+}
+
+// All values of a name, a sequence or else the last value
+struct ValuesDeserializer(Vec<String>);
+
+impl ValuesDeserializer {
+    fn last(mut self) -> ValueDeserializer {
+        ValueDeserializer(self.0.pop().unwrap_or_default())
+    }
+}
+
+macro_rules! forward_to_last {
+    ($($method:ident)*) => {
+        $(
+            fn $method<V: de::Visitor<'de>>(self, visitor: V) -> DeResult<V::Value> {
+                de::Deserializer::$method(self.last(), visitor)
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for ValuesDeserializer {
+    type Error = DeError;
+
+    fn deserialize_seq<V: de::Visitor<'de>>(self, visitor: V) -> DeResult<V::Value> {
+        visitor.visit_seq(ValuesAccess(self.0.into_iter()))
+    }
+
+    fn deserialize_option<V: de::Visitor<'de>>(self, visitor: V) -> DeResult<V::Value> {
+        match self.0.last() {
+            Some(value) if !value.is_empty() => visitor.visit_some(self),
+            _ => visitor.visit_none(),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> DeResult<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> DeResult<V::Value> {
+        de::Deserializer::deserialize_enum(self.last(), name, variants, visitor)
+    }
+
+    forward_to_last! {
+        deserialize_any deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32
+        deserialize_i64 deserialize_u8 deserialize_u16 deserialize_u32 deserialize_u64
+        deserialize_f32 deserialize_f64 deserialize_char deserialize_str deserialize_string
+        deserialize_bytes deserialize_byte_buf deserialize_unit deserialize_identifier
+        deserialize_ignored_any
+    }
+
+    ::serde::forward_to_deserialize_any! {
+        i128 u128 unit_struct tuple tuple_struct map struct
+    }
+}
+
+struct ValuesAccess(vec::IntoIter<String>);
+
+impl<'de> de::SeqAccess<'de> for ValuesAccess {
+    type Error = DeError;
+
+    fn next_element_seed<T: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> DeResult<Option<T::Value>> {
+        match self.0.next() {
+            Some(value) => seed.deserialize(ValueDeserializer(value)).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+// A single value, parsed into whatever type is asked for
+struct ValueDeserializer(String);
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V: de::Visitor<'de>>(self, visitor: V) -> DeResult<V::Value> {
+                match self.0.parse() {
+                    Ok(value) => visitor.$visit(value),
+                    Err(_) => {
+                        let unexpected = de::Unexpected::Str(&self.0);
+                        Err(de::Error::invalid_value(unexpected, &visitor))
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for ValueDeserializer {
+    type Error = DeError;
+
+    fn deserialize_any<V: de::Visitor<'de>>(self, visitor: V) -> DeResult<V::Value> {
+        visitor.visit_string(self.0)
+    }
+
+    deserialize_parsed! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+    }
+
+    fn deserialize_option<V: de::Visitor<'de>>(self, visitor: V) -> DeResult<V::Value> {
+        match self.0.is_empty() {
+            true => visitor.visit_none(),
+            false => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> DeResult<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> DeResult<V::Value> {
+        visitor.visit_enum(self.0.into_deserializer())
+    }
+
+    ::serde::forward_to_deserialize_any! {
+        i128 u128 str string bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
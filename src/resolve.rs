@@ -0,0 +1,246 @@
+use super::guard_double_slash;
+use super::Char;
+use super::Path;
+use super::RelativeRef;
+use super::Segment;
+use super::Uri;
+use super::UriRef;
+
+impl Uri {
+    /// Resolves `reference` against this URI as base, following the algorithm of RFC 3986
+    /// section 5.2.2.
+    pub fn resolve(&self, reference: &UriRef) -> Uri {
+        let base = self;
+        let (scheme, authority, path, query) = match reference {
+            UriRef::Uri(r) => (
+                r.scheme.clone(),
+                r.authority.clone(),
+                r.path.remove_dot_segments(),
+                r.query.clone(),
+            ),
+            UriRef::RelativeRef(r) => {
+                if r.authority.is_some() {
+                    (
+                        base.scheme.clone(),
+                        r.authority.clone(),
+                        r.path.remove_dot_segments(),
+                        r.query.clone(),
+                    )
+                } else if r.path.is_empty() {
+                    (
+                        base.scheme.clone(),
+                        base.authority.clone(),
+                        base.path.clone(),
+                        match &r.query {
+                            Some(query) => Some(query.clone()),
+                            None => base.query.clone(),
+                        },
+                    )
+                } else if r.path.absolute {
+                    (
+                        base.scheme.clone(),
+                        base.authority.clone(),
+                        r.path.remove_dot_segments(),
+                        r.query.clone(),
+                    )
+                } else {
+                    (
+                        base.scheme.clone(),
+                        base.authority.clone(),
+                        merge(base, &r.path).remove_dot_segments(),
+                        r.query.clone(),
+                    )
+                }
+            }
+        };
+
+        Uri {
+            path: guard_double_slash(authority.as_ref(), path),
+            scheme,
+            authority,
+            query,
+            fragment: reference.fragment().cloned(),
+        }
+    }
+}
+
+impl Uri {
+    /// Computes the shortest reference that resolves against this URI to `target`, so that
+    /// `base.resolve(&base.relativize(&target)?) == target`. Resolution removes dot-segments,
+    /// thus there is no such reference for most targets with "." or ".." segments in their path;
+    /// remove them with `Path::remove_dot_segments` first. `None` if there is no reference.
+    pub fn relativize(&self, target: &Uri) -> Option<UriRef> {
+        let base = self;
+        if base.scheme != target.scheme {
+            return round_trip(base, UriRef::Uri(target.clone()), target);
+        }
+
+        let mut candidates: Vec<RelativeRef> = Vec::new();
+        let relative_ref = |authority, path, query| RelativeRef {
+            authority,
+            path,
+            query,
+            fragment: target.fragment.clone(),
+        };
+        if base.authority == target.authority {
+            if base.path == target.path {
+                if base.query == target.query {
+                    candidates.push(relative_ref(None, Path::empty(), None));
+                } else if target.query.is_some() {
+                    candidates.push(relative_ref(None, Path::empty(), target.query.clone()));
+                }
+            }
+            if let Some(path) = relative_path(base, &target.path) {
+                candidates.push(relative_ref(None, path, target.query.clone()));
+            }
+            if target.path.absolute {
+                candidates.push(relative_ref(
+                    None,
+                    target.path.clone(),
+                    target.query.clone(),
+                ));
+            }
+        }
+        if target.authority.is_some() {
+            candidates.push(relative_ref(
+                target.authority.clone(),
+                target.path.clone(),
+                target.query.clone(),
+            ));
+        }
+
+        let mut shortest: Option<(usize, RelativeRef)> = None;
+        for candidate in candidates {
+            let reference = UriRef::RelativeRef(candidate);
+            let s = reference.to_string();
+            let valid = base.resolve(&reference) == *target
+                && UriRef::parse(&s).ok().as_ref() == Some(&reference);
+            let shorter = match &shortest {
+                Some((len, _)) => s.len() < *len,
+                None => true,
+            };
+            if valid && shorter {
+                if let UriRef::RelativeRef(candidate) = reference {
+                    shortest = Some((s.len(), candidate));
+                }
+            }
+        }
+        match shortest {
+            Some((_, relative_ref)) => Some(UriRef::RelativeRef(relative_ref)),
+            None => round_trip(base, UriRef::Uri(target.clone()), target),
+        }
+    }
+}
+
+// The reference, if it resolves against the base to the target
+fn round_trip(base: &Uri, reference: UriRef, target: &Uri) -> Option<UriRef> {
+    match base.resolve(&reference) == *target {
+        true => Some(reference),
+        false => None,
+    }
+}
+
+// The relative-path reference from the directory of the base path to `target`, if both paths are
+// absolute.
+fn relative_path(base: &Uri, target: &Path) -> Option<Path> {
+    let directory: &[Segment] = if base.authority.is_some() && base.path.is_empty() {
+        &[]
+    } else if base.path.absolute {
+        &base.path.segments[..base.path.segments.len() - 1]
+    } else {
+        return None;
+    };
+    if !target.absolute {
+        return None;
+    }
+
+    let target_directory = &target.segments[..target.segments.len() - 1];
+    let common = directory
+        .iter()
+        .zip(target_directory.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut segments: Vec<Segment> = Vec::new();
+    for _ in common..directory.len() {
+        segments.push(Segment(vec![Char::Ascii(b'.'), Char::Ascii(b'.')]));
+    }
+    segments.extend(target.segments[common..].iter().cloned());
+
+    // "", "//x" and "a:b" would not read as the intended relative path, "./", ".//x" and "./a:b" do
+    let first = &segments[0];
+    if first.0.is_empty() || first.0.iter().any(|c| c.is(b':')) {
+        segments.insert(0, Segment(vec![Char::Ascii(b'.')]));
+    }
+
+    Some(Path {
+        segments,
+        absolute: false,
+    })
+}
+
+impl Path {
+    /// Removes the "." and ".." segments of this path, following the algorithm of RFC 3986
+    /// section 5.2.4. Leading dot-segments of a relative path are dropped, and removing its first
+    /// segment leaves the '/' before the next one, so "a/../b" becomes "/b" and "a/.." becomes "/".
+    pub fn remove_dot_segments(&self) -> Path {
+        let mut absolute = self.absolute;
+        let mut input = &self.segments[..];
+        if !absolute {
+            while input.first().map_or(false, |s| s.is_dot() || s.is_dot_dot()) {
+                input = &input[1..];
+            }
+            // an empty first segment is what is left of a '/' after "./" or "../", as in ".//a"
+            if input.first().map_or(false, |first| first.0.is_empty()) {
+                absolute = true;
+                input = &input[1..];
+            }
+            if input.is_empty() {
+                return Path::empty();
+            }
+        }
+
+        let mut segments: Vec<Segment> = Vec::new();
+        let last = input.len().saturating_sub(1);
+        for (i, segment) in input.iter().enumerate() {
+            if segment.is_dot() {
+                if i == last {
+                    segments.push(Segment(Vec::new()));
+                }
+            } else if segment.is_dot_dot() {
+                // the first segment of a relative path has no '/' of its own to take along
+                if segments.pop().is_some() && segments.is_empty() {
+                    absolute = true;
+                }
+                if i == last {
+                    segments.push(Segment(Vec::new()));
+                }
+            } else {
+                segments.push(segment.clone());
+            }
+        }
+
+        if segments.is_empty() {
+            segments.push(Segment(Vec::new()));
+        }
+        Path { segments, absolute }
+    }
+}
+
+// RFC 3986 section 5.2.3
+fn merge(base: &Uri, path: &Path) -> Path {
+    if base.authority.is_some() && base.path.is_empty() {
+        return Path {
+            segments: path.segments.clone(),
+            absolute: true,
+        };
+    }
+
+    let directory = base.path.segments.len().saturating_sub(1);
+    let mut segments = base.path.segments[..directory].to_vec();
+    segments.extend(path.segments.iter().cloned());
+    Path {
+        segments,
+        absolute: base.path.absolute,
+    }
+}
@@ -0,0 +1,75 @@
+use super::Iri;
+use super::IriRef;
+use super::RelativeRef;
+use super::Uri;
+use super::UriRef;
+use common_failures::prelude::*;
+use serde::de;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+// Serializes as the string form and deserializes by parsing it, rejecting invalid input
+macro_rules! impl_serde_as_string {
+    ($type:ident, $expecting:expr) => {
+        impl Serialize for $type {
+            fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $type {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<$type, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserializer.deserialize_str(FromStrVisitor::new($expecting))
+            }
+        }
+    };
+}
+
+impl_serde_as_string!(Uri, "a URI");
+impl_serde_as_string!(UriRef, "a URI reference");
+impl_serde_as_string!(RelativeRef, "a relative reference");
+impl_serde_as_string!(Iri, "an IRI");
+impl_serde_as_string!(IriRef, "an IRI reference");
+
+struct FromStrVisitor<T> {
+    expecting: &'static str,
+    marker: PhantomData<T>,
+}
+
+impl<T> FromStrVisitor<T> {
+    fn new(expecting: &'static str) -> FromStrVisitor<T> {
+        FromStrVisitor {
+            expecting,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'de, T> de::Visitor<'de> for FromStrVisitor<T>
+where
+    T: FromStr<Err = Error>,
+{
+    type Value = T;
+
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(self.expecting)
+    }
+
+    fn visit_str<E>(self, s: &str) -> std::result::Result<T, E>
+    where
+        E: de::Error,
+    {
+        T::from_str(s).map_err(E::custom)
+    }
+}
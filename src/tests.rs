@@ -1,6 +1,7 @@
 use super::*;
 
 use super::char_buf::new_char_buf;
+use std::net::Ipv4Addr;
 
 #[test]
     fn test_parse_ipv4_address() {
@@ -38,49 +39,49 @@ use super::char_buf::new_char_buf;
 fn test_parse_dec_octet() -> Result<()> {
     let mut tb = new_char_buf("".as_bytes());
     let dec_octet = parse_dec_octet(&mut tb)?;
-    assert_eq!(false, dec_octet.is_some());
+    assert!(dec_octet.is_none());
     assert_eq!(0, tb.len());
 
     let mut tb = new_char_buf("0".as_bytes());
     let dec_octet = parse_dec_octet(&mut tb)?;
-    assert_eq!(true, dec_octet.is_some());
+    assert!(dec_octet.is_some());
     let dec_octet = dec_octet.unwrap();
     assert_eq!(0, tb.len());
     assert_eq!("0", dec_octet.to_string());
 
     let mut tb = new_char_buf("1.".as_bytes());
     let dec_octet = parse_dec_octet(&mut tb)?;
-    assert_eq!(true, dec_octet.is_some());
+    assert!(dec_octet.is_some());
     let dec_octet = dec_octet.unwrap();
     assert_eq!(1, tb.len());
     assert_eq!("1", dec_octet.to_string());
 
     let token = tb.pop()?;
-    assert_eq!(true, token.is_some());
+    assert!(token.is_some());
     let token = token.unwrap();
     assert_eq!(".", token.to_string());
 
     let mut tb = new_char_buf("255".as_bytes());
     let dec_octet = parse_dec_octet(&mut tb)?;
-    assert_eq!(true, dec_octet.is_some());
+    assert!(dec_octet.is_some());
     let dec_octet = dec_octet.unwrap();
     assert_eq!(0, tb.len());
     assert_eq!("255", dec_octet.to_string());
 
     let mut tb = new_char_buf("256".as_bytes());
     let dec_octet = parse_dec_octet(&mut tb)?;
-    assert_eq!(false, dec_octet.is_some());
+    assert!(dec_octet.is_none());
     assert_eq!(3, tb.len());
 
     let mut tb = new_char_buf("2555".as_bytes());
     let dec_octet = parse_dec_octet(&mut tb)?;
-    assert_eq!(true, dec_octet.is_some());
+    assert!(dec_octet.is_some());
     let dec_octet = dec_octet.unwrap();
     assert_eq!(0, tb.len());
     assert_eq!("255", dec_octet.to_string());
 
     let token = tb.pop()?;
-    assert_eq!(true, token.is_some());
+    assert!(token.is_some());
     let token = token.unwrap();
     assert_eq!("5", token.to_string());
 
@@ -91,31 +92,31 @@ fn test_parse_dec_octet() -> Result<()> {
 fn test_path() -> Result<()> {
     let mut tb = new_char_buf("".as_bytes());
     let path = parse_path(&mut tb)?;
-    assert_eq!(false, path.absolute);
+    assert!(!path.absolute);
     assert_eq!(1, path.segments.len());
     assert_eq!("", path.to_string());
 
     let mut tb = new_char_buf("/".as_bytes());
     let path = parse_path(&mut tb)?;
-    assert_eq!(true, path.absolute);
+    assert!(path.absolute);
     assert_eq!(1, path.segments.len());
     assert_eq!("/", path.to_string());
 
     let mut tb = new_char_buf("/foo/".as_bytes());
     let path = parse_path(&mut tb)?;
-    assert_eq!(true, path.absolute);
+    assert!(path.absolute);
     assert_eq!(2, path.segments.len());
     assert_eq!("/foo/", path.to_string());
 
     let mut tb = new_char_buf("/foo/bar".as_bytes());
     let path = parse_path(&mut tb)?;
-    assert_eq!(true, path.absolute);
+    assert!(path.absolute);
     assert_eq!(2, path.segments.len());
     assert_eq!("/foo/bar", path.to_string());
 
     let mut tb = new_char_buf("foo/bar".as_bytes());
     let path = parse_path(&mut tb)?;
-    assert_eq!(false, path.absolute);
+    assert!(!path.absolute);
     assert_eq!(2, path.segments.len());
     assert_eq!("foo/bar", path.to_string());
 
@@ -157,3 +158,1579 @@ fn test_fragment() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_uri() -> Result<()> {
+    let uri = Uri::parse("http://example.com/foo/bar?baz=1#qux")?;
+    assert_eq!(&Scheme::HTTP, uri.scheme());
+    assert_eq!("example.com", uri.authority().unwrap().to_string());
+    assert_eq!("/foo/bar", uri.path().to_string());
+    assert_eq!("baz=1", uri.query().unwrap().to_string());
+    assert_eq!("qux", uri.fragment().unwrap().to_string());
+    assert_eq!("http://example.com/foo/bar?baz=1#qux", uri.to_string());
+
+    let uri: Uri = "mailto:john.doe@example.com".parse()?;
+    assert_eq!(&Scheme::MAILTO, uri.scheme());
+    assert_eq!(None, uri.authority());
+    assert_eq!("john.doe@example.com", uri.path().to_string());
+
+    let uri = Uri::parse("file:///etc/hosts")?;
+    assert_eq!("", uri.authority().unwrap().to_string());
+    assert_eq!("/etc/hosts", uri.path().to_string());
+    assert_eq!("file:///etc/hosts", uri.to_string());
+
+    let uri = Uri::parse("urn:isbn:0451450523")?;
+    assert_eq!("isbn:0451450523", uri.path().to_string());
+
+    let uri = Uri::parse("foo:?#")?;
+    assert_eq!("", uri.path().to_string());
+    assert_eq!("", uri.query().unwrap().to_string());
+    assert_eq!("", uri.fragment().unwrap().to_string());
+
+    let uri = Uri::parse("http://example.com/a%20b")?;
+    assert_eq!("/a%20b", uri.path().to_string());
+
+    assert!(Uri::parse("").is_err());
+    assert!(Uri::parse("//example.com").is_err());
+    assert!(Uri::parse("1http://example.com").is_err());
+    assert!(Uri::parse("http://example.com/a b").is_err());
+    assert!(Uri::parse("http://example.com/#a#b").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_uri_ref() -> Result<()> {
+    let uri_ref = UriRef::parse("http://example.com/foo")?;
+    assert!(!uri_ref.is_relative());
+    assert_eq!("http://example.com/foo", uri_ref.to_string());
+
+    let uri_ref = UriRef::parse("../a/b?x#y")?;
+    assert!(uri_ref.is_relative());
+    assert_eq!(None, uri_ref.authority());
+    assert_eq!("../a/b", uri_ref.path().to_string());
+    assert_eq!("x", uri_ref.query().unwrap().to_string());
+    assert_eq!("y", uri_ref.fragment().unwrap().to_string());
+    assert_eq!("../a/b?x#y", uri_ref.to_string());
+
+    let uri_ref: UriRef = "//cdn.example/x".parse()?;
+    assert!(uri_ref.is_relative());
+    assert_eq!("cdn.example", uri_ref.authority().unwrap().to_string());
+    assert_eq!("/x", uri_ref.path().to_string());
+
+    let uri_ref = UriRef::parse("/a:b")?;
+    assert_eq!("/a:b", uri_ref.path().to_string());
+
+    let uri_ref = UriRef::parse("./a:b")?;
+    assert_eq!("./a:b", uri_ref.path().to_string());
+
+    let uri_ref = UriRef::parse("")?;
+    assert!(uri_ref.is_relative());
+    assert_eq!("", uri_ref.to_string());
+
+    let uri_ref = UriRef::parse("?x")?;
+    assert_eq!("", uri_ref.path().to_string());
+    assert_eq!("x", uri_ref.query().unwrap().to_string());
+
+    let uri_ref = UriRef::parse("a:b")?;
+    assert!(!uri_ref.is_relative());
+
+    assert!(UriRef::parse("1a:b").is_err());
+    assert!(RelativeRef::parse("a:b").is_err());
+    assert!(UriRef::parse("a b").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_scheme() -> Result<()> {
+    let mut tb = new_char_buf("HTTP://example.com".as_bytes());
+    let scheme = parse_scheme(&mut tb)?.unwrap();
+    assert_eq!("HTTP", scheme.as_str());
+    assert_eq!(Scheme::HTTP, scheme);
+    assert_eq!("http", scheme.normalize().as_str());
+    assert_eq!("/", tb.pop()?.unwrap().to_string());
+
+    let mut tb = new_char_buf("svn+ssh:".as_bytes());
+    let scheme = parse_scheme(&mut tb)?.unwrap();
+    assert_eq!("svn+ssh", scheme.to_string());
+    assert_eq!(None, tb.pop()?);
+
+    let mut tb = new_char_buf("foo/bar:".as_bytes());
+    assert_eq!(None, parse_scheme(&mut tb)?);
+    assert_eq!(4, tb.len());
+    assert_eq!("f", tb.pop()?.unwrap().to_string());
+
+    let mut tb = new_char_buf("foo".as_bytes());
+    assert_eq!(None, parse_scheme(&mut tb)?);
+    assert_eq!(3, tb.len());
+
+    let mut tb = new_char_buf("1foo:".as_bytes());
+    assert_eq!(None, parse_scheme(&mut tb)?);
+    assert_eq!(1, tb.len());
+
+    assert_eq!(Scheme::URN, Scheme::parse("Urn")?);
+    assert_ne!(Scheme::HTTP, Scheme::HTTPS);
+    assert!(Scheme::parse("").is_err());
+    assert!(Scheme::parse("1foo").is_err());
+    assert!(Scheme::parse("foo:").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_authority() -> Result<()> {
+    let mut tb = new_char_buf("user:pass@example.com:8080/".as_bytes());
+    let authority = parse_authority(&mut tb)?;
+    assert_eq!("user:pass", authority.userinfo().unwrap().to_string());
+    assert_eq!("example.com", authority.host().to_string());
+    assert_eq!(Some(8080), authority.port());
+    assert_eq!("user:pass@example.com:8080", authority.to_string());
+    assert_eq!("/", tb.pop()?.unwrap().to_string());
+
+    let mut tb = new_char_buf("example.com:8080".as_bytes());
+    let authority = parse_authority(&mut tb)?;
+    assert_eq!(None, authority.userinfo());
+    assert_eq!("example.com", authority.host().to_string());
+    assert_eq!(Some(8080), authority.port());
+
+    let mut tb = new_char_buf("127.0.0.1:80".as_bytes());
+    let authority = parse_authority(&mut tb)?;
+    match authority.host() {
+        Host::Ipv4address(ip) => assert_eq!(Ipv4Addr::new(127, 0, 0, 1), ip.to_ipv4_addr()),
+        host => panic!("Unexpected host {:?}", host),
+    }
+    assert_eq!(Some(80), authority.port());
+
+    let mut tb = new_char_buf("127.0.0.1.example".as_bytes());
+    let authority = parse_authority(&mut tb)?;
+    match authority.host() {
+        Host::RegName(reg_name) => assert_eq!("127.0.0.1.example", reg_name.to_string()),
+        host => panic!("Unexpected host {:?}", host),
+    }
+
+    let mut tb = new_char_buf("example.com:".as_bytes());
+    let authority = parse_authority(&mut tb)?;
+    assert_eq!(None, authority.port());
+    assert_eq!("example.com:", authority.to_string());
+
+    let mut tb = new_char_buf("@".as_bytes());
+    let authority = parse_authority(&mut tb)?;
+    assert_eq!("", authority.userinfo().unwrap().to_string());
+    assert_eq!("", authority.host().to_string());
+
+    let mut tb = new_char_buf("example.com:65536".as_bytes());
+    let err = parse_authority(&mut tb).unwrap_err();
+    assert_eq!(
+        Some(&UriError::PortOutOfRange("65536".to_string())),
+        err.downcast_ref::<UriError>()
+    );
+
+    let uri = Uri::parse("ftp://anonymous@ftp.example.com:21/pub")?;
+    let authority = uri.authority().unwrap();
+    assert_eq!("anonymous", authority.userinfo().unwrap().to_string());
+    assert_eq!("ftp.example.com", authority.host().to_string());
+    assert_eq!(Some(21), authority.port());
+
+    assert!(Uri::parse("http://a@b@c/").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_parse_h16() -> Result<()> {
+    let mut tb = new_char_buf("0db8:".as_bytes());
+    let h16 = parse_h16(&mut tb)?.unwrap();
+    assert_eq!("0db8", h16.to_string());
+    assert_eq!(0xdb8, h16.value());
+    assert_eq!(":", tb.pop()?.unwrap().to_string());
+
+    let mut tb = new_char_buf("1".as_bytes());
+    assert_eq!(1, parse_h16(&mut tb)?.unwrap().value());
+
+    let mut tb = new_char_buf("12345".as_bytes());
+    assert_eq!("1234", parse_h16(&mut tb)?.unwrap().to_string());
+    assert_eq!("5", tb.pop()?.unwrap().to_string());
+
+    let mut tb = new_char_buf("g".as_bytes());
+    assert_eq!(None, parse_h16(&mut tb)?);
+    assert_eq!(1, tb.len());
+
+    Ok(())
+}
+
+#[test]
+fn test_parse_ipv6_address() -> Result<()> {
+    let valid = vec![
+        ("1:2:3:4:5:6:7:8", [1, 2, 3, 4, 5, 6, 7, 8]),
+        ("1:2:3:4:5:6:1.2.3.4", [1, 2, 3, 4, 5, 6, 0x102, 0x304]),
+        ("::2:3:4:5:6:7:8", [0, 2, 3, 4, 5, 6, 7, 8]),
+        ("1::3:4:5:6:7:8", [1, 0, 3, 4, 5, 6, 7, 8]),
+        ("1:2::4:5:6:7:8", [1, 2, 0, 4, 5, 6, 7, 8]),
+        ("1:2:3::5:6:7:8", [1, 2, 3, 0, 5, 6, 7, 8]),
+        ("1:2:3:4::6:7:8", [1, 2, 3, 4, 0, 6, 7, 8]),
+        ("1:2:3:4:5::7:8", [1, 2, 3, 4, 5, 0, 7, 8]),
+        ("1:2:3:4:5:6::8", [1, 2, 3, 4, 5, 6, 0, 8]),
+        ("1:2:3:4:5:6:7::", [1, 2, 3, 4, 5, 6, 7, 0]),
+        ("::", [0, 0, 0, 0, 0, 0, 0, 0]),
+        ("::1", [0, 0, 0, 0, 0, 0, 0, 1]),
+        ("2001:db8::1", [0x2001, 0xdb8, 0, 0, 0, 0, 0, 1]),
+        ("::ffff:192.0.2.1", [0, 0, 0, 0, 0, 0xffff, 0xc000, 0x201]),
+        ("::192.0.2.1", [0, 0, 0, 0, 0, 0, 0xc000, 0x201]),
+        ("FE80::0202:B3FF:FE1E:8329", [0xfe80, 0, 0, 0, 0x202, 0xb3ff, 0xfe1e, 0x8329]),
+    ];
+    for (input, segments) in valid {
+        let mut tb = new_char_buf(input.as_bytes());
+        let ipv6_address = parse_ipv6_address(&mut tb)?.unwrap();
+        assert_eq!(segments, ipv6_address.segments(), "{}", input);
+        assert_eq!(input, ipv6_address.to_string());
+        assert_eq!(0, tb.len(), "{}", input);
+        assert_eq!(None, tb.pop()?);
+    }
+
+    let invalid = vec![
+        "",
+        ":",
+        ":1",
+        "1:2:3:4:5:6:7",
+        "1:2:3:4:5:6:7:8:9",
+        "1:2:3:4:5:6:7:8::",
+        "1::2::3",
+        "1:2:3:4:5:6:7:1.2.3.4",
+        "1:",
+        "1.2.3.4",
+    ];
+    for input in invalid {
+        let mut tb = new_char_buf(input.as_bytes());
+        assert_eq!(None, parse_ipv6_address(&mut tb)?, "{}", input);
+        let mut rest = String::new();
+        while let Some(token) = tb.pop()? {
+            rest.push_str(&token.to_string());
+        }
+        assert_eq!(input, rest);
+    }
+
+    let uri = Uri::parse("http://[2001:db8::1]:8080/foo")?;
+    let authority = uri.authority().unwrap();
+    match authority.host() {
+        Host::Ipv6address(ip, None) => assert_eq!(
+            "2001:db8::1".parse::<std::net::Ipv6Addr>().unwrap(),
+            ip.to_ipv6_addr()
+        ),
+        host => panic!("Unexpected host {:?}", host),
+    }
+    assert_eq!(Some(8080), authority.port());
+    assert_eq!("http://[2001:db8::1]:8080/foo", uri.to_string());
+
+    assert!(Uri::parse("http://[2001:db8::1/").is_err());
+    assert!(Uri::parse("http://[2001:db8:::1]/").is_err());
+    assert!(Uri::parse("http://[]/").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_parse_ip_future() -> Result<()> {
+    let mut tb = new_char_buf("v1.fe80::a+en1]".as_bytes());
+    let ip_future = parse_ip_future(&mut tb)?.unwrap();
+    assert_eq!("1", ip_future.version());
+    assert_eq!("fe80::a+en1", ip_future.address());
+    assert_eq!("v1.fe80::a+en1", ip_future.to_string());
+    assert_eq!("]", tb.pop()?.unwrap().to_string());
+
+    let mut tb = new_char_buf("VF.x".as_bytes());
+    assert_eq!("VF.x", parse_ip_future(&mut tb)?.unwrap().to_string());
+
+    let mut tb = new_char_buf("v.x".as_bytes());
+    assert_eq!(None, parse_ip_future(&mut tb)?);
+    assert_eq!(2, tb.len());
+
+    let mut tb = new_char_buf("v1.]".as_bytes());
+    assert_eq!(None, parse_ip_future(&mut tb)?);
+    assert_eq!(4, tb.len());
+
+    let uri = Uri::parse("http://[v1.fe80::a+en1]/")?;
+    assert_eq!(HostKind::IpFuture, uri.authority().unwrap().host().kind());
+    assert_eq!("http://[v1.fe80::a+en1]/", uri.to_string());
+
+    let kind = |s: &str| -> Result<HostKind> {
+        Ok(Uri::parse(s)?.authority().unwrap().host().kind())
+    };
+    assert_eq!(HostKind::Ipv6, kind("http://[::1]/")?);
+    assert_eq!(HostKind::Ipv4, kind("http://127.0.0.1/")?);
+    assert_eq!(HostKind::RegName, kind("http://localhost/")?);
+
+    assert!(Uri::parse("http://[v1.]/").is_err());
+    assert!(Uri::parse("http://[v1x]/").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_zone_id() -> Result<()> {
+    let uri = Uri::parse("http://[fe80::1%25eth0]:8080/")?;
+    let authority = uri.authority().unwrap();
+    let host = authority.host();
+    assert_eq!(HostKind::Ipv6, host.kind());
+    assert_eq!("eth0", host.zone_id().unwrap().to_string());
+    assert_eq!("[fe80::1%25eth0]", host.to_string());
+    assert_eq!("http://[fe80::1%25eth0]:8080/", uri.to_string());
+
+    let socket_addr = host.to_socket_addr_v6(authority.port().unwrap()).unwrap();
+    assert_eq!(8080, socket_addr.port());
+    assert_eq!(0, socket_addr.scope_id());
+
+    let uri = Uri::parse("http://[fe80::1%253]/")?;
+    let host = uri.authority().unwrap().host();
+    assert_eq!("3", host.zone_id().unwrap().to_string());
+    let socket_addr = host.to_socket_addr_v6(80).unwrap();
+    assert_eq!("fe80::1".parse::<std::net::Ipv6Addr>().unwrap(), *socket_addr.ip());
+    assert_eq!(3, socket_addr.scope_id());
+
+    let uri = Uri::parse("http://[::1]/")?;
+    assert_eq!(None, uri.authority().unwrap().host().zone_id());
+
+    let uri = Uri::parse("http://localhost/")?;
+    assert_eq!(None, uri.authority().unwrap().host().to_socket_addr_v6(80));
+
+    assert!(Uri::parse("http://[fe80::1%25]/").is_err());
+    assert!(Uri::parse("http://[fe80::1%eth0]/").is_err());
+    assert!(Uri::parse("http://[fe80::1%25eth/0]/").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_reg_name() -> Result<()> {
+    let mut tb = new_char_buf("www.Example.com.:80".as_bytes());
+    let reg_name = parse_reg_name(&mut tb)?;
+    assert_eq!("www.Example.com.", reg_name.to_string());
+    assert_eq!(vec!["www", "Example", "com"], reg_name.labels());
+    assert!(reg_name.is_fqdn());
+    assert_eq!(Some("com".to_string()), reg_name.tld());
+    assert!(reg_name.validate_dns().is_ok());
+    assert_eq!(":", tb.pop()?.unwrap().to_string());
+
+    let mut tb = new_char_buf("localhost".as_bytes());
+    let reg_name = parse_reg_name(&mut tb)?;
+    assert!(!reg_name.is_fqdn());
+    assert_eq!(Some("localhost".to_string()), reg_name.tld());
+
+    let mut tb = new_char_buf("".as_bytes());
+    let reg_name = parse_reg_name(&mut tb)?;
+    assert_eq!(0, reg_name.labels().len());
+    assert_eq!(None, reg_name.tld());
+    assert!(reg_name.validate_dns().is_ok());
+
+    let strict = ParseOptions {
+        strict_dns: true,
+        ..ParseOptions::default()
+    };
+    assert!(Uri::parse_with("http://3com.example/", &strict).is_ok());
+    assert!(Uri::parse_with("http://127.0.0.1/", &strict).is_ok());
+    assert!(Uri::parse_with("file:///etc/hosts", &strict).is_ok());
+
+    let long_label = "a".repeat(64);
+    let long_name = vec!["a".repeat(63); 4].join(".");
+    for host in &[
+        "-a.example",
+        "a-.example",
+        "a_b.example",
+        "a..example",
+        "%41.example",
+        "a!.example",
+        long_label.as_str(),
+        long_name.as_str(),
+    ] {
+        let uri = format!("http://{}/", host);
+        assert!(Uri::parse(&uri).is_ok(), "{}", host);
+        let err = Uri::parse_with(&uri, &strict).unwrap_err();
+        match err.downcast_ref::<UriError>() {
+            Some(UriError::InvalidDnsName(name, _)) => assert_eq!(host, name),
+            e => panic!("Unexpected error {:?}", e),
+        }
+    }
+    assert!(UriRef::parse_with("//a_b/", &strict).is_err());
+
+    Ok(())
+}
+
+// RFC 3986 section 5.4, references and their targets with the base "http://a/b/c/d;p?q"
+const RFC_3986_EXAMPLES: &[(&str, &str)] = &[
+    // 5.4.1. Normal Examples
+    ("g:h", "g:h"),
+    ("g", "http://a/b/c/g"),
+    ("./g", "http://a/b/c/g"),
+    ("g/", "http://a/b/c/g/"),
+    ("/g", "http://a/g"),
+    ("//g", "http://g"),
+    ("?y", "http://a/b/c/d;p?y"),
+    ("g?y", "http://a/b/c/g?y"),
+    ("#s", "http://a/b/c/d;p?q#s"),
+    ("g#s", "http://a/b/c/g#s"),
+    ("g?y#s", "http://a/b/c/g?y#s"),
+    (";x", "http://a/b/c/;x"),
+    ("g;x", "http://a/b/c/g;x"),
+    ("g;x?y#s", "http://a/b/c/g;x?y#s"),
+    ("", "http://a/b/c/d;p?q"),
+    (".", "http://a/b/c/"),
+    ("./", "http://a/b/c/"),
+    ("..", "http://a/b/"),
+    ("../", "http://a/b/"),
+    ("../g", "http://a/b/g"),
+    ("../..", "http://a/"),
+    ("../../", "http://a/"),
+    ("../../g", "http://a/g"),
+    // 5.4.2. Abnormal Examples
+    ("../../../g", "http://a/g"),
+    ("../../../../g", "http://a/g"),
+    ("/./g", "http://a/g"),
+    ("/../g", "http://a/g"),
+    ("g.", "http://a/b/c/g."),
+    (".g", "http://a/b/c/.g"),
+    ("g..", "http://a/b/c/g.."),
+    ("..g", "http://a/b/c/..g"),
+    ("./../g", "http://a/b/g"),
+    ("./g/.", "http://a/b/c/g/"),
+    ("g/./h", "http://a/b/c/g/h"),
+    ("g/../h", "http://a/b/c/h"),
+    ("g;x=1/./y", "http://a/b/c/g;x=1/y"),
+    ("g;x=1/../y", "http://a/b/c/y"),
+    ("g?y/./x", "http://a/b/c/g?y/./x"),
+    ("g?y/../x", "http://a/b/c/g?y/../x"),
+    ("g#s/./x", "http://a/b/c/g#s/./x"),
+    ("g#s/../x", "http://a/b/c/g#s/../x"),
+    ("http:g", "http:g"),
+    // not in the RFC, an empty first segment after removing dot-segments
+    ("/.//x", "http://a//x"),
+];
+
+#[test]
+fn test_resolve() -> Result<()> {
+    let base = Uri::parse("http://a/b/c/d;p?q")?;
+    for &(reference, target) in RFC_3986_EXAMPLES {
+        let resolved = base.resolve(&UriRef::parse(reference)?);
+        assert_eq!(target, resolved.to_string(), "{}", reference);
+        assert_eq!(Uri::parse(target)?, resolved, "{}", reference);
+    }
+
+    let base = Uri::parse("http://a")?;
+    assert_eq!("http://a/g", base.resolve(&UriRef::parse("g")?).to_string());
+
+    let base = Uri::parse("urn:a/b")?;
+    assert_eq!("urn:a/c", base.resolve(&UriRef::parse("c")?).to_string());
+
+    // without an authority "//x" would become one
+    let base = Uri::parse("foo:/a")?;
+    let resolved = base.resolve(&UriRef::parse("/.//x")?);
+    assert_eq!("foo:/.//x", resolved.to_string());
+    assert_eq!(Uri::parse(&resolved.to_string())?, resolved);
+    assert_eq!("foo:/.//x", base.resolve(&UriRef::parse("a/..//x")?).to_string());
+
+    Ok(())
+}
+
+#[test]
+fn test_remove_dot_segments() -> Result<()> {
+    let remove = |s: &str| -> Result<String> {
+        Ok(UriRef::parse(s)?.path().remove_dot_segments().to_string())
+    };
+    assert_eq!("/a/g", remove("/a/b/c/./../../g")?);
+    assert_eq!("mid/6", remove("mid/content=5/../6")?);
+    assert_eq!("/", remove("/..")?);
+    assert_eq!("/a/", remove("/a/.")?);
+    assert_eq!("/a//c", remove("/a//b/../c")?);
+    assert_eq!("", remove("")?);
+
+    // relative paths, as the buffer algorithm of the RFC treats them
+    assert_eq!("b", remove("../b")?);
+    assert_eq!("b/", remove("./b/.")?);
+    assert_eq!("", remove("../")?);
+    assert_eq!("/", remove("a/..")?);
+    assert_eq!("/b", remove("a/../b")?);
+    assert_eq!("/a", remove(".//a")?);
+
+    Ok(())
+}
+
+#[test]
+fn test_relativize() -> Result<()> {
+    let base = Uri::parse("http://example.com/site/docs/guide/index.html?v=1#top")?;
+    let examples = vec![
+        ("http://example.com/site/docs/img/a.png", "../img/a.png"),
+        ("http://example.com/site/docs/guide/setup.html", "setup.html"),
+        ("http://example.com/site/docs/guide/", "./"),
+        ("http://example.com/site/docs/guide/index.html?v=1", ""),
+        ("http://example.com/site/docs/guide/index.html?v=1#intro", "#intro"),
+        ("http://example.com/site/docs/guide/index.html?v=2", "?v=2"),
+        ("http://example.com/site/docs/guide/index.html", "index.html"),
+        ("http://example.com/", "/"),
+        ("http://example.com", "//example.com"),
+        ("http://example.com/site/docs/guide/a:b", "./a:b"),
+        ("http://example.com/site/docs/guide//x", ".//x"),
+        ("http://example.com//x", "../../..//x"),
+        ("http://cdn.example.com/site/a.png", "//cdn.example.com/site/a.png"),
+        ("https://example.com/site/a.png", "https://example.com/site/a.png"),
+    ];
+    for (target, expected) in examples {
+        let target = Uri::parse(target)?;
+        let reference = base.relativize(&target).unwrap();
+        assert_eq!(expected, reference.to_string());
+        assert_eq!(target, base.resolve(&reference));
+    }
+
+    let base = Uri::parse("http://a/b/c/d;p?q")?;
+    for &(_, target) in RFC_3986_EXAMPLES {
+        let target = Uri::parse(target)?;
+        let reference = base.relativize(&target).unwrap();
+        assert_eq!(target, base.resolve(&reference), "{}", target);
+    }
+
+    // resolution would remove the dot-segments, unless the reference is empty
+    let target = Uri::parse("http://a/b/../c")?;
+    assert_eq!(None, base.relativize(&target));
+    assert_eq!("", target.relativize(&target).unwrap().to_string());
+
+    let bases = vec![
+        "http://a/b/c/d;p?q",
+        "http://a",
+        "http://a/",
+        "http://u@a:8080/b/c/",
+        "mailto:john@example.com",
+        "urn:isbn:123",
+        "file:///",
+    ];
+    let targets = vec![
+        "http://a/b/c/d;p?q",
+        "http://a/b/c/d;p?q#f",
+        "http://a/b/c/g",
+        "http://a/b/c/",
+        "http://a/b/",
+        "http://a/",
+        "http://a",
+        "http://a?q",
+        "http://a/g/h/i",
+        "http://u@a:8080/b/c/x?y",
+        "http://b/c",
+        "http:g",
+        "mailto:jane@example.com",
+        "urn:isbn:456?x",
+        "file:///etc/hosts",
+    ];
+    for base in &bases {
+        let base = Uri::parse(base)?;
+        for target in &targets {
+            let target = Uri::parse(target)?;
+            let reference = base.relativize(&target).unwrap();
+            assert_eq!(target, base.resolve(&reference), "{} {}", base, target);
+            assert_eq!(reference, UriRef::parse(&reference.to_string())?);
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_normalize() -> Result<()> {
+    let uri = Uri::parse("HTTP://User%3a@WWW.Example.COM/%7euser/./a/../b%2f%41?Q=%7E%3d#F%7e")?;
+    assert_eq!(
+        "http://User%3A@www.example.com/~user/b%2FA?Q=~%3D#F~",
+        uri.normalize().to_string()
+    );
+    assert_eq!(Uri::parse(&uri.normalize().to_string())?, uri.normalize());
+
+    // without an authority "//b" would become one
+    for s in &["foo:/.//b", "foo:/a/..//b", "foo:/%2E//b", "foo:/.//"] {
+        let normalized = Uri::parse(s)?.normalize();
+        assert_eq!(Uri::parse(&normalized.to_string())?, normalized, "{}", s);
+        assert!(normalized.authority().is_none(), "{}", s);
+    }
+    assert_eq!("foo:/.//b", Uri::parse("foo:/.//b")?.normalize().to_string());
+
+    let options = NormalizeOptions {
+        lowercase_scheme: false,
+        ..NormalizeOptions::default()
+    };
+    assert_eq!(
+        "HTTP://User%3A@www.example.com/~user/b%2FA?Q=~%3D#F~",
+        uri.normalize_with(&options).to_string()
+    );
+
+    let options = NormalizeOptions {
+        lowercase_host: false,
+        ..NormalizeOptions::default()
+    };
+    assert_eq!(
+        "http://User%3A@WWW.Example.COM/~user/b%2FA?Q=~%3D#F~",
+        uri.normalize_with(&options).to_string()
+    );
+
+    let options = NormalizeOptions {
+        uppercase_percent_encoding: false,
+        ..NormalizeOptions::default()
+    };
+    assert_eq!(
+        "http://User%3a@www.example.com/~user/b%2fA?Q=~%3d#F~",
+        uri.normalize_with(&options).to_string()
+    );
+
+    let options = NormalizeOptions {
+        decode_unreserved: false,
+        ..NormalizeOptions::default()
+    };
+    assert_eq!(
+        "http://User%3A@www.example.com/%7Euser/b%2F%41?Q=%7E%3D#F%7E",
+        uri.normalize_with(&options).to_string()
+    );
+
+    let options = NormalizeOptions {
+        remove_dot_segments: false,
+        ..NormalizeOptions::default()
+    };
+    assert_eq!(
+        "http://User%3A@www.example.com/~user/./a/../b%2FA?Q=~%3D#F~",
+        uri.normalize_with(&options).to_string()
+    );
+
+    let uri = Uri::parse("http://[FE80::A%25Eth0]/")?;
+    assert_eq!("http://[fe80::a%25Eth0]/", uri.normalize().to_string());
+
+    let uri = Uri::parse("http://%41%62c.example/")?;
+    assert_eq!("http://abc.example/", uri.normalize().to_string());
+
+    Ok(())
+}
+
+#[test]
+fn test_normalize_for_scheme() -> Result<()> {
+    let defaults = SchemeDefaults::default();
+    let normalize = |s: &str| -> Result<String> {
+        Ok(Uri::parse(s)?.normalize_for_scheme(&defaults).to_string())
+    };
+    assert_eq!("http://example.com/", normalize("http://example.com")?);
+    assert_eq!("http://example.com/", normalize("HTTP://Example.com:80/")?);
+    assert_eq!("http://example.com/", normalize("http://example.com:/")?);
+    assert_eq!("http://example.com:443/", normalize("http://example.com:443")?);
+    assert_eq!("https://example.com/?q", normalize("https://example.com:443?q")?);
+    assert_eq!("ftp://example.com", normalize("ftp://example.com:21")?);
+    assert_eq!("foo://example.com", normalize("foo://example.com:")?);
+    assert_eq!("foo://example.com:80", normalize("foo://example.com:80")?);
+    assert_eq!("mailto:a@example.com", normalize("mailto:a@example.com")?);
+
+    let mut defaults = SchemeDefaults::default();
+    defaults.insert(
+        Scheme::parse("foo")?,
+        SchemeDefault {
+            port: Some(8080),
+            empty_path_is_root: true,
+        },
+    );
+    let uri = Uri::parse("FOO://example.com:8080")?;
+    assert_eq!("foo://example.com/", uri.normalize_for_scheme(&defaults).to_string());
+    assert_eq!(
+        "foo://example.com:8080",
+        uri.normalize_for_scheme(&SchemeDefaults::new()).to_string()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_equivalent() -> Result<()> {
+    let equivalent = |a: &str, b: &str, level: Level| -> Result<bool> {
+        Ok(Uri::parse(a)?.equivalent(&Uri::parse(b)?, level))
+    };
+    let levels = [Level::Simple, Level::Syntax, Level::Scheme, Level::Protocol];
+    let examples = vec![
+        ("http://example.com/a", "http://example.com/a", [true, true, true, true]),
+        ("http://example.com/%7Ea", "HTTP://Example.com/~a", [false, true, true, true]),
+        ("http://example.com/a/./b", "http://example.com/a/b", [false, true, true, true]),
+        ("http://example.com:80", "http://example.com/", [false, false, true, true]),
+        ("http://example.com/a/", "http://example.com/a", [false, false, false, true]),
+        ("http://example.com/a#x", "http://example.com/a#y", [false, false, false, true]),
+        ("http://example.com/a", "https://example.com/a", [false, false, false, false]),
+        ("http://example.com/a?x", "http://example.com/a?y", [false, false, false, false]),
+    ];
+    for (a, b, expected) in examples {
+        for (level, expected) in levels.iter().zip(expected.iter()) {
+            assert_eq!(*expected, equivalent(a, b, *level)?, "{} {} {:?}", a, b, level);
+        }
+    }
+
+    let mut visits = std::collections::HashMap::new();
+    for s in &["http://Example.com:80", "http://example.com/", "http://example.com/#top"] {
+        let key = NormalizedUri::new(Uri::parse(s)?, Level::Protocol);
+        *visits.entry(key).or_insert(0) += 1;
+    }
+    assert_eq!(1, visits.len());
+    let key = NormalizedUri::new(Uri::parse("HTTP://example.com")?, Level::Protocol);
+    assert_eq!(Some(&3), visits.get(&key));
+    assert_eq!("HTTP://example.com", key.to_string());
+
+    let a = NormalizedUri::new(Uri::parse("http://example.com/")?, Level::Syntax);
+    let b = NormalizedUri::new(Uri::parse("http://example.com/")?, Level::Scheme);
+    assert!(a != b);
+
+    Ok(())
+}
+
+#[test]
+fn test_iri() -> Result<()> {
+    let iri = Iri::parse("http://user@bücher.example/straße/€?q=ü\u{E000}#frag-ñ")?;
+    assert_eq!(&Scheme::HTTP, iri.scheme());
+    assert_eq!("bücher.example", iri.authority().unwrap().host().to_string());
+    assert_eq!("/straße/€", iri.path().to_string());
+    assert_eq!("q=ü\u{E000}", iri.query().unwrap().to_string());
+    assert_eq!("http://user@bücher.example/straße/€?q=ü\u{E000}#frag-ñ", iri.to_string());
+
+    let uri = iri.to_uri();
+    assert_eq!(
+        "http://user@b%C3%BCcher.example/stra%C3%9Fe/%E2%82%AC?q=%C3%BC%EE%80%80#frag-%C3%B1",
+        uri.to_string()
+    );
+    assert_eq!(Uri::parse(&uri.to_string())?, uri);
+    assert_eq!(iri, uri.to_iri());
+
+    // ASCII, invalid UTF-8, bidi formatting characters and iprivate outside the query stay encoded
+    let uri = Uri::parse("http://example.com/%41%C3%28%E2%80%8E%EE%80%80/%c3%bc")?;
+    assert_eq!("http://example.com/%41%C3%28%E2%80%8E%EE%80%80/ü", uri.to_iri().to_string());
+
+    assert!(Iri::parse("http://example.com/\u{E000}").is_err());
+    assert!(Iri::parse("http://example.com/\u{FFFE}").is_err());
+    assert!(Uri::parse("http://bücher.example/").is_err());
+
+    let iri_ref = IriRef::parse("../ä/ö?ü")?;
+    assert!(iri_ref.is_relative());
+    assert_eq!("../ä/ö", iri_ref.path().to_string());
+    assert_eq!("../%C3%A4/%C3%B6?%C3%BC", iri_ref.to_uri_ref().to_string());
+
+    let iri_ref: IriRef = "ftp://ä.example/".parse()?;
+    assert!(!iri_ref.is_relative());
+    assert_eq!("ftp://%C3%A4.example/", iri_ref.to_uri_ref().to_string());
+
+    Ok(())
+}
+
+#[test]
+fn test_idna() -> Result<()> {
+    let options = IdnaOptions::default();
+    assert_eq!("xn--bcher-kva.example", idna::to_ascii("Bücher.example", &options)?);
+    assert_eq!("bücher.example", idna::to_unicode("xn--bcher-kva.example", &options)?);
+    assert_eq!("xn--zca.de", idna::to_ascii("ß.de", &options)?);
+    let transitional = IdnaOptions {
+        transitional_processing: true,
+        ..IdnaOptions::default()
+    };
+    assert_eq!("ss.de", idna::to_ascii("ß.de", &transitional)?);
+    assert_eq!("xn--mgbh0fb.example", idna::to_ascii("مثال.example", &options)?);
+    assert_eq!("example.com.", idna::to_ascii("example.com.", &options)?);
+
+    // RFC 3492, section 7.1 (A) and (L)
+    assert_eq!("xn--egbpdaj6bu4bxfgehfvwxn", idna::to_ascii("ليهمابتكلموشعربي؟", &options)?);
+    assert_eq!("xn--3b-ww4c5e180e575a65lsy2b", idna::to_ascii("3年B組金八先生", &options)?);
+
+    assert!(idna::to_ascii("a_b.example", &options).is_err());
+    assert!(idna::to_ascii("ab--c.example", &options).is_err());
+    assert!(idna::to_ascii("-abc.example", &options).is_err());
+    assert!(idna::to_ascii("xn--a.example", &options).is_err());
+    assert!(idna::to_ascii("\u{301}a.example", &options).is_err());
+    assert!(idna::to_ascii("a\u{200D}b.example", &options).is_err());
+    assert!(idna::to_ascii("a.example.مثال1", &options).is_ok());
+    assert!(idna::to_ascii("aمثال.example", &options).is_err());
+    assert!(idna::to_ascii(&"a".repeat(64), &options).is_err());
+    assert!(idna::to_ascii("a..b", &options).is_err());
+
+    let uri = Uri::parse("http://b%C3%BCcher.Example/")?;
+    let host = uri.authority().unwrap().host();
+    assert_eq!("xn--bcher-kva.example", host.to_ascii()?.to_string());
+    assert_eq!("bücher.example", host.to_unicode()?.to_string());
+    assert_eq!("bücher.example", host.to_ascii()?.to_unicode()?.to_string());
+
+    let iri = Iri::parse("http://bücher.example/")?;
+    let host = iri.authority().unwrap().host();
+    assert_eq!("xn--bcher-kva.example", host.to_ascii()?.to_string());
+
+    let uri = Uri::parse("http://[::1]/")?;
+    assert_eq!("[::1]", uri.authority().unwrap().host().to_ascii()?.to_string());
+    assert!(Uri::parse("http://%C3%28/")?.authority().unwrap().host().to_ascii().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_uri_builder() -> Result<()> {
+    let uri = UriBuilder::new()
+        .scheme("http")
+        .user("j doe")
+        .password("s:cr@t")
+        .host("bücher.example")
+        .port(8080)
+        .path_segment("a b")
+        .path_segment("c/d")
+        .query_pair("q", "x&y=z")
+        .query_pair("1+1", "2")
+        .fragment("top #1")
+        .build()?;
+    assert_eq!(
+        "http://j%20doe:s:cr%40t@b%C3%BCcher.example:8080/a%20b/c%2Fd?q=x%26y%3Dz&1%2B1=2#top%20%231",
+        uri.to_string()
+    );
+    assert_eq!(Uri::parse(&uri.to_string())?, uri);
+    assert_eq!(Some(8080), uri.authority().unwrap().port());
+
+    let uri = UriBuilder::new().scheme("http").host("[::1]").build()?;
+    assert_eq!(HostKind::Ipv6, uri.authority().unwrap().host().kind());
+    assert_eq!("http://[::1]", uri.to_string());
+    let uri = UriBuilder::new().scheme("http").host("127.0.0.1").path_segment("").build()?;
+    assert_eq!(HostKind::Ipv4, uri.authority().unwrap().host().kind());
+    assert_eq!("http://127.0.0.1/", uri.to_string());
+
+    let uri = UriBuilder::new().scheme("mailto").path_segment("a@example.com").build()?;
+    assert_eq!("mailto:a@example.com", uri.to_string());
+    let uri = UriBuilder::new().scheme("urn").path_segment("isbn:0-486").build()?;
+    assert_eq!("urn:isbn:0-486", uri.to_string());
+
+    let error = |builder: UriBuilder| builder.build().unwrap_err().to_string();
+    assert_eq!("Invalid scheme: missing", error(UriBuilder::new().host("example.com")));
+    assert_eq!("Invalid scheme: '1http' is malformed", error(UriBuilder::new().scheme("1http")));
+    assert_eq!("Invalid host: '[::g]' is malformed", error(UriBuilder::new().scheme("http").host("[::g]")));
+    assert_eq!(
+        "Invalid host: missing but required by the userinfo or port",
+        error(UriBuilder::new().scheme("http").port(80))
+    );
+    assert_eq!(
+        "Invalid path: the first segment must not be empty without a host",
+        error(UriBuilder::new().scheme("file").path_segment("").path_segment("etc"))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_percent() -> Result<()> {
+    let to_string = |chars: Vec<Char>| chars.iter().map(|c| c.to_string()).collect::<String>();
+    assert_eq!("a%20b%2Fc:d@e", to_string(percent::encode(b"a b/c:d@e", percent::PATH_SEGMENT)));
+    assert_eq!("a%20b/c:d@e?", to_string(percent::encode(b"a b/c:d@e?", percent::QUERY)));
+    assert_eq!("a/b?%23", to_string(percent::encode(b"a/b?#", percent::FRAGMENT)));
+    assert_eq!("u:p%40h", to_string(percent::encode(b"u:p@h", percent::USERINFO)));
+    assert_eq!("a%3Ab%25%C3%BC", to_string(percent::encode("a:b%ü".as_bytes(), percent::REG_NAME)));
+    assert!(percent::REG_NAME.encodes(b'%'));
+    assert!(!percent::REG_NAME.encodes(b'!'));
+
+    let uri = Uri::parse("http://example.com/%41%c3%bc%2f%FF")?;
+    let chars = &uri.path().segments[0].0;
+    assert_eq!(&b"A\xC3\xBC/\xFF"[..], &percent::decode(chars)[..]);
+    let iri = Iri::parse("http://example.com/ü%20")?;
+    assert_eq!("ü ".as_bytes(), &percent::decode(&iri.path().segments[0].0)[..]);
+
+    Ok(())
+}
+
+#[test]
+fn test_query_pairs() -> Result<()> {
+    let uri = Uri::parse("http://example.com/?a=1&b=x+y%2Bz;a=%C3%BC&&c&=d&e=%FF&a==")?;
+    let query = uri.query().unwrap();
+    let pairs: Vec<(String, String)> = query
+        .pairs()
+        .map(|(name, value)| (name.into_owned(), value.into_owned()))
+        .collect();
+    let expected = vec![
+        ("a", "1"),
+        ("b", "x y+z"),
+        ("a", "ü"),
+        ("c", ""),
+        ("", "d"),
+        ("e", "\u{FFFD}"),
+        ("a", "="),
+    ];
+    let expected: Vec<(String, String)> = expected
+        .into_iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect();
+    assert_eq!(expected, pairs);
+
+    assert_eq!(Some("1"), query.get("a").as_ref().map(|value| value.as_ref()));
+    assert_eq!(vec!["1", "ü", "="], query.get_all("a"));
+    assert_eq!(None, query.get("x"));
+    assert!(query.get_all("x").is_empty());
+    assert!(query.contains_key("c"));
+    assert!(query.contains_key(""));
+    assert!(!query.contains_key("d"));
+    assert!(matches!(query.get("c"), Some(Cow::Borrowed(""))));
+
+    let uri = Uri::parse("http://example.com/?")?;
+    assert_eq!(0, uri.query().unwrap().pairs().count());
+
+    Ok(())
+}
+
+#[test]
+fn test_query_builder() -> Result<()> {
+    let mut builder = QueryBuilder::new();
+    builder
+        .append("b", "x y")
+        .append("a", "1&2=3;4+5")
+        .append("b", "ü#/?")
+        .append("c", "");
+    assert_eq!("b=x%20y&a=1%262%3D3%3B4%2B5&b=%C3%BC%23/?&c=", builder.build().to_string());
+    builder.space_encoding(SpaceEncoding::Plus);
+    assert_eq!("b=x+y&a=1%262%3D3%3B4%2B5&b=%C3%BC%23/?&c=", builder.build().to_string());
+
+    builder.sort();
+    assert_eq!("a=1%262%3D3%3B4%2B5&b=x+y&b=%C3%BC%23/?&c=", builder.build().to_string());
+    builder.replace("b", "z").replace("d", "4").remove("c");
+    assert_eq!("a=1%262%3D3%3B4%2B5&b=z&d=4", builder.build().to_string());
+    let query = builder.build();
+    assert_eq!(Some("1&2=3;4+5"), query.get("a").as_ref().map(|value| value.as_ref()));
+
+    let mut uri = Uri::parse("http://example.com/?b=2;a=1+1#f")?;
+    uri.query_mut().append("c", "3 3").sort();
+    assert_eq!("http://example.com/?a=1+1&b=2&c=3%203#f", uri.to_string());
+    uri.query_mut().space_encoding(SpaceEncoding::Plus).remove("b").append("d", "4 4");
+    assert_eq!("http://example.com/?a=1+1&c=3%203&d=4+4#f", uri.to_string());
+    uri.query_mut().replace("c", "3");
+    assert_eq!("http://example.com/?a=1+1&c=3&d=4+4#f", uri.to_string());
+    {
+        let mut query = uri.query_mut();
+        query.remove("a");
+        query.remove("c").remove("d");
+    }
+    assert_eq!("http://example.com/#f", uri.to_string());
+
+    // pairs that are not changed stay as they are, and the query is not touched if only read
+    let mut uri = Uri::parse("http://example.com/?x=%FF;flag&&a=1+1")?;
+    assert!(!uri.query_mut().is_empty());
+    assert_eq!("http://example.com/?x=%FF;flag&&a=1+1", uri.to_string());
+    uri.query_mut().append("b", "2");
+    assert_eq!("http://example.com/?x=%FF&flag&a=1+1&b=2", uri.to_string());
+    let mut uri = Uri::parse("http://example.com/?")?;
+    assert!(uri.query_mut().is_empty());
+    assert_eq!("http://example.com/?", uri.to_string());
+
+    Ok(())
+}
+
+#[test]
+fn test_nested_query() -> Result<()> {
+    // '[' and ']' must be percent-encoded in a query
+    let uri = Uri::parse(
+        "http://example.com/?filter%5Btags%5D%5B%5D=a&filter%5Btags%5D%5B%5D=b&page%5Bsize%5D=10&q=x",
+    )?;
+    let value = uri.query().unwrap().parse_nested()?;
+    let tags = value.get("filter").and_then(|filter| filter.get("tags")).unwrap();
+    assert_eq!(
+        &QueryValue::Array(vec![
+            QueryValue::String("a".to_string()),
+            QueryValue::String("b".to_string()),
+        ]),
+        tags
+    );
+    assert_eq!(Some("b"), tags.get("1").and_then(|tag| tag.as_str()));
+    assert_eq!(Some("10"), value.get("page").and_then(|page| page.get("size")).and_then(|size| size.as_str()));
+    assert_eq!(Some("x"), value.get("q").and_then(|q| q.as_str()));
+    assert_eq!(
+        "filter%5Btags%5D%5B%5D=a&filter%5Btags%5D%5B%5D=b&page%5Bsize%5D=10&q=x",
+        value.to_query()?.to_string()
+    );
+    assert_eq!(value, value.to_query()?.parse_nested()?);
+
+    // indices order the elements, which may be maps
+    let query = QueryBuilder::new()
+        .append("a[1][x]", "2")
+        .append("a[0][x]", "1")
+        .append("a[0][y]", "3")
+        .append("b[c", "4")
+        .append("d[e]f", "5")
+        .build();
+    let value = query.parse_nested()?;
+    assert_eq!(Some("1"), value.get("a").and_then(|a| a.get("0")).and_then(|a| a.get("x")).and_then(|x| x.as_str()));
+    assert_eq!(Some("3"), value.get("a").and_then(|a| a.get("0")).and_then(|a| a.get("y")).and_then(|y| y.as_str()));
+    assert_eq!(Some("2"), value.get("a").and_then(|a| a.get("1")).and_then(|a| a.get("x")).and_then(|x| x.as_str()));
+    assert_eq!(Some("4"), value.get("b[c").and_then(|b| b.as_str()));
+    assert_eq!(Some("5"), value.get("d[e]f").and_then(|d| d.as_str()));
+    assert_eq!(value, value.to_query()?.parse_nested()?);
+
+    let parse = |query: &str| {
+        let query = query.replace('[', "%5B").replace(']', "%5D");
+        Uri::parse(&format!("http://example.com/?{}", query))?.query().unwrap().parse_nested()
+    };
+    assert!(parse("a=1&a[b]=2").is_err());
+    assert!(parse("a[b]=1&a[]=2").is_err());
+    assert!(parse("a[]=1&a[b]=2").is_err());
+    assert!(parse("a[b]=1&a=2").is_err());
+    assert!(parse("a[b][c][d][e][f]=1").is_ok());
+    assert!(parse("a[b][c][d][e][f][g]=1").is_err());
+    assert!(parse("a[20]=1").is_ok());
+    assert!(parse("a[21]=1").is_err());
+
+    let options = NestedQueryOptions {
+        max_depth: 1,
+        max_array_index: 1,
+    };
+    let query = QueryBuilder::new().append("a[]", "1").append("a[]", "2").append("a[]", "3").build();
+    assert!(query.parse_nested_with(&options).is_err());
+    let query = QueryBuilder::new().append("a[b][c]", "1").build();
+    assert!(query.parse_nested_with(&options).is_err());
+    assert!(QueryValue::String("a".to_string()).to_query().is_err());
+
+    // WHATWG mode accepts the brackets of browser-style input
+    let raw = "http://example.com/?filter[tags][]=a&filter[tags][]=b";
+    assert!(Uri::parse(raw).is_err());
+    let options = ParseOptions {
+        mode: ParseMode::Whatwg,
+        ..ParseOptions::default()
+    };
+    let value = Uri::parse_with(raw, &options)?.query().unwrap().parse_nested()?;
+    assert_eq!(
+        Some("b"),
+        value.get("filter").and_then(|filter| filter.get("tags")).and_then(|tags| tags.get("1")).and_then(|tag| tag.as_str())
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() -> Result<()> {
+    use serde::de::value::Error as ValueError;
+    use serde::de::value::StrDeserializer;
+    use serde::de::IntoDeserializer;
+    use serde::Deserialize;
+
+    let deserializer: StrDeserializer<ValueError> = "http://example.com/a?b#c".into_deserializer();
+    let uri = Uri::deserialize(deserializer)?;
+    assert_eq!(Uri::parse("http://example.com/a?b#c")?, uri);
+    let deserializer: StrDeserializer<ValueError> = "../a b".into_deserializer();
+    assert!(UriRef::deserialize(deserializer).is_err());
+    let deserializer: StrDeserializer<ValueError> = "/ä".into_deserializer();
+    assert_eq!("/ä", IriRef::deserialize(deserializer)?.to_string());
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    #[serde(rename_all = "lowercase")]
+    enum Order {
+        Asc,
+        Desc,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Search {
+        q: String,
+        page: u32,
+        ratio: f64,
+        exact: bool,
+        limit: Option<u8>,
+        offset: Option<i64>,
+        tag: Vec<String>,
+        id: Vec<u16>,
+        order: Order,
+        redirect: Uri,
+    }
+
+    let uri = Uri::parse(
+        "http://example.com/?q=a+b%26c&page=2&ratio=0.5&exact=true&limit=&tag=x&id=1&tag=y&id=2\
+         &order=desc&redirect=http%3A%2F%2Fexample.org%2F&unknown=1",
+    )?;
+    let search: Search = from_query(uri.query().unwrap())?;
+    assert_eq!(
+        Search {
+            q: "a b&c".to_string(),
+            page: 2,
+            ratio: 0.5,
+            exact: true,
+            limit: None,
+            offset: None,
+            tag: vec!["x".to_string(), "y".to_string()],
+            id: vec![1, 2],
+            order: Order::Desc,
+            redirect: Uri::parse("http://example.org/")?,
+        },
+        search
+    );
+
+    let query = QueryBuilder::new().append("q", "a").append("page", "x").build();
+    let error = from_query::<Search>(&query).unwrap_err();
+    assert_eq!("invalid value: string \"x\", expected u32", error.to_string());
+
+    Ok(())
+}
+
+#[test]
+fn test_path_segments() -> Result<()> {
+    let uri = Uri::parse("http://example.com/org/foo%20bar/1.0/foo-1.0.jar")?;
+    let path = uri.path();
+    assert_eq!(vec!["org", "foo bar", "1.0", "foo-1.0.jar"], path.segments());
+    assert_eq!(Some("foo-1.0.jar".to_string()), path.file_name());
+    assert_eq!(Some("jar".to_string()), path.extension());
+
+    let parent = path.parent().unwrap();
+    assert_eq!("/org/foo%20bar/1.0/", parent.to_string());
+    assert_eq!(vec!["org", "foo bar", "1.0", ""], parent.segments());
+    assert_eq!(None, parent.file_name());
+    assert!(path.starts_with(&parent));
+    assert_eq!("foo-1.0.jar", path.strip_prefix(&parent).unwrap().to_string());
+
+    let base = Uri::parse("http://example.com/org/foo%20bar")?;
+    assert!(path.starts_with(base.path()));
+    assert_eq!("1.0/foo-1.0.jar", path.strip_prefix(base.path()).unwrap().to_string());
+    let other = Uri::parse("http://example.com/org/foo")?;
+    assert!(!path.starts_with(other.path()));
+    assert_eq!(None, path.strip_prefix(other.path()));
+    let root = Uri::parse("http://example.com/")?;
+    assert!(path.starts_with(root.path()));
+    assert!(path.starts_with(path));
+    assert!(path.strip_prefix(path).unwrap().is_empty());
+    let double_slash = Uri::parse("http://example.com/a//b")?;
+    let a = Uri::parse("http://example.com/a")?;
+    let rest = double_slash.path().strip_prefix(a.path()).unwrap();
+    assert_eq!(".//b", rest.to_string());
+    assert_eq!(double_slash, a.resolve(&UriRef::parse(&format!("a/{}", rest))?));
+    let colon = Uri::parse("http://h/a/b:c")?;
+    let a_dir = Uri::parse("http://h/a/")?;
+    let rest = colon.path().strip_prefix(a_dir.path()).unwrap();
+    assert_eq!("./b:c", rest.to_string());
+    assert_eq!(colon, a_dir.resolve(&UriRef::parse(&rest.to_string())?));
+
+    let mut path = path.clone();
+    assert_eq!(Some("foo-1.0.jar".to_string()), path.pop());
+    assert_eq!(Some("1.0".to_string()), path.pop());
+    path.push_segment("2.0");
+    path.push_segment("foo 2.0/x.jar");
+    assert_eq!("/org/foo%20bar/2.0/foo%202.0%2Fx.jar", path.to_string());
+    assert_eq!(Some("foo 2.0/x.jar".to_string()), path.file_name());
+    while path.pop().is_some() {}
+    assert_eq!("/", path.to_string());
+    assert_eq!(None, path.parent());
+    assert_eq!(vec![""], path.segments());
+
+    let uri_ref = UriRef::parse("a/.bashrc")?;
+    assert_eq!(Some(".bashrc".to_string()), uri_ref.path().file_name());
+    assert_eq!(None, uri_ref.path().extension());
+    let parent = uri_ref.path().parent().unwrap();
+    assert_eq!("a/", parent.to_string());
+    assert_eq!("", parent.parent().unwrap().to_string());
+    assert_eq!(None, parent.parent().unwrap().parent());
+    assert_eq!(None, UriRef::parse("a/..")?.path().file_name());
+
+    let mut path = UriRef::parse("")?.path().clone();
+    assert!(path.segments().is_empty());
+    path.push_segment("a");
+    assert_eq!("a", path.to_string());
+    path.push_segment("..");
+    path.push_segment(".");
+    assert_eq!("a/%2E%2E/%2E", path.to_string());
+    assert_eq!(vec!["a", "..", "."], path.segments());
+    assert_eq!(path, path.remove_dot_segments());
+    path.push_segment("");
+    path.push_segment("b");
+    assert_eq!("a/%2E%2E/%2E//b", path.to_string());
+
+    Ok(())
+}
+
+#[test]
+fn test_matrix_params() -> Result<()> {
+    let uri = Uri::parse("http://example.com/cars;color=red;year=2012;used/wheels;n=%34")?;
+    let cars = uri.path().segment(0).unwrap();
+    assert_eq!("cars", cars.name());
+    let params = vec![
+        ("color".to_string(), "red".to_string()),
+        ("year".to_string(), "2012".to_string()),
+        ("used".to_string(), "".to_string()),
+    ];
+    assert_eq!(params, cars.params());
+    let wheels = uri.path().segment(1).unwrap();
+    assert_eq!("wheels", wheels.name());
+    assert_eq!(vec![("n".to_string(), "4".to_string())], wheels.params());
+    assert_eq!(None, uri.path().segment(2));
+    assert_eq!(vec!["cars;color=red;year=2012;used", "wheels;n=4"], uri.path().segments());
+
+    let mut uri = uri.clone();
+    {
+        let mut path = uri.path_mut();
+        let cars = path.segment_mut(0).unwrap();
+        cars.remove_param("year");
+        cars.push_param("a;b", "c=d");
+    }
+    assert_eq!("http://example.com/cars;color=red;used;a%3Bb=c%3Dd/wheels;n=%34", uri.to_string());
+    let params = uri.path().segment(0).unwrap().params();
+    assert_eq!(("a;b".to_string(), "c=d".to_string()), params[2]);
+
+    // the path is made to fit the authority again
+    let mut uri = Uri::parse("http://example.com")?;
+    uri.path_mut().push_segment("a");
+    assert_eq!("http://example.com/a", uri.to_string());
+    let mut uri = Uri::parse("sc:/;x/b")?;
+    uri.path_mut().segment_mut(0).unwrap().remove_param("x");
+    assert_eq!("sc:/.//b", uri.to_string());
+    assert_eq!(uri, Uri::parse(&uri.to_string())?);
+    let mut uri = Uri::parse("foo:")?;
+    uri.path_mut().push_segment("");
+    uri.path_mut().push_segment("x");
+    assert_eq!("foo:.//x", uri.to_string());
+    assert_eq!(uri, Uri::parse(&uri.to_string())?);
+    let mut uri = Uri::parse("foo:/")?;
+    uri.path_mut().push_segment("");
+    assert_eq!("foo:/.//", uri.to_string());
+    uri.path_mut().push_segment("x");
+    assert_eq!("foo:/.//x", uri.to_string());
+    assert_eq!(uri, Uri::parse(&uri.to_string())?);
+
+    let uri = UriBuilder::new()
+        .scheme("http")
+        .host("example.com")
+        .path_segment("cars")
+        .matrix_param("color", "red")
+        .matrix_param("year", "2012")
+        .matrix_param("color", "blue")
+        .remove_matrix_param("color")
+        .path_segment("wheels")
+        .build()?;
+    assert_eq!("http://example.com/cars;year=2012/wheels", uri.to_string());
+    let builder = UriBuilder::new().scheme("http").host("example.com").matrix_param("a", "1");
+    assert!(builder.build().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_uri_setters() -> Result<()> {
+    let mut uri = Uri::parse("http://example.com/a?q#f")?;
+    uri.set_scheme("https")?;
+    uri.set_host(Some("[::1]"))?;
+    uri.set_port(Some(8443))?;
+    uri.set_path("/b/c")?;
+    uri.set_query(Some("x=1&y=2"))?;
+    uri.set_fragment(None)?;
+    assert_eq!("https://[::1]:8443/b/c?x=1&y=2", uri.to_string());
+    uri.set_path("")?;
+    uri.set_query(None)?;
+    assert_eq!("https://[::1]:8443", uri.to_string());
+    uri.set_path("//a")?;
+    assert_eq!("https://[::1]:8443//a", uri.to_string());
+
+    assert!(uri.set_scheme("1http").is_err());
+    assert!(uri.set_host(Some("a b")).is_err());
+    assert!(uri.set_path("a/b").is_err());
+    assert!(uri.set_path("/a?b").is_err());
+    assert!(uri.set_query(Some("a#b")).is_err());
+    assert!(uri.set_fragment(Some("a#b")).is_err());
+    assert!(uri.set_host(None).is_err());
+    assert_eq!("https://[::1]:8443//a", uri.to_string());
+
+    let mut uri = Uri::parse("mailto:a@example.com")?;
+    assert!(uri.set_host(Some("example.com")).is_err());
+    assert!(uri.set_port(Some(25)).is_err());
+    assert!(uri.set_path("//a").is_err());
+    uri.set_path("b@example.com")?;
+    uri.set_fragment(Some("top"))?;
+    assert_eq!("mailto:b@example.com#top", uri.to_string());
+
+    let mut uri = Uri::parse("file://host/etc/hosts")?;
+    uri.set_host(None)?;
+    assert_eq!("file:/etc/hosts", uri.to_string());
+    uri.set_host(Some(""))?;
+    assert_eq!("file:///etc/hosts", uri.to_string());
+
+    Ok(())
+}
+
+#[test]
+fn test_whatwg() -> Result<()> {
+    let options = ParseOptions {
+        mode: ParseMode::Whatwg,
+        ..ParseOptions::default()
+    };
+    let parse = |s: &str| Uri::parse_with(s, &options);
+
+    let uri = parse(" HTTP://user@EXAMPLE.com:80\\a\\..\\b/\t./c?d e#f`g\n")?;
+    assert_eq!("http://user@example.com/b/c?d%20e#f%60g", uri.to_string());
+    assert_eq!(None, uri.authority().unwrap().port());
+
+    assert_eq!("http://192.168.0.1/", parse("http://0xC0.0250.1")?.to_string());
+    assert_eq!("http://0.0.0.255/", parse("http://255")?.to_string());
+    assert_eq!("http://[::ffff:c000:280]/", parse("http://[::FFFF:192.0.2.128]")?.to_string());
+    assert_eq!("https://xn--bcher-kva.example/", parse("https://Bücher.example")?.to_string());
+    assert_eq!("file:///C:/x", parse("file:C|\\x")?.to_string());
+    assert_eq!("sc:/.//p", parse("sc:/.//p")?.to_string());
+    assert_eq!("mailto:a%20b", parse("mailto:a b")?.to_string());
+    assert_eq!("sc://%25x/a%5Bb%5D?%7B%7D#a%23b", parse("sc://%x/a[b]?{}#a#b")?.to_string());
+
+    assert!(parse("http://").is_err());
+    assert!(parse("http://1.2.3.256").is_err());
+    assert!(parse("http://a:65536").is_err());
+    assert!(parse("//example.com").is_err());
+
+    let base = parse("http://example.com/a/b?c")?;
+    let resolve = |s: &str| Uri::parse_with_base(s, &base, &options);
+    assert_eq!("http://example.com/a/d", resolve("d")?.to_string());
+    assert_eq!("http://example.com/x", resolve("\\x")?.to_string());
+    assert_eq!("http://example.com/a/other", resolve("http:other")?.to_string());
+    assert_eq!("http://example.com/a/b?c#f", resolve("#f")?.to_string());
+
+    Ok(())
+}
+
+// The URL parsing tests of the web platform tests, see testdata/urltestdata.json
+#[test]
+fn test_whatwg_url_test_data() -> Result<()> {
+    let options = ParseOptions {
+        mode: ParseMode::Whatwg,
+        ..ParseOptions::default()
+    };
+    let data = include_str!("testdata/urltestdata.json");
+    let tests = match Json::parse(&mut data.chars().peekable()) {
+        Json::Array(tests) => tests,
+        _ => panic!("urltestdata.json is not an array"),
+    };
+
+    for test in tests.iter().filter(|test| test.get("input").is_some()) {
+        let input = test.get("input").and_then(Json::as_str).unwrap();
+        let result = match test.get("base").and_then(Json::as_str) {
+            Some(base) => Uri::parse_with(base, &options)
+                .and_then(|base| Uri::parse_with_base(input, &base, &options)),
+            None => Uri::parse_with(input, &options),
+        };
+        if test.get("failure").is_some() {
+            assert!(result.is_err(), "{:?} should fail", input);
+            continue;
+        }
+
+        let uri = match result {
+            Ok(uri) => uri,
+            Err(e) => panic!("{:?} failed: {}", input, e),
+        };
+        // what RFC 3986 does not allow in a component is percent-encoded in addition
+        let expected = |key: &str| test.get(key).and_then(Json::as_str).unwrap();
+        let encoded = |key: &str, set: percent::EncodeSet| to_rfc_3986(expected(key), set);
+        // search and hash start with '?' and '#' unless they are empty
+        let encoded_after = |key: &str, set: percent::EncodeSet| {
+            let value = expected(key);
+            match value.is_empty() {
+                true => String::new(),
+                false => format!("{}{}", &value[..1], to_rfc_3986(&value[1..], set)),
+            }
+        };
+        assert_eq!(href_to_rfc_3986(expected("href")), uri.to_string(), "{:?}", input);
+        assert_eq!(uri, Uri::parse(&uri.to_string())?, "{:?}", input);
+        assert_eq!(expected("protocol"), format!("{}:", uri.scheme()));
+
+        let (userinfo, hostname, port) = match uri.authority() {
+            Some(authority) => (
+                authority.userinfo().map(|userinfo| userinfo.to_string()),
+                authority.host().to_string(),
+                authority.port().map(|port| port.to_string()),
+            ),
+            None => (None, String::new(), None),
+        };
+        let userinfo = userinfo.unwrap_or_default();
+        let mut userinfo = userinfo.splitn(2, ':');
+        assert_eq!(encoded("username", percent::USERINFO), userinfo.next().unwrap_or(""));
+        assert_eq!(encoded("password", percent::USERINFO), userinfo.next().unwrap_or(""));
+        assert_eq!(host_to_rfc_3986(expected("hostname")), hostname);
+        assert_eq!(expected("port"), port.unwrap_or_default());
+
+        let path = uri.path().to_string();
+        let pathname = match uri.authority().is_none() && path.starts_with("/.//") {
+            true => &path[2..],
+            false => &path[..],
+        };
+        assert_eq!(path_to_rfc_3986(expected("pathname")), pathname, "{:?}", input);
+        let search = match uri.query() {
+            Some(query) if !query.to_string().is_empty() => format!("?{}", query),
+            _ => String::new(),
+        };
+        assert_eq!(encoded_after("search", percent::QUERY), search, "{:?}", input);
+        let hash = match uri.fragment() {
+            Some(fragment) if !fragment.to_string().is_empty() => format!("#{}", fragment),
+            _ => String::new(),
+        };
+        assert_eq!(encoded_after("hash", percent::FRAGMENT), hash, "{:?}", input);
+    }
+    Ok(())
+}
+
+// Percent-encodes what `set` does not allow, except for "%" followed by two hex digits
+fn to_rfc_3986(s: &str, set: percent::EncodeSet) -> String {
+    let bytes = s.as_bytes();
+    let mut encoded = String::new();
+    for (i, &b) in bytes.iter().enumerate() {
+        let pct_encoded = b == b'%'
+            && bytes.len() > i + 2
+            && bytes[i + 1].is_ascii_hexdigit()
+            && bytes[i + 2].is_ascii_hexdigit();
+        match set.encodes(b) && !pct_encoded {
+            true => encoded.push_str(&Char::pct_encoded(b).to_string()),
+            false => encoded.push(b as char),
+        }
+    }
+    encoded
+}
+
+fn host_to_rfc_3986(host: &str) -> String {
+    match host.starts_with('[') {
+        true => host.to_string(),
+        false => to_rfc_3986(host, percent::REG_NAME),
+    }
+}
+
+fn path_to_rfc_3986(path: &str) -> String {
+    let segments: Vec<String> = path
+        .split('/')
+        .map(|segment| to_rfc_3986(segment, percent::PATH_SEGMENT))
+        .collect();
+    segments.join("/")
+}
+
+// Encodes the components of the href one by one, as `Uri` does for the URL record
+fn href_to_rfc_3986(href: &str) -> String {
+    let (href, fragment) = match href.find('#') {
+        Some(hash) => (&href[..hash], Some(&href[hash + 1..])),
+        None => (href, None),
+    };
+    let (href, query) = match href.find('?') {
+        Some(question_mark) => (&href[..question_mark], Some(&href[question_mark + 1..])),
+        None => (href, None),
+    };
+    let colon = href.find(':').unwrap();
+    let mut encoded = href[..colon + 1].to_string();
+    let mut path = &href[colon + 1..];
+    if path.starts_with("//") {
+        let end = path[2..].find('/').map_or(path.len(), |slash| slash + 2);
+        let (userinfo, host_port) = match path[2..end].rfind('@') {
+            Some(at) => (Some(&path[2..at + 2]), &path[at + 3..end]),
+            None => (None, &path[2..end]),
+        };
+        let (host, port) = match host_port.rfind(':') {
+            Some(colon) if !host_port[colon..].contains(']') => {
+                (&host_port[..colon], Some(&host_port[colon..]))
+            }
+            _ => (host_port, None),
+        };
+        encoded.push_str("//");
+        if let Some(userinfo) = userinfo {
+            encoded.push_str(&to_rfc_3986(userinfo, percent::USERINFO));
+            encoded.push('@');
+        }
+        encoded.push_str(&host_to_rfc_3986(host));
+        encoded.push_str(port.unwrap_or(""));
+        path = &path[end..];
+    }
+    encoded.push_str(&path_to_rfc_3986(path));
+    if let Some(query) = query {
+        encoded.push('?');
+        encoded.push_str(&to_rfc_3986(query, percent::QUERY));
+    }
+    if let Some(fragment) = fragment {
+        encoded.push('#');
+        encoded.push_str(&to_rfc_3986(fragment, percent::FRAGMENT));
+    }
+    encoded
+}
+
+// Just enough JSON for the test data, numbers and literals are skipped
+enum Json {
+    Literal,
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(members) => members.iter().find(|m| m.0 == key).map(|m| &m.1),
+            _ => None,
+        }
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            Json::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    fn parse(chars: &mut std::iter::Peekable<std::str::Chars>) -> Json {
+        while chars.peek().map_or(false, |c| c.is_whitespace()) {
+            chars.next();
+        }
+        let json = match chars.next() {
+            Some('"') => Json::String(Json::parse_string(chars)),
+            Some('[') => {
+                let mut elements: Vec<Json> = Vec::new();
+                while chars.peek() != Some(&']') {
+                    elements.push(Json::parse(chars));
+                    Json::skip(chars, ',');
+                }
+                chars.next();
+                Json::Array(elements)
+            }
+            Some('{') => {
+                let mut members: Vec<(String, Json)> = Vec::new();
+                loop {
+                    Json::skip(chars, ' ');
+                    if chars.next() != Some('"') {
+                        break;
+                    }
+                    let key = Json::parse_string(chars);
+                    Json::skip(chars, ':');
+                    members.push((key, Json::parse(chars)));
+                    Json::skip(chars, ',');
+                }
+                Json::Object(members)
+            }
+            Some(_) => {
+                while chars.peek().map_or(false, |c| c.is_alphanumeric() || "+-.".contains(*c)) {
+                    chars.next();
+                }
+                Json::Literal
+            }
+            None => panic!("Unexpected end of JSON."),
+        };
+        json
+    }
+
+    fn parse_string(chars: &mut std::iter::Peekable<std::str::Chars>) -> String {
+        let mut s = String::new();
+        let mut high_surrogate: Option<u32> = None;
+        loop {
+            let c = match chars.next() {
+                Some('"') => break,
+                Some('\\') => match chars.next() {
+                    Some('u') => {
+                        let hex: String = chars.by_ref().take(4).collect();
+                        let unit = u32::from_str_radix(&hex, 16).unwrap();
+                        match (high_surrogate.take(), unit) {
+                            (None, 0xD800..=0xDBFF) => {
+                                high_surrogate = Some(unit);
+                                continue;
+                            }
+                            (Some(high), 0xDC00..=0xDFFF) => {
+                                0x10000 + ((high - 0xD800) << 10) + (unit - 0xDC00)
+                            }
+                            (_, unit) => unit,
+                        }
+                    }
+                    Some('n') => '\n' as u32,
+                    Some('r') => '\r' as u32,
+                    Some('t') => '\t' as u32,
+                    Some('b') => 0x08,
+                    Some('f') => 0x0C,
+                    Some(c) => c as u32,
+                    None => panic!("Unexpected end of JSON."),
+                },
+                Some(c) => c as u32,
+                None => panic!("Unexpected end of JSON."),
+            };
+            // lone surrogates become U+FFFD, as in the conversion to a USVString
+            s.push(std::char::from_u32(c).unwrap_or('\u{FFFD}'));
+        }
+        s
+    }
+
+    fn skip(chars: &mut std::iter::Peekable<std::str::Chars>, c: char) {
+        while chars.peek().map_or(false, |&next| next == c || next.is_whitespace()) {
+            chars.next();
+        }
+    }
+}
@@ -0,0 +1,1117 @@
+use super::char_buf::new_char_buf;
+use super::idna;
+use super::parse_component;
+use super::parse_ipv6_address;
+use super::percent;
+use super::percent::EncodeSet;
+use super::Authority;
+use super::Char;
+use super::DecOctet;
+use super::Fragment;
+use super::Host;
+use super::IdnaOptions;
+use super::Ipv4address;
+use super::Path;
+use super::Port;
+use super::Query;
+use super::RegName;
+use super::Scheme;
+use super::Segment;
+use super::Uri;
+use super::UriError;
+use super::Userinfo;
+use common_failures::prelude::*;
+use std::borrow::Cow;
+use std::net::Ipv4Addr;
+
+// The basic URL parser of the WHATWG URL Standard, https://url.spec.whatwg.org/, without state
+// overrides. The state machine works on a URL record as the standard describes it, which is then
+// turned into a `Uri`. A base `Uri` is turned into a URL record the same way.
+//
+// Validation errors that do not make the parser fail are not reported.
+pub fn parse(input: &str, base: Option<&Uri>) -> Result<Uri> {
+    let base = base.map(Url::from_uri);
+    Ok(parse_url(input, base.as_ref())?.into_uri())
+}
+
+struct Url {
+    scheme: String,
+    username: String,
+    password: String,
+    host: Option<UrlHost>,
+    port: Option<u16>,
+    path: UrlPath,
+    query: Option<String>,
+    fragment: Option<String>,
+}
+
+#[derive(Clone)]
+enum UrlHost {
+    Domain(String),
+    Ipv4(u32),
+    Ipv6([u16; 8]),
+    Opaque(String),
+    Empty,
+}
+
+#[derive(Clone)]
+enum UrlPath {
+    Opaque(String),
+    List(Vec<String>),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum State {
+    SchemeStart,
+    Scheme,
+    NoScheme,
+    SpecialRelativeOrAuthority,
+    PathOrAuthority,
+    Relative,
+    RelativeSlash,
+    SpecialAuthoritySlashes,
+    SpecialAuthorityIgnoreSlashes,
+    Authority,
+    Host,
+    Port,
+    File,
+    FileSlash,
+    FileHost,
+    PathStart,
+    Path,
+    OpaquePath,
+    Query,
+    Fragment,
+}
+
+impl Url {
+    fn is_special(&self) -> bool {
+        is_special(&self.scheme)
+    }
+
+    fn path_list(&mut self) -> &mut Vec<String> {
+        match &mut self.path {
+            UrlPath::List(segments) => segments,
+            UrlPath::Opaque(_) => unreachable!("the path states only run for list paths"),
+        }
+    }
+
+    fn shorten_path(&mut self) {
+        let is_file = self.scheme == "file";
+        let path = self.path_list();
+        if is_file && path.len() == 1 && is_normalized_windows_drive_letter(&path[0]) {
+            return;
+        }
+        path.pop();
+    }
+
+    fn from_uri(uri: &Uri) -> Url {
+        let scheme = uri.scheme.as_str().to_ascii_lowercase();
+        let mut username = String::new();
+        let mut password = String::new();
+        let mut host = None;
+        let mut port = None;
+        if let Some(authority) = &uri.authority {
+            if let Some(userinfo) = &authority.userinfo {
+                let userinfo = userinfo.to_string();
+                match userinfo.find(':') {
+                    Some(i) => {
+                        username = userinfo[..i].to_string();
+                        password = userinfo[i + 1..].to_string();
+                    }
+                    None => username = userinfo,
+                }
+            }
+            host = Some(match &authority.host {
+                Host::RegName(reg_name) if reg_name.0.is_empty() => UrlHost::Empty,
+                Host::RegName(reg_name) if is_special(&scheme) => {
+                    UrlHost::Domain(reg_name.to_string())
+                }
+                Host::Ipv4address(ipv4_address) => {
+                    UrlHost::Ipv4(u32::from(ipv4_address.to_ipv4_addr()))
+                }
+                Host::Ipv6address(ipv6_address, _) => UrlHost::Ipv6(ipv6_address.segments()),
+                host => UrlHost::Opaque(host.to_string()),
+            });
+            port = authority.port();
+        }
+
+        let path = if !uri.path.absolute {
+            match host {
+                Some(_) => UrlPath::List(Vec::new()),
+                None => UrlPath::Opaque(uri.path.to_string()),
+            }
+        } else {
+            let mut segments: Vec<String> = uri
+                .path
+                .segments
+                .iter()
+                .map(|segment| segment.to_string())
+                .collect();
+            if host.is_none() && segments.len() > 1 && segments[0] == "." && segments[1].is_empty()
+            {
+                segments.remove(0);
+            }
+            UrlPath::List(segments)
+        };
+
+        Url {
+            scheme,
+            username,
+            password,
+            host,
+            port,
+            path,
+            query: uri.query.as_ref().map(|query| query.to_string()),
+            fragment: uri.fragment.as_ref().map(|fragment| fragment.to_string()),
+        }
+    }
+
+    fn into_uri(self) -> Uri {
+        let path = match self.path {
+            UrlPath::Opaque(path) => Path {
+                segments: path
+                    .split('/')
+                    .map(|s| Segment(encode(s, percent::PATH_SEGMENT)))
+                    .collect(),
+                absolute: false,
+            },
+            UrlPath::List(ref segments) if segments.is_empty() => Path::empty(),
+            UrlPath::List(segments) => {
+                let mut segments: Vec<Segment> = segments
+                    .iter()
+                    .map(|s| Segment(encode(s, percent::PATH_SEGMENT)))
+                    .collect();
+                // The serializer's "/." keeps a path starting with "//" from becoming the authority
+                if self.host.is_none() && segments.len() > 1 && segments[0].0.is_empty() {
+                    segments.insert(0, Segment(vec![Char::Ascii(b'.')]));
+                }
+                Path {
+                    segments,
+                    absolute: true,
+                }
+            }
+        };
+
+        let userinfo = match (self.username.is_empty(), self.password.is_empty()) {
+            (true, true) => None,
+            (_, true) => Some(Userinfo(encode(&self.username, percent::USERINFO))),
+            (_, false) => Some(Userinfo(encode(
+                &format!("{}:{}", self.username, self.password),
+                percent::USERINFO,
+            ))),
+        };
+        let port = self.port;
+        let authority = self.host.map(|host| Authority {
+            userinfo,
+            host: host.into_host(),
+            port: port.map(|port| Port(to_chars(&port.to_string()))),
+        });
+
+        Uri {
+            scheme: Scheme(Cow::Owned(self.scheme)),
+            authority,
+            path,
+            query: self
+                .query
+                .map(|query| Query(encode(&query, percent::QUERY))),
+            fragment: self
+                .fragment
+                .map(|fragment| Fragment(encode(&fragment, percent::FRAGMENT))),
+        }
+    }
+}
+
+impl UrlHost {
+    fn into_host(self) -> Host {
+        match self {
+            UrlHost::Domain(name) | UrlHost::Opaque(name) => {
+                let reg_name = RegName(encode(&name, percent::REG_NAME));
+                // an opaque host like "1.2.3.4" is an IPv4address to RFC 3986
+                match parse_component(&reg_name.to_string(), super::parse_host) {
+                    Ok(host) => host,
+                    Err(_) => Host::RegName(reg_name),
+                }
+            }
+            UrlHost::Empty => Host::RegName(RegName(Vec::new())),
+            UrlHost::Ipv4(address) => {
+                let octets = Ipv4Addr::from(address).octets();
+                let dec_octet = |octet: u8| DecOctet(to_chars(&octet.to_string()));
+                Host::Ipv4address(Ipv4address(
+                    dec_octet(octets[0]),
+                    dec_octet(octets[1]),
+                    dec_octet(octets[2]),
+                    dec_octet(octets[3]),
+                ))
+            }
+            UrlHost::Ipv6(address) => {
+                let serialized = serialize_ipv6(&address);
+                let mut tb = new_char_buf(serialized.as_bytes());
+                match parse_ipv6_address(&mut tb) {
+                    Ok(Some(ipv6_address)) => Host::Ipv6address(ipv6_address, None),
+                    _ => unreachable!("serialized IPv6 addresses are valid IPv6address"),
+                }
+            }
+        }
+    }
+}
+
+// Reads "%" followed by two hex digits as a percent-encoded octet and any other byte as is.
+fn to_chars(s: &str) -> Vec<Char> {
+    let bytes = s.as_bytes();
+    let mut chars: Vec<Char> = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%'
+            && i + 2 < bytes.len()
+            && bytes[i + 1].is_ascii_hexdigit()
+            && bytes[i + 2].is_ascii_hexdigit()
+        {
+            chars.push(Char::PctEncoded(bytes[i + 1], bytes[i + 2]));
+            i += 3;
+        } else {
+            chars.push(Char::Ascii(bytes[i]));
+            i += 1;
+        }
+    }
+    chars
+}
+
+// Like `to_chars`, but also percent-encodes what `set` does not allow, such as the space the URL
+// Standard leaves in an opaque path or the '#' in a fragment
+fn encode(s: &str, set: EncodeSet) -> Vec<Char> {
+    to_chars(s)
+        .into_iter()
+        .map(|c| match c {
+            Char::Ascii(b) if set.encodes(b) => Char::pct_encoded(b),
+            c => c,
+        })
+        .collect()
+}
+
+fn default_port(scheme: &str) -> Option<u16> {
+    match scheme {
+        "ftp" => Some(21),
+        "http" | "ws" => Some(80),
+        "https" | "wss" => Some(443),
+        _ => None,
+    }
+}
+
+fn is_special(scheme: &str) -> bool {
+    scheme == "file" || default_port(scheme).is_some()
+}
+
+fn at(input: &[char], pointer: isize) -> Option<char> {
+    match pointer >= 0 && (pointer as usize) < input.len() {
+        true => Some(input[pointer as usize]),
+        false => None,
+    }
+}
+
+// The code points that end the authority, host and port states
+fn is_delimiter(c: Option<char>, special: bool) -> bool {
+    match c {
+        None | Some('/') | Some('?') | Some('#') => true,
+        Some('\\') => special,
+        _ => false,
+    }
+}
+
+fn parse_url(input: &str, base: Option<&Url>) -> Result<Url> {
+    let input: Vec<char> = input
+        .trim_matches(|c: char| c <= ' ')
+        .chars()
+        .filter(|&c| c != '\t' && c != '\n' && c != '\r')
+        .collect();
+
+    let mut url = Url {
+        scheme: String::new(),
+        username: String::new(),
+        password: String::new(),
+        host: None,
+        port: None,
+        path: UrlPath::List(Vec::new()),
+        query: None,
+        fragment: None,
+    };
+    let mut state = State::SchemeStart;
+    let mut buffer = String::new();
+    let mut at_sign_seen = false;
+    let mut inside_brackets = false;
+    let mut password_token_seen = false;
+    let mut pointer: isize = 0;
+    let len = input.len() as isize;
+    loop {
+        let c = at(&input, pointer);
+        match state {
+            State::SchemeStart => match c {
+                Some(c) if c.is_ascii_alphabetic() => {
+                    buffer.push(c.to_ascii_lowercase());
+                    state = State::Scheme;
+                }
+                _ => {
+                    state = State::NoScheme;
+                    pointer -= 1;
+                }
+            },
+            State::Scheme => match c {
+                Some(c) if c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.' => {
+                    buffer.push(c.to_ascii_lowercase());
+                }
+                Some(':') => {
+                    url.scheme = std::mem::take(&mut buffer);
+                    let base_has_scheme = match base {
+                        Some(base) => base.scheme == url.scheme,
+                        None => false,
+                    };
+                    if url.scheme == "file" {
+                        state = State::File;
+                    } else if url.is_special() && base_has_scheme {
+                        state = State::SpecialRelativeOrAuthority;
+                    } else if url.is_special() {
+                        state = State::SpecialAuthoritySlashes;
+                    } else if at(&input, pointer + 1) == Some('/') {
+                        state = State::PathOrAuthority;
+                        pointer += 1;
+                    } else {
+                        url.path = UrlPath::Opaque(String::new());
+                        state = State::OpaquePath;
+                    }
+                }
+                _ => {
+                    buffer.clear();
+                    state = State::NoScheme;
+                    pointer = -1;
+                }
+            },
+            State::NoScheme => {
+                let base = match base {
+                    Some(base) => base,
+                    None => return Err(format_err!("Missing scheme.")),
+                };
+                match (&base.path, c) {
+                    (UrlPath::Opaque(_), Some('#')) => {
+                        url.scheme = base.scheme.clone();
+                        url.path = base.path.clone();
+                        url.query = base.query.clone();
+                        url.fragment = Some(String::new());
+                        state = State::Fragment;
+                    }
+                    (UrlPath::Opaque(_), _) => {
+                        return Err(format_err!("Base URL with an opaque path."));
+                    }
+                    _ if base.scheme != "file" => {
+                        state = State::Relative;
+                        pointer -= 1;
+                    }
+                    _ => {
+                        state = State::File;
+                        pointer -= 1;
+                    }
+                }
+            }
+            State::SpecialRelativeOrAuthority => {
+                if c == Some('/') && at(&input, pointer + 1) == Some('/') {
+                    state = State::SpecialAuthorityIgnoreSlashes;
+                    pointer += 1;
+                } else {
+                    state = State::Relative;
+                    pointer -= 1;
+                }
+            }
+            State::PathOrAuthority => {
+                if c == Some('/') {
+                    state = State::Authority;
+                } else {
+                    state = State::Path;
+                    pointer -= 1;
+                }
+            }
+            State::Relative => {
+                let base = base.expect("the relative state requires a base");
+                url.scheme = base.scheme.clone();
+                match c {
+                    Some('/') => state = State::RelativeSlash,
+                    Some('\\') if url.is_special() => state = State::RelativeSlash,
+                    _ => {
+                        url.username = base.username.clone();
+                        url.password = base.password.clone();
+                        url.host = base.host.clone();
+                        url.port = base.port;
+                        url.path = base.path.clone();
+                        url.query = base.query.clone();
+                        match c {
+                            Some('?') => {
+                                url.query = Some(String::new());
+                                state = State::Query;
+                            }
+                            Some('#') => {
+                                url.fragment = Some(String::new());
+                                state = State::Fragment;
+                            }
+                            Some(_) => {
+                                url.query = None;
+                                url.shorten_path();
+                                state = State::Path;
+                                pointer -= 1;
+                            }
+                            None => {}
+                        }
+                    }
+                }
+            }
+            State::RelativeSlash => match c {
+                Some('/') | Some('\\') if url.is_special() => {
+                    state = State::SpecialAuthorityIgnoreSlashes;
+                }
+                Some('/') => state = State::Authority,
+                _ => {
+                    let base = base.expect("the relative slash state requires a base");
+                    url.username = base.username.clone();
+                    url.password = base.password.clone();
+                    url.host = base.host.clone();
+                    url.port = base.port;
+                    state = State::Path;
+                    pointer -= 1;
+                }
+            },
+            State::SpecialAuthoritySlashes => {
+                if c == Some('/') && at(&input, pointer + 1) == Some('/') {
+                    pointer += 1;
+                } else {
+                    pointer -= 1;
+                }
+                state = State::SpecialAuthorityIgnoreSlashes;
+            }
+            State::SpecialAuthorityIgnoreSlashes => match c {
+                Some('/') | Some('\\') => {}
+                _ => {
+                    state = State::Authority;
+                    pointer -= 1;
+                }
+            },
+            State::Authority => {
+                if c == Some('@') {
+                    if at_sign_seen {
+                        buffer.insert_str(0, "%40");
+                    }
+                    at_sign_seen = true;
+                    for c in buffer.chars() {
+                        if c == ':' && !password_token_seen {
+                            password_token_seen = true;
+                            continue;
+                        }
+                        match password_token_seen {
+                            true => percent_encode(c, in_userinfo_set, &mut url.password),
+                            false => percent_encode(c, in_userinfo_set, &mut url.username),
+                        }
+                    }
+                    buffer.clear();
+                } else if is_delimiter(c, url.is_special()) {
+                    if at_sign_seen && buffer.is_empty() {
+                        return Err(format_err!("Missing host."));
+                    }
+                    pointer -= buffer.chars().count() as isize + 1;
+                    buffer.clear();
+                    state = State::Host;
+                } else {
+                    buffer.extend(c);
+                }
+            }
+            State::Host => {
+                if c == Some(':') && !inside_brackets {
+                    if buffer.is_empty() {
+                        return Err(format_err!("Missing host."));
+                    }
+                    url.host = Some(parse_host(&buffer, !url.is_special())?);
+                    buffer.clear();
+                    state = State::Port;
+                } else if is_delimiter(c, url.is_special()) {
+                    pointer -= 1;
+                    if url.is_special() && buffer.is_empty() {
+                        return Err(format_err!("Missing host."));
+                    }
+                    url.host = Some(parse_host(&buffer, !url.is_special())?);
+                    buffer.clear();
+                    state = State::PathStart;
+                } else {
+                    match c {
+                        Some('[') => inside_brackets = true,
+                        Some(']') => inside_brackets = false,
+                        _ => {}
+                    }
+                    buffer.extend(c);
+                }
+            }
+            State::Port => match c {
+                Some(c) if c.is_ascii_digit() => buffer.push(c),
+                Some(c) if !is_delimiter(Some(c), url.is_special()) => {
+                    return Err(format_err!("Invalid character '{}' in port.", c));
+                }
+                _ => {
+                    if !buffer.is_empty() {
+                        let port = match buffer.parse::<u16>() {
+                            Ok(port) => port,
+                            Err(_) => return Err(UriError::PortOutOfRange(buffer).into()),
+                        };
+                        url.port = match default_port(&url.scheme) == Some(port) {
+                            true => None,
+                            false => Some(port),
+                        };
+                        buffer.clear();
+                    }
+                    state = State::PathStart;
+                    pointer -= 1;
+                }
+            },
+            State::File => {
+                url.scheme = "file".to_string();
+                url.host = Some(UrlHost::Empty);
+                match (c, base) {
+                    (Some('/'), _) | (Some('\\'), _) => state = State::FileSlash,
+                    (_, Some(base)) if base.scheme == "file" => {
+                        url.host = base.host.clone();
+                        url.path = base.path.clone();
+                        url.query = base.query.clone();
+                        match c {
+                            Some('?') => {
+                                url.query = Some(String::new());
+                                state = State::Query;
+                            }
+                            Some('#') => {
+                                url.fragment = Some(String::new());
+                                state = State::Fragment;
+                            }
+                            Some(_) => {
+                                url.query = None;
+                                match starts_with_windows_drive_letter(&input[pointer as usize..]) {
+                                    true => url.path = UrlPath::List(Vec::new()),
+                                    false => url.shorten_path(),
+                                }
+                                state = State::Path;
+                                pointer -= 1;
+                            }
+                            None => {}
+                        }
+                    }
+                    _ => {
+                        state = State::Path;
+                        pointer -= 1;
+                    }
+                }
+            }
+            State::FileSlash => match c {
+                Some('/') | Some('\\') => state = State::FileHost,
+                _ => {
+                    if let Some(base) = base {
+                        if base.scheme == "file" {
+                            url.host = base.host.clone();
+                            if !starts_with_windows_drive_letter(&input[pointer as usize..]) {
+                                if let UrlPath::List(base_path) = &base.path {
+                                    match base_path.first() {
+                                        Some(first)
+                                            if is_normalized_windows_drive_letter(first) =>
+                                        {
+                                            url.path_list().push(first.clone())
+                                        }
+                                        _ => {}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    state = State::Path;
+                    pointer -= 1;
+                }
+            },
+            State::FileHost => match c {
+                None | Some('/') | Some('\\') | Some('?') | Some('#') => {
+                    pointer -= 1;
+                    if is_windows_drive_letter(&buffer) {
+                        // The buffer is not reset here and becomes the first path segment
+                        state = State::Path;
+                    } else if buffer.is_empty() {
+                        url.host = Some(UrlHost::Empty);
+                        state = State::PathStart;
+                    } else {
+                        let host = match parse_host(&buffer, false)? {
+                            UrlHost::Domain(ref domain) if domain == "localhost" => UrlHost::Empty,
+                            host => host,
+                        };
+                        url.host = Some(host);
+                        buffer.clear();
+                        state = State::PathStart;
+                    }
+                }
+                Some(c) => buffer.push(c),
+            },
+            State::PathStart => {
+                if url.is_special() {
+                    state = State::Path;
+                    if c != Some('/') && c != Some('\\') {
+                        pointer -= 1;
+                    }
+                } else if c == Some('?') {
+                    url.query = Some(String::new());
+                    state = State::Query;
+                } else if c == Some('#') {
+                    url.fragment = Some(String::new());
+                    state = State::Fragment;
+                } else if c.is_some() {
+                    state = State::Path;
+                    if c != Some('/') {
+                        pointer -= 1;
+                    }
+                }
+            }
+            State::Path => {
+                let slash = c == Some('/') || url.is_special() && c == Some('\\');
+                if c.is_none() || slash || c == Some('?') || c == Some('#') {
+                    if is_double_dot_segment(&buffer) {
+                        url.shorten_path();
+                        if !slash {
+                            url.path_list().push(String::new());
+                        }
+                    } else if is_single_dot_segment(&buffer) {
+                        if !slash {
+                            url.path_list().push(String::new());
+                        }
+                    } else {
+                        if url.scheme == "file"
+                            && url.path_list().is_empty()
+                            && is_windows_drive_letter(&buffer)
+                        {
+                            buffer.replace_range(1..2, ":");
+                        }
+                        url.path_list().push(buffer.clone());
+                    }
+                    buffer.clear();
+                    match c {
+                        Some('?') => {
+                            url.query = Some(String::new());
+                            state = State::Query;
+                        }
+                        Some('#') => {
+                            url.fragment = Some(String::new());
+                            state = State::Fragment;
+                        }
+                        _ => {}
+                    }
+                } else if let Some(c) = c {
+                    percent_encode(c, in_path_set, &mut buffer);
+                }
+            }
+            State::OpaquePath => match c {
+                Some('?') => {
+                    url.query = Some(String::new());
+                    state = State::Query;
+                }
+                Some('#') => {
+                    url.fragment = Some(String::new());
+                    state = State::Fragment;
+                }
+                Some(c) => {
+                    if let UrlPath::Opaque(path) = &mut url.path {
+                        percent_encode(c, in_c0_control_set, path);
+                    }
+                }
+                None => {}
+            },
+            State::Query => match c {
+                None | Some('#') => {
+                    let encode_set: fn(char) -> bool = match url.is_special() {
+                        true => in_special_query_set,
+                        false => in_query_set,
+                    };
+                    let query = url.query.get_or_insert_with(String::new);
+                    for c in buffer.chars() {
+                        percent_encode(c, encode_set, query);
+                    }
+                    buffer.clear();
+                    if c == Some('#') {
+                        url.fragment = Some(String::new());
+                        state = State::Fragment;
+                    }
+                }
+                Some(c) => buffer.push(c),
+            },
+            State::Fragment => {
+                if let Some(c) = c {
+                    let fragment = url.fragment.get_or_insert_with(String::new);
+                    percent_encode(c, in_fragment_set, fragment);
+                }
+            }
+        }
+
+        if pointer >= len {
+            break;
+        }
+        pointer += 1;
+    }
+    Ok(url)
+}
+
+fn is_windows_drive_letter(s: &str) -> bool {
+    let mut chars = s.chars();
+    match (chars.next(), chars.next(), chars.next()) {
+        (Some(letter), Some(':'), None) | (Some(letter), Some('|'), None) => {
+            letter.is_ascii_alphabetic()
+        }
+        _ => false,
+    }
+}
+
+fn is_normalized_windows_drive_letter(s: &str) -> bool {
+    is_windows_drive_letter(s) && s.ends_with(':')
+}
+
+fn starts_with_windows_drive_letter(input: &[char]) -> bool {
+    if input.len() < 2 {
+        return false;
+    }
+    let drive_letter: String = input[..2].iter().collect();
+    if !is_windows_drive_letter(&drive_letter) {
+        return false;
+    }
+    matches!(
+        input.get(2),
+        None | Some('/') | Some('\\') | Some('?') | Some('#')
+    )
+}
+
+fn is_single_dot_segment(s: &str) -> bool {
+    s == "." || s.eq_ignore_ascii_case("%2e")
+}
+
+fn is_double_dot_segment(s: &str) -> bool {
+    matches!(
+        s.to_ascii_lowercase().as_str(),
+        ".." | ".%2e" | "%2e." | "%2e%2e"
+    )
+}
+
+fn in_c0_control_set(c: char) -> bool {
+    !(' '..='~').contains(&c)
+}
+
+fn in_fragment_set(c: char) -> bool {
+    match c {
+        ' ' | '"' | '<' | '>' | '`' => true,
+        c => in_c0_control_set(c),
+    }
+}
+
+fn in_query_set(c: char) -> bool {
+    match c {
+        ' ' | '"' | '#' | '<' | '>' => true,
+        c => in_c0_control_set(c),
+    }
+}
+
+fn in_special_query_set(c: char) -> bool {
+    c == '\'' || in_query_set(c)
+}
+
+fn in_path_set(c: char) -> bool {
+    match c {
+        '?' | '`' | '{' | '}' => true,
+        c => in_query_set(c),
+    }
+}
+
+fn in_userinfo_set(c: char) -> bool {
+    match c {
+        '/' | ':' | ';' | '=' | '@' | '['..='^' | '|' => true,
+        c => in_path_set(c),
+    }
+}
+
+fn percent_encode(c: char, encode_set: fn(char) -> bool, output: &mut String) {
+    if !encode_set(c) {
+        output.push(c);
+        return;
+    }
+    let mut buf = [0; 4];
+    for b in c.encode_utf8(&mut buf).bytes() {
+        output.push_str(&format!("%{:02X}", b));
+    }
+}
+
+fn percent_decode(input: &str) -> Vec<u8> {
+    percent::decode(&to_chars(input))
+}
+
+fn is_forbidden_host_code_point(c: char) -> bool {
+    "\0\t\n\r #/:<>?@[\\]^|".contains(c)
+}
+
+fn is_forbidden_domain_code_point(c: char) -> bool {
+    is_forbidden_host_code_point(c) || c <= '\u{1F}' || c == '%' || c == '\u{7F}'
+}
+
+fn parse_host(input: &str, is_not_special: bool) -> Result<UrlHost> {
+    if input.starts_with('[') {
+        if !input.ends_with(']') || input.len() < 2 {
+            return Err(format_err!("Invalid IPv6 address '{}'.", input));
+        }
+        return Ok(UrlHost::Ipv6(parse_ipv6(&input[1..input.len() - 1])?));
+    }
+    if is_not_special {
+        if input.chars().any(is_forbidden_host_code_point) {
+            return Err(format_err!("Invalid host '{}'.", input));
+        }
+        let mut host = String::new();
+        for c in input.chars() {
+            percent_encode(c, in_c0_control_set, &mut host);
+        }
+        return Ok(UrlHost::Opaque(host));
+    }
+
+    let domain = String::from_utf8_lossy(&percent_decode(input)).into_owned();
+    let options = IdnaOptions {
+        use_std3_ascii_rules: false,
+        check_hyphens: false,
+        check_bidi: true,
+        check_joiners: true,
+        transitional_processing: false,
+        verify_dns_length: false,
+    };
+    let ascii_domain = idna::to_ascii(&domain, &options)?;
+    if ascii_domain.is_empty() || ascii_domain.chars().any(is_forbidden_domain_code_point) {
+        return Err(format_err!("Invalid host '{}'.", input));
+    }
+    if ends_in_a_number(&ascii_domain) {
+        return Ok(UrlHost::Ipv4(parse_ipv4(&ascii_domain)?));
+    }
+    Ok(UrlHost::Domain(ascii_domain))
+}
+
+fn ends_in_a_number(input: &str) -> bool {
+    let mut parts: Vec<&str> = input.split('.').collect();
+    if parts.len() > 1 && parts[parts.len() - 1].is_empty() {
+        parts.pop();
+    }
+    let last = parts[parts.len() - 1];
+    if !last.is_empty() && last.bytes().all(|b| b.is_ascii_digit()) {
+        return true;
+    }
+    parse_ipv4_number(last).is_some()
+}
+
+// Decimal, or hexadecimal with "0x", or octal with a leading "0"; saturates instead of overflowing.
+fn parse_ipv4_number(input: &str) -> Option<u64> {
+    if input.is_empty() {
+        return None;
+    }
+    let (digits, radix) =
+        if input.len() >= 2 && (input.starts_with("0x") || input.starts_with("0X")) {
+            (&input[2..], 16)
+        } else if input.len() >= 2 && input.starts_with('0') {
+            (&input[1..], 8)
+        } else {
+            (input, 10)
+        };
+
+    let mut value: u64 = 0;
+    for c in digits.chars() {
+        let digit = c.to_digit(radix)?;
+        value = value
+            .saturating_mul(u64::from(radix))
+            .saturating_add(u64::from(digit));
+    }
+    Some(value)
+}
+
+fn parse_ipv4(input: &str) -> Result<u32> {
+    let invalid = || format_err!("Invalid IPv4 address '{}'.", input);
+
+    let mut parts: Vec<&str> = input.split('.').collect();
+    if parts.len() > 1 && parts[parts.len() - 1].is_empty() {
+        parts.pop();
+    }
+    if parts.len() > 4 {
+        return Err(invalid());
+    }
+    let mut numbers: Vec<u64> = Vec::new();
+    for part in parts {
+        match parse_ipv4_number(part) {
+            Some(number) => numbers.push(number),
+            None => return Err(invalid()),
+        }
+    }
+
+    let last = numbers.pop().unwrap();
+    if numbers.iter().any(|&number| number > 255) {
+        return Err(invalid());
+    }
+    if last >= 256u64.pow(4 - numbers.len() as u32) {
+        return Err(invalid());
+    }
+    let mut ipv4 = last;
+    for (i, number) in numbers.iter().enumerate() {
+        ipv4 += number * 256u64.pow(3 - i as u32);
+    }
+    Ok(ipv4 as u32)
+}
+
+fn parse_ipv6(input: &str) -> Result<[u16; 8]> {
+    let invalid = || format_err!("Invalid IPv6 address '[{}]'.", input);
+
+    let input: Vec<char> = input.chars().collect();
+    let mut address = [0u16; 8];
+    let mut piece_index = 0;
+    let mut compress: Option<usize> = None;
+    let mut pointer = 0;
+    if input.first() == Some(&':') {
+        if input.get(1) != Some(&':') {
+            return Err(invalid());
+        }
+        pointer += 2;
+        piece_index += 1;
+        compress = Some(piece_index);
+    }
+
+    while pointer < input.len() {
+        if piece_index == 8 {
+            return Err(invalid());
+        }
+        if input[pointer] == ':' {
+            if compress.is_some() {
+                return Err(invalid());
+            }
+            pointer += 1;
+            piece_index += 1;
+            compress = Some(piece_index);
+            continue;
+        }
+
+        let mut value: u32 = 0;
+        let mut length = 0;
+        while length < 4 {
+            match input.get(pointer).and_then(|c| c.to_digit(16)) {
+                Some(digit) => {
+                    value = value * 0x10 + digit;
+                    pointer += 1;
+                    length += 1;
+                }
+                None => break,
+            }
+        }
+
+        match input.get(pointer) {
+            Some('.') => {
+                // An IPv4 address in the last two pieces
+                if length == 0 {
+                    return Err(invalid());
+                }
+                pointer -= length;
+                if piece_index > 6 {
+                    return Err(invalid());
+                }
+                let mut numbers_seen = 0;
+                while pointer < input.len() {
+                    if numbers_seen > 0 {
+                        if input[pointer] == '.' && numbers_seen < 4 {
+                            pointer += 1;
+                        } else {
+                            return Err(invalid());
+                        }
+                    }
+                    let mut ipv4_piece: Option<u32> = None;
+                    while let Some(number) = input.get(pointer).and_then(|c| c.to_digit(10)) {
+                        ipv4_piece = match ipv4_piece {
+                            None => Some(number),
+                            Some(0) => return Err(invalid()),
+                            Some(piece) => Some(piece * 10 + number),
+                        };
+                        if ipv4_piece > Some(255) {
+                            return Err(invalid());
+                        }
+                        pointer += 1;
+                    }
+                    match ipv4_piece {
+                        Some(piece) => {
+                            address[piece_index] = address[piece_index] * 0x100 + piece as u16
+                        }
+                        None => return Err(invalid()),
+                    }
+                    numbers_seen += 1;
+                    if numbers_seen == 2 || numbers_seen == 4 {
+                        piece_index += 1;
+                    }
+                }
+                if numbers_seen != 4 {
+                    return Err(invalid());
+                }
+                break;
+            }
+            Some(':') => {
+                pointer += 1;
+                if pointer == input.len() {
+                    return Err(invalid());
+                }
+            }
+            Some(_) => return Err(invalid()),
+            None => {}
+        }
+        address[piece_index] = value as u16;
+        piece_index += 1;
+    }
+
+    match compress {
+        Some(compress) => {
+            let mut swaps = piece_index - compress;
+            piece_index = 7;
+            while piece_index != 0 && swaps > 0 {
+                address.swap(piece_index, compress + swaps - 1);
+                piece_index -= 1;
+                swaps -= 1;
+            }
+        }
+        None if piece_index != 8 => return Err(invalid()),
+        None => {}
+    }
+    Ok(address)
+}
+
+// Lowercase hex pieces with the first longest run of two or more zero pieces compressed to "::".
+fn serialize_ipv6(address: &[u16; 8]) -> String {
+    let mut compress: Option<(usize, usize)> = None;
+    let mut i = 0;
+    while i < 8 {
+        if address[i] != 0 {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < 8 && address[i] == 0 {
+            i += 1;
+        }
+        let longest = match compress {
+            Some((_, len)) => i - start > len,
+            None => i - start > 1,
+        };
+        if longest {
+            compress = Some((start, i - start));
+        }
+    }
+
+    let mut output = String::new();
+    let mut i = 0;
+    while i < 8 {
+        if let Some((start, len)) = compress {
+            if i == start {
+                output.push_str(if i == 0 { "::" } else { ":" });
+                i += len;
+                continue;
+            }
+        }
+        output.push_str(&format!("{:x}", address[i]));
+        if i != 7 {
+            output.push(':');
+        }
+        i += 1;
+    }
+    output
+}